@@ -1,6 +1,6 @@
 use axum::{
     Json,
-    http::StatusCode,
+    http::{HeaderValue, StatusCode, header},
     response::{IntoResponse, Response},
 };
 use serde_json::json;
@@ -14,6 +14,8 @@ pub enum AppError {
     MissingAuthHeader,
     /// 認証ヘッダーの形式が不正
     InvalidAuthFormat,
+    /// `If-Match` ヘッダーの形式が不正
+    InvalidIfMatch,
 }
 
 impl IntoResponse for AppError {
@@ -28,6 +30,10 @@ impl IntoResponse for AppError {
                 StatusCode::UNAUTHORIZED,
                 "Invalid authorization format".to_string(),
             ),
+            AppError::InvalidIfMatch => (
+                StatusCode::BAD_REQUEST,
+                "Invalid If-Match header".to_string(),
+            ),
         };
 
         let body = Json(json!({
@@ -43,12 +49,28 @@ impl IntoResponse for AppError {
 
 impl AppError {
     fn map_usecase_error(error: UseCaseError) -> Response {
+        // 423 / 429 / 503 では再試行できるまでの秒数を Retry-After で通知する
+        let retry_after = match &error {
+            UseCaseError::Locked { retry_after, .. }
+            | UseCaseError::TooManyRequests { retry_after, .. }
+            | UseCaseError::Unavailable { retry_after, .. } => *retry_after,
+            _ => None,
+        };
         let (status, message) = match &error {
             UseCaseError::InvalidInput(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
+            UseCaseError::Validation { message, .. } => (StatusCode::BAD_REQUEST, message.clone()),
             UseCaseError::Authentication(msg) => (StatusCode::UNAUTHORIZED, msg.clone()),
             UseCaseError::Forbidden(msg) => (StatusCode::FORBIDDEN, msg.clone()),
             UseCaseError::NotFound(msg) => (StatusCode::NOT_FOUND, msg.clone()),
             UseCaseError::Conflict(msg) => (StatusCode::CONFLICT, msg.clone()),
+            UseCaseError::PreconditionFailed(msg) => (StatusCode::PRECONDITION_FAILED, msg.clone()),
+            UseCaseError::Locked { message, .. } => (StatusCode::LOCKED, message.clone()),
+            UseCaseError::TooManyRequests { message, .. } => {
+                (StatusCode::TOO_MANY_REQUESTS, message.clone())
+            }
+            UseCaseError::Unavailable { message, .. } => {
+                (StatusCode::SERVICE_UNAVAILABLE, message.clone())
+            }
             UseCaseError::Internal(err) => {
                 tracing::error!(error = ?err, "Internal server error occurred");
                 (
@@ -58,14 +80,27 @@ impl AppError {
             }
         };
 
-        let body = Json(json!({
+        let mut body = json!({
             "error": {
                 "message": message,
                 "type": status.canonical_reason().unwrap_or("Unknown"),
             }
-        }));
+        });
+        // 違反した規則はまとめて返し、クライアントが一度に表示できるようにする
+        if let UseCaseError::Validation { violations, .. } = &error {
+            body["error"]["violations"] = violations
+                .iter()
+                .map(|v| json!({ "code": v.code, "message": v.message }))
+                .collect();
+        }
 
-        (status, body).into_response()
+        let mut response = (status, Json(body)).into_response();
+        if let Some(seconds) = retry_after {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(seconds));
+        }
+        response
     }
 }
 
@@ -0,0 +1,52 @@
+pub mod response;
+
+use self::response::AdminUserResponse;
+use crate::AppState;
+use crate::error::AppError;
+use crate::middleware::auth::{RequirePermission, permissions::UsersRead};
+use crate::middleware::precondition::ETag;
+use axum::{
+    Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+use usecase::user::UserId;
+use usecase::user::admin::GetUserQuery;
+use uuid::Uuid;
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/api/v1/admin/users/{user_id}",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "User retrieved", body = AdminUserResponse,
+            headers(("ETag" = String, description = "Current version of the user, for use in If-Match"))),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "User not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+))]
+pub async fn get_user(
+    State(state): State<Arc<AppState>>,
+    RequirePermission(claims, _): RequirePermission<UsersRead>,
+    Path(user_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state
+        .user_admin
+        .get_user(GetUserQuery {
+            admin_id: claims.sub,
+            user_id: UserId::from(user_id),
+        })
+        .await?;
+    Ok((
+        ETag(user.version.into()),
+        Json(AdminUserResponse::from(user)),
+    ))
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use usecase::user::admin::dto::AdminUserDto;
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AdminUserResponse {
+    /// ユーザーID
+    pub user_id: String,
+    /// メールアドレス
+    pub email: String,
+    /// メールアドレスが確認済みかどうか
+    pub email_verified: bool,
+    /// アカウントの状態（`active` / `suspended` / `locked` / `deleted`）
+    pub status: String,
+    /// 付与されたロール
+    pub roles: Vec<String>,
+}
+
+impl From<AdminUserDto> for AdminUserResponse {
+    fn from(dto: AdminUserDto) -> Self {
+        Self {
+            user_id: dto.user_id.to_string(),
+            email: dto.email.as_ref().to_string(),
+            email_verified: dto.email_verified,
+            status: dto.status.as_str().to_string(),
+            roles: dto.roles.iter().map(|r| r.as_str().to_string()).collect(),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+pub mod request;
+pub mod response;
+
+use self::request::ListUsersParams;
+use self::response::UserPageResponse;
+use crate::AppState;
+use crate::error::AppError;
+use crate::middleware::auth::{RequirePermission, permissions::UsersRead};
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use std::sync::Arc;
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/api/v1/admin/users",
+    params(ListUsersParams),
+    responses(
+        (status = 200, description = "A page of users", body = UserPageResponse),
+        (status = 400, description = "Invalid filter or page size"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+))]
+pub async fn list_users(
+    State(state): State<Arc<AppState>>,
+    RequirePermission(claims, _): RequirePermission<UsersRead>,
+    Query(params): Query<ListUsersParams>,
+) -> Result<Json<UserPageResponse>, AppError> {
+    let page = state
+        .user_admin
+        .list_users(params.into_query(claims))
+        .await?;
+    Ok(Json(UserPageResponse::from(page)))
+}
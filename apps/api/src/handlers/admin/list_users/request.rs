@@ -0,0 +1,32 @@
+use serde::Deserialize;
+use usecase::auth::Claims;
+use usecase::user::admin::ListUsersQuery;
+use usecase::user::{AccountStatus, UserId};
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "openapi", into_params(parameter_in = Query))]
+pub struct ListUsersParams {
+    /// メールアドレスの前方一致
+    pub email_prefix: Option<String>,
+    /// アカウントの状態（`active` / `suspended` / `locked` / `deleted`）
+    #[cfg_attr(feature = "openapi", param(value_type = Option<String>))]
+    pub status: Option<AccountStatus>,
+    /// 前のページの `next_cursor`
+    pub cursor: Option<Uuid>,
+    /// 1 ページの件数（1〜100、既定 50）
+    pub limit: Option<u32>,
+}
+
+impl ListUsersParams {
+    pub fn into_query(self, claims: Claims) -> ListUsersQuery {
+        ListUsersQuery {
+            admin_id: claims.sub,
+            email_prefix: self.email_prefix,
+            status: self.status,
+            cursor: self.cursor.map(UserId::from),
+            limit: self.limit,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::handlers::admin::get_user::response::AdminUserResponse;
+use serde::{Deserialize, Serialize};
+use usecase::user::admin::dto::UserPageDto;
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UserPageResponse {
+    /// 登録順（ID の昇順）のユーザー
+    pub users: Vec<AdminUserResponse>,
+    /// 次のページを取得するためのカーソル。最後のページの場合は `null`
+    pub next_cursor: Option<String>,
+}
+
+impl From<UserPageDto> for UserPageResponse {
+    fn from(dto: UserPageDto) -> Self {
+        Self {
+            users: dto.users.into_iter().map(AdminUserResponse::from).collect(),
+            next_cursor: dto.next_cursor.map(|id| id.to_string()),
+        }
+    }
+}
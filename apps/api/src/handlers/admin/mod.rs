@@ -0,0 +1,6 @@
+pub mod delete_user;
+pub mod force_password_reset;
+pub mod get_user;
+pub mod list_users;
+pub mod reactivate_user;
+pub mod suspend_user;
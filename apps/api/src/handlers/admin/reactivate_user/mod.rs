@@ -0,0 +1,49 @@
+use crate::AppState;
+use crate::error::AppError;
+use crate::middleware::auth::{RequirePermission, permissions::UsersWrite};
+use crate::middleware::precondition::IfMatch;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use std::sync::Arc;
+use usecase::user::UserId;
+use usecase::user::admin::ReactivateUserCommand;
+use uuid::Uuid;
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/api/v1/admin/users/{user_id}/reactivate",
+    params(
+        ("user_id" = Uuid, Path, description = "User ID"),
+        ("If-Match" = Option<String>, Header, description = "Expected user version (ETag of GET /api/v1/admin/users/{user_id})")
+    ),
+    responses(
+        (status = 204, description = "User reactivated"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "User not found"),
+        (status = 409, description = "User is neither suspended nor locked"),
+        (status = 412, description = "User has been modified since the given version")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+))]
+pub async fn reactivate_user(
+    State(state): State<Arc<AppState>>,
+    RequirePermission(claims, _): RequirePermission<UsersWrite>,
+    Path(user_id): Path<Uuid>,
+    IfMatch(expected_version): IfMatch,
+) -> Result<StatusCode, AppError> {
+    state
+        .user_admin
+        .reactivate_user(ReactivateUserCommand {
+            admin_id: claims.sub,
+            user_id: UserId::from(user_id),
+            expected_version: expected_version.map(Into::into),
+        })
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
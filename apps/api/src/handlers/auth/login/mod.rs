@@ -2,11 +2,18 @@ pub mod request;
 pub mod response;
 
 use self::request::LoginRequest;
-use self::response::LoginResponse;
+use self::response::{LoginResponse, MfaChallengeResponse};
 use crate::AppState;
 use crate::error::AppError;
-use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use crate::middleware::client_ip::ClientIp;
+use axum::{
+    Json,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
 use std::sync::Arc;
+use usecase::auth::login::dto::LoginOutcomeDto;
 
 #[cfg_attr(feature = "openapi", utoipa::path(
     post,
@@ -14,14 +21,27 @@ use std::sync::Arc;
     request_body = LoginRequest,
     responses(
         (status = 200, description = "Login successful", body = LoginResponse),
-        (status = 401, description = "Invalid credentials")
+        (status = 202, description = "Password accepted; MFA code required", body = MfaChallengeResponse),
+        (status = 401, description = "Invalid credentials or deleted account"),
+        (status = 403, description = "Account is suspended, an administrator required a password reset, or email address has not been verified (when verification is required)"),
+        (status = 423, description = "Account is locked after repeated failures; see Retry-After"),
+        (status = 429, description = "Too many failed attempts from this client; see Retry-After"),
+        (status = 503, description = "Too many concurrent password verifications; see Retry-After")
     ),
     tag = "auth"
 ))]
 pub async fn login(
     State(state): State<Arc<AppState>>,
+    ClientIp(client_ip): ClientIp,
     Json(req): Json<LoginRequest>,
-) -> Result<impl IntoResponse, AppError> {
-    let response_dto = state.auth_query.login(req.into()).await?;
-    Ok((StatusCode::OK, Json(LoginResponse::from(response_dto))))
+) -> Result<Response, AppError> {
+    let response = match state.auth_query.login(req.into_query(client_ip)).await? {
+        LoginOutcomeDto::Authenticated(dto) => {
+            (StatusCode::OK, Json(LoginResponse::from(dto))).into_response()
+        }
+        LoginOutcomeDto::MfaRequired(dto) => {
+            (StatusCode::ACCEPTED, Json(MfaChallengeResponse::from(dto))).into_response()
+        }
+    };
+    Ok(response)
 }
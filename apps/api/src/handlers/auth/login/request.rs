@@ -1,5 +1,6 @@
 use sensitive_data::{EmailRule, SecretRule, Sensitive};
 use serde::Deserialize;
+use std::net::IpAddr;
 use usecase::auth::login::query::LoginQuery;
 
 #[derive(Debug, Deserialize)]
@@ -13,11 +14,13 @@ pub struct LoginRequest {
     pub password: Sensitive<String, SecretRule>,
 }
 
-impl From<LoginRequest> for LoginQuery {
-    fn from(req: LoginRequest) -> Self {
-        Self {
-            email: req.email,
-            password: req.password,
+impl LoginRequest {
+    /// 接続元の IP アドレスと合わせてクエリを組み立てる。
+    pub fn into_query(self, client_ip: Option<IpAddr>) -> LoginQuery {
+        LoginQuery {
+            email: self.email,
+            password: self.password,
+            client_ip,
         }
     }
 }
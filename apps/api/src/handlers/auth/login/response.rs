@@ -1,6 +1,7 @@
+use chrono::{DateTime, Utc};
 use sensitive_data::{EmailRule, SecretRule, Sensitive};
 use serde::{Deserialize, Serialize};
-use usecase::auth::login::dto::LoginResponseDto;
+use usecase::auth::login::dto::{LoginResponseDto, MfaChallengeDto};
 use uuid::Uuid;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -14,6 +15,9 @@ pub struct LoginResponse {
     /// アクセストークン (JWT)
     #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub token: Sensitive<String, SecretRule>,
+    /// リフレッシュトークン（不透明トークン）
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub refresh_token: Sensitive<String, SecretRule>,
 }
 
 impl From<LoginResponseDto> for LoginResponse {
@@ -22,6 +26,27 @@ impl From<LoginResponseDto> for LoginResponse {
             id: dto.id,
             email: dto.email.into(),
             token: dto.token.expose_as_str().to_string().into(),
+            refresh_token: dto.refresh_token.expose_as_str().to_string().into(),
+        }
+    }
+}
+
+/// MFA が有効なアカウントに対するログイン応答。
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MfaChallengeResponse {
+    /// `/api/v1/auth/login/mfa` に提示するチャレンジトークン
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub mfa_token: Sensitive<String, SecretRule>,
+    /// チャレンジの有効期限
+    pub expires_at: DateTime<Utc>,
+}
+
+impl From<MfaChallengeDto> for MfaChallengeResponse {
+    fn from(dto: MfaChallengeDto) -> Self {
+        Self {
+            mfa_token: dto.mfa_token.expose_as_str().to_string().into(),
+            expires_at: dto.expires_at,
         }
     }
 }
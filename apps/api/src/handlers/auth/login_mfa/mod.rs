@@ -0,0 +1,29 @@
+pub mod request;
+
+use self::request::MfaLoginRequest;
+use crate::AppState;
+use crate::error::AppError;
+use crate::handlers::auth::login::response::LoginResponse;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use std::sync::Arc;
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/api/v1/auth/login/mfa",
+    request_body = MfaLoginRequest,
+    responses(
+        (status = 200, description = "MFA verified; login successful", body = LoginResponse),
+        (status = 400, description = "Malformed code"),
+        (status = 401, description = "Invalid code, invalid/expired challenge or deleted account"),
+        (status = 403, description = "Account is suspended or an administrator required a password reset"),
+        (status = 423, description = "Account is locked")
+    ),
+    tag = "auth"
+))]
+pub async fn login_mfa(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<MfaLoginRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let response_dto = state.mfa_login.verify(req.into()).await?;
+    Ok((StatusCode::OK, Json(LoginResponse::from(response_dto))))
+}
@@ -0,0 +1,23 @@
+use sensitive_data::{SecretRule, Sensitive};
+use serde::Deserialize;
+use usecase::auth::mfa_login::query::MfaLoginQuery;
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MfaLoginRequest {
+    /// ログイン時に返却されたチャレンジトークン
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub mfa_token: Sensitive<String, SecretRule>,
+    /// 認証アプリに表示された 6 桁のコード、またはリカバリーコード
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub code: Sensitive<String, SecretRule>,
+}
+
+impl From<MfaLoginRequest> for MfaLoginQuery {
+    fn from(req: MfaLoginRequest) -> Self {
+        Self {
+            mfa_token: req.mfa_token,
+            code: req.code,
+        }
+    }
+}
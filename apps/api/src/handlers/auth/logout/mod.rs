@@ -0,0 +1,33 @@
+pub mod request;
+
+use self::request::LogoutRequest;
+use crate::AppState;
+use crate::error::AppError;
+use crate::middleware::auth::AuthenticatedUser;
+use axum::{Json, extract::State, http::StatusCode};
+use std::sync::Arc;
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/api/v1/auth/logout",
+    request_body(content = Option<LogoutRequest>),
+    responses(
+        (status = 204, description = "Logged out; the access token (and refresh token family, if given) is revoked"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "auth"
+))]
+pub async fn logout(
+    State(state): State<Arc<AppState>>,
+    AuthenticatedUser(claims): AuthenticatedUser,
+    req: Option<Json<LogoutRequest>>,
+) -> Result<StatusCode, AppError> {
+    let req = req.map(|Json(req)| req).unwrap_or(LogoutRequest {
+        refresh_token: None,
+    });
+    state.logout.logout(req.into_command(claims)).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,24 @@
+use sensitive_data::{SecretRule, Sensitive};
+use serde::Deserialize;
+use usecase::auth::Claims;
+use usecase::auth::logout::command::LogoutCommand;
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct LogoutRequest {
+    /// 併せて失効させるリフレッシュトークン（任意）
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
+    pub refresh_token: Option<Sensitive<String, SecretRule>>,
+}
+
+impl LogoutRequest {
+    /// 認証済みトークンのクレームと合わせてコマンドを組み立てる。
+    pub fn into_command(self, claims: Claims) -> LogoutCommand {
+        LogoutCommand {
+            user_id: claims.sub,
+            jti: claims.jti,
+            exp: claims.exp,
+            refresh_token: self.refresh_token,
+        }
+    }
+}
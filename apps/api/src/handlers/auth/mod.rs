@@ -1,2 +1,9 @@
 pub mod login;
+pub mod login_mfa;
+pub mod logout;
+pub mod password_reset_confirm;
+pub mod password_reset_request;
+pub mod refresh;
 pub mod signup;
+pub mod verify_email;
+pub mod verify_email_resend;
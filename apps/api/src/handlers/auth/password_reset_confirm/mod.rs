@@ -0,0 +1,25 @@
+pub mod request;
+
+use self::request::PasswordResetConfirmRequest;
+use crate::AppState;
+use crate::error::AppError;
+use axum::{Json, extract::State, http::StatusCode};
+use std::sync::Arc;
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/api/v1/auth/password-reset/confirm",
+    request_body = PasswordResetConfirmRequest,
+    responses(
+        (status = 204, description = "Password has been reset"),
+        (status = 400, description = "Invalid or expired token, or the password violates the policy (listed in `error.violations`)")
+    ),
+    tag = "auth"
+))]
+pub async fn password_reset_confirm(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<PasswordResetConfirmRequest>,
+) -> Result<StatusCode, AppError> {
+    state.password_reset.reset_password(req.into()).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
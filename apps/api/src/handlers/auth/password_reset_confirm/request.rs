@@ -0,0 +1,23 @@
+use sensitive_data::{SecretRule, Sensitive};
+use serde::Deserialize;
+use usecase::auth::password_reset::command::ResetPasswordCommand;
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PasswordResetConfirmRequest {
+    /// メールで送付されたリセットトークン
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub token: Sensitive<String, SecretRule>,
+    /// 新しいパスワード
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub new_password: Sensitive<String, SecretRule>,
+}
+
+impl From<PasswordResetConfirmRequest> for ResetPasswordCommand {
+    fn from(req: PasswordResetConfirmRequest) -> Self {
+        Self {
+            token: req.token,
+            new_password: req.new_password,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+pub mod request;
+
+use self::request::PasswordResetRequest;
+use crate::AppState;
+use crate::error::AppError;
+use axum::{Json, extract::State, http::StatusCode};
+use std::sync::Arc;
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/api/v1/auth/password-reset",
+    request_body = PasswordResetRequest,
+    responses(
+        (status = 202, description = "Accepted; a reset link is mailed if the account exists"),
+        (status = 400, description = "Invalid input")
+    ),
+    tag = "auth"
+))]
+pub async fn password_reset_request(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<PasswordResetRequest>,
+) -> Result<StatusCode, AppError> {
+    // アカウントの有無によらず同じ応答を返す
+    state
+        .password_reset
+        .request_password_reset(req.into())
+        .await?;
+    Ok(StatusCode::ACCEPTED)
+}
@@ -0,0 +1,17 @@
+use sensitive_data::{EmailRule, Sensitive};
+use serde::Deserialize;
+use usecase::auth::password_reset::command::RequestPasswordResetCommand;
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PasswordResetRequest {
+    /// パスワードを再設定するアカウントのメールアドレス
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub email: Sensitive<String, EmailRule>,
+}
+
+impl From<PasswordResetRequest> for RequestPasswordResetCommand {
+    fn from(req: PasswordResetRequest) -> Self {
+        Self { email: req.email }
+    }
+}
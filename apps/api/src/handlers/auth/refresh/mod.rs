@@ -0,0 +1,29 @@
+pub mod request;
+pub mod response;
+
+use self::request::RefreshRequest;
+use self::response::RefreshResponse;
+use crate::AppState;
+use crate::error::AppError;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use std::sync::Arc;
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/api/v1/auth/refresh",
+    request_body = RefreshRequest,
+    responses(
+        (status = 200, description = "Tokens rotated successfully", body = RefreshResponse),
+        (status = 401, description = "Invalid, expired, revoked or reused refresh token, or deleted account"),
+        (status = 403, description = "Account is suspended"),
+        (status = 423, description = "Account is locked")
+    ),
+    tag = "auth"
+))]
+pub async fn refresh(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<RefreshRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let response_dto = state.token_refresh.refresh(req.into()).await?;
+    Ok((StatusCode::OK, Json(RefreshResponse::from(response_dto))))
+}
@@ -0,0 +1,19 @@
+use sensitive_data::{SecretRule, Sensitive};
+use serde::Deserialize;
+use usecase::auth::refresh::command::RefreshTokenCommand;
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RefreshRequest {
+    /// ログインまたは前回のリフレッシュで受け取ったリフレッシュトークン
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub refresh_token: Sensitive<String, SecretRule>,
+}
+
+impl From<RefreshRequest> for RefreshTokenCommand {
+    fn from(req: RefreshRequest) -> Self {
+        Self {
+            refresh_token: req.refresh_token,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use sensitive_data::{SecretRule, Sensitive};
+use serde::{Deserialize, Serialize};
+use usecase::auth::refresh::dto::RefreshResponseDto;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RefreshResponse {
+    /// 新しいアクセストークン (JWT)
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub token: Sensitive<String, SecretRule>,
+    /// 新しいリフレッシュトークン。提示したトークンは使用済みとなる
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub refresh_token: Sensitive<String, SecretRule>,
+}
+
+impl From<RefreshResponseDto> for RefreshResponse {
+    fn from(dto: RefreshResponseDto) -> Self {
+        Self {
+            token: dto.token.expose_as_str().to_string().into(),
+            refresh_token: dto.refresh_token.expose_as_str().to_string().into(),
+        }
+    }
+}
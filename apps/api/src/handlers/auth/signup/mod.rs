@@ -5,6 +5,7 @@ use crate::AppState;
 use crate::error::AppError;
 use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
 use std::sync::Arc;
+use usecase::auth::signup::dto::SignupOutcomeDto;
 
 #[cfg_attr(feature = "openapi", utoipa::path(
     post,
@@ -12,8 +13,10 @@ use std::sync::Arc;
     request_body = SignupRequest,
     responses(
         (status = 201, description = "User registered successfully"),
-        (status = 400, description = "Invalid input"),
-        (status = 409, description = "User already exists")
+        (status = 202, description = "Signup accepted; the outcome is not disclosed (when enumeration protection is enabled, also for existing accounts)"),
+        (status = 400, description = "Invalid input; password policy violations are listed together in `error.violations`"),
+        (status = 409, description = "User already exists (only when enumeration protection is disabled)"),
+        (status = 503, description = "Too many concurrent password hashings; see Retry-After")
     ),
     tag = "auth"
 ))]
@@ -21,6 +24,8 @@ pub async fn signup(
     State(state): State<Arc<AppState>>,
     Json(req): Json<SignupRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    state.auth_command.signup(req.into()).await?;
-    Ok(StatusCode::CREATED)
+    match state.auth_command.signup(req.into()).await? {
+        SignupOutcomeDto::Registered(_) => Ok(StatusCode::CREATED),
+        SignupOutcomeDto::Accepted => Ok(StatusCode::ACCEPTED),
+    }
 }
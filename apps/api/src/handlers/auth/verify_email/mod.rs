@@ -0,0 +1,25 @@
+pub mod request;
+
+use self::request::VerifyEmailRequest;
+use crate::AppState;
+use crate::error::AppError;
+use axum::{Json, extract::State, http::StatusCode};
+use std::sync::Arc;
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/api/v1/auth/verify-email",
+    request_body = VerifyEmailRequest,
+    responses(
+        (status = 204, description = "Email address verified"),
+        (status = 400, description = "Invalid or expired token")
+    ),
+    tag = "auth"
+))]
+pub async fn verify_email(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<VerifyEmailRequest>,
+) -> Result<StatusCode, AppError> {
+    state.email_verification.verify_email(req.into()).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
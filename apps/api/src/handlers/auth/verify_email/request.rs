@@ -0,0 +1,17 @@
+use sensitive_data::{SecretRule, Sensitive};
+use serde::Deserialize;
+use usecase::auth::email_verification::command::VerifyEmailCommand;
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct VerifyEmailRequest {
+    /// 確認メールで送付されたトークン
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub token: Sensitive<String, SecretRule>,
+}
+
+impl From<VerifyEmailRequest> for VerifyEmailCommand {
+    fn from(req: VerifyEmailRequest) -> Self {
+        Self { token: req.token }
+    }
+}
@@ -0,0 +1,29 @@
+pub mod request;
+
+use self::request::ResendVerificationEmailRequest;
+use crate::AppState;
+use crate::error::AppError;
+use axum::{Json, extract::State, http::StatusCode};
+use std::sync::Arc;
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/api/v1/auth/verify-email/resend",
+    request_body = ResendVerificationEmailRequest,
+    responses(
+        (status = 202, description = "Accepted; a verification mail is sent if the account is unverified and not in cooldown"),
+        (status = 400, description = "Invalid input")
+    ),
+    tag = "auth"
+))]
+pub async fn verify_email_resend(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<ResendVerificationEmailRequest>,
+) -> Result<StatusCode, AppError> {
+    // アカウントの有無や状態によらず同じ応答を返す
+    state
+        .email_verification
+        .resend_verification_email(req.into())
+        .await?;
+    Ok(StatusCode::ACCEPTED)
+}
@@ -0,0 +1,17 @@
+use sensitive_data::{EmailRule, Sensitive};
+use serde::Deserialize;
+use usecase::auth::email_verification::command::ResendVerificationEmailCommand;
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ResendVerificationEmailRequest {
+    /// 確認メールを再送するアカウントのメールアドレス
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub email: Sensitive<String, EmailRule>,
+}
+
+impl From<ResendVerificationEmailRequest> for ResendVerificationEmailCommand {
+    fn from(req: ResendVerificationEmailRequest) -> Self {
+        Self { email: req.email }
+    }
+}
@@ -1,2 +1,4 @@
+pub mod admin;
 pub mod auth;
 pub mod users;
+pub mod well_known;
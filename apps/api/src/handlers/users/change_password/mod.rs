@@ -0,0 +1,44 @@
+pub mod request;
+
+use self::request::ChangePasswordRequest;
+use crate::AppState;
+use crate::error::AppError;
+use crate::handlers::auth::login::response::LoginResponse;
+use crate::middleware::auth::{RequirePermission, permissions::ProfileWrite};
+use crate::middleware::precondition::IfMatch;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use std::sync::Arc;
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    put,
+    path = "/api/v1/users/me/password",
+    request_body = ChangePasswordRequest,
+    params(
+        ("If-Match" = Option<String>, Header, description = "Expected user version (ETag of GET /api/v1/users/me)")
+    ),
+    responses(
+        (status = 200, description = "Password changed; other sessions are revoked and a new session replaces the current one", body = LoginResponse),
+        (status = 400, description = "The new password is breached or violates the policy (listed in `error.violations`)"),
+        (status = 401, description = "Unauthorized or the current password is incorrect"),
+        (status = 403, description = "Forbidden"),
+        (status = 412, description = "User has been modified since the given version"),
+        (status = 423, description = "Account is locked after repeated failures (shared with login); see Retry-After"),
+        (status = 503, description = "Too many concurrent password hashings; see Retry-After")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "users"
+))]
+pub async fn change_password(
+    State(state): State<Arc<AppState>>,
+    RequirePermission(claims, _): RequirePermission<ProfileWrite>,
+    IfMatch(expected_version): IfMatch,
+    Json(req): Json<ChangePasswordRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let response_dto = state
+        .password_change
+        .change_password(req.into_command(claims, expected_version))
+        .await?;
+    Ok((StatusCode::OK, Json(LoginResponse::from(response_dto))))
+}
@@ -0,0 +1,33 @@
+use sensitive_data::{SecretRule, Sensitive};
+use serde::Deserialize;
+use usecase::auth::Claims;
+use usecase::auth::password_change::command::ChangePasswordCommand;
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChangePasswordRequest {
+    /// 現在のパスワード
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub current_password: Sensitive<String, SecretRule>,
+    /// 新しいパスワード
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub new_password: Sensitive<String, SecretRule>,
+}
+
+impl ChangePasswordRequest {
+    /// 認証済みトークンのクレームと `If-Match` の値と合わせてコマンドを組み立てる。
+    pub fn into_command(
+        self,
+        claims: Claims,
+        expected_version: Option<i32>,
+    ) -> ChangePasswordCommand {
+        ChangePasswordCommand {
+            user_id: claims.sub,
+            jti: claims.jti,
+            exp: claims.exp,
+            current_password: self.current_password,
+            new_password: self.new_password,
+            expected_version: expected_version.map(Into::into),
+        }
+    }
+}
@@ -1,23 +1,40 @@
 pub mod response;
 
 use self::response::MeResponse;
-use crate::middleware::auth::AuthenticatedUser;
-use axum::Json;
+use crate::AppState;
+use crate::error::AppError;
+use crate::middleware::auth::{RequirePermission, permissions::ProfileRead};
+use crate::middleware::precondition::ETag;
+use axum::{Json, extract::State, response::IntoResponse};
+use std::sync::Arc;
+use usecase::user::profile::UserProfileQuery;
 
 #[cfg_attr(feature = "openapi", utoipa::path(
     get,
     path = "/api/v1/users/me",
     responses(
-        (status = 200, description = "User profile retrieved", body = MeResponse),
-        (status = 401, description = "Unauthorized")
+        (status = 200, description = "User profile retrieved", body = MeResponse,
+            headers(("ETag" = String, description = "Current version of the user, for use in If-Match"))),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden")
     ),
     security(
         ("bearer_auth" = [])
     ),
     tag = "users"
 ))]
-pub async fn me(AuthenticatedUser(claims): AuthenticatedUser) -> Json<MeResponse> {
-    Json(MeResponse {
-        user_id: claims.sub.to_string(),
-    })
+pub async fn me(
+    State(state): State<Arc<AppState>>,
+    RequirePermission(claims, _): RequirePermission<ProfileRead>,
+) -> Result<impl IntoResponse, AppError> {
+    let profile = state
+        .user_profile
+        .get_profile(UserProfileQuery {
+            user_id: claims.sub,
+        })
+        .await?;
+    Ok((
+        ETag(profile.version.into()),
+        Json(MeResponse::from(profile)),
+    ))
 }
@@ -1,8 +1,23 @@
 use serde::{Deserialize, Serialize};
+use usecase::user::profile::dto::UserProfileDto;
 
 #[derive(Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct MeResponse {
     /// ユーザーID
     pub user_id: String,
+    /// メールアドレス
+    pub email: String,
+    /// メールアドレスが確認済みかどうか
+    pub email_verified: bool,
+}
+
+impl From<UserProfileDto> for MeResponse {
+    fn from(dto: UserProfileDto) -> Self {
+        Self {
+            user_id: dto.user_id.to_string(),
+            email: dto.email.as_ref().to_string(),
+            email_verified: dto.email_verified,
+        }
+    }
 }
@@ -1 +1,6 @@
+pub mod change_password;
 pub mod me;
+pub mod recovery_codes_regenerate;
+pub mod recovery_codes_status;
+pub mod totp_confirm;
+pub mod totp_enroll;
@@ -0,0 +1,46 @@
+pub mod response;
+
+use self::response::RecoveryCodesResponse;
+use crate::AppState;
+use crate::error::AppError;
+use crate::middleware::auth::{RequirePermission, permissions::ProfileWrite};
+use crate::middleware::precondition::IfMatch;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use std::sync::Arc;
+use usecase::auth::mfa_enrollment::command::RegenerateRecoveryCodesCommand;
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/api/v1/users/me/mfa/recovery-codes",
+    params(
+        ("If-Match" = Option<String>, Header, description = "Expected user version (ETag of GET /api/v1/users/me)")
+    ),
+    responses(
+        (status = 200, description = "Recovery codes regenerated; previous codes are invalidated", body = RecoveryCodesResponse),
+        (status = 400, description = "MFA is not enabled"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 412, description = "User has been modified since the given version")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "users"
+))]
+pub async fn recovery_codes_regenerate(
+    State(state): State<Arc<AppState>>,
+    RequirePermission(claims, _): RequirePermission<ProfileWrite>,
+    IfMatch(expected_version): IfMatch,
+) -> Result<impl IntoResponse, AppError> {
+    let response_dto = state
+        .mfa_enrollment
+        .regenerate_recovery_codes(RegenerateRecoveryCodesCommand {
+            user_id: claims.sub,
+            expected_version: expected_version.map(Into::into),
+        })
+        .await?;
+    Ok((
+        StatusCode::OK,
+        Json(RecoveryCodesResponse::from(response_dto)),
+    ))
+}
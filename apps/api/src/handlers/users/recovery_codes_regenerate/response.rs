@@ -0,0 +1,23 @@
+use sensitive_data::{SecretRule, Sensitive};
+use serde::{Deserialize, Serialize};
+use usecase::auth::mfa_enrollment::dto::RecoveryCodesDto;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RecoveryCodesResponse {
+    /// リカバリーコード（`XXXXX-XXXXX` 形式）。平文を返すのはこの一度のみ
+    #[cfg_attr(feature = "openapi", schema(value_type = Vec<String>))]
+    pub recovery_codes: Vec<Sensitive<String, SecretRule>>,
+}
+
+impl From<RecoveryCodesDto> for RecoveryCodesResponse {
+    fn from(dto: RecoveryCodesDto) -> Self {
+        Self {
+            recovery_codes: dto
+                .codes
+                .iter()
+                .map(|code| code.expose_formatted().into())
+                .collect(),
+        }
+    }
+}
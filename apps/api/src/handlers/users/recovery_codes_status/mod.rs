@@ -0,0 +1,35 @@
+pub mod response;
+
+use self::response::RecoveryCodeStatusResponse;
+use crate::AppState;
+use crate::error::AppError;
+use crate::middleware::auth::{RequirePermission, permissions::ProfileRead};
+use axum::{Json, extract::State};
+use std::sync::Arc;
+use usecase::auth::mfa_enrollment::query::RecoveryCodeStatusQuery;
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/api/v1/users/me/mfa/recovery-codes",
+    responses(
+        (status = 200, description = "Number of unused recovery codes", body = RecoveryCodeStatusResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "users"
+))]
+pub async fn recovery_codes_status(
+    State(state): State<Arc<AppState>>,
+    RequirePermission(claims, _): RequirePermission<ProfileRead>,
+) -> Result<Json<RecoveryCodeStatusResponse>, AppError> {
+    let response_dto = state
+        .mfa_enrollment
+        .get_recovery_code_status(RecoveryCodeStatusQuery {
+            user_id: claims.sub,
+        })
+        .await?;
+    Ok(Json(RecoveryCodeStatusResponse::from(response_dto)))
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use usecase::auth::mfa_enrollment::dto::RecoveryCodeStatusDto;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RecoveryCodeStatusResponse {
+    /// 未使用のリカバリーコードの残数
+    pub remaining: usize,
+}
+
+impl From<RecoveryCodeStatusDto> for RecoveryCodeStatusResponse {
+    fn from(dto: RecoveryCodeStatusDto) -> Self {
+        Self {
+            remaining: dto.remaining,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+pub mod request;
+
+use self::request::TotpConfirmRequest;
+use crate::AppState;
+use crate::error::AppError;
+use crate::handlers::users::recovery_codes_regenerate::response::RecoveryCodesResponse;
+use crate::middleware::auth::{RequirePermission, permissions::ProfileWrite};
+use crate::middleware::precondition::IfMatch;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use std::sync::Arc;
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/api/v1/users/me/mfa/totp/confirm",
+    request_body = TotpConfirmRequest,
+    params(
+        ("If-Match" = Option<String>, Header, description = "Expected user version (ETag of GET /api/v1/users/me)")
+    ),
+    responses(
+        (status = 200, description = "TOTP confirmed; MFA is enabled and recovery codes are issued", body = RecoveryCodesResponse),
+        (status = 400, description = "Malformed code or enrollment not started"),
+        (status = 401, description = "Unauthorized or invalid code"),
+        (status = 409, description = "MFA is already enabled"),
+        (status = 412, description = "User has been modified since the given version")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "users"
+))]
+pub async fn totp_confirm(
+    State(state): State<Arc<AppState>>,
+    RequirePermission(claims, _): RequirePermission<ProfileWrite>,
+    IfMatch(expected_version): IfMatch,
+    Json(req): Json<TotpConfirmRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let response_dto = state
+        .mfa_enrollment
+        .confirm_totp_enrollment(req.into_command(claims, expected_version))
+        .await?;
+    Ok((
+        StatusCode::OK,
+        Json(RecoveryCodesResponse::from(response_dto)),
+    ))
+}
@@ -0,0 +1,27 @@
+use sensitive_data::{SecretRule, Sensitive};
+use serde::Deserialize;
+use usecase::auth::Claims;
+use usecase::auth::mfa_enrollment::command::ConfirmTotpEnrollmentCommand;
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TotpConfirmRequest {
+    /// 認証アプリに表示された 6 桁のコード
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub code: Sensitive<String, SecretRule>,
+}
+
+impl TotpConfirmRequest {
+    /// 認証済みトークンのクレームと `If-Match` の値と合わせてコマンドを組み立てる。
+    pub fn into_command(
+        self,
+        claims: Claims,
+        expected_version: Option<i32>,
+    ) -> ConfirmTotpEnrollmentCommand {
+        ConfirmTotpEnrollmentCommand {
+            user_id: claims.sub,
+            code: self.code,
+            expected_version: expected_version.map(Into::into),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+pub mod response;
+
+use self::response::TotpEnrollmentResponse;
+use crate::AppState;
+use crate::error::AppError;
+use crate::middleware::auth::{RequirePermission, permissions::ProfileWrite};
+use crate::middleware::precondition::IfMatch;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use std::sync::Arc;
+use usecase::auth::mfa_enrollment::command::StartTotpEnrollmentCommand;
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/api/v1/users/me/mfa/totp",
+    params(
+        ("If-Match" = Option<String>, Header, description = "Expected user version (ETag of GET /api/v1/users/me)")
+    ),
+    responses(
+        (status = 200, description = "TOTP enrollment started", body = TotpEnrollmentResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 409, description = "MFA is already enabled"),
+        (status = 412, description = "User has been modified since the given version")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "users"
+))]
+pub async fn totp_enroll(
+    State(state): State<Arc<AppState>>,
+    RequirePermission(claims, _): RequirePermission<ProfileWrite>,
+    IfMatch(expected_version): IfMatch,
+) -> Result<impl IntoResponse, AppError> {
+    let response_dto = state
+        .mfa_enrollment
+        .start_totp_enrollment(StartTotpEnrollmentCommand {
+            user_id: claims.sub,
+            expected_version: expected_version.map(Into::into),
+        })
+        .await?;
+    Ok((
+        StatusCode::OK,
+        Json(TotpEnrollmentResponse::from(response_dto)),
+    ))
+}
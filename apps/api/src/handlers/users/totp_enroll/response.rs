@@ -0,0 +1,23 @@
+use sensitive_data::{SecretRule, Sensitive};
+use serde::{Deserialize, Serialize};
+use usecase::auth::mfa_enrollment::dto::TotpEnrollmentDto;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TotpEnrollmentResponse {
+    /// 手入力用のシークレット (Base32)
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub secret: Sensitive<String, SecretRule>,
+    /// 認証アプリに登録するための otpauth URI
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub otpauth_uri: Sensitive<String, SecretRule>,
+}
+
+impl From<TotpEnrollmentDto> for TotpEnrollmentResponse {
+    fn from(dto: TotpEnrollmentDto) -> Self {
+        Self {
+            secret: dto.secret.expose_as_str().to_string().into(),
+            otpauth_uri: dto.otpauth_uri.into(),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+pub mod response;
+
+use self::response::JwksResponse;
+use crate::AppState;
+use axum::{Json, extract::State};
+use std::sync::Arc;
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/.well-known/jwks.json",
+    responses(
+        (status = 200, description = "Public keys for verifying access tokens", body = JwksResponse)
+    ),
+    tag = "discovery"
+))]
+pub async fn jwks(State(state): State<Arc<AppState>>) -> Json<JwksResponse> {
+    let response_dto = state.discovery.get_jwks().await;
+    Json(JwksResponse::from(response_dto))
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use usecase::auth::JsonWebKey;
+use usecase::auth::discovery::dto::JwksDto;
+
+/// 署名の検証に使用する公開鍵（RFC 7517）
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct JwkResponse {
+    /// 鍵の種類（`OKP` / `RSA`）
+    pub kty: String,
+    /// 用途（常に `sig`）
+    #[serde(rename = "use")]
+    pub use_: String,
+    /// アクセストークンのヘッダーの `kid` に対応する鍵 ID
+    pub kid: String,
+    /// 署名アルゴリズム（`EdDSA` / `RS256`）
+    pub alg: String,
+    /// 曲線名（OKP のみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crv: Option<String>,
+    /// 公開鍵（OKP のみ、Base64URL）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x: Option<String>,
+    /// モジュラス（RSA のみ、Base64URL）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<String>,
+    /// 公開指数（RSA のみ、Base64URL）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct JwksResponse {
+    pub keys: Vec<JwkResponse>,
+}
+
+impl From<JsonWebKey> for JwkResponse {
+    fn from(key: JsonWebKey) -> Self {
+        Self {
+            kty: key.kty,
+            use_: "sig".to_string(),
+            kid: key.kid,
+            alg: key.alg,
+            crv: key.crv,
+            x: key.x,
+            n: key.n,
+            e: key.e,
+        }
+    }
+}
+
+impl From<JwksDto> for JwksResponse {
+    fn from(dto: JwksDto) -> Self {
+        Self {
+            keys: dto.keys.into_iter().map(JwkResponse::from).collect(),
+        }
+    }
+}
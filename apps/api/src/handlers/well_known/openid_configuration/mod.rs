@@ -0,0 +1,21 @@
+pub mod response;
+
+use self::response::OpenIdConfigurationResponse;
+use crate::AppState;
+use axum::{Json, extract::State};
+use std::sync::Arc;
+
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/.well-known/openid-configuration",
+    responses(
+        (status = 200, description = "Issuer, endpoints and supported signing algorithms", body = OpenIdConfigurationResponse)
+    ),
+    tag = "discovery"
+))]
+pub async fn openid_configuration(
+    State(state): State<Arc<AppState>>,
+) -> Json<OpenIdConfigurationResponse> {
+    let response_dto = state.discovery.get_discovery().await;
+    Json(OpenIdConfigurationResponse::from(response_dto))
+}
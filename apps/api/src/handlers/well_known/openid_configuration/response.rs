@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use usecase::auth::discovery::dto::DiscoveryDto;
+
+/// OpenID Connect Discovery 1.0 のプロバイダーメタデータ
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct OpenIdConfigurationResponse {
+    /// アクセストークンの発行者（`iss`）
+    pub issuer: String,
+    /// 公開鍵の一覧の URL
+    pub jwks_uri: String,
+    /// アクセストークンを発行するエンドポイント（ログイン）
+    pub token_endpoint: String,
+    /// アクセストークンで利用者の情報を取得するエンドポイント
+    pub userinfo_endpoint: String,
+    /// トークンを失効させるエンドポイント（ログアウト）
+    pub revocation_endpoint: String,
+    pub response_types_supported: Vec<String>,
+    pub subject_types_supported: Vec<String>,
+    /// アクセストークンの署名アルゴリズム
+    pub id_token_signing_alg_values_supported: Vec<String>,
+}
+
+impl From<DiscoveryDto> for OpenIdConfigurationResponse {
+    fn from(dto: DiscoveryDto) -> Self {
+        let base_url = dto.issuer.trim_end_matches('/');
+        Self {
+            jwks_uri: format!("{base_url}/.well-known/jwks.json"),
+            token_endpoint: format!("{base_url}/api/v1/auth/login"),
+            userinfo_endpoint: format!("{base_url}/api/v1/users/me"),
+            revocation_endpoint: format!("{base_url}/api/v1/auth/logout"),
+            response_types_supported: vec!["token".to_string()],
+            subject_types_supported: vec!["public".to_string()],
+            id_token_signing_alg_values_supported: dto.signing_algorithms,
+            issuer: dto.issuer,
+        }
+    }
+}
@@ -1,10 +1,15 @@
 use axum::{
     Router,
-    routing::{get, post},
+    routing::{get, post, put},
 };
 use std::sync::Arc;
 use tower_http::trace::TraceLayer;
-use usecase::auth::{AuthCommandUseCase, AuthQueryUseCase, AuthService};
+use usecase::auth::{
+    AuthCommandUseCase, AuthQueryUseCase, AuthenticateUseCase, DiscoveryUseCase,
+    EmailVerificationUseCase, LogoutUseCase, MfaEnrollmentUseCase, MfaLoginUseCase,
+    PasswordChangeUseCase, PasswordResetUseCase, TokenRefreshUseCase,
+};
+use usecase::user::{UserAdminUseCase, UserProfileUseCase};
 
 pub mod error;
 pub mod handlers;
@@ -18,7 +23,17 @@ pub mod tests;
 pub struct AppState {
     pub auth_command: Arc<dyn AuthCommandUseCase>,
     pub auth_query: Arc<dyn AuthQueryUseCase>,
-    pub auth_service: Arc<dyn AuthService>,
+    pub token_refresh: Arc<dyn TokenRefreshUseCase>,
+    pub logout: Arc<dyn LogoutUseCase>,
+    pub mfa_enrollment: Arc<dyn MfaEnrollmentUseCase>,
+    pub mfa_login: Arc<dyn MfaLoginUseCase>,
+    pub password_reset: Arc<dyn PasswordResetUseCase>,
+    pub password_change: Arc<dyn PasswordChangeUseCase>,
+    pub email_verification: Arc<dyn EmailVerificationUseCase>,
+    pub authenticate: Arc<dyn AuthenticateUseCase>,
+    pub discovery: Arc<dyn DiscoveryUseCase>,
+    pub user_profile: Arc<dyn UserProfileUseCase>,
+    pub user_admin: Arc<dyn UserAdminUseCase>,
 }
 
 pub fn create_router(state: Arc<AppState>) -> Router {
@@ -35,7 +50,78 @@ pub fn create_router(state: Arc<AppState>) -> Router {
 
     app.route("/api/v1/auth/signup", post(handlers::auth::signup::signup))
         .route("/api/v1/auth/login", post(handlers::auth::login::login))
+        .route(
+            "/api/v1/auth/login/mfa",
+            post(handlers::auth::login_mfa::login_mfa),
+        )
+        .route(
+            "/api/v1/auth/refresh",
+            post(handlers::auth::refresh::refresh),
+        )
+        .route("/api/v1/auth/logout", post(handlers::auth::logout::logout))
+        .route(
+            "/api/v1/auth/password-reset",
+            post(handlers::auth::password_reset_request::password_reset_request),
+        )
+        .route(
+            "/api/v1/auth/password-reset/confirm",
+            post(handlers::auth::password_reset_confirm::password_reset_confirm),
+        )
+        .route(
+            "/api/v1/auth/verify-email",
+            post(handlers::auth::verify_email::verify_email),
+        )
+        .route(
+            "/api/v1/auth/verify-email/resend",
+            post(handlers::auth::verify_email_resend::verify_email_resend),
+        )
         .route("/api/v1/users/me", get(handlers::users::me::me))
+        .route(
+            "/api/v1/users/me/password",
+            put(handlers::users::change_password::change_password),
+        )
+        .route(
+            "/api/v1/users/me/mfa/totp",
+            post(handlers::users::totp_enroll::totp_enroll),
+        )
+        .route(
+            "/api/v1/users/me/mfa/totp/confirm",
+            post(handlers::users::totp_confirm::totp_confirm),
+        )
+        .route(
+            "/api/v1/users/me/mfa/recovery-codes",
+            get(handlers::users::recovery_codes_status::recovery_codes_status)
+                .post(handlers::users::recovery_codes_regenerate::recovery_codes_regenerate),
+        )
+        .route(
+            "/api/v1/admin/users",
+            get(handlers::admin::list_users::list_users),
+        )
+        .route(
+            "/api/v1/admin/users/{user_id}",
+            get(handlers::admin::get_user::get_user)
+                .delete(handlers::admin::delete_user::delete_user),
+        )
+        .route(
+            "/api/v1/admin/users/{user_id}/suspend",
+            post(handlers::admin::suspend_user::suspend_user),
+        )
+        .route(
+            "/api/v1/admin/users/{user_id}/reactivate",
+            post(handlers::admin::reactivate_user::reactivate_user),
+        )
+        .route(
+            "/api/v1/admin/users/{user_id}/password-reset",
+            post(handlers::admin::force_password_reset::force_password_reset),
+        )
+        .route(
+            "/.well-known/jwks.json",
+            get(handlers::well_known::jwks::jwks),
+        )
+        .route(
+            "/.well-known/openid-configuration",
+            get(handlers::well_known::openid_configuration::openid_configuration),
+        )
         .layer(TraceLayer::new_for_http())
         .with_state(state)
 }
@@ -4,8 +4,9 @@ use axum::{
     extract::{FromRef, FromRequestParts},
     http::request::Parts,
 };
+use std::marker::PhantomData;
 use std::sync::Arc;
-use usecase::auth::{AuthToken, Claims};
+use usecase::auth::{AuthToken, Claims, Permission};
 
 pub struct AuthenticatedUser(pub Claims);
 
@@ -33,11 +34,53 @@ where
         let token_str = &auth_header[7..];
         let token = AuthToken::from(token_str.to_string());
 
-        let claims = state
-            .auth_service
-            .verify_token(&token)
-            .map_err(|e| AppError::UseCase(e.into()))?; // verify_token returns AuthServiceError, convert to UseCaseError
+        // 署名・有効期限に加え、サーバー側での失効とアカウントの状態も検証する。
+        // 権限はトークンの値ではなく、現在のロールから導出したものに置き換わる
+        let claims = state.authenticate.authenticate(&token).await?;
 
         Ok(AuthenticatedUser(claims))
     }
 }
+
+/// `RequirePermission<P>` で要求する権限を型で表すためのマーカー。
+pub trait PermissionMarker: Send + Sync + 'static {
+    const PERMISSION: Permission;
+}
+
+/// 各権限に対応するマーカー型。
+pub mod permissions {
+    use super::PermissionMarker;
+    use usecase::auth::Permission;
+
+    macro_rules! permission_marker {
+        ($($name:ident),* $(,)?) => {
+            $(
+                pub struct $name;
+
+                impl PermissionMarker for $name {
+                    const PERMISSION: Permission = Permission::$name;
+                }
+            )*
+        };
+    }
+
+    permission_marker!(ProfileRead, ProfileWrite, UsersRead, UsersWrite);
+}
+
+/// 認証に加えて権限 `P` を要求する。権限が無い場合は 403 を返す。
+pub struct RequirePermission<P: PermissionMarker>(pub Claims, pub PhantomData<P>);
+
+impl<S, P> FromRequestParts<S> for RequirePermission<P>
+where
+    Arc<AppState>: FromRef<S>,
+    S: Send + Sync,
+    P: PermissionMarker,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let AuthenticatedUser(claims) = AuthenticatedUser::from_request_parts(parts, state).await?;
+        claims.authorize(P::PERMISSION)?;
+        Ok(RequirePermission(claims, PhantomData))
+    }
+}
@@ -1 +1,3 @@
 pub mod auth;
+pub mod client_ip;
+pub mod precondition;
@@ -0,0 +1,49 @@
+use crate::error::AppError;
+use axum::{
+    extract::FromRequestParts,
+    http::{HeaderValue, header, request::Parts},
+    response::{IntoResponseParts, ResponseParts},
+};
+
+/// `If-Match` ヘッダーで指定されたリソースのバージョン。
+///
+/// ヘッダーが無い場合や `*` の場合は `None`（事前条件なし）。強い ETag（`"3"`）のみ受け付ける。
+pub struct IfMatch(pub Option<i32>);
+
+impl<S> FromRequestParts<S> for IfMatch
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let Some(value) = parts.headers.get(header::IF_MATCH) else {
+            return Ok(IfMatch(None));
+        };
+        let value = value.to_str().map_err(|_| AppError::InvalidIfMatch)?.trim();
+        if value == "*" {
+            return Ok(IfMatch(None));
+        }
+
+        value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .and_then(|v| v.parse::<i32>().ok())
+            .map(|version| IfMatch(Some(version)))
+            .ok_or(AppError::InvalidIfMatch)
+    }
+}
+
+/// レスポンスに `ETag` ヘッダーとしてリソースのバージョンを付与する。
+pub struct ETag(pub i32);
+
+impl IntoResponseParts for ETag {
+    type Error = std::convert::Infallible;
+
+    fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        let value = HeaderValue::from_str(&format!("\"{}\"", self.0))
+            .expect("a quoted integer is a valid header value");
+        res.headers_mut().insert(header::ETAG, value);
+        Ok(res)
+    }
+}
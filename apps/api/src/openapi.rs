@@ -9,20 +9,61 @@ use utoipa::OpenApi;
     paths(
         handlers::auth::signup::signup,
         handlers::auth::login::login,
+        handlers::auth::login_mfa::login_mfa,
+        handlers::auth::refresh::refresh,
+        handlers::auth::logout::logout,
+        handlers::auth::password_reset_request::password_reset_request,
+        handlers::auth::password_reset_confirm::password_reset_confirm,
+        handlers::auth::verify_email::verify_email,
+        handlers::auth::verify_email_resend::verify_email_resend,
         handlers::users::me::me,
+        handlers::users::change_password::change_password,
+        handlers::users::totp_enroll::totp_enroll,
+        handlers::users::totp_confirm::totp_confirm,
+        handlers::users::recovery_codes_status::recovery_codes_status,
+        handlers::users::recovery_codes_regenerate::recovery_codes_regenerate,
+        handlers::admin::list_users::list_users,
+        handlers::admin::get_user::get_user,
+        handlers::admin::suspend_user::suspend_user,
+        handlers::admin::reactivate_user::reactivate_user,
+        handlers::admin::force_password_reset::force_password_reset,
+        handlers::admin::delete_user::delete_user,
+        handlers::well_known::jwks::jwks,
+        handlers::well_known::openid_configuration::openid_configuration,
     ),
     components(
         schemas(
             handlers::auth::signup::request::SignupRequest,
             handlers::auth::login::request::LoginRequest,
             handlers::auth::login::response::LoginResponse,
+            handlers::auth::login::response::MfaChallengeResponse,
+            handlers::auth::login_mfa::request::MfaLoginRequest,
+            handlers::auth::refresh::request::RefreshRequest,
+            handlers::auth::refresh::response::RefreshResponse,
+            handlers::auth::logout::request::LogoutRequest,
+            handlers::auth::password_reset_request::request::PasswordResetRequest,
+            handlers::auth::password_reset_confirm::request::PasswordResetConfirmRequest,
+            handlers::auth::verify_email::request::VerifyEmailRequest,
+            handlers::auth::verify_email_resend::request::ResendVerificationEmailRequest,
             handlers::users::me::response::MeResponse,
+            handlers::users::change_password::request::ChangePasswordRequest,
+            handlers::users::totp_enroll::response::TotpEnrollmentResponse,
+            handlers::users::totp_confirm::request::TotpConfirmRequest,
+            handlers::users::recovery_codes_status::response::RecoveryCodeStatusResponse,
+            handlers::users::recovery_codes_regenerate::response::RecoveryCodesResponse,
+            handlers::admin::get_user::response::AdminUserResponse,
+            handlers::admin::list_users::response::UserPageResponse,
+            handlers::well_known::jwks::response::JwkResponse,
+            handlers::well_known::jwks::response::JwksResponse,
+            handlers::well_known::openid_configuration::response::OpenIdConfigurationResponse,
         )
     ),
     modifiers(&SecurityAddon),
     tags(
         (name = "auth", description = "Authentication and registration"),
-        (name = "users", description = "User management and profile")
+        (name = "users", description = "User management and profile"),
+        (name = "admin", description = "Administration of user accounts"),
+        (name = "discovery", description = "Public keys and metadata for verifying access tokens")
     )
 )]
 pub struct ApiDoc;
@@ -31,6 +31,7 @@ impl From<LoginRequest> for LoginQuery {
         Self {
             email: req.email.into(),
             password: req.password.into(),
+            client_ip: None,
         }
     }
 }
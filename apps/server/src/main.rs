@@ -1,16 +1,46 @@
 use api::{AppState, create_router};
+use domain::models::auth::{LoginThrottleConfig, PasswordPolicy};
 use domain::models::user::service::UserUniquenessCheckerImpl;
-use infrastructure::auth::jwt::JwtAuthService;
-use infrastructure::auth::password::Argon2PasswordService;
+use domain::models::user::{EmailVerificationPolicy, SignupDisclosurePolicy};
+use infrastructure::auth::breached_password::{
+    InMemoryBreachedPasswordChecker, RangeFileBreachedPasswordChecker,
+};
+use infrastructure::auth::email_verification::JwtEmailVerificationTokenService;
+use infrastructure::auth::jwt::{JwtAuthService, JwtConfig, JwtKeyRing};
+use infrastructure::auth::login_attempt::{InMemoryLoginAttemptStore, PgLoginAttemptStore};
+use infrastructure::auth::opaque_token::RandomOpaqueTokenService;
+use infrastructure::auth::password::{
+    Argon2Algorithm, Argon2Config, Argon2PasswordService, Argon2Version, HashingPoolConfig,
+    PepperRing,
+};
+use infrastructure::auth::recovery_code::RandomRecoveryCodeGenerator;
+use infrastructure::auth::revocation::{InMemoryTokenRevocationStore, PgTokenRevocationStore};
+use infrastructure::auth::totp::RfcTotpService;
 use infrastructure::clock::RealClock;
 use infrastructure::id::UuidV7Generator;
+use infrastructure::mail::{
+    BackgroundMailer, FileMailer, LogMailer, MailLocale, MailRenderer, SmtpConfig, SmtpMailer,
+    SmtpSecurity,
+};
+use infrastructure::outbox::{LogEventPublisher, OutboxRelay, OutboxRelayConfig};
 use infrastructure::repository::tx::SqlxTransactionManager;
 use infrastructure::telemetry::init_telemetry;
 use sensitive_data::MaskingControl;
 use sqlx::postgres::PgPoolOptions;
 use std::env;
+use std::net::SocketAddr;
 use std::sync::Arc;
-use usecase::auth::{AuthCommandUseCaseImpl, AuthQueryUseCaseImpl};
+use std::time::Duration;
+use usecase::auth::{
+    AuthCommandUseCaseImpl, AuthQueryUseCaseImpl, AuthenticateUseCaseImpl, BreachedPasswordChecker,
+    DiscoveryUseCaseImpl, EmailVerificationUseCaseImpl, LoginAttemptStore, LogoutUseCaseImpl,
+    MfaEnrollmentUseCaseImpl, MfaLoginUseCaseImpl, PasswordChangeUseCaseImpl,
+    PasswordResetUseCaseImpl, SignupAttemptMailHandler, TokenRefreshUseCaseImpl,
+    TokenRevocationStore, VerificationMailHandler,
+};
+use usecase::event::{EventSink, InProcessEventSink};
+use usecase::mailer::Mailer;
+use usecase::user::{UserAdminUseCaseImpl, UserProfileUseCaseImpl};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -39,37 +69,375 @@ async fn main() -> anyhow::Result<()> {
     // Infrastructure & Domain Services
     let clock = Arc::new(RealClock);
     let id_generator = Arc::new(UuidV7Generator::new());
-    let tx_manager = Arc::new(SqlxTransactionManager::new(pool, clock.clone()));
+    let tx_manager = Arc::new(SqlxTransactionManager::new(pool.clone(), clock.clone()));
     let uniqueness_checker = Arc::new(UserUniquenessCheckerImpl::new());
-    let password_service = Arc::new(Argon2PasswordService::new());
-    let auth_service = Arc::new(JwtAuthService::new(&jwt_secret, clock.clone()));
+    // Server-side HMAC pepper keys (no pepper when unset)
+    let pepper = match env::var("PASSWORD_PEPPER_FILE")
+        .ok()
+        .filter(|path| !path.is_empty())
+    {
+        Some(path) => Some(PepperRing::from_file(path)?),
+        None => {
+            tracing::warn!("PASSWORD_PEPPER_FILE is not set; password hashes are not peppered");
+            None
+        }
+    };
+    let password_service = Arc::new(Argon2PasswordService::with_config(
+        argon2_config_from_env()?,
+        hashing_pool_config_from_env()?,
+        pepper,
+    )?);
+    let token_service = Arc::new(RandomOpaqueTokenService::new());
+    // Access token signing keys (falls back to HS256 with JWT_SECRET when unset)
+    let jwt_key_ring = match env::var("JWT_KEYS_FILE")
+        .ok()
+        .filter(|path| !path.is_empty())
+    {
+        Some(path) => JwtKeyRing::from_file(path)?,
+        None => {
+            tracing::warn!("JWT_KEYS_FILE is not set; access tokens are signed with JWT_SECRET");
+            JwtKeyRing::from_secret(&jwt_secret)
+        }
+    };
+    let jwt_config = jwt_config_from_env()?;
+    let jwt_issuer = jwt_config.issuer.clone().unwrap_or_default();
+    let auth_service = Arc::new(JwtAuthService::with_config(
+        jwt_key_ring,
+        jwt_config,
+        clock.clone(),
+    ));
+    let verification_token_service = Arc::new(JwtEmailVerificationTokenService::new(&jwt_secret));
+
+    // Reject login until the email address is verified (default: false)
+    let email_verification_policy = match env::var("REQUIRE_EMAIL_VERIFICATION") {
+        Ok(v) if v.to_lowercase() == "true" => EmailVerificationPolicy::Required,
+        _ => EmailVerificationPolicy::Optional,
+    };
+
+    // Answer signup with 202 even for registered emails and notify the owner by mail (default: false)
+    let signup_disclosure_policy = match env::var("SIGNUP_ENUMERATION_PROTECTION") {
+        Ok(v) if v.to_lowercase() == "true" => SignupDisclosurePolicy::Conceal,
+        _ => SignupDisclosurePolicy::Disclose,
+    };
+
+    let password_policy = password_policy_from_env()?;
+
+    // Local corpus of breached passwords in HIBP range-file layout (no check when unset)
+    let breached_password_checker: Arc<dyn BreachedPasswordChecker> =
+        match env::var("BREACHED_PASSWORDS_DIR")
+            .ok()
+            .filter(|dir| !dir.is_empty())
+        {
+            Some(dir) => Arc::new(RangeFileBreachedPasswordChecker::new(dir)?),
+            None => {
+                tracing::warn!(
+                    "BREACHED_PASSWORDS_DIR is not set; breached password check is disabled"
+                );
+                Arc::new(InMemoryBreachedPasswordChecker::default())
+            }
+        };
+
+    // TOTP issuer shown in authenticator apps
+    let totp_issuer = env::var("TOTP_ISSUER").unwrap_or_else(|_| "auth-template".to_string());
+    let totp_service = Arc::new(RfcTotpService::new(totp_issuer));
+
+    // Token revocation store (postgres: shared across instances / memory: single instance)
+    let revocation_store: Arc<dyn TokenRevocationStore> =
+        match env::var("TOKEN_REVOCATION_STORE").as_deref() {
+            Ok("memory") => Arc::new(InMemoryTokenRevocationStore::new(clock.clone())),
+            _ => Arc::new(PgTokenRevocationStore::new(pool.clone(), clock.clone())),
+        };
+
+    // Failed login attempts for lockout / throttling (postgres: shared across instances / memory: single instance)
+    let login_attempt_store: Arc<dyn LoginAttemptStore> =
+        match env::var("LOGIN_ATTEMPT_STORE").as_deref() {
+            Ok("memory") => Arc::new(InMemoryLoginAttemptStore::new()),
+            _ => Arc::new(PgLoginAttemptStore::new(pool.clone())),
+        };
+
+    // Mailer (links in mails point to the frontend at APP_BASE_URL)
+    let app_base_url =
+        env::var("APP_BASE_URL").unwrap_or_else(|_| "http://localhost:3000".to_string());
+    let mail_locale = match env::var("MAIL_LOCALE") {
+        Ok(v) => v.parse()?,
+        Err(_) => MailLocale::default(),
+    };
+    let mailer = build_mailer(MailRenderer::new(app_base_url, mail_locale))?;
+
+    // In-process handlers for domain events (e.g. verification mail on signup)
+    let event_sink: Arc<dyn EventSink> = Arc::new(InProcessEventSink::new(vec![
+        Arc::new(VerificationMailHandler::new(
+            verification_token_service.clone(),
+            mailer.clone(),
+        )),
+        Arc::new(SignupAttemptMailHandler::new(mailer.clone())),
+    ]));
 
     // UseCase instantiation (Implementations from infrastructure/domain are injected here)
     let auth_command = Arc::new(AuthCommandUseCaseImpl::new(
         tx_manager.clone(),
         uniqueness_checker,
         password_service.clone(),
+        breached_password_checker.clone(),
+        event_sink.clone(),
         clock.clone(),
-        id_generator,
+        id_generator.clone(),
+        password_policy,
+        signup_disclosure_policy,
     ));
     let auth_query = Arc::new(AuthQueryUseCaseImpl::new(
-        tx_manager,
-        password_service,
+        tx_manager.clone(),
+        password_service.clone(),
+        token_service.clone(),
         auth_service.clone(),
-        clock,
+        event_sink.clone(),
+        clock.clone(),
+        id_generator.clone(),
+        email_verification_policy,
+        login_attempt_store.clone(),
+        LoginThrottleConfig::default(),
+    ));
+    let token_refresh = Arc::new(TokenRefreshUseCaseImpl::new(
+        tx_manager.clone(),
+        token_service.clone(),
+        auth_service.clone(),
+        clock.clone(),
+        id_generator.clone(),
+    ));
+    let logout = Arc::new(LogoutUseCaseImpl::new(
+        tx_manager.clone(),
+        token_service.clone(),
+        revocation_store.clone(),
+        clock.clone(),
+    ));
+    let mfa_enrollment = Arc::new(MfaEnrollmentUseCaseImpl::new(
+        tx_manager.clone(),
+        totp_service.clone(),
+        password_service.clone(),
+        Arc::new(RandomRecoveryCodeGenerator::new()),
+        clock.clone(),
+    ));
+    let mfa_login = Arc::new(MfaLoginUseCaseImpl::new(
+        tx_manager.clone(),
+        token_service.clone(),
+        totp_service,
+        password_service.clone(),
+        auth_service.clone(),
+        clock.clone(),
+        id_generator.clone(),
     ));
+    let password_change = Arc::new(PasswordChangeUseCaseImpl::new(
+        tx_manager.clone(),
+        password_service.clone(),
+        breached_password_checker.clone(),
+        token_service.clone(),
+        auth_service.clone(),
+        revocation_store.clone(),
+        event_sink.clone(),
+        clock.clone(),
+        id_generator.clone(),
+        password_policy,
+        login_attempt_store.clone(),
+        LoginThrottleConfig::default(),
+    ));
+    let password_reset = Arc::new(PasswordResetUseCaseImpl::new(
+        tx_manager.clone(),
+        password_service,
+        breached_password_checker,
+        token_service.clone(),
+        // Send in the background so the response does not reveal whether the account exists
+        Arc::new(BackgroundMailer::new(mailer.clone())),
+        event_sink.clone(),
+        clock.clone(),
+        id_generator.clone(),
+        password_policy,
+    ));
+    let user_admin = Arc::new(UserAdminUseCaseImpl::new(
+        tx_manager.clone(),
+        token_service,
+        // Send in the background so a mail failure does not fail the already committed reset
+        Arc::new(BackgroundMailer::new(mailer.clone())),
+        event_sink.clone(),
+        login_attempt_store,
+        clock.clone(),
+        id_generator,
+    ));
+    let email_verification = Arc::new(EmailVerificationUseCaseImpl::new(
+        tx_manager.clone(),
+        verification_token_service,
+        mailer,
+        event_sink,
+        clock.clone(),
+    ));
+    let discovery = Arc::new(DiscoveryUseCaseImpl::new(auth_service.clone(), jwt_issuer));
+    let authenticate = Arc::new(AuthenticateUseCaseImpl::new(
+        tx_manager.clone(),
+        auth_service,
+        revocation_store,
+        clock.clone(),
+    ));
+    let user_profile = Arc::new(UserProfileUseCaseImpl::new(tx_manager));
 
     let state = Arc::new(AppState {
         auth_command,
         auth_query,
-        auth_service,
+        token_refresh,
+        logout,
+        authenticate,
+        discovery,
+        mfa_enrollment,
+        mfa_login,
+        password_reset,
+        password_change,
+        email_verification,
+        user_profile,
+        user_admin,
     });
 
     let app = create_router(state);
 
+    // Outbox relay (delivers domain events recorded in the outbox table)
+    let outbox_relay = OutboxRelay::new(
+        pool,
+        Arc::new(LogEventPublisher),
+        clock,
+        OutboxRelayConfig::default(),
+    );
+    tokio::spawn(outbox_relay.run());
+
     let listener = tokio::net::TcpListener::bind("0.0.0.0:8080").await?;
     tracing::info!("listening on {}", listener.local_addr()?);
-    axum::serve(listener, app).await?;
+    // Expose the peer address to handlers (per-IP login throttling)
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await?;
 
     Ok(())
 }
+
+/// Argon2 parameters for new hashes (older hashes are upgraded on the next login)
+fn argon2_config_from_env() -> anyhow::Result<Argon2Config> {
+    let default = Argon2Config::default();
+    Ok(Argon2Config {
+        algorithm: match env::var("ARGON2_ALGORITHM") {
+            Ok(v) => v.parse::<Argon2Algorithm>()?,
+            Err(_) => default.algorithm,
+        },
+        version: match env::var("ARGON2_VERSION") {
+            Ok(v) => Argon2Version::try_from(v.parse::<u32>()?)?,
+            Err(_) => default.version,
+        },
+        memory_kib: match env::var("ARGON2_MEMORY_KIB") {
+            Ok(v) => v.parse()?,
+            Err(_) => default.memory_kib,
+        },
+        iterations: match env::var("ARGON2_ITERATIONS") {
+            Ok(v) => v.parse()?,
+            Err(_) => default.iterations,
+        },
+        parallelism: match env::var("ARGON2_PARALLELISM") {
+            Ok(v) => v.parse()?,
+            Err(_) => default.parallelism,
+        },
+    })
+}
+
+/// Claims and lifetime of access tokens (iss / aud are required on verification when set)
+fn jwt_config_from_env() -> anyhow::Result<JwtConfig> {
+    let default = JwtConfig::default();
+    Ok(JwtConfig {
+        // Public base URL of this API, also published by the discovery endpoints
+        issuer: Some(
+            env::var("JWT_ISSUER").unwrap_or_else(|_| "http://localhost:8080".to_string()),
+        ),
+        audiences: match env::var("JWT_AUDIENCES") {
+            Ok(v) => v
+                .split(',')
+                .map(str::trim)
+                .filter(|audience| !audience.is_empty())
+                .map(str::to_string)
+                .collect(),
+            Err(_) => default.audiences,
+        },
+        leeway: match env::var("JWT_LEEWAY_SECS") {
+            Ok(v) => chrono::Duration::seconds(v.parse()?),
+            Err(_) => default.leeway,
+        },
+        access_token_ttl: match env::var("JWT_ACCESS_TOKEN_TTL_SECS") {
+            Ok(v) => chrono::Duration::seconds(v.parse()?),
+            Err(_) => default.access_token_ttl,
+        },
+    })
+}
+
+/// Limits on concurrent password hashing (requests over the limit get 503 after the queue timeout)
+fn hashing_pool_config_from_env() -> anyhow::Result<HashingPoolConfig> {
+    let default = HashingPoolConfig::default();
+    Ok(HashingPoolConfig {
+        max_concurrency: match env::var("PASSWORD_HASHING_MAX_CONCURRENCY") {
+            Ok(v) => v.parse()?,
+            Err(_) => default.max_concurrency,
+        },
+        queue_timeout: match env::var("PASSWORD_HASHING_QUEUE_TIMEOUT_MS") {
+            Ok(v) => Duration::from_millis(v.parse()?),
+            Err(_) => default.queue_timeout,
+        },
+    })
+}
+
+/// Rules for new passwords (unset variables fall back to the defaults)
+fn password_policy_from_env() -> anyhow::Result<PasswordPolicy> {
+    let default = PasswordPolicy::default();
+    Ok(PasswordPolicy {
+        min_length: match env::var("PASSWORD_MIN_LENGTH") {
+            Ok(v) => v.parse()?,
+            Err(_) => default.min_length,
+        },
+        max_length: match env::var("PASSWORD_MAX_LENGTH") {
+            Ok(v) => v.parse()?,
+            Err(_) => default.max_length,
+        },
+        min_character_classes: match env::var("PASSWORD_MIN_CHARACTER_CLASSES") {
+            Ok(v) => v.parse()?,
+            Err(_) => default.min_character_classes,
+        },
+        reject_email_local_part: match env::var("PASSWORD_REJECT_EMAIL_LOCAL_PART") {
+            Ok(v) => v.to_lowercase() != "false",
+            Err(_) => default.reject_email_local_part,
+        },
+    })
+}
+
+/// Select the mail transport by MAIL_TRANSPORT (log | file | smtp)
+fn build_mailer(renderer: MailRenderer) -> anyhow::Result<Arc<dyn Mailer>> {
+    let from = env::var("MAIL_FROM").unwrap_or_else(|_| "no-reply@localhost".to_string());
+    let mailer: Arc<dyn Mailer> = match env::var("MAIL_TRANSPORT").as_deref() {
+        Ok("file") => {
+            let dir = env::var("MAIL_DIR").unwrap_or_else(|_| "./mail".to_string());
+            Arc::new(FileMailer::new(dir, &from, renderer)?)
+        }
+        Ok("smtp") => {
+            let config = SmtpConfig {
+                host: env::var("SMTP_HOST").expect("SMTP_HOST must be set"),
+                port: match env::var("SMTP_PORT") {
+                    Ok(v) => v.parse()?,
+                    Err(_) => 587,
+                },
+                security: match env::var("SMTP_SECURITY") {
+                    Ok(v) => v.parse()?,
+                    Err(_) => SmtpSecurity::default(),
+                },
+                credentials: env::var("SMTP_USERNAME")
+                    .ok()
+                    .filter(|username| !username.is_empty())
+                    .map(|username| (username, env::var("SMTP_PASSWORD").unwrap_or_default())),
+                timeout: Duration::from_secs(10),
+            };
+            Arc::new(SmtpMailer::new(config, &from, renderer)?)
+        }
+        _ => Arc::new(LogMailer::new(renderer)),
+    };
+    Ok(mailer)
+}
@@ -1,50 +1,42 @@
+mod common;
+
 use axum::{
     body::Body,
     http::{self, Request, StatusCode},
 };
-use domain::models::user::service::UserUniquenessCheckerImpl;
-use infrastructure::auth::jwt::JwtAuthService;
-use infrastructure::auth::password::Argon2PasswordService;
-use infrastructure::repository::tx::SqlxTransactionManager;
+use domain::models::user::{EmailVerificationPolicy, SignupDisclosurePolicy};
+use infrastructure::mail::InMemoryMailer;
 use serde_json::{Value, json};
 use std::sync::Arc;
 use tower::ServiceExt; // for `oneshot`
-use usecase::auth::{AuthCommandUseCaseImpl, AuthQueryUseCaseImpl};
+use usecase::mailer::MailMessage;
 
 // api クレートから必要な定義をインポート
 use api::handlers::auth::login::response::LoginResponse;
-use api::{AppState, create_router};
-
-async fn setup_app(pool: sqlx::PgPool) -> axum::Router {
-    let clock = Arc::new(infrastructure::clock::RealClock);
-    let id_generator = Arc::new(infrastructure::id::UuidV7Generator::new());
-    let tx_manager = Arc::new(SqlxTransactionManager::new(pool, clock.clone()));
-    let uniqueness_checker = Arc::new(UserUniquenessCheckerImpl::new());
-    let password_service = Arc::new(Argon2PasswordService::new());
-    let auth_service = Arc::new(JwtAuthService::new("test-secret", clock.clone()));
-
-    let auth_command = Arc::new(AuthCommandUseCaseImpl::new(
-        tx_manager.clone(),
-        uniqueness_checker,
-        password_service.clone(),
-        clock.clone(),
-        id_generator,
-    ));
-    let auth_query = Arc::new(AuthQueryUseCaseImpl::new(
-        tx_manager,
-        password_service,
-        auth_service.clone(),
-        clock,
-    ));
-
-    let state = Arc::new(AppState {
-        auth_command,
-        auth_query,
-        auth_service,
-    });
+use common::{setup_app, setup_app_with};
+
+/// 送信されたパスワードリセットのトークンを送信順に返す。
+fn password_reset_tokens(mailer: &InMemoryMailer) -> Vec<String> {
+    mailer
+        .sent()
+        .into_iter()
+        .filter_map(|mail| match mail.message {
+            MailMessage::PasswordReset { token, .. } => Some(token.expose_as_str().to_string()),
+            _ => None,
+        })
+        .collect()
+}
 
-    // api ライブラリのルーター生成関数を使用
-    create_router(state)
+/// 送信されたメールアドレス確認用のトークンを送信順に返す。
+fn verification_tokens(mailer: &InMemoryMailer) -> Vec<String> {
+    mailer
+        .sent()
+        .into_iter()
+        .filter_map(|mail| match mail.message {
+            MailMessage::EmailVerification { token, .. } => Some(token.expose_as_str().to_string()),
+            _ => None,
+        })
+        .collect()
 }
 
 #[sqlx::test(migrations = "../../migrations")]
@@ -136,3 +128,1333 @@ async fn test_auth_flow_e2e(pool: sqlx::PgPool) {
 
     assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
 }
+
+async fn post_json(app: &axum::Router, uri: &str, body: Value) -> (StatusCode, Value) {
+    send(app, http::Method::POST, uri, None, Some(body)).await
+}
+
+async fn send(
+    app: &axum::Router,
+    method: http::Method,
+    uri: &str,
+    bearer: Option<&str>,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let mut builder = Request::builder().method(method).uri(uri);
+    if let Some(token) = bearer {
+        builder = builder.header(http::header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+    let body = match body {
+        Some(body) => {
+            builder = builder.header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref());
+            Body::from(body.to_string())
+        }
+        None => Body::empty(),
+    };
+    let response = app
+        .clone()
+        .oneshot(builder.body(body).unwrap())
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body = serde_json::from_slice(&body_bytes).unwrap_or(Value::Null);
+    (status, body)
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_refresh_token_rotation_e2e(pool: sqlx::PgPool) {
+    let app = setup_app(pool).await;
+    let credentials = json!({ "email": "refresh@example.com", "password": "Password123!" });
+
+    let (status, _) = post_json(&app, "/api/v1/auth/signup", credentials.clone()).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, login) = post_json(&app, "/api/v1/auth/login", credentials).await;
+    assert_eq!(status, StatusCode::OK);
+    let first_refresh = login["refresh_token"].as_str().unwrap().to_string();
+
+    // 1. ローテーション: 新しいトークンの組が返る
+    let (status, rotated) = post_json(
+        &app,
+        "/api/v1/auth/refresh",
+        json!({ "refresh_token": first_refresh }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let second_refresh = rotated["refresh_token"].as_str().unwrap().to_string();
+    assert_ne!(first_refresh, second_refresh);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(http::Method::GET)
+                .uri("/api/v1/users/me")
+                .header(
+                    http::header::AUTHORIZATION,
+                    format!("Bearer {}", rotated["token"].as_str().unwrap()),
+                )
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // 2. 使用済みトークンの再提示は拒否される
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/refresh",
+        json!({ "refresh_token": first_refresh }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // 3. 再利用検知によりファミリー全体が失効している
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/refresh",
+        json!({ "refresh_token": second_refresh }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_concurrent_refreshes_do_not_fork_the_family_e2e(pool: sqlx::PgPool) {
+    let app = setup_app(pool).await;
+    let credentials = json!({ "email": "race@example.com", "password": "Password123!" });
+
+    let (status, _) = post_json(&app, "/api/v1/auth/signup", credentials.clone()).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, login) = post_json(&app, "/api/v1/auth/login", credentials).await;
+    assert_eq!(status, StatusCode::OK);
+    let refresh = json!({ "refresh_token": login["refresh_token"] });
+
+    // 1. 同じトークンによる 2 つのローテーションを同時に実行する
+    let (first, second) = tokio::join!(
+        post_json(&app, "/api/v1/auth/refresh", refresh.clone()),
+        post_json(&app, "/api/v1/auth/refresh", refresh),
+    );
+    let mut statuses = [first.0, second.0];
+    statuses.sort();
+    assert_eq!(statuses, [StatusCode::OK, StatusCode::UNAUTHORIZED]);
+
+    // 2. 後続は再利用として検知され、先に発行されたトークンも失効している
+    let rotated = if first.0 == StatusCode::OK {
+        first.1
+    } else {
+        second.1
+    };
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/refresh",
+        json!({ "refresh_token": rotated["refresh_token"] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_logout_revokes_tokens_e2e(pool: sqlx::PgPool) {
+    let app = setup_app(pool).await;
+    let credentials = json!({ "email": "logout@example.com", "password": "Password123!" });
+
+    let (status, _) = post_json(&app, "/api/v1/auth/signup", credentials.clone()).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, login) = post_json(&app, "/api/v1/auth/login", credentials.clone()).await;
+    assert_eq!(status, StatusCode::OK);
+    let token = login["token"].as_str().unwrap();
+    let refresh_token = login["refresh_token"].as_str().unwrap();
+
+    // 1. ログアウト前はアクセストークンが有効
+    let (status, _) = send(
+        &app,
+        http::Method::GET,
+        "/api/v1/users/me",
+        Some(token),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // 2. ログアウト（リフレッシュトークンも併せて失効）
+    let (status, _) = send(
+        &app,
+        http::Method::POST,
+        "/api/v1/auth/logout",
+        Some(token),
+        Some(json!({ "refresh_token": refresh_token })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    // 3. 有効期限内でもアクセストークンは拒否される
+    let (status, _) = send(
+        &app,
+        http::Method::GET,
+        "/api/v1/users/me",
+        Some(token),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // 4. リフレッシュトークンも拒否される
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/refresh",
+        json!({ "refresh_token": refresh_token }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // 5. ボディなしのログアウトも受け付ける
+    let (_, login) = post_json(&app, "/api/v1/auth/login", credentials).await;
+    let (status, _) = send(
+        &app,
+        http::Method::POST,
+        "/api/v1/auth/logout",
+        login["token"].as_str(),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+}
+
+/// テスト側で RFC 6238 の TOTP コードを計算する（`offset` は現在からのステップ差）
+fn totp_code(secret: &str, offset: u64) -> String {
+    use hmac::{Hmac, Mac};
+
+    let key = data_encoding::BASE32_NOPAD
+        .decode(secret.as_bytes())
+        .unwrap();
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let step = now / 30 + offset;
+
+    let mut mac = Hmac::<sha1::Sha1>::new_from_slice(&key).unwrap();
+    mac.update(&step.to_be_bytes());
+    let digest = mac.finalize().into_bytes();
+    let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        digest[offset] & 0x7f,
+        digest[offset + 1],
+        digest[offset + 2],
+        digest[offset + 3],
+    ]);
+    format!("{:06}", binary % 1_000_000)
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_totp_mfa_login_e2e(pool: sqlx::PgPool) {
+    let app = setup_app(pool).await;
+    let credentials = json!({ "email": "mfa@example.com", "password": "Password123!" });
+
+    let (status, _) = post_json(&app, "/api/v1/auth/signup", credentials.clone()).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, login) = post_json(&app, "/api/v1/auth/login", credentials.clone()).await;
+    assert_eq!(status, StatusCode::OK);
+    let token = login["token"].as_str().unwrap();
+
+    // 1. TOTP の登録を開始する
+    let (status, enrollment) = send(
+        &app,
+        http::Method::POST,
+        "/api/v1/users/me/mfa/totp",
+        Some(token),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let secret = enrollment["secret"].as_str().unwrap();
+    assert!(
+        enrollment["otpauth_uri"]
+            .as_str()
+            .unwrap()
+            .starts_with("otpauth://totp/e2e:mfa%40example.com?")
+    );
+
+    // 2. 誤ったコードでは確定できない
+    let wrong_code = if totp_code(secret, 0) == "000000" {
+        "111111"
+    } else {
+        "000000"
+    };
+    let (status, _) = send(
+        &app,
+        http::Method::POST,
+        "/api/v1/users/me/mfa/totp/confirm",
+        Some(token),
+        Some(json!({ "code": wrong_code })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // 3. 正しいコードで確定すると、リカバリーコードが発行される
+    let confirm_code = totp_code(secret, 0);
+    let (status, confirmed) = send(
+        &app,
+        http::Method::POST,
+        "/api/v1/users/me/mfa/totp/confirm",
+        Some(token),
+        Some(json!({ "code": confirm_code })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let recovery_codes = confirmed["recovery_codes"].as_array().unwrap().clone();
+    assert_eq!(recovery_codes.len(), 10);
+
+    // 4. パスワード認証のみではトークンは発行されず、チャレンジが返る
+    let (status, challenge) = post_json(&app, "/api/v1/auth/login", credentials.clone()).await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    assert!(challenge.get("token").is_none());
+    let mfa_token = challenge["mfa_token"].as_str().unwrap();
+
+    // 5. 確定に使ったコードは再利用できない
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/login/mfa",
+        json!({ "mfa_token": mfa_token, "code": confirm_code }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // 6. 次のステップのコード（許容範囲内）でログインが完了する
+    let (status, login) = post_json(
+        &app,
+        "/api/v1/auth/login/mfa",
+        json!({ "mfa_token": mfa_token, "code": totp_code(secret, 1) }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(
+        &app,
+        http::Method::GET,
+        "/api/v1/users/me",
+        login["token"].as_str(),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // 7. チャレンジは使い捨て
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/login/mfa",
+        json!({ "mfa_token": mfa_token, "code": totp_code(secret, 2) }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // 8. 認証アプリの代わりにリカバリーコードでログインできる
+    let (_, challenge) = post_json(&app, "/api/v1/auth/login", credentials.clone()).await;
+    let (status, login) = post_json(
+        &app,
+        "/api/v1/auth/login/mfa",
+        json!({ "mfa_token": challenge["mfa_token"], "code": recovery_codes[0] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let token = login["token"].as_str().unwrap();
+
+    let (status, recovery_status) = send(
+        &app,
+        http::Method::GET,
+        "/api/v1/users/me/mfa/recovery-codes",
+        Some(token),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(recovery_status["remaining"], 9);
+
+    // 9. 使用済みのリカバリーコードは再利用できない
+    let (_, challenge) = post_json(&app, "/api/v1/auth/login", credentials).await;
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/login/mfa",
+        json!({ "mfa_token": challenge["mfa_token"], "code": recovery_codes[0] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // 10. 再発行すると残数が戻る
+    let (status, regenerated) = send(
+        &app,
+        http::Method::POST,
+        "/api/v1/users/me/mfa/recovery-codes",
+        Some(token),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(regenerated["recovery_codes"].as_array().unwrap().len(), 10);
+    let (_, recovery_status) = send(
+        &app,
+        http::Method::GET,
+        "/api/v1/users/me/mfa/recovery-codes",
+        Some(token),
+        None,
+    )
+    .await;
+    assert_eq!(recovery_status["remaining"], 10);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_password_reset_e2e(pool: sqlx::PgPool) {
+    let mailer = Arc::new(InMemoryMailer::new());
+    let app = setup_app_with(
+        pool,
+        mailer.clone(),
+        EmailVerificationPolicy::Optional,
+        SignupDisclosurePolicy::Disclose,
+    )
+    .await;
+    let email = "reset@example.com";
+    let old_password = "Password123!";
+    let new_password = "NewPassword456!";
+
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/signup",
+        json!({ "email": email, "password": old_password }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    // 1. リセット要求 → メールでトークンが届く
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/password-reset",
+        json!({ "email": email }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    let sent = mailer.sent();
+    assert_eq!(sent.last().unwrap().to.as_ref(), email);
+    let tokens = password_reset_tokens(&mailer);
+    assert_eq!(tokens.len(), 1);
+    let token = tokens[0].clone();
+
+    // 2. トークンで新しいパスワードを設定
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/password-reset/confirm",
+        json!({ "token": token, "new_password": new_password }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    // 3. 新しいパスワードでのみログインできる
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/login",
+        json!({ "email": email, "password": new_password }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/login",
+        json!({ "email": email, "password": old_password }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // 4. トークンは再利用できない
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/password-reset/confirm",
+        json!({ "token": token, "new_password": "Another789!" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // 5. 未登録のメールアドレスでも同じ応答を返し、メールは送信しない
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/password-reset",
+        json!({ "email": "unknown@example.com" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    assert_eq!(password_reset_tokens(&mailer).len(), 1);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_concurrent_password_resets_use_the_token_once_e2e(pool: sqlx::PgPool) {
+    let mailer = Arc::new(InMemoryMailer::new());
+    let app = setup_app_with(
+        pool,
+        mailer.clone(),
+        EmailVerificationPolicy::Optional,
+        SignupDisclosurePolicy::Disclose,
+    )
+    .await;
+    let email = "reset-race@example.com";
+
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/signup",
+        json!({ "email": email, "password": "Password123!" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/password-reset",
+        json!({ "email": email }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    let token = password_reset_tokens(&mailer)[0].clone();
+
+    // 同じトークンによる 2 つの再設定を同時に実行すると、一方のみが成功する
+    let (first, second) = tokio::join!(
+        post_json(
+            &app,
+            "/api/v1/auth/password-reset/confirm",
+            json!({ "token": token, "new_password": "First-Secret42" }),
+        ),
+        post_json(
+            &app,
+            "/api/v1/auth/password-reset/confirm",
+            json!({ "token": token, "new_password": "Second-Secret42" }),
+        ),
+    );
+    let mut statuses = [first.0, second.0];
+    statuses.sort();
+    assert_eq!(statuses, [StatusCode::NO_CONTENT, StatusCode::BAD_REQUEST]);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_email_verification_e2e(pool: sqlx::PgPool) {
+    let mailer = Arc::new(InMemoryMailer::new());
+    let app = setup_app_with(
+        pool,
+        mailer.clone(),
+        EmailVerificationPolicy::Required,
+        SignupDisclosurePolicy::Disclose,
+    )
+    .await;
+    let credentials = json!({ "email": "verify@example.com", "password": "Password123!" });
+
+    // 1. 登録時に確認メールが送信され、確認前はログインできない
+    let (status, _) = post_json(&app, "/api/v1/auth/signup", credentials.clone()).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let tokens = verification_tokens(&mailer);
+    assert_eq!(tokens.len(), 1);
+
+    let (status, _) = post_json(&app, "/api/v1/auth/login", credentials.clone()).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // 2. クールダウン中の再送要求は受け付けるが、メールは送信しない
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/verify-email/resend",
+        json!({ "email": "verify@example.com" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    assert_eq!(verification_tokens(&mailer).len(), 1);
+
+    // 3. 不正なトークンは拒否される
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/verify-email",
+        json!({ "token": "not-a-token" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // 4. 確認後はログインできる
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/verify-email",
+        json!({ "token": tokens[0] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = post_json(&app, "/api/v1/auth/login", credentials).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+/// ヘッダーを付けてリクエストを送信し、ステータス・`ETag`・ボディを返す。
+async fn send_with_headers(
+    app: &axum::Router,
+    method: http::Method,
+    uri: &str,
+    token: &str,
+    headers: &[(http::header::HeaderName, &str)],
+) -> (StatusCode, Option<String>, Value) {
+    let mut builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header(http::header::AUTHORIZATION, format!("Bearer {}", token));
+    for (name, value) in headers {
+        builder = builder.header(name, *value);
+    }
+    let response = app
+        .clone()
+        .oneshot(builder.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let etag = response
+        .headers()
+        .get(http::header::ETAG)
+        .map(|value| value.to_str().unwrap().to_string());
+    let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body = serde_json::from_slice(&body_bytes).unwrap_or(Value::Null);
+    (status, etag, body)
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_user_etag_and_if_match_e2e(pool: sqlx::PgPool) {
+    let mailer = Arc::new(InMemoryMailer::new());
+    let app = setup_app_with(
+        pool,
+        mailer.clone(),
+        EmailVerificationPolicy::Optional,
+        SignupDisclosurePolicy::Disclose,
+    )
+    .await;
+    let credentials = json!({ "email": "etag@example.com", "password": "Password123!" });
+
+    let (status, _) = post_json(&app, "/api/v1/auth/signup", credentials.clone()).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, login) = post_json(&app, "/api/v1/auth/login", credentials).await;
+    assert_eq!(status, StatusCode::OK);
+    let token = login["token"].as_str().unwrap();
+
+    // 1. プロフィールと現在のバージョンが取得できる
+    let (status, etag, me) =
+        send_with_headers(&app, http::Method::GET, "/api/v1/users/me", token, &[]).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(me["email"], "etag@example.com");
+    assert_eq!(me["email_verified"], false);
+    let first_etag = etag.unwrap();
+    assert_eq!(first_etag, "\"1\"");
+
+    // 2. メールアドレスの確認でユーザーが更新され、バージョンが進む
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/verify-email",
+        json!({ "token": verification_tokens(&mailer)[0] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, etag, me) =
+        send_with_headers(&app, http::Method::GET, "/api/v1/users/me", token, &[]).await;
+    assert_eq!(me["email_verified"], true);
+    let current_etag = etag.unwrap();
+    assert_eq!(current_etag, "\"2\"");
+
+    // 3. 古いバージョンを指定した変更は拒否される
+    let (status, _, _) = send_with_headers(
+        &app,
+        http::Method::POST,
+        "/api/v1/users/me/mfa/totp",
+        token,
+        &[(http::header::IF_MATCH, first_etag.as_str())],
+    )
+    .await;
+    assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+
+    // 4. 不正な形式の If-Match は 400
+    let (status, _, _) = send_with_headers(
+        &app,
+        http::Method::POST,
+        "/api/v1/users/me/mfa/totp",
+        token,
+        &[(http::header::IF_MATCH, "2")],
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // 5. 最新のバージョンを指定すれば受け付けられる
+    let (status, _, _) = send_with_headers(
+        &app,
+        http::Method::POST,
+        "/api/v1/users/me/mfa/totp",
+        token,
+        &[(http::header::IF_MATCH, current_etag.as_str())],
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_permissions_are_enforced_e2e(pool: sqlx::PgPool) {
+    let app = setup_app(pool.clone()).await;
+    let credentials = json!({ "email": "rbac@example.com", "password": "Password123!" });
+
+    let (status, _) = post_json(&app, "/api/v1/auth/signup", credentials.clone()).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, login) = post_json(&app, "/api/v1/auth/login", credentials.clone()).await;
+    assert_eq!(status, StatusCode::OK);
+    let token = login["token"].as_str().unwrap();
+
+    // 1. 一般ユーザーのロールで自分のプロフィールを参照・変更できる
+    let (status, _) = send(
+        &app,
+        http::Method::GET,
+        "/api/v1/users/me",
+        Some(token),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // 2. ロールを失うと、発行済みのトークンでも直ちに 403 になる
+    sqlx::query("DELETE FROM user_roles WHERE user_id = $1::uuid")
+        .bind(login["id"].as_str().unwrap())
+        .execute(&pool)
+        .await
+        .unwrap();
+    let (status, _) = send(
+        &app,
+        http::Method::GET,
+        "/api/v1/users/me",
+        Some(token),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // リフレッシュ後のトークンも同じく 403 になる
+    let (status, rotated) = post_json(
+        &app,
+        "/api/v1/auth/refresh",
+        json!({ "refresh_token": login["refresh_token"] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let token = rotated["token"].as_str().unwrap();
+
+    let (status, _) = send(
+        &app,
+        http::Method::GET,
+        "/api/v1/users/me",
+        Some(token),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = send(
+        &app,
+        http::Method::POST,
+        "/api/v1/users/me/mfa/totp",
+        Some(token),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // 3. 新しくログインしたトークンにも権限は含まれない
+    let (status, login) = post_json(&app, "/api/v1/auth/login", credentials).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(
+        &app,
+        http::Method::GET,
+        "/api/v1/users/me/mfa/recovery-codes",
+        login["token"].as_str(),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+/// ユーザーに管理者ロールを付与する（管理者を作成する API は無いため直接登録する）。
+async fn grant_admin(pool: &sqlx::PgPool, user_id: &str) {
+    sqlx::query(
+        r#"
+        INSERT INTO user_roles (
+            user_id, role,
+            created_at, created_by, created_pgm_cd, created_tx_id,
+            updated_at, updated_by, updated_pgm_cd, updated_tx_id
+        ) VALUES ($1::uuid, 'admin', now(), 'e2e', 'e2e', 'e2e', now(), 'e2e', 'e2e', 'e2e')
+        "#,
+    )
+    .bind(user_id)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_admin_user_management_e2e(pool: sqlx::PgPool) {
+    let mailer = Arc::new(InMemoryMailer::new());
+    let app = setup_app_with(
+        pool.clone(),
+        mailer.clone(),
+        EmailVerificationPolicy::Optional,
+        SignupDisclosurePolicy::Disclose,
+    )
+    .await;
+    let admin_credentials = json!({ "email": "admin@example.com", "password": "Password123!" });
+    let target_credentials = json!({ "email": "target@example.com", "password": "Password123!" });
+
+    for credentials in [&admin_credentials, &target_credentials] {
+        let (status, _) = post_json(&app, "/api/v1/auth/signup", credentials.clone()).await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+    let (_, target_login) = post_json(&app, "/api/v1/auth/login", target_credentials.clone()).await;
+    let target_id = target_login["id"].as_str().unwrap().to_string();
+    let (_, admin_login) = post_json(&app, "/api/v1/auth/login", admin_credentials.clone()).await;
+    grant_admin(&pool, admin_login["id"].as_str().unwrap()).await;
+
+    // 1. 一般ユーザーは管理 API を利用できない
+    let (status, _) = send(
+        &app,
+        http::Method::GET,
+        "/api/v1/admin/users",
+        target_login["token"].as_str(),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // 管理者ロールは次に発行されるトークンから有効になる
+    let (_, admin_login) = post_json(&app, "/api/v1/auth/login", admin_credentials).await;
+    let admin = admin_login["token"].as_str().unwrap();
+    let admin_id = admin_login["id"].as_str().unwrap();
+
+    // 2. カーソルによるページングと絞り込み
+    let (status, page) = send(
+        &app,
+        http::Method::GET,
+        "/api/v1/admin/users?limit=1",
+        Some(admin),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(page["users"][0]["email"], "admin@example.com");
+    assert_eq!(page["users"][0]["roles"], json!(["user", "admin"]));
+    let cursor = page["next_cursor"].as_str().unwrap();
+    let (_, page) = send(
+        &app,
+        http::Method::GET,
+        &format!("/api/v1/admin/users?limit=1&cursor={}", cursor),
+        Some(admin),
+        None,
+    )
+    .await;
+    assert_eq!(page["users"][0]["user_id"], target_id.as_str());
+    assert_eq!(page["next_cursor"], Value::Null);
+
+    let (_, page) = send(
+        &app,
+        http::Method::GET,
+        "/api/v1/admin/users?email_prefix=targ",
+        Some(admin),
+        None,
+    )
+    .await;
+    assert_eq!(page["users"].as_array().unwrap().len(), 1);
+
+    let (status, _) = send(
+        &app,
+        http::Method::GET,
+        "/api/v1/admin/users?limit=0",
+        Some(admin),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // 3. 停止するとリフレッシュトークンが失効する
+    let user_uri = format!("/api/v1/admin/users/{}", target_id);
+    let (status, etag, user) =
+        send_with_headers(&app, http::Method::GET, &user_uri, admin, &[]).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(user["status"], "active");
+    let etag = etag.unwrap();
+
+    let (status, _, _) = send_with_headers(
+        &app,
+        http::Method::POST,
+        &format!("{}/suspend", user_uri),
+        admin,
+        &[(http::header::IF_MATCH, etag.as_str())],
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/refresh",
+        json!({ "refresh_token": target_login["refresh_token"] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // 発行済みのアクセストークンも、停止後は利用できない
+    let (status, _) = send(
+        &app,
+        http::Method::GET,
+        "/api/v1/users/me",
+        target_login["token"].as_str(),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = post_json(&app, "/api/v1/auth/login", target_credentials.clone()).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (_, page) = send(
+        &app,
+        http::Method::GET,
+        "/api/v1/admin/users?status=suspended",
+        Some(admin),
+        None,
+    )
+    .await;
+    assert_eq!(page["users"][0]["user_id"], target_id.as_str());
+
+    let (status, _) = send(
+        &app,
+        http::Method::POST,
+        &format!("{}/suspend", user_uri),
+        Some(admin),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, _) = send(
+        &app,
+        http::Method::POST,
+        &format!("{}/reactivate", user_uri),
+        Some(admin),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    // 4. パスワードリセットのメールが送付され、再設定されるまで現在のパスワードではログインできない
+    let (status, _) = send(
+        &app,
+        http::Method::POST,
+        &format!("{}/password-reset", user_uri),
+        Some(admin),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert_eq!(password_reset_tokens(&mailer).len(), 1);
+    let (status, _) = post_json(&app, "/api/v1/auth/login", target_credentials.clone()).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // 5. 古いバージョンを指定した削除は拒否され、自分自身は削除できない
+    let (status, _, _) = send_with_headers(
+        &app,
+        http::Method::DELETE,
+        &user_uri,
+        admin,
+        &[(http::header::IF_MATCH, etag.as_str())],
+    )
+    .await;
+    assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+
+    let (status, _) = send(
+        &app,
+        http::Method::DELETE,
+        &format!("/api/v1/admin/users/{}", admin_id),
+        Some(admin),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = send(&app, http::Method::DELETE, &user_uri, Some(admin), None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, user) = send(&app, http::Method::GET, &user_uri, Some(admin), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(user["status"], "deleted");
+    let (status, _) = post_json(&app, "/api/v1/auth/login", target_credentials).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = send(&app, http::Method::DELETE, &user_uri, Some(admin), None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+/// 接続元のアドレスを付与してログインし、ステータスと `Retry-After` を返す。
+async fn login_from(
+    app: &axum::Router,
+    client_ip: &str,
+    credentials: Value,
+) -> (StatusCode, Option<String>) {
+    let addr: std::net::SocketAddr = format!("{}:40000", client_ip).parse().unwrap();
+    let request = Request::builder()
+        .method(http::Method::POST)
+        .uri("/api/v1/auth/login")
+        .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+        .extension(axum::extract::ConnectInfo(addr))
+        .body(Body::from(credentials.to_string()))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let retry_after = response
+        .headers()
+        .get(http::header::RETRY_AFTER)
+        .map(|v| v.to_str().unwrap().to_string());
+    (response.status(), retry_after)
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_login_lockout_e2e(pool: sqlx::PgPool) {
+    let app = setup_app(pool.clone()).await;
+    let credentials = json!({ "email": "lockout@example.com", "password": "Password123!" });
+    let wrong = json!({ "email": "lockout@example.com", "password": "WrongPassword!" });
+
+    let (status, _) = post_json(&app, "/api/v1/auth/signup", credentials.clone()).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    // 1. 上限までの失敗は 401、以降は正しいパスワードでも 423 と Retry-After を返す
+    for _ in 0..5 {
+        let (status, _) = login_from(&app, "192.0.2.1", wrong.clone()).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+    let (status, retry_after) = login_from(&app, "192.0.2.1", credentials.clone()).await;
+    assert_eq!(status, StatusCode::LOCKED);
+    let retry_after: u64 = retry_after.unwrap().parse().unwrap();
+    assert!((1..=30).contains(&retry_after));
+    // 管理者から見たアカウントの状態もロック中になる
+    let status: String =
+        sqlx::query_scalar("SELECT status FROM users WHERE email = 'lockout@example.com'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(status, "locked");
+
+    // 2. 同じ接続元から多数のアカウントを試すと 429 になる（未登録のアカウントも集計する）
+    for i in 0..20 {
+        let guess = json!({ "email": format!("guess{}@example.com", i), "password": "x" });
+        let (status, _) = login_from(&app, "192.0.2.2", guess).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+    let guess = json!({ "email": "guess-last@example.com", "password": "x" });
+    let (status, retry_after) = login_from(&app, "192.0.2.2", guess.clone()).await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert!(retry_after.is_some());
+
+    // 他の接続元には影響しない
+    let (status, _) = login_from(&app, "192.0.2.3", guess).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_signup_enumeration_protection_e2e(pool: sqlx::PgPool) {
+    let mailer = Arc::new(InMemoryMailer::new());
+    let app = setup_app_with(
+        pool,
+        mailer.clone(),
+        EmailVerificationPolicy::Optional,
+        SignupDisclosurePolicy::Conceal,
+    )
+    .await;
+    let credentials = json!({ "email": "conceal@example.com", "password": "Password123!" });
+
+    // 1. 新規登録も登録済みの再登録も同じく 202 を返す
+    let (status, _) = post_json(&app, "/api/v1/auth/signup", credentials.clone()).await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    let retry = json!({ "email": "conceal@example.com", "password": "Another456!" });
+    let (status, _) = post_json(&app, "/api/v1/auth/signup", retry.clone()).await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+
+    // 2. 所有者へ通知が送られ、既存のアカウントは変更されない
+    let notices: Vec<_> = mailer
+        .sent()
+        .into_iter()
+        .filter(|mail| matches!(mail.message, MailMessage::SignupAttempted { .. }))
+        .collect();
+    assert_eq!(notices.len(), 1);
+    assert_eq!(notices[0].to.as_ref(), "conceal@example.com");
+
+    let (status, _) = post_json(&app, "/api/v1/auth/login", credentials).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = post_json(&app, "/api/v1/auth/login", retry).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+/// 同じリクエストを繰り返し、最も短かった所要時間を返す。
+async fn min_login_duration(app: &axum::Router, credentials: Value) -> std::time::Duration {
+    let mut fastest = std::time::Duration::MAX;
+    for _ in 0..3 {
+        let started = std::time::Instant::now();
+        let (status, _) = post_json(app, "/api/v1/auth/login", credentials.clone()).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        fastest = fastest.min(started.elapsed());
+    }
+    fastest
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_login_timing_does_not_reveal_account_existence(pool: sqlx::PgPool) {
+    let app = setup_app(pool).await;
+    let credentials = json!({ "email": "timing@example.com", "password": "Password123!" });
+    let (status, _) = post_json(&app, "/api/v1/auth/signup", credentials).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let wrong_password = min_login_duration(
+        &app,
+        json!({ "email": "timing@example.com", "password": "WrongPassword!" }),
+    )
+    .await;
+    let unknown_account = min_login_duration(
+        &app,
+        json!({ "email": "nobody@example.com", "password": "WrongPassword!" }),
+    )
+    .await;
+
+    // どちらも Argon2 の照合が支配的になるため、所要時間は同程度になる
+    let ratio = unknown_account.as_secs_f64() / wrong_password.as_secs_f64();
+    assert!(
+        (0.5..2.0).contains(&ratio),
+        "unknown account: {:?}, wrong password: {:?}",
+        unknown_account,
+        wrong_password
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_signup_reports_password_policy_violations(pool: sqlx::PgPool) {
+    let app = setup_app(pool).await;
+
+    let (status, body) = post_json(
+        &app,
+        "/api/v1/auth/signup",
+        json!({ "email": "policy@example.com", "password": "policy" }),
+    )
+    .await;
+
+    // 違反した規則がまとめて返される
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let codes: Vec<&str> = body["error"]["violations"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| v["code"].as_str().unwrap())
+        .collect();
+    assert_eq!(codes, ["too_short", "too_weak", "contains_email"]);
+
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/signup",
+        json!({ "email": "policy@example.com", "password": "Password123!" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_signup_rejects_breached_password(pool: sqlx::PgPool) {
+    let app = setup_app(pool).await;
+
+    let (status, body) = post_json(
+        &app,
+        "/api/v1/auth/signup",
+        json!({ "email": "breached@example.com", "password": "Qwerty123!" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(
+        body["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("data breach")
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_change_password_e2e(pool: sqlx::PgPool) {
+    let app = setup_app(pool).await;
+    let credentials = json!({ "email": "change@example.com", "password": "Password123!" });
+
+    let (status, _) = post_json(&app, "/api/v1/auth/signup", credentials.clone()).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (_, current) = post_json(&app, "/api/v1/auth/login", credentials.clone()).await;
+    let (_, other) = post_json(&app, "/api/v1/auth/login", credentials.clone()).await;
+    let token = current["token"].as_str().unwrap();
+
+    // 1. 現在のパスワードが誤っている場合は変更できない
+    let (status, _) = send(
+        &app,
+        http::Method::PUT,
+        "/api/v1/users/me/password",
+        Some(token),
+        Some(json!({ "current_password": "Wrong123!", "new_password": "Fresh-Secret42" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    // 2. ポリシーに違反する新しいパスワードは違反内容とともに拒否される
+    let (status, body) = send(
+        &app,
+        http::Method::PUT,
+        "/api/v1/users/me/password",
+        Some(token),
+        Some(json!({ "current_password": "Password123!", "new_password": "short" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(
+        body["error"]["violations"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|v| v["code"] == "too_short")
+    );
+
+    // 3. 変更すると新しいセッションが返る
+    // （トークンの発行日時は秒単位のため、他の端末のログインとは秒をまたいで変更する）
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+    let (status, changed) = send(
+        &app,
+        http::Method::PUT,
+        "/api/v1/users/me/password",
+        Some(token),
+        Some(json!({ "current_password": "Password123!", "new_password": "Fresh-Secret42" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(
+        &app,
+        http::Method::GET,
+        "/api/v1/users/me",
+        changed["token"].as_str(),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // 4. 変更前に発行したアクセストークンと、他の端末のリフレッシュトークンは失効する
+    for access_token in [&current["token"], &other["token"]] {
+        let (status, _) = send(
+            &app,
+            http::Method::GET,
+            "/api/v1/users/me",
+            access_token.as_str(),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+    for refresh_token in [&current["refresh_token"], &other["refresh_token"]] {
+        let (status, _) = post_json(
+            &app,
+            "/api/v1/auth/refresh",
+            json!({ "refresh_token": refresh_token }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/refresh",
+        json!({ "refresh_token": changed["refresh_token"] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // 5. 以前のパスワードではログインできない
+    let (status, _) = post_json(&app, "/api/v1/auth/login", credentials).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, _) = post_json(
+        &app,
+        "/api/v1/auth/login",
+        json!({ "email": "change@example.com", "password": "Fresh-Secret42" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_discovery_endpoints_e2e(pool: sqlx::PgPool) {
+    let app = setup_app(pool).await;
+
+    // 1. JWKS は署名鍵の公開鍵のみを公開する
+    let (status, jwks) = send(
+        &app,
+        http::Method::GET,
+        "/.well-known/jwks.json",
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let keys = jwks["keys"].as_array().unwrap();
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0]["kid"], "test-1");
+    assert_eq!(keys[0]["kty"], "OKP");
+    assert_eq!(keys[0]["crv"], "Ed25519");
+    assert_eq!(keys[0]["alg"], "EdDSA");
+    assert_eq!(keys[0]["use"], "sig");
+    assert!(keys[0]["x"].is_string());
+    assert!(keys[0].get("d").is_none());
+
+    // 2. 発行したアクセストークンのヘッダーは公開鍵の kid を指す
+    let credentials = json!({ "email": "discovery@example.com", "password": "Password123!" });
+    let (status, _) = post_json(&app, "/api/v1/auth/signup", credentials.clone()).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, login) = post_json(&app, "/api/v1/auth/login", credentials).await;
+    assert_eq!(status, StatusCode::OK);
+    let token = login["token"].as_str().unwrap();
+    let mut segments = token.split('.').map(|segment| {
+        let json = data_encoding::BASE64URL_NOPAD
+            .decode(segment.as_bytes())
+            .unwrap();
+        serde_json::from_slice::<Value>(&json).unwrap()
+    });
+    let header = segments.next().unwrap();
+    assert_eq!(header["kid"], "test-1");
+    assert_eq!(header["alg"], "EdDSA");
+    let payload = segments.next().unwrap();
+    assert_eq!(payload["iss"], common::ISSUER);
+    assert_eq!(payload["aud"], json!([common::AUDIENCE]));
+
+    // 3. ディスカバリーは発行者とエンドポイント、署名アルゴリズムを示す
+    let (status, configuration) = send(
+        &app,
+        http::Method::GET,
+        "/.well-known/openid-configuration",
+        None,
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(configuration["issuer"], common::ISSUER);
+    assert_eq!(
+        configuration["jwks_uri"],
+        format!("{}/.well-known/jwks.json", common::ISSUER)
+    );
+    assert_eq!(
+        configuration["token_endpoint"],
+        format!("{}/api/v1/auth/login", common::ISSUER)
+    );
+    assert_eq!(
+        configuration["userinfo_endpoint"],
+        format!("{}/api/v1/users/me", common::ISSUER)
+    );
+    assert_eq!(
+        configuration["revocation_endpoint"],
+        format!("{}/api/v1/auth/logout", common::ISSUER)
+    );
+    assert_eq!(configuration["response_types_supported"], json!(["token"]));
+    assert_eq!(configuration["subject_types_supported"], json!(["public"]));
+    assert_eq!(
+        configuration["id_token_signing_alg_values_supported"],
+        json!(["EdDSA"])
+    );
+}
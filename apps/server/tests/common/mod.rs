@@ -0,0 +1,225 @@
+//! 複数のテストクレートで共有するアプリケーションの組み立て。
+// テストクレートごとに使用する関数が異なるため、未使用の警告を抑制する
+#![allow(dead_code)]
+
+use api::{AppState, create_router};
+use domain::models::auth::{LoginThrottleConfig, PasswordPolicy};
+use domain::models::user::service::UserUniquenessCheckerImpl;
+use domain::models::user::{EmailVerificationPolicy, SignupDisclosurePolicy};
+use infrastructure::auth::breached_password::InMemoryBreachedPasswordChecker;
+use infrastructure::auth::email_verification::JwtEmailVerificationTokenService;
+use infrastructure::auth::jwt::{JwtAuthService, JwtConfig, JwtKeyRing};
+use infrastructure::auth::login_attempt::PgLoginAttemptStore;
+use infrastructure::auth::opaque_token::RandomOpaqueTokenService;
+use infrastructure::auth::password::Argon2PasswordService;
+use infrastructure::auth::recovery_code::RandomRecoveryCodeGenerator;
+use infrastructure::auth::revocation::PgTokenRevocationStore;
+use infrastructure::auth::totp::RfcTotpService;
+use infrastructure::mail::InMemoryMailer;
+use infrastructure::repository::tx::SqlxTransactionManager;
+use std::sync::Arc;
+
+/// テストで使用するアクセストークンの発行者
+pub const ISSUER: &str = "https://auth.example.com";
+
+/// テストで使用するアクセストークンの受信者
+pub const AUDIENCE: &str = "auth-template";
+use usecase::auth::{
+    AuthCommandUseCaseImpl, AuthQueryUseCaseImpl, AuthenticateUseCaseImpl, DiscoveryUseCaseImpl,
+    EmailVerificationUseCaseImpl, LogoutUseCaseImpl, MfaEnrollmentUseCaseImpl, MfaLoginUseCaseImpl,
+    PasswordChangeUseCaseImpl, PasswordResetUseCaseImpl, SignupAttemptMailHandler,
+    TokenRefreshUseCaseImpl, VerificationMailHandler,
+};
+use usecase::event::{EventSink, InProcessEventSink};
+use usecase::user::{UserAdminUseCaseImpl, UserProfileUseCaseImpl};
+
+pub async fn setup_app(pool: sqlx::PgPool) -> axum::Router {
+    setup_app_with(
+        pool,
+        Arc::new(InMemoryMailer::new()),
+        EmailVerificationPolicy::Optional,
+        SignupDisclosurePolicy::Disclose,
+    )
+    .await
+}
+
+pub async fn setup_app_with(
+    pool: sqlx::PgPool,
+    mailer: Arc<InMemoryMailer>,
+    email_verification_policy: EmailVerificationPolicy,
+    signup_disclosure_policy: SignupDisclosurePolicy,
+) -> axum::Router {
+    build_app(
+        pool,
+        mailer,
+        email_verification_policy,
+        signup_disclosure_policy,
+        Arc::new(Argon2PasswordService::new()),
+    )
+}
+
+pub fn build_app(
+    pool: sqlx::PgPool,
+    mailer: Arc<InMemoryMailer>,
+    email_verification_policy: EmailVerificationPolicy,
+    signup_disclosure_policy: SignupDisclosurePolicy,
+    password_service: Arc<Argon2PasswordService>,
+) -> axum::Router {
+    let clock = Arc::new(infrastructure::clock::RealClock);
+    let id_generator = Arc::new(infrastructure::id::UuidV7Generator::new());
+    let tx_manager = Arc::new(SqlxTransactionManager::new(pool.clone(), clock.clone()));
+    let uniqueness_checker = Arc::new(UserUniquenessCheckerImpl::new());
+    let token_service = Arc::new(RandomOpaqueTokenService::new());
+    let jwt_key_ring = JwtKeyRing::from_file(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/jwt/keys.txt"
+    ))
+    .unwrap();
+    let jwt_config = JwtConfig {
+        issuer: Some(ISSUER.to_string()),
+        audiences: vec![AUDIENCE.to_string()],
+        ..JwtConfig::default()
+    };
+    let auth_service = Arc::new(JwtAuthService::with_config(
+        jwt_key_ring,
+        jwt_config,
+        clock.clone(),
+    ));
+    let verification_token_service = Arc::new(JwtEmailVerificationTokenService::new("test-secret"));
+    let revocation_store = Arc::new(PgTokenRevocationStore::new(pool.clone(), clock.clone()));
+    let login_attempt_store = Arc::new(PgLoginAttemptStore::new(pool));
+    let totp_service = Arc::new(RfcTotpService::new("e2e"));
+    let breached_password_checker = Arc::new(InMemoryBreachedPasswordChecker::new([
+        "Password1!",
+        "Qwerty123!",
+    ]));
+
+    let event_sink: Arc<dyn EventSink> = Arc::new(InProcessEventSink::new(vec![
+        Arc::new(VerificationMailHandler::new(
+            verification_token_service.clone(),
+            mailer.clone(),
+        )),
+        Arc::new(SignupAttemptMailHandler::new(mailer.clone())),
+    ]));
+
+    let auth_command = Arc::new(AuthCommandUseCaseImpl::new(
+        tx_manager.clone(),
+        uniqueness_checker,
+        password_service.clone(),
+        breached_password_checker.clone(),
+        event_sink.clone(),
+        clock.clone(),
+        id_generator.clone(),
+        PasswordPolicy::default(),
+        signup_disclosure_policy,
+    ));
+    let auth_query = Arc::new(AuthQueryUseCaseImpl::new(
+        tx_manager.clone(),
+        password_service.clone(),
+        token_service.clone(),
+        auth_service.clone(),
+        event_sink.clone(),
+        clock.clone(),
+        id_generator.clone(),
+        email_verification_policy,
+        login_attempt_store.clone(),
+        LoginThrottleConfig::default(),
+    ));
+    let token_refresh = Arc::new(TokenRefreshUseCaseImpl::new(
+        tx_manager.clone(),
+        token_service.clone(),
+        auth_service.clone(),
+        clock.clone(),
+        id_generator.clone(),
+    ));
+    let logout = Arc::new(LogoutUseCaseImpl::new(
+        tx_manager.clone(),
+        token_service.clone(),
+        revocation_store.clone(),
+        clock.clone(),
+    ));
+    let mfa_enrollment = Arc::new(MfaEnrollmentUseCaseImpl::new(
+        tx_manager.clone(),
+        totp_service.clone(),
+        password_service.clone(),
+        Arc::new(RandomRecoveryCodeGenerator::new()),
+        clock.clone(),
+    ));
+    let mfa_login = Arc::new(MfaLoginUseCaseImpl::new(
+        tx_manager.clone(),
+        token_service.clone(),
+        totp_service,
+        password_service.clone(),
+        auth_service.clone(),
+        clock.clone(),
+        id_generator.clone(),
+    ));
+    let password_change = Arc::new(PasswordChangeUseCaseImpl::new(
+        tx_manager.clone(),
+        password_service.clone(),
+        breached_password_checker.clone(),
+        token_service.clone(),
+        auth_service.clone(),
+        revocation_store.clone(),
+        event_sink.clone(),
+        clock.clone(),
+        id_generator.clone(),
+        PasswordPolicy::default(),
+        login_attempt_store.clone(),
+        LoginThrottleConfig::default(),
+    ));
+    let password_reset = Arc::new(PasswordResetUseCaseImpl::new(
+        tx_manager.clone(),
+        password_service,
+        breached_password_checker,
+        token_service.clone(),
+        mailer.clone(),
+        event_sink.clone(),
+        clock.clone(),
+        id_generator.clone(),
+        PasswordPolicy::default(),
+    ));
+    let user_admin = Arc::new(UserAdminUseCaseImpl::new(
+        tx_manager.clone(),
+        token_service,
+        mailer.clone(),
+        event_sink.clone(),
+        login_attempt_store,
+        clock.clone(),
+        id_generator,
+    ));
+    let email_verification = Arc::new(EmailVerificationUseCaseImpl::new(
+        tx_manager.clone(),
+        verification_token_service,
+        mailer,
+        event_sink,
+        clock.clone(),
+    ));
+    let discovery = Arc::new(DiscoveryUseCaseImpl::new(auth_service.clone(), ISSUER));
+    let authenticate = Arc::new(AuthenticateUseCaseImpl::new(
+        tx_manager.clone(),
+        auth_service,
+        revocation_store,
+        clock,
+    ));
+    let user_profile = Arc::new(UserProfileUseCaseImpl::new(tx_manager));
+
+    let state = Arc::new(AppState {
+        auth_command,
+        auth_query,
+        token_refresh,
+        logout,
+        authenticate,
+        discovery,
+        mfa_enrollment,
+        mfa_login,
+        password_reset,
+        password_change,
+        email_verification,
+        user_profile,
+        user_admin,
+    });
+
+    // api ライブラリのルーター生成関数を使用
+    create_router(state)
+}
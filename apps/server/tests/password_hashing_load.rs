@@ -0,0 +1,147 @@
+mod common;
+
+use axum::{
+    body::Body,
+    http::{self, Request, StatusCode},
+};
+use domain::models::user::{EmailVerificationPolicy, SignupDisclosurePolicy};
+use infrastructure::auth::password::{Argon2Config, Argon2PasswordService, HashingPoolConfig};
+use infrastructure::mail::InMemoryMailer;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tower::ServiceExt; // for `oneshot`
+
+/// 同時に送るログインの数（計算の同時実行数を大きく上回る）
+const STORM_SIZE: usize = 40;
+
+async fn call(
+    app: &axum::Router,
+    method: http::Method,
+    uri: &str,
+    bearer: Option<&str>,
+    body: Option<Value>,
+) -> (StatusCode, Option<String>, Value) {
+    let mut builder = Request::builder().method(method).uri(uri);
+    if let Some(token) = bearer {
+        builder = builder.header(http::header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+    let body = match body {
+        Some(body) => {
+            builder = builder.header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref());
+            Body::from(body.to_string())
+        }
+        None => Body::empty(),
+    };
+    let response = app
+        .clone()
+        .oneshot(builder.body(body).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let retry_after = response
+        .headers()
+        .get(http::header::RETRY_AFTER)
+        .map(|v| v.to_str().unwrap().to_string());
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+    (status, retry_after, body)
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_other_endpoints_stay_responsive_during_login_storm(pool: sqlx::PgPool) {
+    let password_service = Arc::new(
+        Argon2PasswordService::with_config(
+            Argon2Config::default(),
+            HashingPoolConfig {
+                max_concurrency: 2,
+                queue_timeout: Duration::from_millis(50),
+            },
+            None,
+        )
+        .unwrap(),
+    );
+    let app = common::build_app(
+        pool,
+        Arc::new(InMemoryMailer::new()),
+        EmailVerificationPolicy::Optional,
+        SignupDisclosurePolicy::Disclose,
+        password_service,
+    );
+
+    let credentials = json!({ "email": "load@example.com", "password": "Password123!" });
+    let (status, _, _) = call(
+        &app,
+        http::Method::POST,
+        "/api/v1/auth/signup",
+        None,
+        Some(credentials.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, _, login) = call(
+        &app,
+        http::Method::POST,
+        "/api/v1/auth/login",
+        None,
+        Some(credentials),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let token = login["token"].as_str().unwrap().to_string();
+
+    // 1. 未登録のアカウントへのログインを集中させる（いずれもダミーのハッシュと照合する）
+    let storm: Vec<_> = (0..STORM_SIZE)
+        .map(|i| {
+            let app = app.clone();
+            tokio::spawn(async move {
+                call(
+                    &app,
+                    http::Method::POST,
+                    "/api/v1/auth/login",
+                    None,
+                    Some(json!({ "email": format!("storm-{i}@example.com"), "password": "Wrong123!" })),
+                )
+                .await
+            })
+        })
+        .collect();
+
+    // 2. その間もハッシュ計算を伴わないエンドポイントは待たされずに応答する
+    let mut slowest = Duration::ZERO;
+    for _ in 0..10 {
+        let started = Instant::now();
+        let (status, _, _) = call(
+            &app,
+            http::Method::GET,
+            "/api/v1/users/me",
+            Some(&token),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        slowest = slowest.max(started.elapsed());
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(
+        slowest < Duration::from_secs(1),
+        "GET /api/v1/users/me took {slowest:?} during the login storm"
+    );
+
+    // 3. 処理しきれないログインは 503 と Retry-After で再試行を促す
+    let mut rejected = 0;
+    for handle in storm {
+        let (status, retry_after, _) = handle.await.unwrap();
+        match status {
+            StatusCode::UNAUTHORIZED => {}
+            StatusCode::SERVICE_UNAVAILABLE => {
+                assert_eq!(retry_after.as_deref(), Some("1"));
+                rejected += 1;
+            }
+            other => panic!("unexpected status during the login storm: {other}"),
+        }
+    }
+    assert!(rejected > 0, "no login was rejected by back-pressure");
+}
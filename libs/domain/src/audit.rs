@@ -0,0 +1,68 @@
+use crate::models::user::UserId;
+use std::fmt;
+
+/// 操作の実行主体。監査カラム（`*_by`）に記録される。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Actor {
+    /// 認証済みユーザー
+    User(UserId),
+    /// 未認証のリクエスト（サインアップ、ログイン等）
+    Anonymous,
+    /// バッチ・バックグラウンド処理
+    System,
+}
+
+impl fmt::Display for Actor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::User(id) => write!(f, "{}", id),
+            Self::Anonymous => f.write_str("anonymous"),
+            Self::System => f.write_str("system"),
+        }
+    }
+}
+
+/// トランザクション単位で引き回す監査情報。
+///
+/// トランザクション ID はトランザクション管理側で採番するため、ここには含めない。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuditContext {
+    actor: Actor,
+    program_code: &'static str,
+}
+
+impl AuditContext {
+    /// `program_code` はユースケースごとに固定のプログラムコード（`*_pgm_cd`）。
+    pub fn new(actor: Actor, program_code: &'static str) -> Self {
+        Self {
+            actor,
+            program_code,
+        }
+    }
+
+    pub fn system(program_code: &'static str) -> Self {
+        Self::new(Actor::System, program_code)
+    }
+
+    pub fn actor(&self) -> Actor {
+        self.actor
+    }
+
+    pub fn program_code(&self) -> &'static str {
+        self.program_code
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_actor_display() {
+        let id = Uuid::now_v7();
+        assert_eq!(Actor::User(UserId::from(id)).to_string(), id.to_string());
+        assert_eq!(Actor::Anonymous.to_string(), "anonymous");
+        assert_eq!(Actor::System.to_string(), "system");
+    }
+}
@@ -1,5 +1,8 @@
-use crate::models::auth::PasswordServiceError;
 use crate::models::auth::error::AuthError;
+use crate::models::auth::{
+    MfaError, MfaRepositoryError, PasswordResetError, PasswordResetRepositoryError,
+    PasswordServiceError, RefreshTokenError, RefreshTokenRepositoryError, TotpCodeError,
+};
 use crate::models::user::{UserError, UserRepositoryError, UserUniquenessViolation};
 use crate::repository::tx::IntoTxError;
 use thiserror::Error;
@@ -40,6 +43,18 @@ impl From<crate::models::user::EmailError> for DomainError {
     }
 }
 
+impl From<crate::models::user::EmailVerificationError> for DomainError {
+    fn from(error: crate::models::user::EmailVerificationError) -> Self {
+        Self::User(UserError::from(error))
+    }
+}
+
+impl From<crate::models::user::AccountStatusError> for DomainError {
+    fn from(error: crate::models::user::AccountStatusError) -> Self {
+        Self::User(UserError::from(error))
+    }
+}
+
 impl From<crate::models::user::PasswordError> for DomainError {
     fn from(error: crate::models::user::PasswordError) -> Self {
         Self::User(UserError::from(error))
@@ -52,6 +67,48 @@ impl From<PasswordServiceError> for DomainError {
     }
 }
 
+impl From<RefreshTokenError> for DomainError {
+    fn from(error: RefreshTokenError) -> Self {
+        Self::Auth(AuthError::from(error))
+    }
+}
+
+impl From<RefreshTokenRepositoryError> for DomainError {
+    fn from(error: RefreshTokenRepositoryError) -> Self {
+        Self::Auth(AuthError::from(error))
+    }
+}
+
+impl From<MfaError> for DomainError {
+    fn from(error: MfaError) -> Self {
+        Self::Auth(AuthError::from(error))
+    }
+}
+
+impl From<TotpCodeError> for DomainError {
+    fn from(error: TotpCodeError) -> Self {
+        Self::Auth(AuthError::from(MfaError::from(error)))
+    }
+}
+
+impl From<MfaRepositoryError> for DomainError {
+    fn from(error: MfaRepositoryError) -> Self {
+        Self::Auth(AuthError::from(error))
+    }
+}
+
+impl From<PasswordResetError> for DomainError {
+    fn from(error: PasswordResetError) -> Self {
+        Self::Auth(AuthError::from(error))
+    }
+}
+
+impl From<PasswordResetRepositoryError> for DomainError {
+    fn from(error: PasswordResetRepositoryError) -> Self {
+        Self::Auth(AuthError::from(error))
+    }
+}
+
 impl IntoTxError for DomainError {
     fn into_tx_error(error: impl Into<anyhow::Error>) -> Self {
         Self::Infrastructure(error.into())
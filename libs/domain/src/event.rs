@@ -0,0 +1,96 @@
+use crate::error::DomainError;
+use crate::models::user::UserEvent;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// 集約で発生したドメインイベント。Outbox を経由して外部へ配信される。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DomainEvent {
+    User(UserEvent),
+}
+
+impl DomainEvent {
+    /// イベントが発生した集約の種類
+    pub fn aggregate_type(&self) -> &'static str {
+        match self {
+            Self::User(_) => "User",
+        }
+    }
+
+    /// イベントが発生した集約の ID
+    pub fn aggregate_id(&self) -> Uuid {
+        match self {
+            Self::User(event) => event.user_id().into(),
+        }
+    }
+
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            Self::User(event) => event.event_type(),
+        }
+    }
+
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        match self {
+            Self::User(event) => event.occurred_at(),
+        }
+    }
+}
+
+impl From<UserEvent> for DomainEvent {
+    fn from(event: UserEvent) -> Self {
+        Self::User(event)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum OutboxRepositoryError {
+    #[error("Failed to serialize event: {0}")]
+    SerializationFailed(#[source] anyhow::Error),
+
+    #[error("Database query failed: {0}")]
+    QueryFailed(#[source] anyhow::Error),
+
+    #[error("Unexpected repository error")]
+    Unexpected(#[from] anyhow::Error),
+}
+
+/// ドメインイベントを業務データと同じトランザクションで記録する Outbox。
+///
+/// 記録されたイベントはコミット後にリレーが取り出して配信する。
+#[async_trait]
+pub trait OutboxRepository: Send + Sync {
+    async fn append(&self, events: &[DomainEvent]) -> Result<(), OutboxRepositoryError>;
+}
+
+impl From<OutboxRepositoryError> for DomainError {
+    fn from(error: OutboxRepositoryError) -> Self {
+        Self::Infrastructure(error.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::user::{Email, UserId};
+
+    #[test]
+    fn test_user_event_metadata() {
+        let user_id = UserId::from(Uuid::now_v7());
+        let now = Utc::now();
+        let event = DomainEvent::from(UserEvent::UserRegistered {
+            user_id,
+            email: Email::try_from("event@example.com").unwrap(),
+            occurred_at: now,
+        });
+
+        assert_eq!(event.aggregate_type(), "User");
+        assert_eq!(event.aggregate_id(), Uuid::from(user_id));
+        assert_eq!(event.event_type(), "UserRegistered");
+        assert_eq!(event.occurred_at(), now);
+    }
+}
@@ -1,16 +1,22 @@
+pub mod audit;
 pub mod clock;
 pub mod entity;
 pub mod error;
+pub mod event;
 pub mod id;
 pub mod models;
 pub mod repository;
 pub mod sensitive_data;
 #[cfg(feature = "test-utils")]
 pub mod test_utils;
+pub mod version;
 
+pub use audit::{Actor, AuditContext};
 pub use clock::Clock;
 pub use domain_macros::{Entity, SensitiveDebug};
 pub use entity::Entity;
 pub use error::{DomainError, DomainResult};
+pub use event::DomainEvent;
 pub use id::IdGenerator;
 pub use sensitive_data::{Sensitive, SensitiveData};
+pub use version::Version;
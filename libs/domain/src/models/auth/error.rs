@@ -1,6 +1,9 @@
 use thiserror::Error;
 
-use crate::models::auth::PasswordServiceError;
+use crate::models::auth::{
+    MfaError, MfaRepositoryError, PasswordResetError, PasswordResetRepositoryError,
+    PasswordServiceError, RefreshTokenError, RefreshTokenRepositoryError,
+};
 
 #[derive(Debug, Error)]
 pub enum AuthError {
@@ -12,4 +15,25 @@ pub enum AuthError {
 
     #[error("Password service failure")]
     PasswordService(#[from] PasswordServiceError),
+
+    #[error("Invalid refresh token")]
+    InvalidRefreshToken,
+
+    #[error(transparent)]
+    RefreshToken(#[from] RefreshTokenError),
+
+    #[error(transparent)]
+    RefreshTokenRepository(#[from] RefreshTokenRepositoryError),
+
+    #[error(transparent)]
+    Mfa(#[from] MfaError),
+
+    #[error(transparent)]
+    MfaRepository(#[from] MfaRepositoryError),
+
+    #[error(transparent)]
+    PasswordReset(#[from] PasswordResetError),
+
+    #[error(transparent)]
+    PasswordResetRepository(#[from] PasswordResetRepositoryError),
 }
@@ -0,0 +1,169 @@
+use crate::models::user::Email;
+use chrono::{DateTime, Duration, Utc};
+use std::fmt;
+use std::net::IpAddr;
+use thiserror::Error;
+
+/// 失敗回数の上限に達しており、`until` まで試行を受け付けない
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq)]
+#[error("Too many failed attempts; retry after {until}")]
+pub struct Throttled {
+    pub until: DateTime<Utc>,
+}
+
+/// 失敗した試行を集計する単位。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ThrottleKey {
+    /// ログイン対象のアカウント（未登録のメールアドレスも同じく集計し、存在を推測させない）
+    Account(Email),
+    /// 接続元の IP アドレス
+    ClientIp(IpAddr),
+}
+
+impl fmt::Display for ThrottleKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Account(email) => write!(f, "account:{}", email.as_ref()),
+            Self::ClientIp(ip) => write!(f, "ip:{}", ip),
+        }
+    }
+}
+
+/// 集計期間（スライディングウィンドウ）内の失敗回数に応じて、指数的に延びるロックアウトを判定する方針。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoginThrottlePolicy {
+    /// 失敗を集計する期間
+    pub window: Duration,
+    /// ロックアウトを開始する失敗回数
+    pub max_failures: u32,
+    /// 最初のロックアウトの長さ（以降は失敗のたびに倍になる）
+    pub base_lockout: Duration,
+    /// ロックアウトの上限
+    pub max_lockout: Duration,
+}
+
+impl LoginThrottlePolicy {
+    /// アカウント単位の既定値
+    pub const ACCOUNT: Self = Self {
+        window: Duration::hours(1),
+        max_failures: 5,
+        base_lockout: Duration::seconds(30),
+        max_lockout: Duration::minutes(15),
+    };
+
+    /// 接続元単位の既定値。NAT 等で複数の利用者が共有するため上限を緩くする。
+    pub const CLIENT_IP: Self = Self {
+        window: Duration::hours(1),
+        max_failures: 20,
+        base_lockout: Duration::seconds(30),
+        max_lockout: Duration::minutes(15),
+    };
+
+    /// `now` 時点の集計期間の開始時刻
+    pub fn window_start(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now - self.window
+    }
+
+    /// 集計期間内の失敗日時からロックアウトの終了時刻を求める。上限に達していない場合は `None`。
+    pub fn locked_until(&self, failures: &[DateTime<Utc>]) -> Option<DateTime<Utc>> {
+        let count = u32::try_from(failures.len()).unwrap_or(u32::MAX);
+        if count < self.max_failures {
+            return None;
+        }
+        let last = failures.iter().max()?;
+        Some(*last + self.lockout_for(count))
+    }
+
+    /// 試行を受け付けられるかを検証する。
+    pub fn ensure_allowed(
+        &self,
+        failures: &[DateTime<Utc>],
+        now: DateTime<Utc>,
+    ) -> Result<(), Throttled> {
+        match self.locked_until(failures) {
+            Some(until) if until > now => Err(Throttled { until }),
+            _ => Ok(()),
+        }
+    }
+
+    fn lockout_for(&self, failures: u32) -> Duration {
+        // 上限を超えた回数ぶん倍にする（桁あふれする場合は上限とする）
+        let exponent = failures - self.max_failures;
+        2i32.checked_pow(exponent)
+            .and_then(|factor| self.base_lockout.checked_mul(factor))
+            .map_or(self.max_lockout, |lockout| lockout.min(self.max_lockout))
+    }
+}
+
+/// ログインの試行を制限する設定。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoginThrottleConfig {
+    pub account: LoginThrottlePolicy,
+    pub client_ip: LoginThrottlePolicy,
+}
+
+impl LoginThrottleConfig {
+    /// いずれかの判定に使われうる最も古い時刻。これより前の記録は破棄してよい。
+    pub fn retention_start(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now - self.account.window.max(self.client_ip.window)
+    }
+}
+
+impl Default for LoginThrottleConfig {
+    fn default() -> Self {
+        Self {
+            account: LoginThrottlePolicy::ACCOUNT,
+            client_ip: LoginThrottlePolicy::CLIENT_IP,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failures(now: DateTime<Utc>, count: usize) -> Vec<DateTime<Utc>> {
+        (0..count)
+            .map(|i| now - Duration::seconds((count - i) as i64))
+            .collect()
+    }
+
+    #[test]
+    fn test_lockout_grows_exponentially_up_to_limit() {
+        let policy = LoginThrottlePolicy::ACCOUNT;
+        let now = Utc::now();
+        let last = now - Duration::seconds(1);
+
+        assert_eq!(policy.locked_until(&failures(now, 4)), None);
+        assert_eq!(
+            policy.locked_until(&failures(now, 5)),
+            Some(last + Duration::seconds(30))
+        );
+        assert_eq!(
+            policy.locked_until(&failures(now, 6)),
+            Some(last + Duration::seconds(60))
+        );
+        assert_eq!(
+            policy.locked_until(&failures(now, 7)),
+            Some(last + Duration::seconds(120))
+        );
+        assert_eq!(
+            policy.locked_until(&failures(now, 64)),
+            Some(last + Duration::minutes(15))
+        );
+    }
+
+    #[test]
+    fn test_ensure_allowed_after_lockout_ends() {
+        let policy = LoginThrottlePolicy::ACCOUNT;
+        let now = Utc::now();
+        let recorded = failures(now, 5);
+        let until = now - Duration::seconds(1) + Duration::seconds(30);
+
+        assert_eq!(
+            policy.ensure_allowed(&recorded, now),
+            Err(Throttled { until })
+        );
+        assert_eq!(policy.ensure_allowed(&recorded, until), Ok(()));
+    }
+}
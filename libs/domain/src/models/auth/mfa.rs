@@ -0,0 +1,186 @@
+use crate::Entity;
+use crate::models::auth::{OpaqueTokenHash, TotpCodeError};
+use crate::models::user::UserId;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use derive_more::{AsRef, Display, From, Into};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// MFA チャレンジの有効期間。
+pub const MFA_CHALLENGE_TTL: Duration = Duration::minutes(5);
+
+/// 1 つのチャレンジに対して許容するコード誤入力の回数。
+pub const MFA_CHALLENGE_MAX_ATTEMPTS: u32 = 5;
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum MfaError {
+    #[error("MFA is already enabled")]
+    AlreadyEnabled,
+
+    #[error("MFA enrollment has not been started")]
+    NotEnrolled,
+
+    #[error("Invalid MFA code")]
+    InvalidCode,
+
+    #[error(transparent)]
+    MalformedCode(#[from] TotpCodeError),
+
+    #[error("Invalid or expired MFA challenge")]
+    InvalidChallenge,
+}
+
+#[derive(Debug, Error)]
+pub enum MfaRepositoryError {
+    #[error("Database query failed: {0}")]
+    QueryFailed(#[source] anyhow::Error),
+
+    #[error("Data mapping failed: {0}")]
+    MappingFailed(#[source] anyhow::Error),
+
+    #[error("Unexpected repository error")]
+    Unexpected(#[from] anyhow::Error),
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, From, Into, AsRef, Display,
+)]
+pub struct MfaChallengeId(Uuid);
+
+/// パスワード認証を通過し、第二要素の入力を待っている状態。
+///
+/// チャレンジトークンの平文はクライアントへ返却し、ハッシュ値のみを保持する。
+#[derive(Debug, Clone, Serialize, Deserialize, Entity)]
+pub struct MfaChallenge {
+    #[entity(id)]
+    id: MfaChallengeId,
+    user_id: UserId,
+    token_hash: OpaqueTokenHash,
+    expires_at: DateTime<Utc>,
+    failed_attempts: u32,
+}
+
+impl MfaChallenge {
+    /// パスワード認証の成功時にチャレンジを発行する。
+    pub fn issue(
+        id: MfaChallengeId,
+        user_id: UserId,
+        token_hash: OpaqueTokenHash,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            token_hash,
+            expires_at: now + MFA_CHALLENGE_TTL,
+            failed_attempts: 0,
+        }
+    }
+
+    /// データベース等から取得した値を MfaChallenge に再構成する。
+    pub fn reconstruct(
+        id: MfaChallengeId,
+        user_id: UserId,
+        token_hash: OpaqueTokenHash,
+        expires_at: DateTime<Utc>,
+        failed_attempts: u32,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            token_hash,
+            expires_at,
+            failed_attempts,
+        }
+    }
+
+    /// チャレンジがまだ回答可能かを検証する。
+    pub fn ensure_usable(&self, now: DateTime<Utc>) -> Result<(), MfaError> {
+        if now >= self.expires_at || self.failed_attempts >= MFA_CHALLENGE_MAX_ATTEMPTS {
+            return Err(MfaError::InvalidChallenge);
+        }
+        Ok(())
+    }
+
+    /// コードの誤入力を記録する。
+    pub fn record_failure(self) -> Self {
+        Self {
+            failed_attempts: self.failed_attempts + 1,
+            ..self
+        }
+    }
+
+    pub fn id(&self) -> MfaChallengeId {
+        self.id
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn token_hash(&self) -> &OpaqueTokenHash {
+        &self.token_hash
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+
+    pub fn failed_attempts(&self) -> u32 {
+        self.failed_attempts
+    }
+}
+
+#[async_trait]
+pub trait MfaChallengeRepository: Send + Sync {
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &OpaqueTokenHash,
+    ) -> Result<Option<MfaChallenge>, MfaRepositoryError>;
+
+    async fn save(&self, challenge: &MfaChallenge) -> Result<(), MfaRepositoryError>;
+
+    /// 回答済みのチャレンジを削除する（使い捨て）
+    async fn delete(&self, id: MfaChallengeId) -> Result<(), MfaRepositoryError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[fixture]
+    fn now() -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    #[fixture]
+    fn challenge(now: DateTime<Utc>) -> MfaChallenge {
+        MfaChallenge::issue(
+            MfaChallengeId::from(Uuid::now_v7()),
+            UserId::from(Uuid::now_v7()),
+            OpaqueTokenHash::from_str_unchecked("hash"),
+            now,
+        )
+    }
+
+    #[rstest]
+    fn test_challenge_expires(challenge: MfaChallenge, now: DateTime<Utc>) {
+        assert!(challenge.ensure_usable(now).is_ok());
+        assert_eq!(
+            challenge.ensure_usable(now + MFA_CHALLENGE_TTL),
+            Err(MfaError::InvalidChallenge)
+        );
+    }
+
+    #[rstest]
+    fn test_challenge_exhausted_after_max_attempts(challenge: MfaChallenge, now: DateTime<Utc>) {
+        let exhausted = (0..MFA_CHALLENGE_MAX_ATTEMPTS).fold(challenge, |c, _| c.record_failure());
+        assert_eq!(
+            exhausted.ensure_usable(now),
+            Err(MfaError::InvalidChallenge)
+        );
+    }
+}
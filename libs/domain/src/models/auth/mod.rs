@@ -1,6 +1,37 @@
 pub mod error;
+pub mod login_throttle;
+pub mod mfa;
+pub mod opaque_token;
+pub mod password_policy;
+pub mod password_reset;
+pub mod recovery_code;
+pub mod refresh_token;
+pub mod totp;
 
 pub use error::AuthError;
+pub use login_throttle::{LoginThrottleConfig, LoginThrottlePolicy, ThrottleKey, Throttled};
+pub use mfa::{
+    MFA_CHALLENGE_MAX_ATTEMPTS, MFA_CHALLENGE_TTL, MfaChallenge, MfaChallengeId,
+    MfaChallengeRepository, MfaError, MfaRepositoryError,
+};
+pub use opaque_token::{OpaqueToken, OpaqueTokenHash, OpaqueTokenService};
+pub use password_policy::{PasswordPolicy, ValidatedPassword};
+pub use password_reset::{
+    PASSWORD_RESET_TOKEN_TTL, PasswordResetError, PasswordResetRepositoryError, PasswordResetToken,
+    PasswordResetTokenId, PasswordResetTokenRepository,
+};
+pub use recovery_code::{
+    RECOVERY_CODE_COUNT, RECOVERY_CODE_LENGTH, RecoveryCode, RecoveryCodeEntry, RecoveryCodeError,
+    RecoveryCodeGenerator, RecoveryCodeRepository, RecoveryCodeSet,
+};
+pub use refresh_token::{
+    RefreshToken, RefreshTokenError, RefreshTokenFamilyId, RefreshTokenId, RefreshTokenRepository,
+    RefreshTokenRepositoryError, RefreshTokenStatus,
+};
+pub use totp::{
+    TOTP_DIGITS, TotpCode, TotpCodeError, TotpCredential, TotpCredentialRepository, TotpSecret,
+    TotpService,
+};
 
 use crate::SensitiveDebug;
 use crate::models::user::PasswordHash;
@@ -9,6 +40,7 @@ use derive_more::{Display, From};
 use sensitive_data::{SecretRule, SensitiveData};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use unicode_normalization::UnicodeNormalization;
 
 #[derive(Debug, Error)]
 pub enum PasswordServiceError {
@@ -17,6 +49,10 @@ pub enum PasswordServiceError {
 
     #[error("Failed to verify password")]
     VerificationFailed(#[source] anyhow::Error),
+
+    /// 同時に実行できる数を超え、待ち時間内に処理を開始できなかった
+    #[error("Password service is overloaded")]
+    Overloaded,
 }
 
 /// 生の（ハッシュ化前の）パスワードを表現する値オブジェクト。
@@ -39,6 +75,14 @@ impl RawPassword {
     pub fn expose_as_str(&self) -> &str {
         &self.0
     }
+
+    /// Unicode 正規化（NFKC）した値を返す。
+    ///
+    /// 入力環境によって見た目が同じでも符号化が異なる場合があるため、
+    /// ハッシュ化・照合の前に必ず適用する。
+    pub fn normalized(&self) -> Self {
+        Self(self.0.nfkc().collect())
+    }
 }
 
 impl From<&str> for RawPassword {
@@ -70,4 +114,12 @@ pub trait PasswordService: Send + Sync {
 
     /// 生のパスワードをハッシュ化する（サインアップ用）
     async fn hash(&self, password: &RawPassword) -> Result<PasswordHash, PasswordServiceError>;
+
+    /// 実在のハッシュと同じ設定で事前に計算したダミーのハッシュ。
+    ///
+    /// 未登録のアカウントでも同等の照合を行い、応答時間からアカウントの有無を推測させないために使用する。
+    fn dummy_hash(&self) -> &PasswordHash;
+
+    /// ハッシュが現在の設定（アルゴリズムやコストパラメータ）と異なり、再ハッシュが必要か判定する
+    fn needs_rehash(&self, hash: &PasswordHash) -> bool;
 }
@@ -0,0 +1,81 @@
+use crate::SensitiveDebug;
+use derive_more::{AsRef, Display, From};
+use sensitive_data::{SecretRule, SensitiveData};
+use serde::{Deserialize, Serialize};
+
+/// 推測困難なランダム値からなる不透明トークン（平文）。
+///
+/// リフレッシュトークン等、サーバー側に状態を持つトークンの「値」そのものを表す。
+/// 平文はクライアントへ一度だけ返却し、永続化には `OpaqueTokenHash` を使用する。
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Display, From, SensitiveDebug)]
+pub struct OpaqueToken(String);
+
+impl OpaqueToken {
+    /// トークンの内容を文字列として露出させます。
+    pub fn expose_as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for OpaqueToken {
+    fn from(s: &str) -> Self {
+        Self(s.to_string())
+    }
+}
+
+impl SensitiveData for OpaqueToken {
+    fn to_masked_string(&self) -> String {
+        Self::mask_raw(&self.0)
+    }
+
+    fn mask_raw(input: &str) -> String {
+        SecretRule::mask_raw(input)
+    }
+}
+
+/// 不透明トークンのハッシュ値。検索キーとして永続化される。
+#[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Display, AsRef, SensitiveDebug)]
+pub struct OpaqueTokenHash(String);
+
+impl OpaqueTokenHash {
+    /// データベース等から取得した文字列を OpaqueTokenHash に再構成する。
+    ///
+    /// 注意: このメソッドは形式チェックを行わない。
+    pub fn from_str_unchecked(s: impl Into<String>) -> Self {
+        Self(s.into())
+    }
+}
+
+impl SensitiveData for OpaqueTokenHash {
+    fn to_masked_string(&self) -> String {
+        Self::mask_raw(&self.0)
+    }
+
+    fn mask_raw(input: &str) -> String {
+        SecretRule::mask_raw(input)
+    }
+}
+
+/// 不透明トークンの生成およびハッシュ化を行うドメインサービス。
+///
+/// ハッシュは検索に使用するため決定的（同じ入力に対して同じ出力）である必要がある。
+pub trait OpaqueTokenService: Send + Sync {
+    /// 暗号論的に安全な乱数から新しいトークンを生成する
+    fn generate(&self) -> OpaqueToken;
+
+    /// トークンを永続化用のハッシュ値に変換する
+    fn hash(&self, token: &OpaqueToken) -> OpaqueTokenHash;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opaque_token_masking() {
+        let token = OpaqueToken::from("raw-refresh-token");
+        assert_eq!(token.to_masked_string(), "***");
+        assert_eq!(format!("{:?}", token), "\"***\"");
+        assert_eq!(token.expose_as_str(), "raw-refresh-token");
+    }
+}
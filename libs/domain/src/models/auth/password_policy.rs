@@ -0,0 +1,185 @@
+use crate::models::auth::RawPassword;
+use crate::models::user::{Email, PasswordError, PasswordViolation};
+use unicode_normalization::UnicodeNormalization;
+
+/// 方針を満たすことを検証済みの（Unicode 正規化済みの）パスワード。
+///
+/// `PasswordPolicy::validate` を介してのみ生成できる。
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ValidatedPassword(RawPassword);
+
+impl ValidatedPassword {
+    /// ハッシュ化のために `PasswordService` へ渡す形式で参照する。
+    pub fn as_raw(&self) -> &RawPassword {
+        &self.0
+    }
+}
+
+/// 新しく設定するパスワードの方針を表すドメインサービス。起動時の設定で変更できる。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    /// 最小文字数（Unicode のスカラー値で数える）
+    pub min_length: usize,
+    /// 最大文字数
+    pub max_length: usize,
+    /// 含めるべき文字種（英小文字・英大文字・数字・記号）の数
+    pub min_character_classes: usize,
+    /// メールアドレスのローカル部を含むパスワードを拒否するか
+    pub reject_email_local_part: bool,
+}
+
+/// これより短いローカル部は、偶然の一致が多いため比較しない
+const MIN_COMPARED_LOCAL_PART_LENGTH: usize = 3;
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            max_length: 128,
+            min_character_classes: 3,
+            reject_email_local_part: true,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /// 正規化したパスワードを検証する。違反した規則はすべてまとめて返す。
+    pub fn validate(
+        &self,
+        password: &RawPassword,
+        email: &Email,
+    ) -> Result<ValidatedPassword, PasswordError> {
+        let password = password.normalized();
+        let value = password.expose_as_str();
+        let mut violations = Vec::new();
+
+        let length = value.chars().count();
+        if length < self.min_length {
+            violations.push(PasswordViolation::TooShort {
+                min: self.min_length,
+            });
+        }
+        if length > self.max_length {
+            violations.push(PasswordViolation::TooLong {
+                max: self.max_length,
+            });
+        }
+        if character_classes(value) < self.min_character_classes {
+            violations.push(PasswordViolation::TooWeak {
+                required: self.min_character_classes,
+            });
+        }
+        if self.reject_email_local_part && contains_local_part(value, email) {
+            violations.push(PasswordViolation::ContainsEmail);
+        }
+
+        if violations.is_empty() {
+            Ok(ValidatedPassword(password))
+        } else {
+            Err(PasswordError::PolicyViolation(violations))
+        }
+    }
+}
+
+/// 含まれる文字種（小文字・大文字・数字・記号）の数。大文字・小文字の区別がない文字はいずれにも数えない。
+fn character_classes(value: &str) -> usize {
+    let classes: [fn(char) -> bool; 4] = [
+        char::is_lowercase,
+        char::is_uppercase,
+        char::is_numeric,
+        |c| !c.is_alphanumeric(),
+    ];
+    classes
+        .iter()
+        .filter(|class| value.chars().any(*class))
+        .count()
+}
+
+fn contains_local_part(value: &str, email: &Email) -> bool {
+    let local_part: String = email.local_part().nfkc().collect::<String>().to_lowercase();
+    local_part.chars().count() >= MIN_COMPARED_LOCAL_PART_LENGTH
+        && value.to_lowercase().contains(&local_part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[fixture]
+    fn email() -> Email {
+        Email::try_from("alice@example.com").unwrap()
+    }
+
+    #[rstest]
+    #[case::enough_classes("Correct-Horse1")]
+    #[case::three_of_four("horse-battery-9")]
+    #[case::non_ascii("パスワードはPass1")]
+    fn test_validate_accepts(email: Email, #[case] password: &str) {
+        let policy = PasswordPolicy::default();
+        assert!(
+            policy
+                .validate(&RawPassword::from(password), &email)
+                .is_ok()
+        );
+    }
+
+    #[rstest]
+    fn test_validate_reports_all_violations(email: Email) {
+        let policy = PasswordPolicy::default();
+        let result = policy.validate(&RawPassword::from("alice"), &email);
+        assert_eq!(
+            result,
+            Err(PasswordError::PolicyViolation(vec![
+                PasswordViolation::TooShort { min: 8 },
+                PasswordViolation::TooWeak { required: 3 },
+                PasswordViolation::ContainsEmail,
+            ]))
+        );
+    }
+
+    #[rstest]
+    fn test_validate_rejects_local_part_case_insensitively(email: Email) {
+        let policy = PasswordPolicy::default();
+        let result = policy.validate(&RawPassword::from("My-ALICE-Pass1"), &email);
+        assert_eq!(
+            result,
+            Err(PasswordError::PolicyViolation(vec![
+                PasswordViolation::ContainsEmail
+            ]))
+        );
+
+        let lenient = PasswordPolicy {
+            reject_email_local_part: false,
+            ..policy
+        };
+        assert!(
+            lenient
+                .validate(&RawPassword::from("My-ALICE-Pass1"), &email)
+                .is_ok()
+        );
+    }
+
+    #[rstest]
+    fn test_validate_normalizes_and_counts_characters(email: Email) {
+        let policy = PasswordPolicy {
+            min_length: 4,
+            max_length: 4,
+            min_character_classes: 1,
+            reject_email_local_part: true,
+        };
+        // 全角英数字は NFKC で半角に揃えられ、結合文字は 1 文字にまとめられる
+        let validated = policy
+            .validate(&RawPassword::from("ＡＢc\u{0301}d"), &email)
+            .unwrap();
+        assert_eq!(validated.as_raw().expose_as_str(), "ABćd");
+
+        let result = policy.validate(&RawPassword::from("abcde"), &email);
+        assert_eq!(
+            result,
+            Err(PasswordError::PolicyViolation(vec![
+                PasswordViolation::TooLong { max: 4 }
+            ]))
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use crate::Entity;
+use crate::models::auth::OpaqueTokenHash;
+use crate::models::user::UserId;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use derive_more::{AsRef, Display, From, Into};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// パスワードリセットトークンの有効期間。
+pub const PASSWORD_RESET_TOKEN_TTL: Duration = Duration::minutes(30);
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum PasswordResetError {
+    #[error("Invalid or expired password reset token")]
+    InvalidToken,
+}
+
+#[derive(Debug, Error)]
+pub enum PasswordResetRepositoryError {
+    #[error("Database query failed: {0}")]
+    QueryFailed(#[source] anyhow::Error),
+
+    #[error("Data mapping failed: {0}")]
+    MappingFailed(#[source] anyhow::Error),
+
+    #[error("Unexpected repository error")]
+    Unexpected(#[from] anyhow::Error),
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, From, Into, AsRef, Display,
+)]
+pub struct PasswordResetTokenId(Uuid);
+
+/// メールで送付したパスワードリセット用の使い捨てトークン。
+///
+/// トークンの平文はメールでのみ送付し、ハッシュ値のみを保持する。
+#[derive(Debug, Clone, Serialize, Deserialize, Entity)]
+pub struct PasswordResetToken {
+    #[entity(id)]
+    id: PasswordResetTokenId,
+    user_id: UserId,
+    token_hash: OpaqueTokenHash,
+    expires_at: DateTime<Utc>,
+}
+
+impl PasswordResetToken {
+    /// リセット要求の受付時にトークンを発行する。
+    pub fn issue(
+        id: PasswordResetTokenId,
+        user_id: UserId,
+        token_hash: OpaqueTokenHash,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            token_hash,
+            expires_at: now + PASSWORD_RESET_TOKEN_TTL,
+        }
+    }
+
+    /// データベース等から取得した値を PasswordResetToken に再構成する。
+    pub fn reconstruct(
+        id: PasswordResetTokenId,
+        user_id: UserId,
+        token_hash: OpaqueTokenHash,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            token_hash,
+            expires_at,
+        }
+    }
+
+    /// トークンが有効期限内かを検証する。
+    pub fn ensure_usable(&self, now: DateTime<Utc>) -> Result<(), PasswordResetError> {
+        if now >= self.expires_at {
+            return Err(PasswordResetError::InvalidToken);
+        }
+        Ok(())
+    }
+
+    pub fn id(&self) -> PasswordResetTokenId {
+        self.id
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn token_hash(&self) -> &OpaqueTokenHash {
+        &self.token_hash
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+}
+
+#[async_trait]
+pub trait PasswordResetTokenRepository: Send + Sync {
+    /// トークンを読み込み、トランザクションの終了まで他の更新を待たせる。
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &OpaqueTokenHash,
+    ) -> Result<Option<PasswordResetToken>, PasswordResetRepositoryError>;
+
+    async fn save(&self, token: &PasswordResetToken) -> Result<(), PasswordResetRepositoryError>;
+
+    /// ユーザーの未使用トークンをすべて無効化する（再発行時・リセット完了時）
+    async fn delete_by_user_id(&self, user_id: UserId) -> Result<(), PasswordResetRepositoryError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_expires() {
+        let now = Utc::now();
+        let token = PasswordResetToken::issue(
+            PasswordResetTokenId::from(Uuid::now_v7()),
+            UserId::from(Uuid::now_v7()),
+            OpaqueTokenHash::from_str_unchecked("hash"),
+            now,
+        );
+
+        assert!(token.ensure_usable(now).is_ok());
+        assert_eq!(
+            token.ensure_usable(now + PASSWORD_RESET_TOKEN_TTL),
+            Err(PasswordResetError::InvalidToken)
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use crate::SensitiveDebug;
+use crate::models::auth::{MfaError, MfaRepositoryError, RawPassword};
+use crate::models::user::{PasswordHash, UserId};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use sensitive_data::{SecretRule, SensitiveData};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// 一度に発行するリカバリーコードの数。
+pub const RECOVERY_CODE_COUNT: usize = 10;
+
+/// リカバリーコードの文字数（区切りのハイフンを除く）。
+pub const RECOVERY_CODE_LENGTH: usize = 10;
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum RecoveryCodeError {
+    #[error("Recovery code must be {RECOVERY_CODE_LENGTH} alphanumeric characters")]
+    InvalidFormat,
+}
+
+/// 平文のリカバリーコード。
+///
+/// 内部では区切りを除いた大文字英数字で保持し、表示時のみ 5 文字ごとにハイフンで区切る。
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Display, SensitiveDebug)]
+pub struct RecoveryCode(String);
+
+impl RecoveryCode {
+    /// 表示用の形式（`XXXXX-XXXXX`）で露出させます。
+    pub fn expose_formatted(&self) -> String {
+        let (head, tail) = self.0.split_at(self.0.len() / 2);
+        format!("{head}-{tail}")
+    }
+
+    /// ハッシュ化・照合のために `PasswordService` へ渡す形式に変換する。
+    pub fn to_raw_password(&self) -> RawPassword {
+        RawPassword::from(self.0.as_str())
+    }
+}
+
+impl TryFrom<String> for RecoveryCode {
+    type Error = RecoveryCodeError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        // 表示どおりのハイフン区切りや小文字での入力を許容する
+        let code: String = s
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        if code.len() != RECOVERY_CODE_LENGTH || !code.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(RecoveryCodeError::InvalidFormat);
+        }
+        Ok(Self(code))
+    }
+}
+
+impl SensitiveData for RecoveryCode {
+    fn to_masked_string(&self) -> String {
+        Self::mask_raw(&self.0)
+    }
+
+    fn mask_raw(input: &str) -> String {
+        SecretRule::mask_raw(input)
+    }
+}
+
+/// 保存されている 1 件のリカバリーコード。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecoveryCodeEntry {
+    code_hash: PasswordHash,
+    used_at: Option<DateTime<Utc>>,
+}
+
+impl RecoveryCodeEntry {
+    /// データベース等から取得した値を RecoveryCodeEntry に再構成する。
+    pub fn reconstruct(code_hash: PasswordHash, used_at: Option<DateTime<Utc>>) -> Self {
+        Self { code_hash, used_at }
+    }
+
+    pub fn code_hash(&self) -> &PasswordHash {
+        &self.code_hash
+    }
+
+    pub fn used_at(&self) -> Option<DateTime<Utc>> {
+        self.used_at
+    }
+
+    pub fn is_used(&self) -> bool {
+        self.used_at.is_some()
+    }
+}
+
+/// ユーザーに発行されたリカバリーコードの一式。
+///
+/// 再発行時は一式をまるごと置き換え、以前のコードはすべて無効になる。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecoveryCodeSet {
+    user_id: UserId,
+    codes: Vec<RecoveryCodeEntry>,
+}
+
+impl RecoveryCodeSet {
+    /// ハッシュ化済みのコードから新しい一式を発行する。
+    pub fn issue(user_id: UserId, code_hashes: Vec<PasswordHash>) -> Self {
+        Self {
+            user_id,
+            codes: code_hashes
+                .into_iter()
+                .map(|code_hash| RecoveryCodeEntry {
+                    code_hash,
+                    used_at: None,
+                })
+                .collect(),
+        }
+    }
+
+    /// データベース等から取得した値を RecoveryCodeSet に再構成する。
+    pub fn reconstruct(user_id: UserId, codes: Vec<RecoveryCodeEntry>) -> Self {
+        Self { user_id, codes }
+    }
+
+    /// 未使用のコードの位置とハッシュを列挙する。
+    pub fn unused(&self) -> impl Iterator<Item = (usize, &PasswordHash)> {
+        self.codes
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| !entry.is_used())
+            .map(|(index, entry)| (index, &entry.code_hash))
+    }
+
+    /// 照合に成功したコードを使用済みにする。
+    pub fn consume(mut self, index: usize, now: DateTime<Utc>) -> Result<Self, MfaError> {
+        match self.codes.get_mut(index) {
+            Some(entry) if !entry.is_used() => {
+                entry.used_at = Some(now);
+                Ok(self)
+            }
+            _ => Err(MfaError::InvalidCode),
+        }
+    }
+
+    /// 未使用のコードの残数。
+    pub fn remaining(&self) -> usize {
+        self.unused().count()
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn codes(&self) -> &[RecoveryCodeEntry] {
+        &self.codes
+    }
+}
+
+/// リカバリーコードの平文を生成するドメインサービス。
+pub trait RecoveryCodeGenerator: Send + Sync {
+    /// 暗号論的に安全な乱数から新しいコードを生成する
+    fn generate(&self) -> RecoveryCode;
+}
+
+#[async_trait]
+pub trait RecoveryCodeRepository: Send + Sync {
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<RecoveryCodeSet>, MfaRepositoryError>;
+
+    /// ユーザーのリカバリーコードを一式で保存する（既存の一式は置き換える）
+    async fn save(&self, codes: &RecoveryCodeSet) -> Result<(), MfaRepositoryError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+    use uuid::Uuid;
+
+    #[fixture]
+    fn code_set() -> RecoveryCodeSet {
+        RecoveryCodeSet::issue(
+            UserId::from(Uuid::now_v7()),
+            vec![
+                PasswordHash::from_str_unchecked("h0"),
+                PasswordHash::from_str_unchecked("h1"),
+            ],
+        )
+    }
+
+    #[rstest]
+    #[case("ABCDE-12345", true)]
+    #[case("abcde12345", true)]
+    #[case("ABCDE 12345", true)]
+    #[case("ABCDE-1234", false)]
+    #[case("ABCDE-1234!", false)]
+    fn test_recovery_code_format(#[case] input: &str, #[case] expected: bool) {
+        assert_eq!(RecoveryCode::try_from(input.to_string()).is_ok(), expected);
+    }
+
+    #[rstest]
+    fn test_recovery_code_normalized() {
+        let code = RecoveryCode::try_from("abcde-12345".to_string()).unwrap();
+        assert_eq!(code.expose_formatted(), "ABCDE-12345");
+        assert_eq!(code.to_raw_password().expose_as_str(), "ABCDE12345");
+    }
+
+    #[rstest]
+    fn test_consume_is_single_use(code_set: RecoveryCodeSet) {
+        assert_eq!(code_set.remaining(), 2);
+
+        let consumed = code_set.consume(1, Utc::now()).unwrap();
+        assert_eq!(consumed.remaining(), 1);
+        assert_eq!(consumed.unused().map(|(i, _)| i).collect::<Vec<_>>(), [0]);
+
+        assert_eq!(
+            consumed.clone().consume(1, Utc::now()).unwrap_err(),
+            MfaError::InvalidCode
+        );
+        assert_eq!(
+            consumed.consume(2, Utc::now()).unwrap_err(),
+            MfaError::InvalidCode
+        );
+    }
+}
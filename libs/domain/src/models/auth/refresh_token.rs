@@ -0,0 +1,291 @@
+use crate::Entity;
+use crate::models::auth::OpaqueTokenHash;
+use crate::models::user::UserId;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use derive_more::{AsRef, Display, From, Into};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// リフレッシュトークンの有効期間。
+pub const REFRESH_TOKEN_TTL: Duration = Duration::days(30);
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, From, Into, AsRef, Display,
+)]
+pub struct RefreshTokenId(Uuid);
+
+/// ローテーションによって連鎖するリフレッシュトークンの系列（ファミリー）の識別子。
+///
+/// ファミリーIDは、ログイン時に最初に発行されたトークンのIDを引き継ぐ。
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, From, Into, AsRef, Display,
+)]
+pub struct RefreshTokenFamilyId(Uuid);
+
+impl From<RefreshTokenId> for RefreshTokenFamilyId {
+    fn from(id: RefreshTokenId) -> Self {
+        Self(id.into())
+    }
+}
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum RefreshTokenError {
+    #[error("Refresh token has expired")]
+    Expired,
+
+    #[error("Refresh token has been revoked")]
+    Revoked,
+
+    /// 使用済みのトークンが再提示された（漏洩の疑い）
+    #[error("Refresh token reuse detected")]
+    ReuseDetected { family_id: RefreshTokenFamilyId },
+}
+
+#[derive(Debug, Error)]
+pub enum RefreshTokenRepositoryError {
+    #[error("Database query failed: {0}")]
+    QueryFailed(#[source] anyhow::Error),
+
+    #[error("Data mapping failed: {0}")]
+    MappingFailed(#[source] anyhow::Error),
+
+    #[error("Unexpected repository error")]
+    Unexpected(#[from] anyhow::Error),
+}
+
+/// リフレッシュトークンのライフサイクル上の状態。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RefreshTokenStatus {
+    /// 未使用
+    Active,
+    /// ローテーションにより使用済み
+    Used { used_at: DateTime<Utc> },
+    /// 失効済み
+    Revoked { revoked_at: DateTime<Utc> },
+}
+
+/// サーバー側で状態を管理するリフレッシュトークン。
+///
+/// 平文のトークン値は保持せず、ハッシュ値のみを保持する。
+#[derive(Debug, Clone, Serialize, Deserialize, Entity)]
+pub struct RefreshToken {
+    #[entity(id)]
+    id: RefreshTokenId,
+    family_id: RefreshTokenFamilyId,
+    user_id: UserId,
+    token_hash: OpaqueTokenHash,
+    issued_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    status: RefreshTokenStatus,
+}
+
+impl RefreshToken {
+    /// 新しいファミリーの先頭となるトークンを発行する（ログイン時）。
+    pub fn issue(
+        id: RefreshTokenId,
+        user_id: UserId,
+        token_hash: OpaqueTokenHash,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            family_id: RefreshTokenFamilyId::from(id),
+            user_id,
+            token_hash,
+            issued_at: now,
+            expires_at: now + REFRESH_TOKEN_TTL,
+            status: RefreshTokenStatus::Active,
+        }
+    }
+
+    /// データベース等から取得した値を RefreshToken に再構成する。
+    pub fn reconstruct(
+        id: RefreshTokenId,
+        family_id: RefreshTokenFamilyId,
+        user_id: UserId,
+        token_hash: OpaqueTokenHash,
+        issued_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+        status: RefreshTokenStatus,
+    ) -> Self {
+        Self {
+            id,
+            family_id,
+            user_id,
+            token_hash,
+            issued_at,
+            expires_at,
+            status,
+        }
+    }
+
+    /// トークンを使用済みにし、同じファミリーに属する次のトークンを発行する。
+    ///
+    /// 戻り値は `(使用済みになったトークン, 新しいトークン)` の組。
+    pub fn rotate(
+        self,
+        next_id: RefreshTokenId,
+        next_hash: OpaqueTokenHash,
+        now: DateTime<Utc>,
+    ) -> Result<(RefreshToken, RefreshToken), RefreshTokenError> {
+        match self.status {
+            RefreshTokenStatus::Revoked { .. } => return Err(RefreshTokenError::Revoked),
+            RefreshTokenStatus::Used { .. } => {
+                return Err(RefreshTokenError::ReuseDetected {
+                    family_id: self.family_id,
+                });
+            }
+            RefreshTokenStatus::Active => {}
+        }
+        if now >= self.expires_at {
+            return Err(RefreshTokenError::Expired);
+        }
+
+        let next = Self {
+            id: next_id,
+            family_id: self.family_id,
+            user_id: self.user_id,
+            token_hash: next_hash,
+            issued_at: now,
+            expires_at: now + REFRESH_TOKEN_TTL,
+            status: RefreshTokenStatus::Active,
+        };
+        let used = Self {
+            status: RefreshTokenStatus::Used { used_at: now },
+            ..self
+        };
+
+        Ok((used, next))
+    }
+
+    pub fn id(&self) -> RefreshTokenId {
+        self.id
+    }
+
+    pub fn family_id(&self) -> RefreshTokenFamilyId {
+        self.family_id
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn token_hash(&self) -> &OpaqueTokenHash {
+        &self.token_hash
+    }
+
+    pub fn issued_at(&self) -> DateTime<Utc> {
+        self.issued_at
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+
+    pub fn status(&self) -> RefreshTokenStatus {
+        self.status
+    }
+}
+
+#[async_trait]
+pub trait RefreshTokenRepository: Send + Sync {
+    /// トークンを読み込み、トランザクションの終了まで他の更新を待たせる。
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &OpaqueTokenHash,
+    ) -> Result<Option<RefreshToken>, RefreshTokenRepositoryError>;
+
+    async fn save(&self, token: &RefreshToken) -> Result<(), RefreshTokenRepositoryError>;
+
+    /// 指定したファミリーに属する未失効のトークンをすべて失効させる。
+    async fn revoke_family(
+        &self,
+        family_id: RefreshTokenFamilyId,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<(), RefreshTokenRepositoryError>;
+
+    /// ユーザーの未失効のトークンをすべて失効させる（アカウント停止時等）。
+    async fn revoke_all_by_user_id(
+        &self,
+        user_id: UserId,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<(), RefreshTokenRepositoryError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[fixture]
+    fn now() -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    #[fixture]
+    fn token(now: DateTime<Utc>) -> RefreshToken {
+        RefreshToken::issue(
+            RefreshTokenId::from(Uuid::now_v7()),
+            UserId::from(Uuid::now_v7()),
+            OpaqueTokenHash::from_str_unchecked("hash-1"),
+            now,
+        )
+    }
+
+    #[rstest]
+    fn test_issue_starts_new_family(token: RefreshToken) {
+        assert_eq!(token.family_id(), RefreshTokenFamilyId::from(token.id()));
+        assert_eq!(token.status(), RefreshTokenStatus::Active);
+    }
+
+    #[rstest]
+    fn test_rotate_keeps_family_and_marks_used(token: RefreshToken, now: DateTime<Utc>) {
+        let (used, next) = token
+            .clone()
+            .rotate(
+                RefreshTokenId::from(Uuid::now_v7()),
+                OpaqueTokenHash::from_str_unchecked("hash-2"),
+                now,
+            )
+            .unwrap();
+
+        assert_eq!(used.status(), RefreshTokenStatus::Used { used_at: now });
+        assert_eq!(next.family_id(), token.family_id());
+        assert_eq!(next.user_id(), token.user_id());
+        assert_eq!(next.status(), RefreshTokenStatus::Active);
+    }
+
+    #[rstest]
+    fn test_rotate_used_token_detects_reuse(token: RefreshToken, now: DateTime<Utc>) {
+        let family_id = token.family_id();
+        let (used, _) = token
+            .rotate(
+                RefreshTokenId::from(Uuid::now_v7()),
+                OpaqueTokenHash::from_str_unchecked("hash-2"),
+                now,
+            )
+            .unwrap();
+
+        let result = used.rotate(
+            RefreshTokenId::from(Uuid::now_v7()),
+            OpaqueTokenHash::from_str_unchecked("hash-3"),
+            now,
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            RefreshTokenError::ReuseDetected { family_id }
+        );
+    }
+
+    #[rstest]
+    fn test_rotate_expired_token(token: RefreshToken, now: DateTime<Utc>) {
+        let result = token.rotate(
+            RefreshTokenId::from(Uuid::now_v7()),
+            OpaqueTokenHash::from_str_unchecked("hash-2"),
+            now + REFRESH_TOKEN_TTL,
+        );
+        assert_eq!(result.unwrap_err(), RefreshTokenError::Expired);
+    }
+}
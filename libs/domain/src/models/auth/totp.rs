@@ -0,0 +1,249 @@
+use crate::SensitiveDebug;
+use crate::models::auth::{MfaError, MfaRepositoryError};
+use crate::models::user::UserId;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use sensitive_data::{SecretRule, SensitiveData};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// TOTP の共有シークレット（Base32 表現）。
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Display, SensitiveDebug)]
+pub struct TotpSecret(String);
+
+impl TotpSecret {
+    /// データベース等から取得した文字列を TotpSecret に再構成する。
+    ///
+    /// 注意: このメソッドは形式チェックを行わない。
+    pub fn from_str_unchecked(s: impl Into<String>) -> Self {
+        Self(s.into())
+    }
+
+    /// シークレットを Base32 文字列として露出させます。
+    pub fn expose_as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl SensitiveData for TotpSecret {
+    fn to_masked_string(&self) -> String {
+        Self::mask_raw(&self.0)
+    }
+
+    fn mask_raw(input: &str) -> String {
+        SecretRule::mask_raw(input)
+    }
+}
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum TotpCodeError {
+    #[error("TOTP code must be {TOTP_DIGITS} digits")]
+    InvalidFormat,
+}
+
+/// TOTP のコード桁数。
+pub const TOTP_DIGITS: usize = 6;
+
+/// 利用者が入力したワンタイムコード。
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Display, SensitiveDebug)]
+pub struct TotpCode(String);
+
+impl TotpCode {
+    pub fn expose_as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for TotpCode {
+    type Error = TotpCodeError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        // 認証アプリの表示に合わせて空白区切りの入力を許容する
+        let code: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        if code.len() != TOTP_DIGITS || !code.chars().all(|c| c.is_ascii_digit()) {
+            return Err(TotpCodeError::InvalidFormat);
+        }
+        Ok(Self(code))
+    }
+}
+
+impl SensitiveData for TotpCode {
+    fn to_masked_string(&self) -> String {
+        Self::mask_raw(&self.0)
+    }
+
+    fn mask_raw(input: &str) -> String {
+        SecretRule::mask_raw(input)
+    }
+}
+
+/// ユーザーに紐づく TOTP の登録情報。
+///
+/// 登録開始時点では未確認（`confirmed_at` が `None`）であり、
+/// 最初のコードで確認されたときに MFA が有効になる。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TotpCredential {
+    user_id: UserId,
+    secret: TotpSecret,
+    confirmed_at: Option<DateTime<Utc>>,
+    /// 最後に受理したタイムステップ（同一コードの再利用防止）
+    last_used_step: Option<u64>,
+}
+
+impl TotpCredential {
+    /// 新しいシークレットで登録を開始する。
+    pub fn enroll(user_id: UserId, secret: TotpSecret) -> Self {
+        Self {
+            user_id,
+            secret,
+            confirmed_at: None,
+            last_used_step: None,
+        }
+    }
+
+    /// データベース等から取得した値を TotpCredential に再構成する。
+    pub fn reconstruct(
+        user_id: UserId,
+        secret: TotpSecret,
+        confirmed_at: Option<DateTime<Utc>>,
+        last_used_step: Option<u64>,
+    ) -> Self {
+        Self {
+            user_id,
+            secret,
+            confirmed_at,
+            last_used_step,
+        }
+    }
+
+    /// 最初のコードの照合結果をもって登録を確定し、MFA を有効にする。
+    pub fn confirm(self, matched_step: u64, now: DateTime<Utc>) -> Result<Self, MfaError> {
+        if self.is_enabled() {
+            return Err(MfaError::AlreadyEnabled);
+        }
+        Ok(Self {
+            confirmed_at: Some(now),
+            last_used_step: Some(matched_step),
+            ..self
+        })
+    }
+
+    /// ログイン時に照合したタイムステップを受理する。
+    ///
+    /// 受理済みのステップ以前のコードは再利用とみなして拒否する。
+    pub fn accept_step(self, matched_step: u64) -> Result<Self, MfaError> {
+        if !self.is_enabled() {
+            return Err(MfaError::NotEnrolled);
+        }
+        if self.last_used_step.is_some_and(|last| matched_step <= last) {
+            return Err(MfaError::InvalidCode);
+        }
+        Ok(Self {
+            last_used_step: Some(matched_step),
+            ..self
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.confirmed_at.is_some()
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn secret(&self) -> &TotpSecret {
+        &self.secret
+    }
+
+    pub fn confirmed_at(&self) -> Option<DateTime<Utc>> {
+        self.confirmed_at
+    }
+
+    pub fn last_used_step(&self) -> Option<u64> {
+        self.last_used_step
+    }
+}
+
+/// RFC 6238 に基づく TOTP の生成・照合を行うドメインサービス。
+pub trait TotpService: Send + Sync {
+    /// 暗号論的に安全な乱数から新しいシークレットを生成する
+    fn generate_secret(&self) -> TotpSecret;
+
+    /// 認証アプリに登録するための `otpauth://` URI を生成する
+    fn provisioning_uri(&self, secret: &TotpSecret, account_name: &str) -> String;
+
+    /// `now` を基準に許容範囲内のタイムステップでコードを照合し、一致したステップを返す
+    fn verify(&self, secret: &TotpSecret, code: &TotpCode, now: DateTime<Utc>) -> Option<u64>;
+}
+
+#[async_trait]
+pub trait TotpCredentialRepository: Send + Sync {
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<TotpCredential>, MfaRepositoryError>;
+
+    async fn save(&self, credential: &TotpCredential) -> Result<(), MfaRepositoryError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+    use uuid::Uuid;
+
+    #[fixture]
+    fn credential() -> TotpCredential {
+        TotpCredential::enroll(
+            UserId::from(Uuid::now_v7()),
+            TotpSecret::from_str_unchecked("JBSWY3DPEHPK3PXP"),
+        )
+    }
+
+    #[rstest]
+    #[case("123456", true)]
+    #[case("123 456", true)]
+    #[case("12345", false)]
+    #[case("12345a", false)]
+    fn test_totp_code_format(#[case] input: &str, #[case] expected: bool) {
+        assert_eq!(TotpCode::try_from(input.to_string()).is_ok(), expected);
+    }
+
+    #[rstest]
+    fn test_confirm_enables_mfa(credential: TotpCredential) {
+        assert!(!credential.is_enabled());
+
+        let confirmed = credential.confirm(100, Utc::now()).unwrap();
+        assert!(confirmed.is_enabled());
+        assert_eq!(confirmed.last_used_step(), Some(100));
+
+        assert_eq!(
+            confirmed.confirm(101, Utc::now()).unwrap_err(),
+            MfaError::AlreadyEnabled
+        );
+    }
+
+    #[rstest]
+    fn test_accept_step_rejects_replay(credential: TotpCredential) {
+        let confirmed = credential.confirm(100, Utc::now()).unwrap();
+
+        assert_eq!(
+            confirmed.clone().accept_step(100).unwrap_err(),
+            MfaError::InvalidCode
+        );
+        assert_eq!(
+            confirmed.accept_step(101).unwrap().last_used_step(),
+            Some(101)
+        );
+    }
+
+    #[rstest]
+    fn test_accept_step_requires_confirmation(credential: TotpCredential) {
+        assert_eq!(
+            credential.accept_step(100).unwrap_err(),
+            MfaError::NotEnrolled
+        );
+    }
+}
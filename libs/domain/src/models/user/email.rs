@@ -16,6 +16,13 @@ pub enum EmailError {
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Display, AsRef, SensitiveDebug)]
 pub struct Email(String);
 
+impl Email {
+    /// `@` より前の部分
+    pub fn local_part(&self) -> &str {
+        self.0.rsplit_once('@').map_or(&self.0, |(local, _)| local)
+    }
+}
+
 impl TryFrom<String> for Email {
     type Error = EmailError;
 
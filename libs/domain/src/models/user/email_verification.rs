@@ -0,0 +1,184 @@
+use crate::models::user::{User, UserId, UserRepositoryError};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// 確認メールに記載するトークンの有効期間。
+pub const EMAIL_VERIFICATION_TOKEN_TTL: Duration = Duration::hours(24);
+
+/// 確認メールを再送できるようになるまでの間隔。
+pub const EMAIL_VERIFICATION_RESEND_COOLDOWN: Duration = Duration::seconds(60);
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum EmailVerificationError {
+    #[error("Invalid or expired email verification token")]
+    InvalidToken,
+
+    #[error("Email address has not been verified")]
+    NotVerified,
+}
+
+/// メールアドレスの確認状態。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EmailVerificationStatus {
+    Unverified,
+    Verified { verified_at: DateTime<Utc> },
+}
+
+impl EmailVerificationStatus {
+    /// データベース等に保存された確認日時から状態を再構成する。
+    pub fn from_verified_at(verified_at: Option<DateTime<Utc>>) -> Self {
+        match verified_at {
+            Some(verified_at) => Self::Verified { verified_at },
+            None => Self::Unverified,
+        }
+    }
+
+    pub fn verified_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Verified { verified_at } => Some(*verified_at),
+            Self::Unverified => None,
+        }
+    }
+
+    pub fn is_verified(&self) -> bool {
+        matches!(self, Self::Verified { .. })
+    }
+}
+
+/// 未確認のアカウントにログインを許可するかどうかの方針。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EmailVerificationPolicy {
+    /// 未確認でもログインを許可する
+    #[default]
+    Optional,
+    /// メールアドレスの確認が済むまでログインを拒否する
+    Required,
+}
+
+impl EmailVerificationPolicy {
+    /// 方針に照らしてログインを許可できるかを検証する。
+    pub fn ensure_login_allowed(&self, user: &User) -> Result<(), EmailVerificationError> {
+        match self {
+            Self::Required if !user.email_verification().is_verified() => {
+                Err(EmailVerificationError::NotVerified)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// ユーザーへ最後に確認メールを送信した記録。再送のクールダウン判定に使用する。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailVerificationDispatch {
+    user_id: UserId,
+    last_sent_at: DateTime<Utc>,
+}
+
+impl EmailVerificationDispatch {
+    /// 確認メールの送信を記録する。
+    pub fn record(user_id: UserId, now: DateTime<Utc>) -> Self {
+        Self {
+            user_id,
+            last_sent_at: now,
+        }
+    }
+
+    /// データベース等から取得した値を EmailVerificationDispatch に再構成する。
+    pub fn reconstruct(user_id: UserId, last_sent_at: DateTime<Utc>) -> Self {
+        Self {
+            user_id,
+            last_sent_at,
+        }
+    }
+
+    /// クールダウンが明けており、再送してよいかを判定する。
+    pub fn can_resend(&self, now: DateTime<Utc>) -> bool {
+        now >= self.last_sent_at + EMAIL_VERIFICATION_RESEND_COOLDOWN
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn last_sent_at(&self) -> DateTime<Utc> {
+        self.last_sent_at
+    }
+}
+
+#[async_trait]
+pub trait EmailVerificationDispatchRepository: Send + Sync {
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<EmailVerificationDispatch>, UserRepositoryError>;
+
+    /// ユーザーごとに 1 件の記録を保存する（既存の記録は置き換える）
+    async fn save(&self, dispatch: &EmailVerificationDispatch) -> Result<(), UserRepositoryError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::user::{Email, PasswordHash, UserEvent, UserIdentity};
+    use rstest::*;
+    use uuid::Uuid;
+
+    #[fixture]
+    fn user() -> User {
+        User::new(
+            UserId::from(Uuid::now_v7()),
+            Email::try_from("verify@example.com").unwrap(),
+            PasswordHash::from_str_unchecked("hash"),
+        )
+    }
+
+    #[rstest]
+    fn test_verify_email_keeps_first_verification(user: User) {
+        let now = Utc::now();
+        assert!(!user.email_verification().is_verified());
+
+        let user_id = user.id();
+        let (verified, events) = user.verify_email(now);
+        assert_eq!(verified.email_verification().verified_at(), Some(now));
+        assert_eq!(
+            events,
+            vec![UserEvent::EmailVerified {
+                user_id,
+                occurred_at: now,
+            }]
+        );
+
+        let (reverified, events) = verified.verify_email(now + Duration::hours(1));
+        assert_eq!(reverified.email_verification().verified_at(), Some(now));
+        assert!(events.is_empty());
+    }
+
+    #[rstest]
+    fn test_policy_rejects_unverified_only_when_required(user: User) {
+        assert!(
+            EmailVerificationPolicy::Optional
+                .ensure_login_allowed(&user)
+                .is_ok()
+        );
+        assert_eq!(
+            EmailVerificationPolicy::Required.ensure_login_allowed(&user),
+            Err(EmailVerificationError::NotVerified)
+        );
+        assert!(
+            EmailVerificationPolicy::Required
+                .ensure_login_allowed(&user.verify_email(Utc::now()).0)
+                .is_ok()
+        );
+    }
+
+    #[rstest]
+    fn test_resend_cooldown(user: User) {
+        let now = Utc::now();
+        let dispatch = EmailVerificationDispatch::record(user.id(), now);
+
+        assert!(!dispatch.can_resend(now + Duration::seconds(59)));
+        assert!(dispatch.can_resend(now + EMAIL_VERIFICATION_RESEND_COOLDOWN));
+    }
+}
@@ -1,5 +1,7 @@
+use crate::Version;
 use crate::models::user::{
-    EmailError, PasswordError, UserRepositoryError, UserUniquenessViolation,
+    AccountStatusError, EmailError, EmailVerificationError, PasswordError, UserRepositoryError,
+    UserUniquenessViolation,
 };
 use thiserror::Error;
 
@@ -14,9 +16,23 @@ pub enum UserError {
     #[error(transparent)]
     Uniqueness(#[from] UserUniquenessViolation),
 
+    #[error(transparent)]
+    EmailVerification(#[from] EmailVerificationError),
+
     #[error(transparent)]
     Repository(#[from] UserRepositoryError),
 
     #[error("User not found")]
     NotFound,
+
+    /// クライアントが参照したバージョンが最新ではない
+    #[error("User version mismatch (expected {expected}, actual {actual})")]
+    VersionMismatch { expected: Version, actual: Version },
+
+    #[error(transparent)]
+    AccountStatus(#[from] AccountStatusError),
+
+    /// 管理者の要求によりパスワードの再設定が必要（現在のパスワードは使用できない）
+    #[error("Password must be reset before it can be used")]
+    PasswordResetRequired,
 }
@@ -0,0 +1,101 @@
+use crate::models::user::{Email, UserId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// User 集約で発生したドメインイベント。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum UserEvent {
+    /// ユーザーが新規登録された
+    UserRegistered {
+        user_id: UserId,
+        email: Email,
+        occurred_at: DateTime<Utc>,
+    },
+    /// 登録済みのメールアドレスで新規登録が試みられた（所有者への通知に使用する）
+    SignupAttempted {
+        user_id: UserId,
+        email: Email,
+        occurred_at: DateTime<Utc>,
+    },
+    /// パスワードが変更された
+    PasswordChanged {
+        user_id: UserId,
+        occurred_at: DateTime<Utc>,
+    },
+    /// メールアドレスが確認された
+    EmailVerified {
+        user_id: UserId,
+        occurred_at: DateTime<Utc>,
+    },
+    /// 管理者によりアカウントが停止された
+    UserSuspended {
+        user_id: UserId,
+        occurred_at: DateTime<Utc>,
+    },
+    /// 一定時刻までアカウントの利用が制限された
+    UserLocked {
+        user_id: UserId,
+        locked_until: DateTime<Utc>,
+        occurred_at: DateTime<Utc>,
+    },
+    /// 管理者によりパスワードの再設定が要求された
+    PasswordResetRequired {
+        user_id: UserId,
+        occurred_at: DateTime<Utc>,
+    },
+    /// 停止中のアカウントが再開された
+    UserReactivated {
+        user_id: UserId,
+        occurred_at: DateTime<Utc>,
+    },
+    /// アカウントが削除された
+    UserDeleted {
+        user_id: UserId,
+        occurred_at: DateTime<Utc>,
+    },
+}
+
+impl UserEvent {
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            Self::UserRegistered { .. } => "UserRegistered",
+            Self::SignupAttempted { .. } => "SignupAttempted",
+            Self::PasswordChanged { .. } => "PasswordChanged",
+            Self::EmailVerified { .. } => "EmailVerified",
+            Self::UserSuspended { .. } => "UserSuspended",
+            Self::UserLocked { .. } => "UserLocked",
+            Self::PasswordResetRequired { .. } => "PasswordResetRequired",
+            Self::UserReactivated { .. } => "UserReactivated",
+            Self::UserDeleted { .. } => "UserDeleted",
+        }
+    }
+
+    pub fn user_id(&self) -> UserId {
+        match self {
+            Self::UserRegistered { user_id, .. }
+            | Self::SignupAttempted { user_id, .. }
+            | Self::PasswordChanged { user_id, .. }
+            | Self::EmailVerified { user_id, .. }
+            | Self::UserSuspended { user_id, .. }
+            | Self::UserLocked { user_id, .. }
+            | Self::PasswordResetRequired { user_id, .. }
+            | Self::UserReactivated { user_id, .. }
+            | Self::UserDeleted { user_id, .. } => *user_id,
+        }
+    }
+
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        match self {
+            Self::UserRegistered { occurred_at, .. }
+            | Self::SignupAttempted { occurred_at, .. }
+            | Self::PasswordChanged { occurred_at, .. }
+            | Self::EmailVerified { occurred_at, .. }
+            | Self::UserSuspended { occurred_at, .. }
+            | Self::UserLocked { occurred_at, .. }
+            | Self::PasswordResetRequired { occurred_at, .. }
+            | Self::UserReactivated { occurred_at, .. }
+            | Self::UserDeleted { occurred_at, .. } => *occurred_at,
+        }
+    }
+}
@@ -1,17 +1,33 @@
 pub mod email;
+pub mod email_verification;
 pub mod error;
+pub mod event;
 pub mod password_hash;
+pub mod role;
 pub mod service;
+pub mod status;
 pub mod user_id;
 
 pub use email::{Email, EmailError};
+pub use email_verification::{
+    EMAIL_VERIFICATION_RESEND_COOLDOWN, EMAIL_VERIFICATION_TOKEN_TTL, EmailVerificationDispatch,
+    EmailVerificationDispatchRepository, EmailVerificationError, EmailVerificationPolicy,
+    EmailVerificationStatus,
+};
 pub use error::UserError;
-pub use password_hash::{PasswordError, PasswordHash};
-pub use service::{UserUniquenessChecker, UserUniquenessViolation};
+pub use event::UserEvent;
+pub use password_hash::{PasswordError, PasswordHash, PasswordViolation};
+pub use role::{Permission, Role, UnknownRole};
+pub use service::{SignupDisclosurePolicy, UserUniquenessChecker, UserUniquenessViolation};
+pub use status::{
+    AccountState, AccountStatus, AccountStatusError, ActiveAccount, DeletedAccount,
+    InconsistentAccountState, LockedAccount, SuspendedAccount, UnknownAccountStatus,
+};
 pub use user_id::UserId;
 
-use crate::Entity;
+use crate::{Entity, Version};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -37,6 +53,10 @@ pub enum UserRepositoryError {
     #[error("Data mapping failed: {0}")]
     MappingFailed(#[source] anyhow::Error),
 
+    /// 読み込み後に他のトランザクションが更新した（楽観ロックの競合）
+    #[error("User was modified by another transaction")]
+    ConcurrentModification,
+
     #[error("Unexpected repository error")]
     Unexpected(#[from] anyhow::Error),
 }
@@ -47,19 +67,333 @@ pub struct User {
     id: UserId,
     email: Email,
     password_hash: PasswordHash,
+    email_verification: EmailVerificationStatus,
+    /// 付与されたロール（重複なし・昇順）
+    roles: Vec<Role>,
+    state: AccountState,
+    /// 最後にパスワードを変更した日時（これより前に発行したトークンは受け付けない）
+    credentials_changed_at: Option<DateTime<Utc>>,
+    /// 管理者がパスワードの再設定を要求した日時（再設定されるまでパスワードでのログインを拒否する）
+    password_reset_required_at: Option<DateTime<Utc>>,
+    version: Version,
 }
 
 impl User {
-    /// ユーザーモデルの新規生成。
+    /// ユーザーモデルの生成。メールアドレスは未確認、ロールは一般ユーザーの状態で作成される。
+    ///
+    /// イベントを発生させないため、新規登録のワークフローでは `register` を使用すること。
     pub fn new(id: UserId, email: Email, password_hash: PasswordHash) -> Self {
         Self {
             id,
             email,
             password_hash,
+            email_verification: EmailVerificationStatus::Unverified,
+            roles: vec![Role::User],
+            state: AccountState::new(),
+            credentials_changed_at: None,
+            password_reset_required_at: None,
+            version: Version::NEW,
+        }
+    }
+
+    /// ユーザーを新規登録する。
+    pub fn register(
+        id: UserId,
+        email: Email,
+        password_hash: PasswordHash,
+        now: DateTime<Utc>,
+    ) -> (Self, Vec<UserEvent>) {
+        let user = Self::new(id, email, password_hash);
+        let event = UserEvent::UserRegistered {
+            user_id: user.id,
+            email: user.email.clone(),
+            occurred_at: now,
+        };
+        (user, vec![event])
+    }
+
+    /// 登録済みのメールアドレスで新規登録が試みられたことを記録する。状態は変化しない。
+    pub fn signup_attempted(&self, now: DateTime<Utc>) -> Vec<UserEvent> {
+        vec![UserEvent::SignupAttempted {
+            user_id: self.id,
+            email: self.email.clone(),
+            occurred_at: now,
+        }]
+    }
+
+    /// データベース等から取得した値を User に再構成する。
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstruct(
+        id: UserId,
+        email: Email,
+        password_hash: PasswordHash,
+        email_verification: EmailVerificationStatus,
+        roles: Vec<Role>,
+        state: AccountState,
+        credentials_changed_at: Option<DateTime<Utc>>,
+        password_reset_required_at: Option<DateTime<Utc>>,
+        version: Version,
+    ) -> Self {
+        Self {
+            id,
+            email,
+            password_hash,
+            email_verification,
+            roles: normalize_roles(roles),
+            state,
+            credentials_changed_at,
+            password_reset_required_at,
+            version,
+        }
+    }
+
+    pub fn roles(&self) -> &[Role] {
+        &self.roles
+    }
+
+    /// 付与されたロールから導出した権限
+    pub fn permissions(&self) -> Vec<Permission> {
+        Role::permissions_of(&self.roles)
+    }
+
+    /// ロールを付与する（付与済みの場合は何もしない）
+    pub fn grant_role(self, role: Role) -> Self {
+        let mut roles = self.roles.clone();
+        roles.push(role);
+        Self {
+            roles: normalize_roles(roles),
+            ..self
+        }
+    }
+
+    /// ロールを剥奪する（未付与の場合は何もしない）
+    pub fn revoke_role(self, role: Role) -> Self {
+        let roles = self.roles.iter().copied().filter(|r| *r != role).collect();
+        Self { roles, ..self }
+    }
+
+    pub fn state(&self) -> &AccountState {
+        &self.state
+    }
+
+    pub fn status(&self) -> AccountStatus {
+        self.state.status()
+    }
+
+    /// ログイン・認証を許可できる状態かを検証する
+    pub fn ensure_active(&self, now: DateTime<Utc>) -> Result<(), AccountStatusError> {
+        self.state.ensure_active(now)
+    }
+
+    /// 利用を停止する（利用中・ロック中のアカウントのみ）
+    pub fn suspend(self, now: DateTime<Utc>) -> Result<(Self, Vec<UserEvent>), UserError> {
+        let suspended = match self.state {
+            AccountState::Active(active) => active.suspend(now),
+            AccountState::Locked(locked) => locked.suspend(now),
+            _ => return Err(self.invalid_transition(AccountStatus::Suspended)),
+        };
+        let event = UserEvent::UserSuspended {
+            user_id: self.id,
+            occurred_at: now,
+        };
+        let user = Self {
+            state: AccountState::Suspended(suspended),
+            ..self
+        };
+        Ok((user, vec![event]))
+    }
+
+    /// 停止中・ロック中のアカウントを利用可能に戻す
+    pub fn reactivate(self, now: DateTime<Utc>) -> Result<(Self, Vec<UserEvent>), UserError> {
+        let active = match self.state {
+            AccountState::Suspended(suspended) => suspended.reactivate(),
+            AccountState::Locked(locked) => locked.unlock(),
+            _ => return Err(self.invalid_transition(AccountStatus::Active)),
+        };
+        let event = UserEvent::UserReactivated {
+            user_id: self.id,
+            occurred_at: now,
+        };
+        let user = Self {
+            state: AccountState::Active(active),
+            ..self
+        };
+        Ok((user, vec![event]))
+    }
+
+    /// `until` まで利用を制限する。制限時刻を過ぎたロックは解除したうえで掛け直す。
+    pub fn lock(
+        self,
+        until: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<(Self, Vec<UserEvent>), UserError> {
+        let locked = match self.state {
+            AccountState::Active(active) => active.lock(now, until),
+            AccountState::Locked(locked) if locked.is_expired(now) => {
+                locked.unlock().lock(now, until)
+            }
+            _ => return Err(self.invalid_transition(AccountStatus::Locked)),
+        };
+        let event = UserEvent::UserLocked {
+            user_id: self.id,
+            locked_until: until,
+            occurred_at: now,
+        };
+        let user = Self {
+            state: AccountState::Locked(locked),
+            ..self
+        };
+        Ok((user, vec![event]))
+    }
+
+    /// 管理者がパスワードの再設定を要求する（削除済みのアカウントは対象外）
+    ///
+    /// 再設定されるまで現在のパスワードを使用できなくし、発行済みのトークンも拒否の対象とする。
+    pub fn require_password_reset(
+        self,
+        now: DateTime<Utc>,
+    ) -> Result<(Self, Vec<UserEvent>), UserError> {
+        if let AccountState::Deleted(_) = self.state {
+            return Err(self.invalid_transition(AccountStatus::Deleted));
+        }
+        let event = UserEvent::PasswordResetRequired {
+            user_id: self.id,
+            occurred_at: now,
+        };
+        let user = Self {
+            credentials_changed_at: Some(now),
+            password_reset_required_at: Some(now),
+            ..self
+        };
+        Ok((user, vec![event]))
+    }
+
+    pub fn password_reset_required_at(&self) -> Option<DateTime<Utc>> {
+        self.password_reset_required_at
+    }
+
+    /// 現在のパスワードでログイン・パスワード変更を行えるかを検証する
+    pub fn ensure_password_usable(&self) -> Result<(), UserError> {
+        if self.password_reset_required_at.is_some() {
+            return Err(UserError::PasswordResetRequired);
+        }
+        Ok(())
+    }
+
+    /// 削除済みにする。行は残し、以降のログイン・認証を拒否する。
+    pub fn delete(self, now: DateTime<Utc>) -> Result<(Self, Vec<UserEvent>), UserError> {
+        let deleted = match self.state {
+            AccountState::Active(active) => active.delete(now),
+            AccountState::Suspended(suspended) => suspended.delete(now),
+            AccountState::Locked(locked) => locked.delete(now),
+            AccountState::Deleted(_) => {
+                return Err(self.invalid_transition(AccountStatus::Deleted));
+            }
+        };
+        let event = UserEvent::UserDeleted {
+            user_id: self.id,
+            occurred_at: now,
+        };
+        let user = Self {
+            state: AccountState::Deleted(deleted),
+            ..self
+        };
+        Ok((user, vec![event]))
+    }
+
+    fn invalid_transition(&self, to: AccountStatus) -> UserError {
+        AccountStatusError::InvalidTransition {
+            from: self.status(),
+            to,
+        }
+        .into()
+    }
+
+    /// 読み込んだ時点のバージョン（未保存の場合は `Version::NEW`）
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    /// クライアントが参照していたバージョンと一致することを確認する（`If-Match` 等の事前条件）
+    pub fn ensure_version(&self, expected: Version) -> Result<(), UserError> {
+        if self.version != expected {
+            return Err(UserError::VersionMismatch {
+                expected,
+                actual: self.version,
+            });
+        }
+        Ok(())
+    }
+
+    /// メールアドレスを確認済みにする（確認済みの場合は最初の確認日時を保持し、イベントも発生しない）
+    pub fn verify_email(self, now: DateTime<Utc>) -> (Self, Vec<UserEvent>) {
+        if self.email_verification.is_verified() {
+            return (self, vec![]);
+        }
+        let event = UserEvent::EmailVerified {
+            user_id: self.id,
+            occurred_at: now,
+        };
+        let user = Self {
+            email_verification: EmailVerificationStatus::Verified { verified_at: now },
+            ..self
+        };
+        (user, vec![event])
+    }
+
+    pub fn email_verification(&self) -> &EmailVerificationStatus {
+        &self.email_verification
+    }
+
+    /// パスワードを変更する（ハッシュ化は `PasswordService` で事前に行う）
+    ///
+    /// 変更前に発行したトークンは `is_token_issued_before_credentials_change` で拒否できる。
+    /// 管理者による再設定の要求はこの変更で満たされる。
+    pub fn change_password(
+        self,
+        password_hash: PasswordHash,
+        now: DateTime<Utc>,
+    ) -> (Self, Vec<UserEvent>) {
+        let event = UserEvent::PasswordChanged {
+            user_id: self.id,
+            occurred_at: now,
+        };
+        let user = Self {
+            password_hash,
+            credentials_changed_at: Some(now),
+            password_reset_required_at: None,
+            ..self
+        };
+        (user, vec![event])
+    }
+
+    pub fn credentials_changed_at(&self) -> Option<DateTime<Utc>> {
+        self.credentials_changed_at
+    }
+
+    /// `issued_at` に発行されたトークンが最後のパスワード変更より前のものかを判定する。
+    ///
+    /// JWT の `iat` は秒単位のため、変更日時も秒未満を切り捨てて比較する。
+    pub fn is_token_issued_before_credentials_change(&self, issued_at: DateTime<Utc>) -> bool {
+        self.credentials_changed_at
+            .is_some_and(|changed_at| issued_at.timestamp() < changed_at.timestamp())
+    }
+
+    /// 同じパスワードを現在の設定でハッシュ化し直した値に置き換える（変更ではないためイベントは発行しない）
+    pub fn rehash_password(self, password_hash: PasswordHash) -> Self {
+        Self {
+            password_hash,
+            ..self
         }
     }
 }
 
+fn normalize_roles(mut roles: Vec<Role>) -> Vec<Role> {
+    roles.sort();
+    roles.dedup();
+    roles
+}
+
 impl UserIdentity for User {
     fn id(&self) -> UserId {
         self.id
@@ -78,14 +412,32 @@ impl Authenticatable for User {
 
 #[async_trait]
 pub trait UserRepository: Send + Sync {
+    async fn find_by_id(&self, id: UserId) -> Result<Option<User>, UserRepositoryError>;
     async fn find_by_email(&self, email: &Email) -> Result<Option<User>, UserRepositoryError>;
+    /// 保存する。永続化済みのユーザーは読み込み時のバージョンと一致する場合のみ更新し、
+    /// 不一致の場合は `ConcurrentModification` を返す。保存後に再度更新する場合は再取得すること。
     async fn save(&self, user: &User) -> Result<(), UserRepositoryError>;
+    /// 条件に一致するユーザーを ID（UUIDv7 のため登録順）の昇順で取得する。
+    async fn search(&self, criteria: &UserSearchCriteria)
+    -> Result<Vec<User>, UserRepositoryError>;
+}
+
+/// 管理者向けのユーザー一覧の検索条件。`after` より後の ID から最大 `limit` 件を取得する。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserSearchCriteria {
+    /// メールアドレスの前方一致
+    pub email_prefix: Option<String>,
+    pub status: Option<AccountStatus>,
+    /// 前のページの最後のユーザー ID（カーソル）
+    pub after: Option<UserId>,
+    pub limit: u32,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::models::user::user_id::UserId;
+    use chrono::Utc;
     use uuid::Uuid;
 
     #[test]
@@ -105,4 +457,214 @@ mod tests {
         // Different ID should not be equal
         assert_ne!(user1, user2);
     }
+
+    #[test]
+    fn test_register_emits_user_registered() {
+        let id = UserId::from(Uuid::now_v7());
+        let email = Email::try_from("new@example.com").unwrap();
+        let now = Utc::now();
+
+        let (user, events) = User::register(
+            id,
+            email.clone(),
+            PasswordHash::from_str_unchecked("hash"),
+            now,
+        );
+
+        assert_eq!(user.id(), id);
+        assert!(!user.email_verification().is_verified());
+        assert_eq!(
+            events,
+            vec![UserEvent::UserRegistered {
+                user_id: id,
+                email,
+                occurred_at: now,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_change_password_emits_password_changed() {
+        let id = UserId::from(Uuid::now_v7());
+        let user = User::new(
+            id,
+            Email::try_from("change@example.com").unwrap(),
+            PasswordHash::from_str_unchecked("old"),
+        );
+        let now = Utc::now();
+
+        assert!(!user.is_token_issued_before_credentials_change(now));
+
+        let (user, events) = user.change_password(PasswordHash::from_str_unchecked("new"), now);
+
+        assert_eq!(
+            user.password_hash(),
+            &PasswordHash::from_str_unchecked("new")
+        );
+        assert_eq!(user.credentials_changed_at(), Some(now));
+        // 変更前に発行されたトークンのみを拒否する（同じ秒に発行されたものは受け付ける）
+        assert!(user.is_token_issued_before_credentials_change(now - chrono::Duration::seconds(1)));
+        assert!(!user.is_token_issued_before_credentials_change(now));
+        assert_eq!(
+            events,
+            vec![UserEvent::PasswordChanged {
+                user_id: id,
+                occurred_at: now,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_operations_keep_loaded_version() {
+        let user = User::reconstruct(
+            UserId::from(Uuid::now_v7()),
+            Email::try_from("version@example.com").unwrap(),
+            PasswordHash::from_str_unchecked("old"),
+            EmailVerificationStatus::Unverified,
+            vec![Role::User],
+            AccountState::new(),
+            None,
+            None,
+            Version::from(3),
+        );
+
+        let (user, _) = user.change_password(PasswordHash::from_str_unchecked("new"), Utc::now());
+        let (user, _) = user.verify_email(Utc::now());
+
+        assert_eq!(user.version(), Version::from(3));
+        assert!(user.ensure_version(Version::from(3)).is_ok());
+        assert!(matches!(
+            user.ensure_version(Version::from(2)),
+            Err(UserError::VersionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_new_user_has_new_version() {
+        let user = User::new(
+            UserId::from(Uuid::now_v7()),
+            Email::try_from("fresh@example.com").unwrap(),
+            PasswordHash::from_str_unchecked("hash"),
+        );
+        assert!(user.version().is_new());
+    }
+
+    #[test]
+    fn test_grant_and_revoke_roles() {
+        let user = User::new(
+            UserId::from(Uuid::now_v7()),
+            Email::try_from("roles@example.com").unwrap(),
+            PasswordHash::from_str_unchecked("hash"),
+        );
+        assert_eq!(user.roles(), &[Role::User]);
+        assert!(!user.permissions().contains(&Permission::UsersWrite));
+
+        let admin = user.grant_role(Role::Admin).grant_role(Role::Admin);
+        assert_eq!(admin.roles(), &[Role::User, Role::Admin]);
+        assert!(admin.permissions().contains(&Permission::UsersWrite));
+
+        let demoted = admin.revoke_role(Role::Admin);
+        assert_eq!(demoted.roles(), &[Role::User]);
+    }
+
+    #[test]
+    fn test_suspend_and_reactivate() {
+        let now = Utc::now();
+        let user = User::new(
+            UserId::from(Uuid::now_v7()),
+            Email::try_from("status@example.com").unwrap(),
+            PasswordHash::from_str_unchecked("hash"),
+        );
+        assert_eq!(user.status(), AccountStatus::Active);
+
+        // 利用中のアカウントは再開できない
+        assert!(matches!(
+            user.clone().reactivate(now),
+            Err(UserError::AccountStatus(
+                AccountStatusError::InvalidTransition {
+                    from: AccountStatus::Active,
+                    to: AccountStatus::Active,
+                }
+            ))
+        ));
+
+        let (suspended, events) = user.suspend(now).unwrap();
+        assert_eq!(suspended.status(), AccountStatus::Suspended);
+        assert_eq!(
+            events,
+            vec![UserEvent::UserSuspended {
+                user_id: suspended.id(),
+                occurred_at: now,
+            }]
+        );
+        assert!(suspended.clone().suspend(now).is_err());
+
+        let (reactivated, events) = suspended.reactivate(now).unwrap();
+        assert_eq!(reactivated.status(), AccountStatus::Active);
+        assert_eq!(events[0].event_type(), "UserReactivated");
+    }
+
+    #[test]
+    fn test_lock_and_delete() {
+        let now = Utc::now();
+        let until = now + chrono::Duration::minutes(15);
+        let user = User::new(
+            UserId::from(Uuid::now_v7()),
+            Email::try_from("lock@example.com").unwrap(),
+            PasswordHash::from_str_unchecked("hash"),
+        );
+
+        let (locked, events) = user.lock(until, now).unwrap();
+        assert_eq!(locked.status(), AccountStatus::Locked);
+        assert_eq!(
+            locked.ensure_active(now),
+            Err(AccountStatusError::Locked { until })
+        );
+        assert_eq!(events[0].event_type(), "UserLocked");
+        // 有効なロックは掛け直せないが、期限切れであれば掛け直せる
+        assert!(locked.clone().lock(until, now).is_err());
+        assert!(
+            locked
+                .clone()
+                .lock(until + chrono::Duration::minutes(30), until)
+                .is_ok()
+        );
+
+        let (deleted, events) = locked.delete(now).unwrap();
+        assert_eq!(deleted.status(), AccountStatus::Deleted);
+        assert_eq!(deleted.ensure_active(now), Err(AccountStatusError::Deleted));
+        assert_eq!(events[0].event_type(), "UserDeleted");
+
+        // 削除済みのアカウントからは遷移できない
+        assert!(deleted.clone().reactivate(now).is_err());
+        assert!(deleted.clone().suspend(now).is_err());
+        assert!(deleted.clone().require_password_reset(now).is_err());
+        assert!(deleted.delete(now).is_err());
+    }
+
+    #[test]
+    fn test_require_password_reset_until_changed() {
+        let now = Utc::now();
+        let user = User::new(
+            UserId::from(Uuid::now_v7()),
+            Email::try_from("forced@example.com").unwrap(),
+            PasswordHash::from_str_unchecked("old"),
+        );
+        assert!(user.ensure_password_usable().is_ok());
+
+        let (required, events) = user.require_password_reset(now).unwrap();
+        assert!(matches!(
+            required.ensure_password_usable(),
+            Err(UserError::PasswordResetRequired)
+        ));
+        assert_eq!(events[0].event_type(), "PasswordResetRequired");
+        // 要求前に発行されたトークンも拒否の対象となる
+        assert!(
+            required.is_token_issued_before_credentials_change(now - chrono::Duration::seconds(1))
+        );
+
+        let (changed, _) = required.change_password(PasswordHash::from_str_unchecked("new"), now);
+        assert!(changed.ensure_password_usable().is_ok());
+        assert_eq!(changed.password_reset_required_at(), None);
+    }
 }
@@ -4,12 +4,41 @@ use sensitive_data::{SecretRule, SensitiveData};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+/// パスワードの方針に対する個々の違反。
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum PasswordViolation {
+    #[error("Password must be at least {min} characters long")]
+    TooShort { min: usize },
+    #[error("Password must be at most {max} characters long")]
+    TooLong { max: usize },
+    #[error(
+        "Password must contain at least {required} of: lowercase letters, uppercase letters, digits, symbols"
+    )]
+    TooWeak { required: usize },
+    #[error("Password must not contain the email address")]
+    ContainsEmail,
+}
+
+impl PasswordViolation {
+    /// API 等でクライアントが違反を識別するためのコード
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::TooShort { .. } => "too_short",
+            Self::TooLong { .. } => "too_long",
+            Self::TooWeak { .. } => "too_weak",
+            Self::ContainsEmail => "contains_email",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Error, PartialEq, Eq)]
 pub enum PasswordError {
-    #[error("Password is too short")]
-    TooShort,
-    #[error("Password lacks required characters")]
-    TooWeak,
+    /// 方針に違反している（違反した規則をすべて保持する）
+    #[error("Password does not satisfy the policy ({} violation(s))", .0.len())]
+    PolicyViolation(Vec<PasswordViolation>),
+    /// 過去に漏洩したパスワードに含まれている
+    #[error("Password has appeared in a data breach; choose a different password")]
+    Breached,
     #[error("Invalid password hash format: {found}")]
     InvalidFormat { found: String },
 }
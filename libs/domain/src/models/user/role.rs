@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// 操作ごとの権限。アクセストークンに埋め込まれ、API のハンドラー単位で要求される。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Permission {
+    /// 自分のプロフィールの参照
+    #[serde(rename = "profile:read")]
+    ProfileRead,
+    /// 自分のプロフィール・認証設定の変更
+    #[serde(rename = "profile:write")]
+    ProfileWrite,
+    /// 任意のユーザーの参照
+    #[serde(rename = "users:read")]
+    UsersRead,
+    /// 任意のユーザーの管理（停止・削除等）
+    #[serde(rename = "users:write")]
+    UsersWrite,
+}
+
+impl Permission {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ProfileRead => "profile:read",
+            Self::ProfileWrite => "profile:write",
+            Self::UsersRead => "users:read",
+            Self::UsersWrite => "users:write",
+        }
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("Unknown role: {0}")]
+pub struct UnknownRole(pub String);
+
+/// ユーザーに付与するロール。権限はロールから導出する。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// 登録済みの一般ユーザー
+    User,
+    /// ユーザー管理を行う管理者
+    Admin,
+}
+
+impl Role {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::Admin => "admin",
+        }
+    }
+
+    pub fn permissions(self) -> &'static [Permission] {
+        match self {
+            Self::User => &[Permission::ProfileRead, Permission::ProfileWrite],
+            Self::Admin => &[
+                Permission::ProfileRead,
+                Permission::ProfileWrite,
+                Permission::UsersRead,
+                Permission::UsersWrite,
+            ],
+        }
+    }
+
+    /// 複数のロールが持つ権限の和集合（重複なし・順序固定）
+    pub fn permissions_of(roles: &[Role]) -> Vec<Permission> {
+        roles
+            .iter()
+            .flat_map(|role| role.permissions().iter().copied())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Role {
+    type Err = UnknownRole;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "user" => Ok(Self::User),
+            "admin" => Ok(Self::Admin),
+            other => Err(UnknownRole(other.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permissions_of_merges_roles() {
+        assert_eq!(
+            Role::permissions_of(&[Role::User]),
+            vec![Permission::ProfileRead, Permission::ProfileWrite]
+        );
+        assert_eq!(
+            Role::permissions_of(&[Role::Admin, Role::User]),
+            vec![
+                Permission::ProfileRead,
+                Permission::ProfileWrite,
+                Permission::UsersRead,
+                Permission::UsersWrite,
+            ]
+        );
+        assert!(Role::permissions_of(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_role_round_trips_through_str() {
+        for role in [Role::User, Role::Admin] {
+            assert_eq!(role.as_str().parse::<Role>(), Ok(role));
+        }
+        assert_eq!("root".parse::<Role>(), Err(UnknownRole("root".to_string())));
+    }
+}
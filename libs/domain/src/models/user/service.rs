@@ -11,6 +11,16 @@ pub enum UserUniquenessViolation {
     Infrastructure(#[from] Box<UserRepositoryError>),
 }
 
+/// 登録済みのメールアドレスでの新規登録を、応答で明かすかどうかの方針。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SignupDisclosurePolicy {
+    /// 登録済みであることを応答で明かす（409）
+    #[default]
+    Disclose,
+    /// 応答を常に同じにし、登録済みの場合は所有者へメールで通知する
+    Conceal,
+}
+
 #[async_trait]
 pub trait UserUniquenessChecker: Send + Sync {
     /// 指定されたメールアドレスが既に使用されていないかチェックする。
@@ -54,7 +64,7 @@ impl UserUniquenessChecker for UserUniquenessCheckerImpl {
 mod tests {
     use super::*;
     use crate::id::IdGenerator;
-    use crate::models::user::{PasswordHash, User, UserId};
+    use crate::models::user::{PasswordHash, User, UserId, UserSearchCriteria};
     use rstest::*;
 
     pub struct StubUserRepository {
@@ -64,12 +74,21 @@ mod tests {
 
     #[async_trait]
     impl UserRepository for StubUserRepository {
+        async fn find_by_id(&self, _id: UserId) -> Result<Option<User>, UserRepositoryError> {
+            Ok(self.found_user.clone())
+        }
         async fn find_by_email(&self, _email: &Email) -> Result<Option<User>, UserRepositoryError> {
             Ok(self.found_user.clone())
         }
         async fn save(&self, _user: &User) -> Result<(), UserRepositoryError> {
             Ok(())
         }
+        async fn search(
+            &self,
+            _criteria: &UserSearchCriteria,
+        ) -> Result<Vec<User>, UserRepositoryError> {
+            Ok(self.found_user.clone().into_iter().collect())
+        }
     }
 
     #[fixture]
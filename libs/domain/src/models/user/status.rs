@@ -0,0 +1,317 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("Unknown account status: {0}")]
+pub struct UnknownAccountStatus(pub String);
+
+/// 永続化された値の組み合わせが状態として成り立たない
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("Inconsistent account state: {0}")]
+pub struct InconsistentAccountState(pub AccountStatus);
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum AccountStatusError {
+    #[error("Account is suspended")]
+    Suspended,
+
+    #[error("Account is locked until {until}")]
+    Locked { until: DateTime<Utc> },
+
+    #[error("Account has been deleted")]
+    Deleted,
+
+    /// 現在の状態からは遷移できない
+    #[error("Account cannot be changed from {from} to {to}")]
+    InvalidTransition {
+        from: AccountStatus,
+        to: AccountStatus,
+    },
+}
+
+/// アカウントの利用状態の種別。永続化・検索条件・API での表現に使用する。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountStatus {
+    /// 通常どおり利用できる
+    Active,
+    /// 管理者により利用を停止されている
+    Suspended,
+    /// 一定時刻まで利用が制限されている
+    Locked,
+    /// 削除済み（行は監査のために残す）
+    Deleted,
+}
+
+impl AccountStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Suspended => "suspended",
+            Self::Locked => "locked",
+            Self::Deleted => "deleted",
+        }
+    }
+}
+
+impl fmt::Display for AccountStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AccountStatus {
+    type Err = UnknownAccountStatus;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "active" => Ok(Self::Active),
+            "suspended" => Ok(Self::Suspended),
+            "locked" => Ok(Self::Locked),
+            "deleted" => Ok(Self::Deleted),
+            other => Err(UnknownAccountStatus(other.to_string())),
+        }
+    }
+}
+
+/// 利用中のアカウント
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActiveAccount(());
+
+impl ActiveAccount {
+    pub fn suspend(self, now: DateTime<Utc>) -> SuspendedAccount {
+        SuspendedAccount { suspended_at: now }
+    }
+
+    pub fn lock(self, now: DateTime<Utc>, until: DateTime<Utc>) -> LockedAccount {
+        LockedAccount {
+            locked_at: now,
+            locked_until: until,
+        }
+    }
+
+    pub fn delete(self, now: DateTime<Utc>) -> DeletedAccount {
+        DeletedAccount { deleted_at: now }
+    }
+}
+
+/// 管理者により停止されたアカウント
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuspendedAccount {
+    suspended_at: DateTime<Utc>,
+}
+
+impl SuspendedAccount {
+    pub fn suspended_at(&self) -> DateTime<Utc> {
+        self.suspended_at
+    }
+
+    pub fn reactivate(self) -> ActiveAccount {
+        ActiveAccount(())
+    }
+
+    pub fn delete(self, now: DateTime<Utc>) -> DeletedAccount {
+        DeletedAccount { deleted_at: now }
+    }
+}
+
+/// 一定時刻まで利用を制限されたアカウント
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedAccount {
+    locked_at: DateTime<Utc>,
+    locked_until: DateTime<Utc>,
+}
+
+impl LockedAccount {
+    pub fn locked_at(&self) -> DateTime<Utc> {
+        self.locked_at
+    }
+
+    pub fn locked_until(&self) -> DateTime<Utc> {
+        self.locked_until
+    }
+
+    /// 制限時刻を過ぎているか
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.locked_until <= now
+    }
+
+    pub fn unlock(self) -> ActiveAccount {
+        ActiveAccount(())
+    }
+
+    pub fn suspend(self, now: DateTime<Utc>) -> SuspendedAccount {
+        SuspendedAccount { suspended_at: now }
+    }
+
+    pub fn delete(self, now: DateTime<Utc>) -> DeletedAccount {
+        DeletedAccount { deleted_at: now }
+    }
+}
+
+/// 削除済みのアカウント。ここからの遷移は存在しない。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeletedAccount {
+    deleted_at: DateTime<Utc>,
+}
+
+impl DeletedAccount {
+    pub fn deleted_at(&self) -> DateTime<Utc> {
+        self.deleted_at
+    }
+}
+
+/// アカウントの利用状態。状態ごとの型が許可された遷移のみを持つ。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AccountState {
+    Active(ActiveAccount),
+    Suspended(SuspendedAccount),
+    Locked(LockedAccount),
+    Deleted(DeletedAccount),
+}
+
+impl AccountState {
+    /// 新規登録時の状態
+    pub fn new() -> Self {
+        Self::Active(ActiveAccount(()))
+    }
+
+    /// データベース等に保存された種別・遷移日時・制限時刻から状態を再構成する。
+    pub fn reconstruct(
+        status: AccountStatus,
+        changed_at: Option<DateTime<Utc>>,
+        locked_until: Option<DateTime<Utc>>,
+    ) -> Result<Self, InconsistentAccountState> {
+        match (status, changed_at, locked_until) {
+            (AccountStatus::Active, _, None) => Ok(Self::new()),
+            (AccountStatus::Suspended, Some(suspended_at), None) => {
+                Ok(Self::Suspended(SuspendedAccount { suspended_at }))
+            }
+            (AccountStatus::Locked, Some(locked_at), Some(locked_until)) => {
+                Ok(Self::Locked(LockedAccount {
+                    locked_at,
+                    locked_until,
+                }))
+            }
+            (AccountStatus::Deleted, Some(deleted_at), None) => {
+                Ok(Self::Deleted(DeletedAccount { deleted_at }))
+            }
+            (status, _, _) => Err(InconsistentAccountState(status)),
+        }
+    }
+
+    pub fn status(&self) -> AccountStatus {
+        match self {
+            Self::Active(_) => AccountStatus::Active,
+            Self::Suspended(_) => AccountStatus::Suspended,
+            Self::Locked(_) => AccountStatus::Locked,
+            Self::Deleted(_) => AccountStatus::Deleted,
+        }
+    }
+
+    /// 最後に状態が遷移した日時（利用中の場合は `None`）
+    pub fn changed_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Active(_) => None,
+            Self::Suspended(s) => Some(s.suspended_at),
+            Self::Locked(l) => Some(l.locked_at),
+            Self::Deleted(d) => Some(d.deleted_at),
+        }
+    }
+
+    pub fn locked_until(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Locked(l) => Some(l.locked_until),
+            _ => None,
+        }
+    }
+
+    /// ログイン・認証を許可できる状態かを検証する。制限時刻を過ぎたロックは利用中とみなす。
+    pub fn ensure_active(&self, now: DateTime<Utc>) -> Result<(), AccountStatusError> {
+        match self {
+            Self::Active(_) => Ok(()),
+            Self::Locked(l) if l.is_expired(now) => Ok(()),
+            Self::Locked(l) => Err(AccountStatusError::Locked {
+                until: l.locked_until,
+            }),
+            Self::Suspended(_) => Err(AccountStatusError::Suspended),
+            Self::Deleted(_) => Err(AccountStatusError::Deleted),
+        }
+    }
+}
+
+impl Default for AccountState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_status_round_trips_through_str() {
+        for status in [
+            AccountStatus::Active,
+            AccountStatus::Suspended,
+            AccountStatus::Locked,
+            AccountStatus::Deleted,
+        ] {
+            assert_eq!(status.as_str().parse::<AccountStatus>(), Ok(status));
+        }
+        assert_eq!(
+            "banned".parse::<AccountStatus>(),
+            Err(UnknownAccountStatus("banned".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_ensure_active_per_state() {
+        let now = Utc::now();
+        let AccountState::Active(active) = AccountState::new() else {
+            unreachable!()
+        };
+
+        assert_eq!(AccountState::Active(active).ensure_active(now), Ok(()));
+        assert_eq!(
+            AccountState::Suspended(active.suspend(now)).ensure_active(now),
+            Err(AccountStatusError::Suspended)
+        );
+        assert_eq!(
+            AccountState::Deleted(active.delete(now)).ensure_active(now),
+            Err(AccountStatusError::Deleted)
+        );
+
+        // 制限時刻を過ぎたロックは利用中とみなす
+        let until = now + Duration::minutes(15);
+        let locked = AccountState::Locked(active.lock(now, until));
+        assert_eq!(
+            locked.ensure_active(now),
+            Err(AccountStatusError::Locked { until })
+        );
+        assert_eq!(locked.ensure_active(until), Ok(()));
+    }
+
+    #[test]
+    fn test_reconstruct_rejects_inconsistent_values() {
+        let now = Utc::now();
+        let locked =
+            AccountState::reconstruct(AccountStatus::Locked, Some(now), Some(now)).unwrap();
+        assert_eq!(locked.status(), AccountStatus::Locked);
+        assert_eq!(locked.locked_until(), Some(now));
+
+        assert_eq!(
+            AccountState::reconstruct(AccountStatus::Suspended, None, None),
+            Err(InconsistentAccountState(AccountStatus::Suspended))
+        );
+        assert_eq!(
+            AccountState::reconstruct(AccountStatus::Active, None, Some(now)),
+            Err(InconsistentAccountState(AccountStatus::Active))
+        );
+    }
+}
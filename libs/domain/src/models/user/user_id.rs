@@ -8,6 +8,8 @@ use uuid::Uuid;
     Copy,
     PartialEq,
     Eq,
+    PartialOrd,
+    Ord,
     Hash,
     Serialize,
     Deserialize,
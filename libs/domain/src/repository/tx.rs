@@ -3,7 +3,13 @@ use futures_util::future::BoxFuture;
 use std::fmt::Debug;
 use std::sync::Arc;
 
-use crate::models::user::UserRepository;
+use crate::audit::AuditContext;
+use crate::event::OutboxRepository;
+use crate::models::auth::{
+    MfaChallengeRepository, PasswordResetTokenRepository, RecoveryCodeRepository,
+    RefreshTokenRepository, TotpCredentialRepository,
+};
+use crate::models::user::{EmailVerificationDispatchRepository, UserRepository};
 
 /// DB等のシステムエラーを、そのドメインのエラー型に変換するためのトレイト
 pub trait IntoTxError {
@@ -12,13 +18,21 @@ pub trait IntoTxError {
 
 pub trait RepositoryFactory: Send + Sync {
     fn user_repository(&self) -> Arc<dyn UserRepository + '_>;
-    // 将来的な拡張:
-    // fn outbox_repository(&self) -> Arc<dyn OutboxRepository + '_>;
+    fn refresh_token_repository(&self) -> Arc<dyn RefreshTokenRepository + '_>;
+    fn totp_credential_repository(&self) -> Arc<dyn TotpCredentialRepository + '_>;
+    fn mfa_challenge_repository(&self) -> Arc<dyn MfaChallengeRepository + '_>;
+    fn recovery_code_repository(&self) -> Arc<dyn RecoveryCodeRepository + '_>;
+    fn password_reset_token_repository(&self) -> Arc<dyn PasswordResetTokenRepository + '_>;
+    fn email_verification_dispatch_repository(
+        &self,
+    ) -> Arc<dyn EmailVerificationDispatchRepository + '_>;
+    fn outbox_repository(&self) -> Arc<dyn OutboxRepository + '_>;
 }
 
 #[async_trait]
 pub trait TransactionManager: Send + Sync {
-    async fn execute<T, E, F>(&self, f: F) -> Result<T, E>
+    /// `context` はトランザクション内の全ての書き込みの監査カラムに記録される。
+    async fn execute<T, E, F>(&self, context: AuditContext, f: F) -> Result<T, E>
     where
         T: Send,
         E: IntoTxError + Debug + Send + Sync,
@@ -28,7 +42,9 @@ pub trait TransactionManager: Send + Sync {
 // 便利なマクロはそのまま
 #[macro_export]
 macro_rules! tx {
-    ($tm:expr, |$factory:ident| $body:expr) => {
-        $tm.execute::<_, _, _>(move |$factory| std::boxed::Box::pin(async move { $body }))
+    ($tm:expr, $ctx:expr, |$factory:ident| $body:expr) => {
+        $tm.execute::<_, _, _>($ctx, move |$factory| {
+            std::boxed::Box::pin(async move { $body })
+        })
     };
 }
@@ -0,0 +1,32 @@
+use derive_more::{Display, From, Into};
+use serde::{Deserialize, Serialize};
+
+/// 楽観ロックに使用する集約のバージョン。テーブルの `lock_no` に対応する。
+///
+/// 未保存の集約は `Version::NEW` を持ち、永続化済みの集約は読み込んだ時点の `lock_no` を持つ。
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Default,
+    Serialize,
+    Deserialize,
+    From,
+    Into,
+    Display,
+)]
+pub struct Version(i32);
+
+impl Version {
+    /// まだ一度も保存されていない集約のバージョン
+    pub const NEW: Self = Self(0);
+
+    pub fn is_new(self) -> bool {
+        self == Self::NEW
+    }
+}
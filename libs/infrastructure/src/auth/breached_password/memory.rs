@@ -0,0 +1,59 @@
+use super::split_sha1;
+use async_trait::async_trait;
+use domain::models::auth::RawPassword;
+use std::collections::{HashMap, HashSet};
+use usecase::auth::BreachedPasswordChecker;
+use usecase::error::BreachedPasswordError;
+
+/// 指定したパスワードを漏洩済みとして扱う、メモリ上の `BreachedPasswordChecker` の実装。
+///
+/// 範囲ファイルと同じく接頭辞ごとに残りのハッシュを保持する。テスト用途を想定する。
+#[derive(Default)]
+pub struct InMemoryBreachedPasswordChecker {
+    ranges: HashMap<String, HashSet<String>>,
+}
+
+impl InMemoryBreachedPasswordChecker {
+    pub fn new<'a>(passwords: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut ranges: HashMap<String, HashSet<String>> = HashMap::new();
+        for password in passwords {
+            let (prefix, suffix) = split_sha1(&RawPassword::from(password));
+            ranges.entry(prefix).or_default().insert(suffix);
+        }
+        Self { ranges }
+    }
+}
+
+#[async_trait]
+impl BreachedPasswordChecker for InMemoryBreachedPasswordChecker {
+    async fn is_breached(&self, password: &RawPassword) -> Result<bool, BreachedPasswordError> {
+        let (prefix, suffix) = split_sha1(password);
+        Ok(self
+            .ranges
+            .get(&prefix)
+            .is_some_and(|range| range.contains(&suffix)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_only_listed_passwords_are_breached() {
+        let checker = InMemoryBreachedPasswordChecker::new(["password", "Password123!"]);
+
+        assert!(
+            checker
+                .is_breached(&RawPassword::from("password"))
+                .await
+                .unwrap()
+        );
+        assert!(
+            !checker
+                .is_breached(&RawPassword::from("Correct-Horse-Battery-9"))
+                .await
+                .unwrap()
+        );
+    }
+}
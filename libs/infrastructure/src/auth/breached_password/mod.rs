@@ -0,0 +1,19 @@
+pub mod memory;
+pub mod range_file;
+
+pub use memory::InMemoryBreachedPasswordChecker;
+pub use range_file::RangeFileBreachedPasswordChecker;
+
+use data_encoding::HEXUPPER;
+use domain::models::auth::RawPassword;
+use sha1::{Digest, Sha1};
+
+/// 範囲照会に使う接頭辞の桁数（HIBP の range API と同じ）
+const PREFIX_LENGTH: usize = 5;
+
+/// パスワードの SHA-1（大文字の 16 進表記）を、照会に使う接頭辞と照合する残りに分ける。
+fn split_sha1(password: &RawPassword) -> (String, String) {
+    let digest = HEXUPPER.encode(&Sha1::digest(password.expose_as_str().as_bytes()));
+    let (prefix, suffix) = digest.split_at(PREFIX_LENGTH);
+    (prefix.to_string(), suffix.to_string())
+}
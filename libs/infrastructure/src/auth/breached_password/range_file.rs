@@ -0,0 +1,105 @@
+use super::split_sha1;
+use anyhow::Context;
+use async_trait::async_trait;
+use domain::models::auth::RawPassword;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use usecase::auth::BreachedPasswordChecker;
+use usecase::error::BreachedPasswordError;
+
+/// ディスク上の範囲ファイル（HIBP の Pwned Passwords と同じ形式）を照会する `BreachedPasswordChecker` の実装。
+///
+/// ディレクトリには SHA-1 の先頭 5 桁ごとに `<接頭辞>.txt` を置き、各行を
+/// `<残りの 35 桁>:<出現回数>` とする。パスワードやハッシュ全体がプロセス外へ出ることはない。
+pub struct RangeFileBreachedPasswordChecker {
+    dir: PathBuf,
+}
+
+impl RangeFileBreachedPasswordChecker {
+    /// 読み取れるディレクトリでない場合はエラーとする（照会のたびに未漏洩と判定されるのを防ぐ）。
+    pub fn new(dir: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let dir = dir.into();
+        std::fs::read_dir(&dir).with_context(|| {
+            format!(
+                "breached password directory {} is not readable",
+                dir.display()
+            )
+        })?;
+        Ok(Self { dir })
+    }
+}
+
+#[async_trait]
+impl BreachedPasswordChecker for RangeFileBreachedPasswordChecker {
+    async fn is_breached(&self, password: &RawPassword) -> Result<bool, BreachedPasswordError> {
+        let (prefix, suffix) = split_sha1(password);
+        let path = self.dir.join(format!("{}.txt", prefix));
+
+        let range = match tokio::fs::read_to_string(&path).await {
+            Ok(range) => range,
+            // 該当する範囲がない場合は漏洩済みのハッシュも存在しない
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(false),
+            Err(e) => return Err(BreachedPasswordError::LookupFailed(e.into())),
+        };
+
+        Ok(range.lines().any(|line| {
+            let (hash, count) = line.trim().split_once(':').unwrap_or((line.trim(), ""));
+            // 出現回数 0 の行はパディング（応答長を揃えるためのダミー）として扱う
+            hash.eq_ignore_ascii_case(&suffix) && count.trim() != "0"
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_looks_up_range_file_by_sha1_prefix() {
+        let dir = std::env::temp_dir().join(format!("pwned-{}", uuid::Uuid::now_v7()));
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        // SHA-1("password") = 5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8
+        tokio::fs::write(
+            dir.join("5BAA6.txt"),
+            "003D68EB55068C33ACE09247EE4C639306B:3\r\n\
+             1E4C9B93F3F0682250B6CF8331B7EE68FD8:9545824\r\n",
+        )
+        .await
+        .unwrap();
+        // SHA-1("Password123!") = 49EFEF5F70D47ADC2DB2EB397FBEF5F7BC560E29
+        tokio::fs::write(
+            dir.join("49EFE.txt"),
+            "F5F70D47ADC2DB2EB397FBEF5F7BC560E29:0\r\n",
+        )
+        .await
+        .unwrap();
+        let checker = RangeFileBreachedPasswordChecker::new(&dir).unwrap();
+
+        let breached = |password: &'static str| {
+            let checker = &checker;
+            async move {
+                checker
+                    .is_breached(&RawPassword::from(password))
+                    .await
+                    .unwrap()
+            }
+        };
+        assert!(breached("password").await);
+        // パディング行・範囲ファイルのない接頭辞は漏洩済みとしない
+        assert!(!breached("Password123!").await);
+        assert!(!breached("Correct-Horse-Battery-9").await);
+
+        tokio::fs::remove_dir_all(&dir).await.unwrap();
+    }
+
+    #[test]
+    fn test_new_rejects_missing_or_non_directory_path() {
+        let dir = std::env::temp_dir().join(format!("pwned-{}", uuid::Uuid::now_v7()));
+        assert!(RangeFileBreachedPasswordChecker::new(&dir).is_err());
+
+        // ディレクトリではなくファイルを指定した場合
+        std::fs::write(&dir, "").unwrap();
+        assert!(RangeFileBreachedPasswordChecker::new(&dir).is_err());
+        std::fs::remove_file(&dir).unwrap();
+    }
+}
@@ -0,0 +1,149 @@
+use chrono::{DateTime, Utc};
+use domain::models::user::{Email, UserId};
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use serde::{Deserialize, Serialize};
+use usecase::auth::{
+    EmailVerificationClaims, EmailVerificationToken, EmailVerificationTokenService,
+};
+use usecase::error::AuthServiceError;
+
+/// アクセストークンと取り違えないよう、確認用トークンに付与する `aud`。
+const EMAIL_VERIFICATION_AUDIENCE: &str = "email-verification";
+
+/// JWT の形式でエンコードする確認用トークンのペイロード。
+#[derive(Debug, Serialize, Deserialize)]
+struct EmailVerificationJwtClaims {
+    sub: UserId,
+    email: String,
+    exp: usize,
+    aud: String,
+}
+
+/// HMAC で署名した JWT を確認用トークンとして使用する `EmailVerificationTokenService` の実装。
+pub struct JwtEmailVerificationTokenService {
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+}
+
+impl JwtEmailVerificationTokenService {
+    pub fn new(secret: &str) -> Self {
+        Self {
+            encoding_key: EncodingKey::from_secret(secret.as_bytes()),
+            decoding_key: DecodingKey::from_secret(secret.as_bytes()),
+        }
+    }
+}
+
+impl EmailVerificationTokenService for JwtEmailVerificationTokenService {
+    fn issue(
+        &self,
+        user_id: UserId,
+        email: &Email,
+        expires_at: DateTime<Utc>,
+    ) -> Result<EmailVerificationToken, AuthServiceError> {
+        let claims = EmailVerificationJwtClaims {
+            sub: user_id,
+            email: email.as_ref().to_string(),
+            exp: expires_at.timestamp() as usize,
+            aud: EMAIL_VERIFICATION_AUDIENCE.to_string(),
+        };
+
+        encode(&Header::default(), &claims, &self.encoding_key)
+            .map(EmailVerificationToken::from)
+            .map_err(|e| AuthServiceError::IssuanceFailed(anyhow::Error::from(e)))
+    }
+
+    fn verify(
+        &self,
+        token: &EmailVerificationToken,
+    ) -> Result<EmailVerificationClaims, AuthServiceError> {
+        let mut validation = Validation::default();
+        validation.set_audience(&[EMAIL_VERIFICATION_AUDIENCE]);
+
+        decode::<EmailVerificationJwtClaims>(token.expose_as_str(), &self.decoding_key, &validation)
+            .map(|data| EmailVerificationClaims {
+                sub: data.claims.sub,
+                email: data.claims.email,
+                exp: data.claims.exp,
+            })
+            .map_err(|e| match *e.kind() {
+                jsonwebtoken::errors::ErrorKind::ExpiredSignature => AuthServiceError::TokenExpired,
+                jsonwebtoken::errors::ErrorKind::InvalidToken
+                | jsonwebtoken::errors::ErrorKind::InvalidSignature
+                | jsonwebtoken::errors::ErrorKind::InvalidAudience
+                | jsonwebtoken::errors::ErrorKind::MissingRequiredClaim(_)
+                | jsonwebtoken::errors::ErrorKind::Json(_)
+                | jsonwebtoken::errors::ErrorKind::Base64(_)
+                | jsonwebtoken::errors::ErrorKind::Utf8(_) => AuthServiceError::InvalidToken,
+                _ => AuthServiceError::VerificationFailed(anyhow::Error::from(e)),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::JwtAuthService;
+    use crate::clock::RealClock;
+    use chrono::Duration;
+    use domain::models::user::Role;
+    use std::sync::Arc;
+    use usecase::auth::{AuthService, AuthToken};
+    use uuid::Uuid;
+
+    #[test]
+    fn test_issue_and_verify() {
+        let service = JwtEmailVerificationTokenService::new("secret");
+        let user_id = UserId::from(Uuid::now_v7());
+        let email = Email::try_from("verify@example.com").unwrap();
+
+        let token = service
+            .issue(user_id, &email, Utc::now() + Duration::hours(1))
+            .unwrap();
+        let claims = service.verify(&token).unwrap();
+
+        assert_eq!(claims.sub, user_id);
+        assert_eq!(claims.email, "verify@example.com");
+    }
+
+    #[test]
+    fn test_rejects_expired_and_foreign_tokens() {
+        let service = JwtEmailVerificationTokenService::new("secret");
+        let user_id = UserId::from(Uuid::now_v7());
+        let email = Email::try_from("verify@example.com").unwrap();
+
+        let expired = service
+            .issue(user_id, &email, Utc::now() - Duration::hours(1))
+            .unwrap();
+        assert!(matches!(
+            service.verify(&expired),
+            Err(AuthServiceError::TokenExpired)
+        ));
+
+        let other_secret = JwtEmailVerificationTokenService::new("other")
+            .issue(user_id, &email, Utc::now() + Duration::hours(1))
+            .unwrap();
+        assert!(matches!(
+            service.verify(&other_secret),
+            Err(AuthServiceError::InvalidToken)
+        ));
+
+        // 同じ鍵で署名されたアクセストークンは確認用トークンとして受理しない
+        let access_token = JwtAuthService::new("secret", Arc::new(RealClock))
+            .issue_token(user_id, &[Role::User])
+            .unwrap();
+        assert!(matches!(
+            service.verify(&EmailVerificationToken::from(access_token.expose_as_str())),
+            Err(AuthServiceError::InvalidToken)
+        ));
+        // 逆に確認用トークンもアクセストークンとしては受理されない
+        let token = service
+            .issue(user_id, &email, Utc::now() + Duration::hours(1))
+            .unwrap();
+        assert!(
+            JwtAuthService::new("secret", Arc::new(RealClock))
+                .verify_token(&AuthToken::from(token.expose_as_str()))
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,293 @@
+use anyhow::{Context, bail, ensure};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, Utc};
+use ed25519_dalek::SigningKey;
+use ed25519_dalek::pkcs8::DecodePrivateKey;
+use jsonwebtoken::jwk::{
+    AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, KeyAlgorithm,
+    OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse,
+};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+use std::path::Path;
+
+/// 共有鍵に割り当てる鍵 ID
+const SECRET_KEY_ID: &str = "default";
+
+/// アクセストークンの署名鍵
+pub struct JwtKey {
+    kid: String,
+    algorithm: Algorithm,
+    activates_at: Option<DateTime<Utc>>,
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    /// 公開鍵。共有鍵の場合は `None`
+    jwk: Option<Jwk>,
+}
+
+impl JwtKey {
+    /// PKCS#8 形式の秘密鍵の PEM から作成する（`EdDSA` は Ed25519、`RS256` は RSA）
+    pub fn from_pem(
+        kid: impl Into<String>,
+        algorithm: Algorithm,
+        pem: &[u8],
+    ) -> anyhow::Result<Self> {
+        let kid = kid.into();
+        ensure!(!kid.is_empty(), "JWT key id must not be empty");
+        let (encoding_key, algorithm_parameters) = match algorithm {
+            Algorithm::EdDSA => {
+                let encoding_key = EncodingKey::from_ed_pem(pem)?;
+                let public_key = SigningKey::from_pkcs8_der(encoding_key.inner())
+                    .map_err(|e| anyhow::anyhow!("invalid Ed25519 key: {e}"))?
+                    .verifying_key();
+                let parameters = AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+                    key_type: OctetKeyPairType::OctetKeyPair,
+                    curve: EllipticCurve::Ed25519,
+                    x: URL_SAFE_NO_PAD.encode(public_key.as_bytes()),
+                });
+                (encoding_key, parameters)
+            }
+            Algorithm::RS256 => {
+                let encoding_key = EncodingKey::from_rsa_pem(pem)?;
+                let parameters = Jwk::from_encoding_key(&encoding_key, algorithm)?.algorithm;
+                (encoding_key, parameters)
+            }
+            other => bail!("unsupported JWT signing algorithm: {other:?}"),
+        };
+        let jwk = Jwk {
+            common: CommonParameters {
+                public_key_use: Some(PublicKeyUse::Signature),
+                key_algorithm: Some(match algorithm {
+                    Algorithm::EdDSA => KeyAlgorithm::EdDSA,
+                    _ => KeyAlgorithm::RS256,
+                }),
+                key_id: Some(kid.clone()),
+                ..Default::default()
+            },
+            algorithm: algorithm_parameters,
+        };
+        Ok(Self {
+            kid,
+            algorithm,
+            activates_at: None,
+            decoding_key: DecodingKey::from_jwk(&jwk)?,
+            encoding_key,
+            jwk: Some(jwk),
+        })
+    }
+
+    /// HS256 の共有鍵から作成する
+    pub fn from_secret(secret: &[u8]) -> Self {
+        Self {
+            kid: SECRET_KEY_ID.to_string(),
+            algorithm: Algorithm::HS256,
+            activates_at: None,
+            encoding_key: EncodingKey::from_secret(secret),
+            decoding_key: DecodingKey::from_secret(secret),
+            jwk: None,
+        }
+    }
+
+    /// 署名への使用を開始する日時を設定する。それまでは検証にのみ使用する
+    pub fn activates_at(mut self, at: DateTime<Utc>) -> Self {
+        self.activates_at = Some(at);
+        self
+    }
+
+    pub fn kid(&self) -> &str {
+        &self.kid
+    }
+
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// 公開鍵の JWK。共有鍵の場合は `None`
+    pub fn jwk(&self) -> Option<&Jwk> {
+        self.jwk.as_ref()
+    }
+
+    pub(super) fn encoding_key(&self) -> &EncodingKey {
+        &self.encoding_key
+    }
+
+    pub(super) fn decoding_key(&self) -> &DecodingKey {
+        &self.decoding_key
+    }
+
+    fn is_active_at(&self, now: DateTime<Utc>) -> bool {
+        self.activates_at.is_none_or(|at| at <= now)
+    }
+}
+
+impl std::fmt::Debug for JwtKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // 鍵そのものは出力しない
+        f.debug_struct("JwtKey")
+            .field("kid", &self.kid)
+            .field("algorithm", &self.algorithm)
+            .field("activates_at", &self.activates_at)
+            .finish()
+    }
+}
+
+/// アクセストークンの署名鍵の一覧。
+///
+/// 署名には使用開始日時を過ぎた鍵のうち最後のものを使用し、すべての鍵を `kid` による検証に使用する。
+/// 鍵を削除するのは、後継の鍵の使用開始からアクセストークンの有効期間が過ぎた後にする。
+#[derive(Debug)]
+pub struct JwtKeyRing {
+    keys: Vec<JwtKey>,
+}
+
+impl JwtKeyRing {
+    /// 鍵を古い順に受け取る
+    pub fn new(keys: impl IntoIterator<Item = JwtKey>) -> anyhow::Result<Self> {
+        let mut ring = Self { keys: Vec::new() };
+        for key in keys {
+            ensure!(
+                ring.find(&key.kid).is_none(),
+                "duplicate JWT key id: {:?}",
+                key.kid
+            );
+            ring.keys.push(key);
+        }
+        ensure!(!ring.keys.is_empty(), "no JWT key is defined");
+        Ok(ring)
+    }
+
+    /// HS256 の共有鍵 1 つからなる一覧を作成する
+    pub fn from_secret(secret: &str) -> Self {
+        Self {
+            keys: vec![JwtKey::from_secret(secret.as_bytes())],
+        }
+    }
+
+    /// `<鍵 ID> <アルゴリズム> <PEM のパス> [<使用開始日時 (RFC 3339)>]` を 1 行ずつ古い順に記述したファイルから読み込む
+    ///
+    /// 空行と `#` で始まる行は無視する。相対パスはこのファイルのディレクトリを基準とする。
+    pub fn from_file(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read JWT key file {}", path.display()))?;
+        Self::parse(&content, path.parent().unwrap_or(Path::new("")))
+    }
+
+    fn parse(content: &str, base_dir: &Path) -> anyhow::Result<Self> {
+        let mut keys = Vec::new();
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let (kid, algorithm, pem_path, activates_at) = match fields[..] {
+                [kid, algorithm, pem_path] => (kid, algorithm, pem_path, None),
+                [kid, algorithm, pem_path, activates_at] => {
+                    (kid, algorithm, pem_path, Some(activates_at))
+                }
+                _ => bail!(
+                    "line {}: expected `<key id> <algorithm> <pem path> [<activates at>]`",
+                    index + 1
+                ),
+            };
+            let algorithm: Algorithm = algorithm
+                .parse()
+                .with_context(|| format!("line {}: unknown algorithm {algorithm}", index + 1))?;
+            let pem_path = base_dir.join(pem_path);
+            let pem = std::fs::read(&pem_path)
+                .with_context(|| format!("failed to read JWT key {}", pem_path.display()))?;
+            let mut key = JwtKey::from_pem(kid, algorithm, &pem)
+                .with_context(|| format!("line {}: invalid JWT key {kid}", index + 1))?;
+            if let Some(activates_at) = activates_at {
+                let activates_at = DateTime::parse_from_rfc3339(activates_at)
+                    .with_context(|| format!("line {}: invalid activation time", index + 1))?;
+                key = key.activates_at(activates_at.with_timezone(&Utc));
+            }
+            keys.push(key);
+        }
+        Self::new(keys)
+    }
+
+    /// 指定した日時に署名へ使用する鍵
+    pub(super) fn signing_key(&self, now: DateTime<Utc>) -> Option<&JwtKey> {
+        self.keys.iter().rev().find(|key| key.is_active_at(now))
+    }
+
+    /// 使用開始前の鍵を含むすべての鍵
+    pub(super) fn keys(&self) -> &[JwtKey] {
+        &self.keys
+    }
+
+    pub(super) fn find(&self, kid: &str) -> Option<&JwtKey> {
+        self.keys.iter().find(|key| key.kid == kid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    const ED25519_A: &[u8] = include_bytes!("testdata/ed25519_a.pem");
+    const RSA: &[u8] = include_bytes!("testdata/rsa.pem");
+
+    #[test]
+    fn test_parse_loads_keys_relative_to_the_file() {
+        let base_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/auth/jwt");
+        let content = "# rotated 2026-10\n\
+                       a EdDSA testdata/ed25519_a.pem\n\
+                       \n\
+                       b RS256 testdata/rsa.pem 2026-11-01T00:00:00Z\n";
+        let ring = JwtKeyRing::parse(content, &base_dir).unwrap();
+
+        let before = DateTime::parse_from_rfc3339("2026-10-31T23:59:59Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(ring.signing_key(before).unwrap().kid(), "a");
+        assert_eq!(
+            ring.signing_key(before + Duration::seconds(1))
+                .unwrap()
+                .kid(),
+            "b"
+        );
+        assert_eq!(ring.find("b").unwrap().algorithm(), Algorithm::RS256);
+        assert!(ring.find("c").is_none());
+    }
+
+    #[test]
+    fn test_rejects_invalid_keys() {
+        let base_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/auth/jwt");
+        for content in [
+            "",
+            "a EdDSA",
+            "a HS256 testdata/ed25519_a.pem",
+            "a EdDSA testdata/rsa.pem",
+            "a EdDSA testdata/missing.pem",
+            "a EdDSA testdata/ed25519_a.pem tomorrow",
+            "a EdDSA testdata/ed25519_a.pem\na RS256 testdata/rsa.pem",
+        ] {
+            assert!(
+                JwtKeyRing::parse(content, &base_dir).is_err(),
+                "{content:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_public_key_is_derived_from_the_private_key() {
+        let key = JwtKey::from_pem("a", Algorithm::EdDSA, ED25519_A).unwrap();
+        let jwk = key.jwk().unwrap();
+        assert_eq!(jwk.common.key_id.as_deref(), Some("a"));
+        assert!(matches!(
+            &jwk.algorithm,
+            AlgorithmParameters::OctetKeyPair(params) if URL_SAFE_NO_PAD.decode(&params.x).unwrap().len() == 32
+        ));
+
+        let key = JwtKey::from_pem("b", Algorithm::RS256, RSA).unwrap();
+        assert!(matches!(
+            &key.jwk().unwrap().algorithm,
+            AlgorithmParameters::RSA(params) if params.e == "AQAB"
+        ));
+        assert!(JwtKey::from_secret(b"secret").jwk().is_none());
+    }
+}
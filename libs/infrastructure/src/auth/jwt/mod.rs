@@ -0,0 +1,483 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+use domain::clock::Clock;
+use domain::models::user::{Role, UserId};
+use jsonwebtoken::jwk::AlgorithmParameters;
+use jsonwebtoken::{Algorithm, Header, Validation, decode, decode_header, encode};
+use serde::Serialize;
+use usecase::auth::{AuthService, AuthToken, Claims, ClaimsEnricher, CustomClaims, JsonWebKey};
+use usecase::error::AuthServiceError;
+use uuid::Uuid;
+
+mod key_ring;
+
+pub use self::key_ring::{JwtKey, JwtKeyRing};
+
+/// アクセストークンの発行・検証の設定
+#[derive(Debug, Clone)]
+pub struct JwtConfig {
+    /// `iss` に設定し、検証時に一致を要求する発行者
+    pub issuer: Option<String>,
+    /// `aud` に設定し、検証時にいずれかとの一致を要求する受信者
+    pub audiences: Vec<String>,
+    /// 有効期限の検証で許容する時刻のずれ
+    pub leeway: Duration,
+    /// アクセストークンの有効期間。失効はリフレッシュトークンのローテーションで補う。
+    pub access_token_ttl: Duration,
+}
+
+impl Default for JwtConfig {
+    fn default() -> Self {
+        Self {
+            issuer: None,
+            audiences: Vec::new(),
+            leeway: Duration::seconds(60),
+            access_token_ttl: Duration::minutes(15),
+        }
+    }
+}
+
+/// 署名するペイロード。`iss` / `aud` は設定から付与する
+#[derive(Serialize)]
+struct IssuedClaims<'a> {
+    #[serde(flatten)]
+    claims: &'a Claims,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    iss: Option<&'a str>,
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    aud: &'a [String],
+}
+
+pub struct JwtAuthService<C: Clock> {
+    key_ring: JwtKeyRing,
+    config: JwtConfig,
+    claims_enrichers: Vec<Arc<dyn ClaimsEnricher>>,
+    clock: Arc<C>,
+}
+
+impl<C: Clock> JwtAuthService<C> {
+    /// HS256 の共有鍵で署名・検証する
+    pub fn new(secret: &str, clock: Arc<C>) -> Self {
+        Self::with_key_ring(JwtKeyRing::from_secret(secret), clock)
+    }
+
+    pub fn with_key_ring(key_ring: JwtKeyRing, clock: Arc<C>) -> Self {
+        Self::with_config(key_ring, JwtConfig::default(), clock)
+    }
+
+    pub fn with_config(key_ring: JwtKeyRing, config: JwtConfig, clock: Arc<C>) -> Self {
+        Self {
+            key_ring,
+            config,
+            claims_enrichers: Vec::new(),
+            clock,
+        }
+    }
+
+    /// 発行するトークンにカスタムクレームを追加する処理を登録する
+    pub fn with_claims_enricher(mut self, enricher: Arc<dyn ClaimsEnricher>) -> Self {
+        self.claims_enrichers.push(enricher);
+        self
+    }
+
+    fn validation(&self, key: &JwtKey) -> Validation {
+        let mut validation = Validation::new(key.algorithm());
+        validation.leeway = self.config.leeway.num_seconds().max(0) as u64;
+        // 設定した発行者・受信者は省略を許さない
+        if let Some(issuer) = &self.config.issuer {
+            validation.set_issuer(&[issuer]);
+            validation.required_spec_claims.insert("iss".to_string());
+        }
+        if self.config.audiences.is_empty() {
+            validation.validate_aud = false;
+        } else {
+            validation.set_audience(&self.config.audiences);
+            validation.required_spec_claims.insert("aud".to_string());
+        }
+        validation
+    }
+}
+
+impl<C: Clock> AuthService for JwtAuthService<C> {
+    fn issue_token(&self, user_id: UserId, roles: &[Role]) -> Result<AuthToken, AuthServiceError> {
+        let now = self.clock.now();
+        let iat = now.timestamp() as usize;
+        let exp = (now + self.config.access_token_ttl).timestamp() as usize;
+
+        let mut custom = CustomClaims::default();
+        for enricher in &self.claims_enrichers {
+            enricher.enrich(user_id, roles, &mut custom)?;
+        }
+
+        let claims = Claims {
+            sub: user_id,
+            iat,
+            exp,
+            jti: Uuid::new_v4(),
+            roles: roles.to_vec(),
+            permissions: Role::permissions_of(roles),
+            custom,
+        };
+        let payload = IssuedClaims {
+            claims: &claims,
+            iss: self.config.issuer.as_deref(),
+            aud: &self.config.audiences,
+        };
+
+        let key = self.key_ring.signing_key(now).ok_or_else(|| {
+            AuthServiceError::IssuanceFailed(anyhow::anyhow!("no JWT signing key is active"))
+        })?;
+        let mut header = Header::new(key.algorithm());
+        header.kid = Some(key.kid().to_string());
+
+        encode(&header, &payload, key.encoding_key())
+            .map(AuthToken::from)
+            .map_err(|e| AuthServiceError::IssuanceFailed(anyhow::Error::from(e)))
+    }
+
+    fn verify_token(&self, token: &AuthToken) -> Result<Claims, AuthServiceError> {
+        // kid で鍵を選び、その鍵のアルゴリズム以外の署名は受け付けない
+        let key = decode_header(token.expose_as_str())
+            .ok()
+            .and_then(|header| header.kid)
+            .and_then(|kid| self.key_ring.find(&kid))
+            .ok_or(AuthServiceError::InvalidToken)?;
+
+        decode::<Claims>(
+            token.expose_as_str(),
+            key.decoding_key(),
+            &self.validation(key),
+        )
+        .map(|data| data.claims)
+        .map_err(|e| match *e.kind() {
+            jsonwebtoken::errors::ErrorKind::ExpiredSignature => AuthServiceError::TokenExpired,
+            jsonwebtoken::errors::ErrorKind::InvalidToken
+            | jsonwebtoken::errors::ErrorKind::InvalidSignature
+            | jsonwebtoken::errors::ErrorKind::InvalidAlgorithm
+            | jsonwebtoken::errors::ErrorKind::InvalidIssuer
+            | jsonwebtoken::errors::ErrorKind::MissingRequiredClaim(_)
+            | jsonwebtoken::errors::ErrorKind::InvalidAudience
+            | jsonwebtoken::errors::ErrorKind::InvalidSubject => AuthServiceError::InvalidToken,
+            _ => AuthServiceError::VerificationFailed(anyhow::Error::from(e)),
+        })
+    }
+
+    fn verification_keys(&self) -> Vec<JsonWebKey> {
+        // 使用開始前の鍵も公開し、切り替え前に検証側へ行き渡らせる
+        self.key_ring
+            .keys()
+            .iter()
+            .filter_map(|key| {
+                let jwk = key.jwk()?;
+                let alg = match key.algorithm() {
+                    Algorithm::EdDSA => "EdDSA",
+                    _ => "RS256",
+                };
+                let (kty, crv, x, n, e) = match &jwk.algorithm {
+                    AlgorithmParameters::OctetKeyPair(params) => {
+                        ("OKP", Some("Ed25519"), Some(&params.x), None, None)
+                    }
+                    AlgorithmParameters::RSA(params) => {
+                        ("RSA", None, None, Some(&params.n), Some(&params.e))
+                    }
+                    _ => return None,
+                };
+                Some(JsonWebKey {
+                    kty: kty.to_string(),
+                    kid: key.kid().to_string(),
+                    alg: alg.to_string(),
+                    crv: crv.map(str::to_string),
+                    x: x.cloned(),
+                    n: n.cloned(),
+                    e: e.cloned(),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use domain::test_utils::FixedClock;
+    use jsonwebtoken::DecodingKey;
+
+    const ED25519_A: &[u8] = include_bytes!("testdata/ed25519_a.pem");
+    const ED25519_B: &[u8] = include_bytes!("testdata/ed25519_b.pem");
+    const RSA: &[u8] = include_bytes!("testdata/rsa.pem");
+
+    fn service(keys: Vec<JwtKey>, offset: Duration) -> JwtAuthService<FixedClock> {
+        JwtAuthService::with_key_ring(
+            JwtKeyRing::new(keys).unwrap(),
+            Arc::new(FixedClock::new(Utc::now() + offset)),
+        )
+    }
+
+    #[test]
+    fn test_signs_with_the_key_id_and_algorithm_of_the_key() {
+        for (algorithm, pem) in [(Algorithm::EdDSA, ED25519_A), (Algorithm::RS256, RSA)] {
+            let service = service(
+                vec![JwtKey::from_pem("k1", algorithm, pem).unwrap()],
+                Duration::zero(),
+            );
+            let user_id = UserId::from(Uuid::new_v4());
+            let token = service.issue_token(user_id, &[Role::User]).unwrap();
+
+            let header = decode_header(token.expose_as_str()).unwrap();
+            assert_eq!(header.alg, algorithm);
+            assert_eq!(header.kid.as_deref(), Some("k1"));
+            assert_eq!(service.verify_token(&token).unwrap().sub, user_id);
+        }
+    }
+
+    #[test]
+    fn test_scheduled_rotation_keeps_outstanding_tokens_valid() {
+        let rotation_at = Utc::now() + Duration::hours(1);
+        let keys = || {
+            vec![
+                JwtKey::from_pem("a", Algorithm::EdDSA, ED25519_A).unwrap(),
+                JwtKey::from_pem("b", Algorithm::EdDSA, ED25519_B)
+                    .unwrap()
+                    .activates_at(rotation_at),
+            ]
+        };
+        let before = service(keys(), Duration::zero());
+        let after = service(keys(), Duration::hours(2));
+
+        // 1. 使用開始前は既存の鍵で署名する
+        let old_token = before
+            .issue_token(UserId::from(Uuid::new_v4()), &[Role::User])
+            .unwrap();
+        assert_eq!(
+            decode_header(old_token.expose_as_str())
+                .unwrap()
+                .kid
+                .as_deref(),
+            Some("a")
+        );
+
+        // 2. 使用開始後は新しい鍵で署名し、既存の鍵によるトークンも引き続き検証できる
+        let new_token = after
+            .issue_token(UserId::from(Uuid::new_v4()), &[Role::User])
+            .unwrap();
+        assert_eq!(
+            decode_header(new_token.expose_as_str())
+                .unwrap()
+                .kid
+                .as_deref(),
+            Some("b")
+        );
+        assert!(after.verify_token(&old_token).is_ok());
+        assert!(before.verify_token(&new_token).is_ok());
+
+        // 3. 古い鍵を削除すると、その鍵によるトークンは無効になる
+        let retired = service(
+            vec![JwtKey::from_pem("b", Algorithm::EdDSA, ED25519_B).unwrap()],
+            Duration::hours(2),
+        );
+        assert!(matches!(
+            retired.verify_token(&old_token),
+            Err(AuthServiceError::InvalidToken)
+        ));
+    }
+
+    #[test]
+    fn test_rejects_tokens_signed_by_another_key_with_the_same_id() {
+        let issuer = service(
+            vec![JwtKey::from_pem("k1", Algorithm::EdDSA, ED25519_A).unwrap()],
+            Duration::zero(),
+        );
+        let token = issuer
+            .issue_token(UserId::from(Uuid::new_v4()), &[Role::User])
+            .unwrap();
+
+        for key in [
+            JwtKey::from_pem("k1", Algorithm::EdDSA, ED25519_B).unwrap(),
+            JwtKey::from_pem("k1", Algorithm::RS256, RSA).unwrap(),
+        ] {
+            assert!(matches!(
+                service(vec![key], Duration::zero()).verify_token(&token),
+                Err(AuthServiceError::InvalidToken)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_publishes_public_keys_that_verify_issued_tokens() {
+        let service = service(
+            vec![
+                JwtKey::from_pem("a", Algorithm::RS256, RSA).unwrap(),
+                JwtKey::from_pem("b", Algorithm::EdDSA, ED25519_A)
+                    .unwrap()
+                    .activates_at(Utc::now() - Duration::minutes(1)),
+                JwtKey::from_pem("c", Algorithm::EdDSA, ED25519_B)
+                    .unwrap()
+                    .activates_at(Utc::now() + Duration::days(1)),
+            ],
+            Duration::zero(),
+        );
+        let keys = service.verification_keys();
+        assert_eq!(
+            keys.iter().map(|key| key.kid.as_str()).collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+
+        // 公開鍵だけで署名を検証できる
+        let token = service
+            .issue_token(UserId::from(Uuid::new_v4()), &[Role::User])
+            .unwrap();
+        let published = keys.iter().find(|key| key.kid == "b").unwrap();
+        assert_eq!(
+            (published.kty.as_str(), published.crv.as_deref()),
+            ("OKP", Some("Ed25519"))
+        );
+        let decoding_key = DecodingKey::from_ed_components(published.x.as_ref().unwrap()).unwrap();
+        assert!(
+            decode::<Claims>(
+                token.expose_as_str(),
+                &decoding_key,
+                &Validation::new(Algorithm::EdDSA)
+            )
+            .is_ok()
+        );
+
+        // 共有鍵は公開しない
+        let hmac = JwtAuthService::new("secret", Arc::new(FixedClock::new(Utc::now())));
+        assert!(hmac.verification_keys().is_empty());
+    }
+
+    fn configured(config: JwtConfig, offset: Duration) -> JwtAuthService<FixedClock> {
+        JwtAuthService::with_config(
+            JwtKeyRing::new(vec![
+                JwtKey::from_pem("k1", Algorithm::EdDSA, ED25519_A).unwrap(),
+            ])
+            .unwrap(),
+            config,
+            Arc::new(FixedClock::new(Utc::now() + offset)),
+        )
+    }
+
+    fn issue(service: &JwtAuthService<FixedClock>) -> AuthToken {
+        service
+            .issue_token(UserId::from(Uuid::new_v4()), &[Role::User])
+            .unwrap()
+    }
+
+    #[test]
+    fn test_validates_issuer_and_audience() {
+        let config = JwtConfig {
+            issuer: Some("https://auth.example.com".to_string()),
+            audiences: vec!["api".to_string(), "admin".to_string()],
+            ..JwtConfig::default()
+        };
+        let token = issue(&configured(config.clone(), Duration::zero()));
+
+        for (accepted, config) in [
+            (true, config.clone()),
+            (
+                true,
+                JwtConfig {
+                    audiences: vec!["admin".to_string()],
+                    ..config.clone()
+                },
+            ),
+            (
+                false,
+                JwtConfig {
+                    issuer: Some("https://other.example.com".to_string()),
+                    ..config.clone()
+                },
+            ),
+            (
+                false,
+                JwtConfig {
+                    audiences: vec!["billing".to_string()],
+                    ..config.clone()
+                },
+            ),
+        ] {
+            let result = configured(config, Duration::zero()).verify_token(&token);
+            if accepted {
+                assert!(result.is_ok());
+            } else {
+                assert!(matches!(result, Err(AuthServiceError::InvalidToken)));
+            }
+        }
+
+        // 発行者や受信者を持たないトークンは拒否する
+        let untagged = issue(&configured(JwtConfig::default(), Duration::zero()));
+        assert!(matches!(
+            configured(config, Duration::zero()).verify_token(&untagged),
+            Err(AuthServiceError::InvalidToken)
+        ));
+    }
+
+    #[test]
+    fn test_lifetime_and_leeway_are_configurable() {
+        let config = JwtConfig {
+            access_token_ttl: Duration::minutes(1),
+            leeway: Duration::seconds(60),
+            ..JwtConfig::default()
+        };
+        let claims = configured(config.clone(), Duration::zero())
+            .verify_token(&issue(&configured(config.clone(), Duration::zero())))
+            .unwrap();
+        assert_eq!(claims.exp - claims.iat, 60);
+
+        // 30 秒前に期限切れになったトークン
+        let expired = issue(&configured(config.clone(), Duration::seconds(-90)));
+        assert!(
+            configured(config.clone(), Duration::zero())
+                .verify_token(&expired)
+                .is_ok()
+        );
+        let strict = JwtConfig {
+            leeway: Duration::zero(),
+            ..config
+        };
+        assert!(matches!(
+            configured(strict, Duration::zero()).verify_token(&expired),
+            Err(AuthServiceError::TokenExpired)
+        ));
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct TenantId(String);
+
+    impl usecase::auth::CustomClaim for TenantId {
+        const NAME: &'static str = "tenant";
+    }
+
+    struct TenantEnricher;
+
+    impl ClaimsEnricher for TenantEnricher {
+        fn enrich(
+            &self,
+            _user_id: UserId,
+            _roles: &[Role],
+            claims: &mut CustomClaims,
+        ) -> Result<(), AuthServiceError> {
+            claims.insert(&TenantId("acme".to_string()))
+        }
+    }
+
+    #[test]
+    fn test_enrichers_add_typed_custom_claims() {
+        let service = configured(
+            JwtConfig {
+                issuer: Some("https://auth.example.com".to_string()),
+                ..JwtConfig::default()
+            },
+            Duration::zero(),
+        )
+        .with_claims_enricher(Arc::new(TenantEnricher));
+
+        let claims = service.verify_token(&issue(&service)).unwrap();
+        assert_eq!(
+            claims.custom.get::<TenantId>().unwrap(),
+            Some(TenantId("acme".to_string()))
+        );
+    }
+}
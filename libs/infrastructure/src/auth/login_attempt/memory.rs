@@ -0,0 +1,192 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::models::auth::ThrottleKey;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use usecase::auth::LoginAttemptStore;
+use usecase::error::LoginAttemptError;
+
+/// プロセス内のメモリに失敗の記録を保持する `LoginAttemptStore` の実装。
+///
+/// 単一インスタンス構成や開発・テスト用途を想定する。
+#[derive(Default)]
+pub struct InMemoryLoginAttemptStore {
+    failures: Mutex<HashMap<String, Vec<DateTime<Utc>>>>,
+}
+
+impl InMemoryLoginAttemptStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl LoginAttemptStore for InMemoryLoginAttemptStore {
+    async fn record_failure(
+        &self,
+        key: &ThrottleKey,
+        attempted_at: DateTime<Utc>,
+        retain_since: DateTime<Utc>,
+    ) -> Result<(), LoginAttemptError> {
+        let mut failures = self.failures.lock().unwrap();
+        // 集計期間外の記録はもう判定に使われないため破棄する
+        failures.retain(|_, at| {
+            at.retain(|at| *at >= retain_since);
+            !at.is_empty()
+        });
+        failures
+            .entry(key.to_string())
+            .or_default()
+            .push(attempted_at);
+        Ok(())
+    }
+
+    async fn failures_since(
+        &self,
+        key: &ThrottleKey,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<DateTime<Utc>>, LoginAttemptError> {
+        let failures = self.failures.lock().unwrap();
+        let mut recent: Vec<_> = failures
+            .get(&key.to_string())
+            .map(|at| at.iter().copied().filter(|at| *at >= since).collect())
+            .unwrap_or_default();
+        recent.sort();
+        Ok(recent)
+    }
+
+    async fn remove_failure(
+        &self,
+        key: &ThrottleKey,
+        attempted_at: DateTime<Utc>,
+    ) -> Result<(), LoginAttemptError> {
+        if let Some(at) = self.failures.lock().unwrap().get_mut(&key.to_string())
+            && let Some(index) = at.iter().rposition(|at| *at == attempted_at)
+        {
+            at.remove(index);
+        }
+        Ok(())
+    }
+
+    async fn clear(&self, key: &ThrottleKey) -> Result<(), LoginAttemptError> {
+        self.failures.lock().unwrap().remove(&key.to_string());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use domain::models::auth::{LoginThrottleConfig, LoginThrottlePolicy};
+    use domain::models::user::Email;
+    use std::sync::Arc;
+    use usecase::auth::LoginThrottle;
+    use usecase::error::UseCaseError;
+
+    #[tokio::test]
+    async fn test_record_purge_and_clear() {
+        let now = Utc::now();
+        let store = InMemoryLoginAttemptStore::new();
+        let account = ThrottleKey::Account(Email::try_from("user@example.com").unwrap());
+        let client_ip = ThrottleKey::ClientIp("203.0.113.7".parse().unwrap());
+
+        store
+            .record_failure(
+                &client_ip,
+                now - Duration::hours(2),
+                now - Duration::hours(3),
+            )
+            .await
+            .unwrap();
+        store
+            .record_failure(
+                &account,
+                now - Duration::minutes(5),
+                now - Duration::hours(1),
+            )
+            .await
+            .unwrap();
+        store
+            .record_failure(&account, now, now - Duration::hours(1))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            store
+                .failures_since(&account, now - Duration::minutes(10))
+                .await
+                .unwrap(),
+            vec![now - Duration::minutes(5), now]
+        );
+        // 保持期間より前の記録は他のキーでも破棄される
+        assert!(
+            store
+                .failures_since(&client_ip, now - Duration::hours(3))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        store.remove_failure(&account, now).await.unwrap();
+        assert_eq!(
+            store
+                .failures_since(&account, now - Duration::minutes(10))
+                .await
+                .unwrap(),
+            vec![now - Duration::minutes(5)]
+        );
+
+        store.clear(&account).await.unwrap();
+        assert!(
+            store
+                .failures_since(&account, now - Duration::hours(1))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_attempts_do_not_exceed_the_limit() {
+        let now = Utc::now();
+        let store = Arc::new(InMemoryLoginAttemptStore::new());
+        let throttle = Arc::new(LoginThrottle::new(
+            store.clone(),
+            LoginThrottleConfig::default(),
+        ));
+        let account = ThrottleKey::Account(Email::try_from("user@example.com").unwrap());
+
+        // 同じアカウントへ一斉に試行する。照合中の試行も互いの判定に含まれる
+        let attempts: Vec<_> = (0..20)
+            .map(|_| {
+                let throttle = throttle.clone();
+                let account = account.clone();
+                tokio::spawn(async move {
+                    let result = throttle.begin_attempt(&account, None, now).await;
+                    tokio::task::yield_now().await;
+                    result
+                })
+            })
+            .collect();
+        let mut allowed = 0;
+        for attempt in attempts {
+            match attempt.await.unwrap() {
+                Ok(()) => allowed += 1,
+                Err(e) => assert!(matches!(e, UseCaseError::Locked { .. })),
+            }
+        }
+
+        // 照合まで進んだ試行は上限以下で、いずれも失敗として記録されている
+        let max_failures = LoginThrottlePolicy::ACCOUNT.max_failures as usize;
+        assert!((1..=max_failures).contains(&allowed), "{allowed}");
+        assert_eq!(
+            store
+                .failures_since(&account, now - Duration::hours(1))
+                .await
+                .unwrap()
+                .len(),
+            allowed
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub mod memory;
+pub mod postgres;
+
+pub use memory::InMemoryLoginAttemptStore;
+pub use postgres::PgLoginAttemptStore;
@@ -0,0 +1,189 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::models::auth::ThrottleKey;
+use sqlx::PgPool;
+use usecase::auth::LoginAttemptStore;
+use usecase::error::LoginAttemptError;
+
+use crate::repository::audit::AuditStamp;
+
+/// 監査カラムに記録するプログラムコード
+const PROGRAM_CODE: &str = "auth-login-attempt";
+
+/// PostgreSQL に失敗の記録を保持する `LoginAttemptStore` の実装。
+///
+/// 複数インスタンス間で記録を共有する本番構成を想定する。
+pub struct PgLoginAttemptStore {
+    pool: PgPool,
+}
+
+impl PgLoginAttemptStore {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LoginAttemptStore for PgLoginAttemptStore {
+    async fn record_failure(
+        &self,
+        key: &ThrottleKey,
+        attempted_at: DateTime<Utc>,
+        retain_since: DateTime<Utc>,
+    ) -> Result<(), LoginAttemptError> {
+        let audit = AuditStamp::system(PROGRAM_CODE, attempted_at);
+
+        sqlx::query!(
+            r#"
+            INSERT INTO login_failures (
+                throttle_key, attempted_at,
+                created_at, created_by, created_pgm_cd, created_tx_id,
+                updated_at, updated_by, updated_pgm_cd, updated_tx_id
+            ) VALUES ($1, $2, $2, $3, $4, $5, $2, $3, $4, $5)
+            "#,
+            key.to_string(),
+            attempted_at,
+            audit.by,
+            audit.pgm_cd,
+            audit.tx_id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| LoginAttemptError::StoreFailed(e.into()))?;
+
+        // 集計期間外の記録はもう判定に使われないため破棄する
+        sqlx::query!(
+            "DELETE FROM login_failures WHERE attempted_at < $1",
+            retain_since
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| LoginAttemptError::StoreFailed(e.into()))?;
+
+        Ok(())
+    }
+
+    async fn failures_since(
+        &self,
+        key: &ThrottleKey,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<DateTime<Utc>>, LoginAttemptError> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT attempted_at FROM login_failures
+            WHERE throttle_key = $1 AND attempted_at >= $2
+            ORDER BY attempted_at
+            "#,
+            key.to_string(),
+            since,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| LoginAttemptError::StoreFailed(e.into()))
+    }
+
+    async fn remove_failure(
+        &self,
+        key: &ThrottleKey,
+        attempted_at: DateTime<Utc>,
+    ) -> Result<(), LoginAttemptError> {
+        sqlx::query!(
+            r#"
+            DELETE FROM login_failures
+            WHERE id = (
+                SELECT id FROM login_failures
+                WHERE throttle_key = $1 AND attempted_at = $2
+                ORDER BY id DESC
+                LIMIT 1
+            )
+            "#,
+            key.to_string(),
+            attempted_at,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| LoginAttemptError::StoreFailed(e.into()))?;
+
+        Ok(())
+    }
+
+    async fn clear(&self, key: &ThrottleKey) -> Result<(), LoginAttemptError> {
+        sqlx::query!(
+            "DELETE FROM login_failures WHERE throttle_key = $1",
+            key.to_string()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| LoginAttemptError::StoreFailed(e.into()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use domain::models::user::Email;
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_record_failures_since_and_clear(pool: PgPool) {
+        let store = PgLoginAttemptStore::new(pool);
+        // マイクロ秒未満は保存時に切り捨てられるため、比較できる精度に揃える
+        let now = DateTime::from_timestamp(Utc::now().timestamp(), 0).unwrap();
+        let account = ThrottleKey::Account(Email::try_from("user@example.com").unwrap());
+        let client_ip = ThrottleKey::ClientIp("203.0.113.7".parse().unwrap());
+        let retain_since = now - Duration::hours(1);
+
+        store
+            .record_failure(
+                &client_ip,
+                now - Duration::hours(2),
+                now - Duration::hours(3),
+            )
+            .await
+            .unwrap();
+        store
+            .record_failure(&account, now - Duration::minutes(5), retain_since)
+            .await
+            .unwrap();
+        store
+            .record_failure(&account, now, retain_since)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            store
+                .failures_since(&account, now - Duration::minutes(10))
+                .await
+                .unwrap(),
+            vec![now - Duration::minutes(5), now]
+        );
+        // 保持期間より前の記録は他のキーでも破棄される
+        assert!(
+            store
+                .failures_since(&client_ip, now - Duration::hours(3))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        store.remove_failure(&account, now).await.unwrap();
+        assert_eq!(
+            store
+                .failures_since(&account, now - Duration::minutes(10))
+                .await
+                .unwrap(),
+            vec![now - Duration::minutes(5)]
+        );
+
+        store.clear(&account).await.unwrap();
+        assert!(
+            store
+                .failures_since(&account, retain_since)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
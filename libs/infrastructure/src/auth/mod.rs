@@ -1,5 +1,19 @@
+pub mod breached_password;
+pub mod email_verification;
 pub mod jwt;
+pub mod login_attempt;
+pub mod opaque_token;
 pub mod password;
+pub mod recovery_code;
+pub mod revocation;
+pub mod totp;
 
-pub use jwt::JwtAuthService;
-pub use password::Argon2PasswordService;
+pub use breached_password::{InMemoryBreachedPasswordChecker, RangeFileBreachedPasswordChecker};
+pub use email_verification::JwtEmailVerificationTokenService;
+pub use jwt::{JwtAuthService, JwtConfig, JwtKey, JwtKeyRing};
+pub use login_attempt::{InMemoryLoginAttemptStore, PgLoginAttemptStore};
+pub use opaque_token::RandomOpaqueTokenService;
+pub use password::{Argon2Config, Argon2PasswordService, HashingPoolConfig, PepperRing};
+pub use recovery_code::RandomRecoveryCodeGenerator;
+pub use revocation::{InMemoryTokenRevocationStore, PgTokenRevocationStore};
+pub use totp::RfcTotpService;
@@ -0,0 +1,54 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use domain::models::auth::{OpaqueToken, OpaqueTokenHash, OpaqueTokenService};
+use rand::{RngCore, rngs::OsRng};
+use sha2::{Digest, Sha256};
+
+/// トークン長（バイト数）。256 bit のエントロピーを持たせる。
+const TOKEN_BYTES: usize = 32;
+
+/// OS の乱数源でトークンを生成し、SHA-256 でハッシュ化するサービス。
+///
+/// トークン自体が十分なエントロピーを持つため、パスワードのような
+/// 低速ハッシュやソルトは不要であり、決定的なハッシュで検索可能にする。
+pub struct RandomOpaqueTokenService;
+
+impl RandomOpaqueTokenService {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for RandomOpaqueTokenService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OpaqueTokenService for RandomOpaqueTokenService {
+    fn generate(&self) -> OpaqueToken {
+        let mut bytes = [0u8; TOKEN_BYTES];
+        OsRng.fill_bytes(&mut bytes);
+        OpaqueToken::from(URL_SAFE_NO_PAD.encode(bytes))
+    }
+
+    fn hash(&self, token: &OpaqueToken) -> OpaqueTokenHash {
+        let digest = Sha256::digest(token.expose_as_str().as_bytes());
+        OpaqueTokenHash::from_str_unchecked(URL_SAFE_NO_PAD.encode(digest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_is_unique_and_hash_is_deterministic() {
+        let service = RandomOpaqueTokenService::new();
+        let t1 = service.generate();
+        let t2 = service.generate();
+
+        assert_ne!(t1, t2);
+        assert_eq!(service.hash(&t1), service.hash(&t1));
+        assert_ne!(service.hash(&t1), service.hash(&t2));
+    }
+}
@@ -0,0 +1,415 @@
+mod pepper;
+
+pub use self::pepper::PepperRing;
+pub use argon2::{Algorithm as Argon2Algorithm, Version as Argon2Version};
+use argon2::{
+    Argon2, KeyId, Params, ParamsBuilder,
+    password_hash::{
+        PasswordHash as Argon2Hash, PasswordHasher, PasswordVerifier, SaltString, rand_core::OsRng,
+    },
+};
+use async_trait::async_trait;
+use domain::models::auth::{PasswordService, PasswordServiceError, RawPassword};
+use domain::models::user::PasswordHash;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+/// 新しくハッシュ化する際の Argon2 のアルゴリズムとコストパラメータ。
+///
+/// 照合は保存されたハッシュに含まれるパラメータで行うため、変更前のハッシュもそのまま検証できる。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Argon2Config {
+    pub algorithm: Argon2Algorithm,
+    pub version: Argon2Version,
+    /// メモリコスト（KiB）
+    pub memory_kib: u32,
+    /// 反復回数
+    pub iterations: u32,
+    /// 並列度
+    pub parallelism: u32,
+}
+
+impl Default for Argon2Config {
+    fn default() -> Self {
+        Self {
+            algorithm: Argon2Algorithm::default(),
+            version: Argon2Version::default(),
+            memory_kib: Params::DEFAULT_M_COST,
+            iterations: Params::DEFAULT_T_COST,
+            parallelism: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+/// Argon2 の計算を同時に実行する数と、空きを待つ時間の上限。
+///
+/// 計算は Tokio のワーカースレッドを塞がないようブロッキング用のスレッドで行い、
+/// 同時実行数を制限して CPU とメモリ（メモリコスト × 同時実行数）の消費を抑える。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HashingPoolConfig {
+    pub max_concurrency: usize,
+    /// 超えた場合は `PasswordServiceError::Overloaded` を返す
+    pub queue_timeout: Duration,
+}
+
+impl Default for HashingPoolConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrency: std::thread::available_parallelism().map_or(1, |n| n.get()),
+            queue_timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Argon2 の計算に必要な状態。ブロッキング用のスレッドへ複製して渡す。
+#[derive(Clone)]
+struct Hasher {
+    /// 新しいハッシュのパラメータ（ペッパーを使用する場合は現在の鍵 ID を含む）
+    argon2: Argon2<'static>,
+    pepper: Option<Arc<PepperRing>>,
+}
+
+impl Hasher {
+    fn hash(&self, password: &[u8]) -> Result<String, argon2::password_hash::Error> {
+        let salt = SaltString::generate(&mut OsRng);
+        let hash = match &self.pepper {
+            Some(pepper) => {
+                let peppered = pepper
+                    .apply(pepper.current_id().as_bytes(), password)
+                    .expect("the current pepper key is always present");
+                self.argon2.hash_password(&peppered, &salt)?
+            }
+            None => self.argon2.hash_password(password, &salt)?,
+        };
+        Ok(hash.to_string())
+    }
+
+    fn verify(&self, password: &[u8], hash: &PasswordHash) -> Result<bool, PasswordServiceError> {
+        let parsed_hash = Argon2Hash::new(hash.as_ref())
+            .map_err(|e| PasswordServiceError::VerificationFailed(e.into()))?;
+        let params = Params::try_from(&parsed_hash)
+            .map_err(|e| PasswordServiceError::VerificationFailed(e.into()))?;
+
+        // 照合はハッシュに記録された鍵 ID の鍵で行う（鍵 ID がなければペッパー導入前のハッシュ）
+        let key_id = params.keyid();
+        if key_id.is_empty() {
+            return Ok(self.argon2.verify_password(password, &parsed_hash).is_ok());
+        }
+        let peppered = self
+            .pepper
+            .as_ref()
+            .and_then(|pepper| pepper.apply(key_id, password))
+            .ok_or_else(|| {
+                PasswordServiceError::VerificationFailed(anyhow::anyhow!(
+                    "pepper key {:?} is not configured",
+                    String::from_utf8_lossy(key_id)
+                ))
+            })?;
+        Ok(self.argon2.verify_password(&peppered, &parsed_hash).is_ok())
+    }
+}
+
+pub struct Argon2PasswordService {
+    config: Argon2Config,
+    hasher: Hasher,
+    dummy_hash: PasswordHash,
+    permits: Arc<Semaphore>,
+    queue_timeout: Duration,
+}
+
+impl Argon2PasswordService {
+    pub fn new() -> Self {
+        Self::with_config(Argon2Config::default(), HashingPoolConfig::default(), None)
+            .expect("default Argon2 params must be valid")
+    }
+
+    /// パラメータが Argon2 の許容範囲外の場合はエラーを返す
+    ///
+    /// `pepper` を指定した場合、新しいハッシュにはその現在の鍵を適用する。
+    pub fn with_config(
+        config: Argon2Config,
+        pool: HashingPoolConfig,
+        pepper: Option<PepperRing>,
+    ) -> Result<Self, argon2::password_hash::Error> {
+        let mut params = ParamsBuilder::new();
+        params
+            .m_cost(config.memory_kib)
+            .t_cost(config.iterations)
+            .p_cost(config.parallelism);
+        if let Some(pepper) = &pepper {
+            params.keyid(KeyId::new(pepper.current_id().as_bytes())?);
+        }
+        let hasher = Hasher {
+            argon2: Argon2::new(config.algorithm, config.version, params.build()?),
+            pepper: pepper.map(Arc::new),
+        };
+
+        // 照合の手間を実在のハッシュと揃えるため、同じ設定で推測できない値をハッシュ化しておく
+        let salt = SaltString::generate(&mut OsRng);
+        let dummy_hash = hasher.hash(salt.as_str().as_bytes())?;
+        Ok(Self {
+            config,
+            hasher,
+            dummy_hash: PasswordHash::from_str_unchecked(dummy_hash),
+            permits: Arc::new(Semaphore::new(pool.max_concurrency)),
+            queue_timeout: pool.queue_timeout,
+        })
+    }
+
+    /// 空きを待ってからブロッキング用のスレッドで `f` を実行する
+    async fn run_blocking<T, F>(&self, f: F) -> Result<T, PasswordServiceError>
+    where
+        F: FnOnce(&Hasher) -> Result<T, PasswordServiceError> + Send + 'static,
+        T: Send + 'static,
+    {
+        let permit = tokio::time::timeout(
+            self.queue_timeout,
+            Arc::clone(&self.permits).acquire_owned(),
+        )
+        .await
+        .map_err(|_| PasswordServiceError::Overloaded)?
+        .expect("the semaphore is never closed");
+
+        // 呼び出し元が中断しても計算は続くため、完了するまで枠を保持する
+        let hasher = self.hasher.clone();
+        tokio::task::spawn_blocking(move || {
+            let _permit = permit;
+            f(&hasher)
+        })
+        .await
+        .map_err(|e| PasswordServiceError::HashingFailed(e.into()))?
+    }
+
+    /// 新しいハッシュに記録する鍵 ID（ペッパーを使用しない場合は空）
+    fn current_key_id(&self) -> &[u8] {
+        self.hasher
+            .pepper
+            .as_ref()
+            .map_or(&[], |pepper| pepper.current_id().as_bytes())
+    }
+}
+
+impl Default for Argon2PasswordService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl PasswordService for Argon2PasswordService {
+    async fn verify(
+        &self,
+        password: &RawPassword,
+        hash: &PasswordHash,
+    ) -> Result<bool, PasswordServiceError> {
+        let password = password.clone();
+        let hash = hash.clone();
+        self.run_blocking(move |hasher| hasher.verify(password.expose_as_str().as_bytes(), &hash))
+            .await
+    }
+
+    async fn hash(&self, password: &RawPassword) -> Result<PasswordHash, PasswordServiceError> {
+        let password = password.clone();
+        self.run_blocking(move |hasher| {
+            let hash_str = hasher
+                .hash(password.expose_as_str().as_bytes())
+                .map_err(|e| PasswordServiceError::HashingFailed(e.into()))?;
+
+            Ok(PasswordHash::from_str_unchecked(hash_str))
+        })
+        .await
+    }
+
+    fn dummy_hash(&self) -> &PasswordHash {
+        &self.dummy_hash
+    }
+
+    fn needs_rehash(&self, hash: &PasswordHash) -> bool {
+        // 解釈できないハッシュも現在の設定で置き換える
+        let Ok(parsed) = Argon2Hash::new(hash.as_ref()) else {
+            return true;
+        };
+        let Ok(params) = Params::try_from(&parsed) else {
+            return true;
+        };
+        parsed.algorithm != self.config.algorithm.ident()
+            || parsed.version != Some(self.config.version.into())
+            || params.m_cost() != self.config.memory_kib
+            || params.t_cost() != self.config.iterations
+            || params.p_cost() != self.config.parallelism
+            || params.keyid() != self.current_key_id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_dummy_hash_costs_the_same_as_real_hashes() {
+        let service = Argon2PasswordService::new();
+        let real = service
+            .hash(&RawPassword::from("Password123!"))
+            .await
+            .unwrap();
+
+        // 照合の手間はアルゴリズムとパラメータで決まるため、これらが一致すること
+        let real = Argon2Hash::new(real.as_ref()).unwrap();
+        let dummy = Argon2Hash::new(service.dummy_hash().as_ref()).unwrap();
+        assert_eq!(dummy.algorithm, real.algorithm);
+        assert_eq!(dummy.version, real.version);
+        assert_eq!(dummy.params, real.params);
+
+        let matched = service
+            .verify(&RawPassword::from("Password123!"), service.dummy_hash())
+            .await
+            .unwrap();
+        assert!(!matched);
+    }
+
+    #[tokio::test]
+    async fn test_needs_rehash_when_params_change() {
+        let weak = Argon2PasswordService::with_config(
+            Argon2Config {
+                memory_kib: 8 * 1024,
+                iterations: 1,
+                ..Argon2Config::default()
+            },
+            HashingPoolConfig::default(),
+            None,
+        )
+        .unwrap();
+        let password = RawPassword::from("Password123!");
+        let old_hash = weak.hash(&password).await.unwrap();
+        assert!(!weak.needs_rehash(&old_hash));
+
+        // 変更前のハッシュも照合でき、現在の設定で再ハッシュが必要と判定される
+        let service = Argon2PasswordService::new();
+        assert!(service.verify(&password, &old_hash).await.unwrap());
+        assert!(service.needs_rehash(&old_hash));
+
+        let argon2i = Argon2PasswordService::with_config(
+            Argon2Config {
+                algorithm: Argon2Algorithm::Argon2i,
+                ..Argon2Config::default()
+            },
+            HashingPoolConfig::default(),
+            None,
+        )
+        .unwrap();
+        assert!(argon2i.needs_rehash(&service.hash(&password).await.unwrap()));
+
+        let new_hash = service.hash(&password).await.unwrap();
+        assert!(!service.needs_rehash(&new_hash));
+        assert!(service.needs_rehash(&PasswordHash::from_str_unchecked("not-a-phc-string")));
+    }
+
+    #[test]
+    fn test_with_config_rejects_invalid_params() {
+        let result = Argon2PasswordService::with_config(
+            Argon2Config {
+                parallelism: 0,
+                ..Argon2Config::default()
+            },
+            HashingPoolConfig::default(),
+            None,
+        );
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_when_the_pool_stays_busy() {
+        let service = Arc::new(
+            Argon2PasswordService::with_config(
+                Argon2Config::default(),
+                HashingPoolConfig {
+                    max_concurrency: 1,
+                    queue_timeout: Duration::from_millis(1),
+                },
+                None,
+            )
+            .unwrap(),
+        );
+        let password = RawPassword::from("Password123!");
+
+        // 1 件目が枠を占有している間、2 件目は待ち時間を超えて拒否される
+        let (first, second) = tokio::join!(service.hash(&password), async {
+            tokio::task::yield_now().await;
+            service.hash(&password).await
+        });
+        assert!(first.is_ok());
+        assert!(matches!(second, Err(PasswordServiceError::Overloaded)));
+
+        // 完了後は再び受け付ける
+        assert!(service.hash(&password).await.is_ok());
+    }
+
+    fn peppered(keys: &[(&str, u8)]) -> Argon2PasswordService {
+        let ring = PepperRing::new(
+            keys.iter()
+                .map(|(id, byte)| (id.to_string(), vec![*byte; 32])),
+        )
+        .unwrap();
+        Argon2PasswordService::with_config(
+            Argon2Config {
+                memory_kib: 1024,
+                iterations: 1,
+                ..Argon2Config::default()
+            },
+            HashingPoolConfig::default(),
+            Some(ring),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_pepper_key_id_is_recorded_and_rotated() {
+        let password = RawPassword::from("Password123!");
+        let v1 = peppered(&[("v1", 1)]);
+        let v1_hash = v1.hash(&password).await.unwrap();
+        assert!(v1_hash.as_ref().contains("keyid="));
+        assert!(v1.verify(&password, &v1_hash).await.unwrap());
+        assert!(!v1.needs_rehash(&v1_hash));
+
+        // 鍵を追加した後も古い鍵のハッシュを照合でき、新しい鍵での再ハッシュが必要と判定される
+        let rotated = peppered(&[("v1", 1), ("v2", 2)]);
+        assert!(rotated.verify(&password, &v1_hash).await.unwrap());
+        assert!(
+            !rotated
+                .verify(&RawPassword::from("Wrong123!"), &v1_hash)
+                .await
+                .unwrap()
+        );
+        assert!(rotated.needs_rehash(&v1_hash));
+        let v2_hash = rotated.hash(&password).await.unwrap();
+        assert!(!rotated.needs_rehash(&v2_hash));
+
+        // 同じ ID でも鍵が異なれば一致しない
+        let other_secret = peppered(&[("v1", 9)]);
+        assert!(!other_secret.verify(&password, &v1_hash).await.unwrap());
+
+        // 鍵を削除するとその鍵によるハッシュは照合できない
+        let removed = peppered(&[("v2", 2)]);
+        assert!(matches!(
+            removed.verify(&password, &v1_hash).await,
+            Err(PasswordServiceError::VerificationFailed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_unpeppered_hashes_migrate_to_pepper() {
+        let password = RawPassword::from("Password123!");
+        let legacy_hash = Argon2PasswordService::new().hash(&password).await.unwrap();
+
+        let service = peppered(&[("v1", 1)]);
+        assert!(service.verify(&password, &legacy_hash).await.unwrap());
+        assert!(service.needs_rehash(&legacy_hash));
+        assert!(
+            service
+                .verify(&password, service.dummy_hash())
+                .await
+                .is_ok()
+        );
+    }
+}
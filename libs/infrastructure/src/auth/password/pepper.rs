@@ -0,0 +1,146 @@
+use anyhow::{Context, bail, ensure};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::path::Path;
+
+/// 鍵 ID の最大長（Argon2 の `keyid` パラメータの上限）
+const MAX_KEY_ID_LEN: usize = 8;
+
+/// 鍵として要求する最小の長さ（バイト）
+const MIN_SECRET_LEN: usize = 32;
+
+struct PepperKey {
+    id: String,
+    secret: Vec<u8>,
+}
+
+/// パスワードに HMAC-SHA256 で適用するサーバー側の秘密鍵（ペッパー）の一覧。
+///
+/// 新しいハッシュには最後の鍵を使用し、それ以前の鍵は既存のハッシュの照合にのみ使用する。
+/// 使用した鍵の ID はハッシュの `keyid` パラメータに記録される。
+pub struct PepperRing {
+    keys: Vec<PepperKey>,
+}
+
+impl PepperRing {
+    /// 鍵 ID と鍵の組を古い順に受け取る
+    pub fn new(keys: impl IntoIterator<Item = (String, Vec<u8>)>) -> anyhow::Result<Self> {
+        let mut ring = Self { keys: Vec::new() };
+        for (id, secret) in keys {
+            ensure!(
+                !id.is_empty()
+                    && id.len() <= MAX_KEY_ID_LEN
+                    && id.bytes().all(|b| b.is_ascii_alphanumeric()),
+                "pepper key id must be 1 to {MAX_KEY_ID_LEN} ASCII alphanumeric characters: {id:?}"
+            );
+            ensure!(
+                secret.len() >= MIN_SECRET_LEN,
+                "pepper key {id:?} must be at least {MIN_SECRET_LEN} bytes"
+            );
+            ensure!(
+                ring.find(id.as_bytes()).is_none(),
+                "duplicate pepper key id: {id:?}"
+            );
+            ring.keys.push(PepperKey { id, secret });
+        }
+        ensure!(!ring.keys.is_empty(), "no pepper key is defined");
+        Ok(ring)
+    }
+
+    /// `<鍵 ID>:<Base64 の鍵>` を 1 行ずつ古い順に記述したファイルから読み込む
+    ///
+    /// 空行と `#` で始まる行は無視する。ローテーションでは末尾に新しい鍵を追加し、
+    /// 古い鍵はその鍵によるハッシュが残っている間は削除しない。
+    pub fn from_file(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read pepper key file {}", path.display()))?;
+        Self::parse(&content)
+    }
+
+    fn parse(content: &str) -> anyhow::Result<Self> {
+        let mut keys = Vec::new();
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((id, secret)) = line.split_once(':') else {
+                bail!("line {}: expected `<key id>:<base64 secret>`", index + 1);
+            };
+            let secret = STANDARD
+                .decode(secret.trim())
+                .with_context(|| format!("line {}: invalid base64 secret", index + 1))?;
+            keys.push((id.trim().to_string(), secret));
+        }
+        Self::new(keys)
+    }
+
+    /// 新しいハッシュに使用する鍵の ID
+    pub(super) fn current_id(&self) -> &str {
+        &self.keys[self.keys.len() - 1].id
+    }
+
+    /// 指定した ID の鍵を適用する。未知の ID の場合は `None`
+    pub(super) fn apply(&self, id: &[u8], password: &[u8]) -> Option<[u8; 32]> {
+        let key = self.find(id)?;
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&key.secret).expect("HMAC accepts keys of any size");
+        mac.update(password);
+        Some(mac.finalize().into_bytes().into())
+    }
+
+    fn find(&self, id: &[u8]) -> Option<&PepperKey> {
+        self.keys.iter().find(|key| key.id.as_bytes() == id)
+    }
+}
+
+impl std::fmt::Debug for PepperRing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // 鍵そのものは出力しない
+        f.debug_struct("PepperRing")
+            .field(
+                "key_ids",
+                &self.keys.iter().map(|key| &key.id).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secret(byte: u8) -> String {
+        STANDARD.encode([byte; MIN_SECRET_LEN])
+    }
+
+    #[test]
+    fn test_parse_uses_the_last_key_for_new_hashes() {
+        let content = format!("# rotated 2026-10\nv1:{}\n\nv2:{}\n", secret(1), secret(2));
+        let ring = PepperRing::parse(&content).unwrap();
+
+        assert_eq!(ring.current_id(), "v2");
+        assert_ne!(ring.apply(b"v1", b"pw"), ring.apply(b"v2", b"pw"));
+        assert_eq!(ring.apply(b"v3", b"pw"), None);
+        assert_eq!(
+            format!("{ring:?}"),
+            r#"PepperRing { key_ids: ["v1", "v2"] }"#
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_keys() {
+        let short = STANDARD.encode([0u8; MIN_SECRET_LEN - 1]);
+        for content in [
+            String::new(),
+            format!("v1={}", secret(1)),
+            format!("v1:{short}"),
+            format!("toolongid:{}", secret(1)),
+            format!("v1:{}\nv1:{}", secret(1), secret(2)),
+        ] {
+            assert!(PepperRing::parse(&content).is_err(), "{content:?}");
+        }
+    }
+}
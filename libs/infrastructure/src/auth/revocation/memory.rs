@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::clock::Clock;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use usecase::auth::TokenRevocationStore;
+use usecase::error::TokenRevocationError;
+use uuid::Uuid;
+
+/// プロセス内のメモリに失効情報を保持する `TokenRevocationStore` の実装。
+///
+/// 単一インスタンス構成や開発・テスト用途を想定する。
+pub struct InMemoryTokenRevocationStore<C: Clock> {
+    revoked: Mutex<HashMap<Uuid, DateTime<Utc>>>,
+    clock: Arc<C>,
+}
+
+impl<C: Clock> InMemoryTokenRevocationStore<C> {
+    pub fn new(clock: Arc<C>) -> Self {
+        Self {
+            revoked: Mutex::new(HashMap::new()),
+            clock,
+        }
+    }
+}
+
+#[async_trait]
+impl<C: Clock> TokenRevocationStore for InMemoryTokenRevocationStore<C> {
+    async fn revoke(&self, jti: Uuid, exp: usize) -> Result<(), TokenRevocationError> {
+        let expires_at = super::expires_at(exp)?;
+        let now = self.clock.now();
+        let mut revoked = self.revoked.lock().unwrap();
+        // 期限切れのエントリはもう検証を通過しないため破棄する
+        revoked.retain(|_, expires_at| *expires_at > now);
+        revoked.insert(jti, expires_at);
+        Ok(())
+    }
+
+    async fn is_revoked(&self, jti: Uuid) -> Result<bool, TokenRevocationError> {
+        let now = self.clock.now();
+        let revoked = self.revoked.lock().unwrap();
+        Ok(revoked
+            .get(&jti)
+            .is_some_and(|expires_at| *expires_at > now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::test_utils::FixedClock;
+
+    #[tokio::test]
+    async fn test_revoke_and_expire() {
+        let now = Utc::now();
+        let store = InMemoryTokenRevocationStore::new(Arc::new(FixedClock::new(now)));
+        let active_jti = Uuid::new_v4();
+        let expired_jti = Uuid::new_v4();
+
+        assert!(!store.is_revoked(active_jti).await.unwrap());
+        store
+            .revoke(
+                active_jti,
+                (now + chrono::Duration::minutes(15)).timestamp() as usize,
+            )
+            .await
+            .unwrap();
+        store
+            .revoke(
+                expired_jti,
+                (now - chrono::Duration::minutes(1)).timestamp() as usize,
+            )
+            .await
+            .unwrap();
+
+        assert!(store.is_revoked(active_jti).await.unwrap());
+        // 有効期限を過ぎたトークンは失効情報を保持する必要がない
+        assert!(!store.is_revoked(expired_jti).await.unwrap());
+    }
+}
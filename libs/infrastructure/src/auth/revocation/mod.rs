@@ -0,0 +1,18 @@
+pub mod memory;
+pub mod postgres;
+
+pub use memory::InMemoryTokenRevocationStore;
+pub use postgres::PgTokenRevocationStore;
+
+use chrono::{DateTime, Utc};
+use usecase::error::TokenRevocationError;
+
+/// JWT の `exp`（UNIX 秒）を日時に変換する。
+fn expires_at(exp: usize) -> Result<DateTime<Utc>, TokenRevocationError> {
+    i64::try_from(exp)
+        .ok()
+        .and_then(|secs| DateTime::from_timestamp(secs, 0))
+        .ok_or_else(|| {
+            TokenRevocationError::StoreFailed(anyhow::anyhow!("exp out of range: {exp}"))
+        })
+}
@@ -0,0 +1,106 @@
+use async_trait::async_trait;
+use domain::clock::Clock;
+use sqlx::PgPool;
+use std::sync::Arc;
+use usecase::auth::TokenRevocationStore;
+use usecase::error::TokenRevocationError;
+use uuid::Uuid;
+
+use crate::repository::audit::AuditStamp;
+
+/// 監査カラムに記録するプログラムコード
+const PROGRAM_CODE: &str = "auth-token-revocation";
+
+/// PostgreSQL に失効情報を保持する `TokenRevocationStore` の実装。
+///
+/// 複数インスタンス間で失効情報を共有する本番構成を想定する。
+pub struct PgTokenRevocationStore<C: Clock> {
+    pool: PgPool,
+    clock: Arc<C>,
+}
+
+impl<C: Clock> PgTokenRevocationStore<C> {
+    pub fn new(pool: PgPool, clock: Arc<C>) -> Self {
+        Self { pool, clock }
+    }
+}
+
+#[async_trait]
+impl<C: Clock> TokenRevocationStore for PgTokenRevocationStore<C> {
+    async fn revoke(&self, jti: Uuid, exp: usize) -> Result<(), TokenRevocationError> {
+        let expires_at = super::expires_at(exp)?;
+        let audit = AuditStamp::system(PROGRAM_CODE, self.clock.now());
+        let now = audit.at;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO revoked_access_tokens (
+                jti, expires_at, revoked_at,
+                created_at, created_by, created_pgm_cd, created_tx_id,
+                updated_at, updated_by, updated_pgm_cd, updated_tx_id
+            ) VALUES ($1, $2, $3, $3, $4, $5, $6, $3, $4, $5, $6)
+            ON CONFLICT (jti) DO NOTHING
+            "#,
+            jti,
+            expires_at,
+            now,
+            audit.by,
+            audit.pgm_cd,
+            audit.tx_id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| TokenRevocationError::StoreFailed(e.into()))?;
+
+        // 期限切れのエントリはもう検証を通過しないため破棄する
+        sqlx::query!(
+            "DELETE FROM revoked_access_tokens WHERE expires_at <= $1",
+            now
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| TokenRevocationError::StoreFailed(e.into()))?;
+
+        Ok(())
+    }
+
+    async fn is_revoked(&self, jti: Uuid) -> Result<bool, TokenRevocationError> {
+        let revoked = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM revoked_access_tokens
+                WHERE jti = $1 AND expires_at > $2
+            ) AS "revoked!"
+            "#,
+            jti,
+            self.clock.now(),
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| TokenRevocationError::StoreFailed(e.into()))?;
+
+        Ok(revoked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::RealClock;
+    use chrono::{Duration, Utc};
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_revoke_and_is_revoked(pool: PgPool) {
+        let store = PgTokenRevocationStore::new(pool, Arc::new(RealClock));
+        let jti = Uuid::new_v4();
+        let exp = (Utc::now() + Duration::minutes(15)).timestamp() as usize;
+
+        assert!(!store.is_revoked(jti).await.unwrap());
+
+        store.revoke(jti, exp).await.unwrap();
+        // 二重の失効は冪等に扱われる
+        store.revoke(jti, exp).await.unwrap();
+
+        assert!(store.is_revoked(jti).await.unwrap());
+    }
+}
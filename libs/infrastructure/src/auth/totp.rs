@@ -0,0 +1,155 @@
+use chrono::{DateTime, Utc};
+use data_encoding::BASE32_NOPAD;
+use domain::models::auth::{TOTP_DIGITS, TotpCode, TotpSecret, TotpService};
+use hmac::{Hmac, Mac};
+use rand::{RngCore, rngs::OsRng};
+use sha1::Sha1;
+use subtle::ConstantTimeEq;
+
+/// シークレット長（バイト数）。RFC 4226 の推奨に従い 160 bit とする。
+const SECRET_BYTES: usize = 20;
+
+/// タイムステップの長さ（秒）。
+const PERIOD_SECS: i64 = 30;
+
+/// 端末の時刻ずれを考慮して前後に許容するステップ数。
+const ALLOWED_SKEW_STEPS: u64 = 1;
+
+/// RFC 6238 (HMAC-SHA1, 6 桁, 30 秒) に基づく TOTP サービス。
+///
+/// 主要な認証アプリが既定で対応するパラメータに固定している。
+pub struct RfcTotpService {
+    issuer: String,
+}
+
+impl RfcTotpService {
+    /// `issuer` は認証アプリ上でアカウントの発行元として表示される。
+    pub fn new(issuer: impl Into<String>) -> Self {
+        Self {
+            issuer: issuer.into(),
+        }
+    }
+
+    /// RFC 4226 の HOTP 値を計算する。
+    fn hotp(key: &[u8], counter: u64) -> String {
+        let mut mac = Hmac::<Sha1>::new_from_slice(key).expect("HMAC accepts keys of any size");
+        mac.update(&counter.to_be_bytes());
+        let digest = mac.finalize().into_bytes();
+
+        // 動的切り捨て (Dynamic Truncation)
+        let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes([
+            digest[offset] & 0x7f,
+            digest[offset + 1],
+            digest[offset + 2],
+            digest[offset + 3],
+        ]);
+        let modulus = 10u32.pow(TOTP_DIGITS as u32);
+        format!("{:0width$}", binary % modulus, width = TOTP_DIGITS)
+    }
+}
+
+impl TotpService for RfcTotpService {
+    fn generate_secret(&self) -> TotpSecret {
+        let mut bytes = [0u8; SECRET_BYTES];
+        OsRng.fill_bytes(&mut bytes);
+        TotpSecret::from_str_unchecked(BASE32_NOPAD.encode(&bytes))
+    }
+
+    fn provisioning_uri(&self, secret: &TotpSecret, account_name: &str) -> String {
+        let issuer = percent_encode(&self.issuer);
+        format!(
+            "otpauth://totp/{issuer}:{account}?secret={secret}&issuer={issuer}&algorithm=SHA1&digits={TOTP_DIGITS}&period={PERIOD_SECS}",
+            account = percent_encode(account_name),
+            secret = secret.expose_as_str(),
+        )
+    }
+
+    fn verify(&self, secret: &TotpSecret, code: &TotpCode, now: DateTime<Utc>) -> Option<u64> {
+        let key = BASE32_NOPAD
+            .decode(secret.expose_as_str().as_bytes())
+            .ok()?;
+        let current = u64::try_from(now.timestamp() / PERIOD_SECS).ok()?;
+
+        let first = current.saturating_sub(ALLOWED_SKEW_STEPS);
+        let last = current + ALLOWED_SKEW_STEPS;
+        (first..=last).find(|&step| {
+            Self::hotp(&key, step)
+                .as_bytes()
+                .ct_eq(code.expose_as_str().as_bytes())
+                .into()
+        })
+    }
+}
+
+/// RFC 3986 の非予約文字以外をパーセントエンコードする。
+fn percent_encode(input: &str) -> String {
+    input
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// RFC 6238 Appendix B のテスト用シークレット ("12345678901234567890")
+    fn rfc_secret() -> TotpSecret {
+        TotpSecret::from_str_unchecked(BASE32_NOPAD.encode(b"12345678901234567890"))
+    }
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(secs, 0).unwrap()
+    }
+
+    #[test]
+    fn test_rfc6238_vectors() {
+        let service = RfcTotpService::new("Test");
+        // 8 桁の期待値の下 6 桁
+        let vectors = [
+            (59, "287082"),
+            (1111111109, "081804"),
+            (1234567890, "005924"),
+            (2000000000, "279037"),
+        ];
+
+        for (secs, expected) in vectors {
+            let code = TotpCode::try_from(expected.to_string()).unwrap();
+            assert_eq!(
+                service.verify(&rfc_secret(), &code, at(secs)),
+                Some(secs as u64 / 30),
+                "T = {secs}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_allows_one_step_of_skew() {
+        let service = RfcTotpService::new("Test");
+        let code = TotpCode::try_from("287082".to_string()).unwrap();
+
+        assert_eq!(service.verify(&rfc_secret(), &code, at(59 + 30)), Some(1));
+        assert_eq!(service.verify(&rfc_secret(), &code, at(59 + 60)), None);
+    }
+
+    #[test]
+    fn test_provisioning_uri() {
+        let service = RfcTotpService::new("My App");
+        let secret = service.generate_secret();
+
+        let uri = service.provisioning_uri(&secret, "user@example.com");
+        assert_eq!(
+            uri,
+            format!(
+                "otpauth://totp/My%20App:user%40example.com?secret={}&issuer=My%20App&algorithm=SHA1&digits=6&period=30",
+                secret.expose_as_str()
+            )
+        );
+    }
+}
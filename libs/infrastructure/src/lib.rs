@@ -1,5 +1,7 @@
 pub mod auth;
 pub mod clock;
 pub mod id;
+pub mod mail;
+pub mod outbox;
 pub mod repository;
 pub mod telemetry;
@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use usecase::error::MailerError;
+use usecase::mailer::{Mail, Mailer};
+
+/// 送信をバックグラウンドのタスクで行い、完了を待たずに返す `Mailer` のデコレーター。
+///
+/// 送信の成否や所要時間を応答に反映させたくない場合に使用する。失敗はログに記録する。
+pub struct BackgroundMailer {
+    inner: Arc<dyn Mailer>,
+}
+
+impl BackgroundMailer {
+    pub fn new(inner: Arc<dyn Mailer>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl Mailer for BackgroundMailer {
+    async fn send(&self, mail: Mail) -> Result<(), MailerError> {
+        let inner = Arc::clone(&self.inner);
+        tokio::spawn(async move {
+            if let Err(e) = inner.send(mail).await {
+                tracing::warn!(error = ?e, "Failed to send mail in background");
+            }
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mail::InMemoryMailer;
+    use chrono::Utc;
+    use domain::models::user::Email;
+    use usecase::mailer::MailMessage;
+
+    struct FailingMailer;
+
+    #[async_trait]
+    impl Mailer for FailingMailer {
+        async fn send(&self, _mail: Mail) -> Result<(), MailerError> {
+            Err(MailerError::DeliveryFailed(anyhow::anyhow!("smtp down")))
+        }
+    }
+
+    fn mail() -> Mail {
+        Mail {
+            to: Email::try_from("user@example.com").unwrap(),
+            message: MailMessage::SignupAttempted {
+                attempted_at: Utc::now(),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_is_delivered_in_background() {
+        let inner = Arc::new(InMemoryMailer::new());
+        let mailer = BackgroundMailer::new(inner.clone());
+
+        mailer.send(mail()).await.unwrap();
+
+        for _ in 0..100 {
+            if !inner.sent().is_empty() {
+                break;
+            }
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(inner.sent().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failure_is_not_returned() {
+        let mailer = BackgroundMailer::new(Arc::new(FailingMailer));
+
+        assert!(mailer.send(mail()).await.is_ok());
+    }
+}
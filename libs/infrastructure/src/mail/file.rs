@@ -0,0 +1,96 @@
+use super::{MailRenderer, build_message, parse_mailbox};
+use async_trait::async_trait;
+use lettre::message::Mailbox;
+use lettre::{AsyncFileTransport, AsyncTransport, Tokio1Executor};
+use std::path::PathBuf;
+use usecase::error::MailerError;
+use usecase::mailer::{Mail, Mailer};
+
+/// 送信の代わりにメールを `.eml` ファイルとしてディレクトリへ書き出す `Mailer` の実装。
+///
+/// ステージング環境等でメールクライアントから内容を確認する用途を想定する。
+pub struct FileMailer {
+    transport: AsyncFileTransport<Tokio1Executor>,
+    from: Mailbox,
+    renderer: MailRenderer,
+}
+
+impl FileMailer {
+    /// 出力先のディレクトリが存在しない場合は作成する。
+    pub fn new(
+        dir: impl Into<PathBuf>,
+        from: &str,
+        renderer: MailRenderer,
+    ) -> anyhow::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self {
+            transport: AsyncFileTransport::new(dir),
+            from: parse_mailbox(from)?,
+            renderer,
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    async fn send(&self, mail: Mail) -> Result<(), MailerError> {
+        let rendered = self.renderer.render(&mail.message);
+        let message = build_message(&self.from, &mail, rendered)?;
+        let id = self
+            .transport
+            .send(message)
+            .await
+            .map_err(|e| MailerError::DeliveryFailed(e.into()))?;
+        tracing::debug!(id = %id, "Mail written to file");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mail::MailLocale;
+    use chrono::Utc;
+    use domain::models::auth::OpaqueToken;
+    use domain::models::user::Email;
+    use usecase::mailer::MailMessage;
+
+    #[tokio::test]
+    async fn test_send_writes_eml_file() {
+        let dir = std::env::temp_dir().join(format!("file-mailer-{}", uuid::Uuid::now_v7()));
+        let mailer = FileMailer::new(
+            &dir,
+            "no-reply@example.com",
+            MailRenderer::new("https://app.example.com", MailLocale::En),
+        )
+        .unwrap();
+
+        mailer
+            .send(Mail {
+                to: Email::try_from("user@example.com").unwrap(),
+                message: MailMessage::PasswordReset {
+                    token: OpaqueToken::from("reset-token"),
+                    expires_at: Utc::now(),
+                },
+            })
+            .await
+            .unwrap();
+
+        let files: Vec<_> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].extension().unwrap(), "eml");
+
+        let eml = std::fs::read_to_string(&files[0]).unwrap();
+        assert!(eml.contains("From: no-reply@example.com"));
+        assert!(eml.contains("To: user@example.com"));
+        assert!(eml.contains("Subject: Reset your password"));
+        // 本文は quoted-printable でエンコードされる
+        assert!(eml.contains("https://app.example.com/reset-password?token=3Dreset-token"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
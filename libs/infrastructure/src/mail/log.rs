@@ -0,0 +1,31 @@
+use super::MailRenderer;
+use async_trait::async_trait;
+use usecase::error::MailerError;
+use usecase::mailer::{Mail, Mailer};
+
+/// 送信の代わりにメール内容をログへ出力する `Mailer` の実装。
+///
+/// ローカル開発用途を想定する。本文にはトークンの平文が含まれるため本番では使用しないこと。
+pub struct LogMailer {
+    renderer: MailRenderer,
+}
+
+impl LogMailer {
+    pub fn new(renderer: MailRenderer) -> Self {
+        Self { renderer }
+    }
+}
+
+#[async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, mail: Mail) -> Result<(), MailerError> {
+        let rendered = self.renderer.render(&mail.message);
+        tracing::info!(
+            to = ?mail.to,
+            subject = %rendered.subject,
+            "Mail delivered to log:\n{}",
+            rendered.body
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use std::sync::Mutex;
+use usecase::error::MailerError;
+use usecase::mailer::{Mail, Mailer};
+
+/// 送信したメールをメモリ上に保持するだけの `Mailer` の実装。
+///
+/// 実際には送信しないため、テスト等で送信内容を検証する用途に使用する。
+#[derive(Default)]
+pub struct InMemoryMailer {
+    sent: Mutex<Vec<Mail>>,
+}
+
+impl InMemoryMailer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// これまでに送信されたメールを送信順に返す。
+    pub fn sent(&self) -> Vec<Mail> {
+        self.sent.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl Mailer for InMemoryMailer {
+    async fn send(&self, mail: Mail) -> Result<(), MailerError> {
+        self.sent.lock().unwrap().push(mail);
+        Ok(())
+    }
+}
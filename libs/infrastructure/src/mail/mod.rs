@@ -0,0 +1,42 @@
+pub mod background;
+pub mod file;
+pub mod log;
+pub mod memory;
+pub mod smtp;
+pub mod template;
+
+pub use background::BackgroundMailer;
+pub use file::FileMailer;
+pub use log::LogMailer;
+pub use memory::InMemoryMailer;
+pub use smtp::{SmtpConfig, SmtpMailer, SmtpSecurity};
+pub use template::{MailLocale, MailRenderer, RenderedMail};
+
+use lettre::Message;
+use lettre::message::Mailbox;
+use lettre::message::header::ContentType;
+use usecase::error::MailerError;
+use usecase::mailer::Mail;
+
+/// 差出人のアドレスを検証して `Mailbox` に変換する。
+fn parse_mailbox(address: &str) -> anyhow::Result<Mailbox> {
+    address
+        .parse()
+        .map_err(|e| anyhow::anyhow!("Invalid mail address '{}': {}", address, e))
+}
+
+/// テンプレートから組み立てた内容で送信用のメッセージ（RFC 5322）を作成する。
+fn build_message(
+    from: &Mailbox,
+    mail: &Mail,
+    rendered: RenderedMail,
+) -> Result<Message, MailerError> {
+    let to = parse_mailbox(mail.to.as_ref()).map_err(MailerError::DeliveryFailed)?;
+    Message::builder()
+        .from(from.clone())
+        .to(to)
+        .subject(rendered.subject)
+        .header(ContentType::TEXT_PLAIN)
+        .body(rendered.body)
+        .map_err(|e| MailerError::DeliveryFailed(e.into()))
+}
@@ -0,0 +1,188 @@
+use super::{MailRenderer, build_message, parse_mailbox};
+use async_trait::async_trait;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Tokio1Executor};
+use std::str::FromStr;
+use std::time::Duration;
+use usecase::error::MailerError;
+use usecase::mailer::{Mail, Mailer};
+
+/// SMTP サーバーとの接続の保護方式。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SmtpSecurity {
+    /// 平文で接続する（ローカルのメールキャッチャー等に限定すること）
+    None,
+    /// 平文で接続後、STARTTLS で TLS に切り替える
+    #[default]
+    StartTls,
+    /// 最初から TLS で接続する
+    Tls,
+}
+
+impl FromStr for SmtpSecurity {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "none" => Ok(Self::None),
+            "starttls" => Ok(Self::StartTls),
+            "tls" => Ok(Self::Tls),
+            other => Err(anyhow::anyhow!("Unsupported SMTP security: {}", other)),
+        }
+    }
+}
+
+/// SMTP サーバーへの接続設定。
+#[derive(Debug, Clone)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub security: SmtpSecurity,
+    /// ユーザー名とパスワード（認証が不要な場合は `None`）
+    pub credentials: Option<(String, String)>,
+    pub timeout: Duration,
+}
+
+/// SMTP サーバー経由でメールを送信する `Mailer` の実装。
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    renderer: MailRenderer,
+}
+
+impl SmtpMailer {
+    pub fn new(config: SmtpConfig, from: &str, renderer: MailRenderer) -> anyhow::Result<Self> {
+        let builder = match config.security {
+            SmtpSecurity::None => {
+                AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host)
+            }
+            SmtpSecurity::StartTls => {
+                AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)?
+            }
+            SmtpSecurity::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host)?,
+        }
+        .port(config.port)
+        .timeout(Some(config.timeout));
+
+        let builder = match config.credentials {
+            Some((username, password)) => builder.credentials(Credentials::new(username, password)),
+            None => builder,
+        };
+
+        Ok(Self {
+            transport: builder.build(),
+            from: parse_mailbox(from)?,
+            renderer,
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, mail: Mail) -> Result<(), MailerError> {
+        let rendered = self.renderer.render(&mail.message);
+        let message = build_message(&self.from, &mail, rendered)?;
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| MailerError::DeliveryFailed(e.into()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mail::MailLocale;
+    use chrono::Utc;
+    use domain::models::auth::OpaqueToken;
+    use domain::models::user::Email;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+    use usecase::mailer::MailMessage;
+
+    /// 1 通分の SMTP セッションを受け付け、エンベロープと DATA の内容を返す簡易 SMTP サーバー。
+    async fn fake_smtp_server() -> (u16, tokio::task::JoinHandle<(Vec<String>, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (reader, mut writer) = stream.into_split();
+            let mut lines = BufReader::new(reader).lines();
+            let mut commands = Vec::new();
+            let mut data = String::new();
+
+            writer.write_all(b"220 fake ESMTP\r\n").await.unwrap();
+            while let Some(line) = lines.next_line().await.unwrap() {
+                let command = line.to_ascii_uppercase();
+                commands.push(line.clone());
+                if command.starts_with("EHLO") {
+                    writer.write_all(b"250 fake\r\n").await.unwrap();
+                } else if command.starts_with("DATA") {
+                    writer.write_all(b"354 go ahead\r\n").await.unwrap();
+                    while let Some(line) = lines.next_line().await.unwrap() {
+                        if line == "." {
+                            break;
+                        }
+                        data.push_str(&line);
+                        data.push('\n');
+                    }
+                    writer.write_all(b"250 queued\r\n").await.unwrap();
+                    break;
+                } else {
+                    writer.write_all(b"250 ok\r\n").await.unwrap();
+                }
+            }
+            (commands, data)
+        });
+
+        (port, handle)
+    }
+
+    #[tokio::test]
+    async fn test_send_delivers_to_smtp_server() {
+        let (port, server) = fake_smtp_server().await;
+        let mailer = SmtpMailer::new(
+            SmtpConfig {
+                host: "127.0.0.1".to_string(),
+                port,
+                security: SmtpSecurity::None,
+                credentials: None,
+                timeout: Duration::from_secs(5),
+            },
+            "no-reply@example.com",
+            MailRenderer::new("https://app.example.com", MailLocale::En),
+        )
+        .unwrap();
+
+        mailer
+            .send(Mail {
+                to: Email::try_from("user@example.com").unwrap(),
+                message: MailMessage::PasswordReset {
+                    token: OpaqueToken::from("reset-token"),
+                    expires_at: Utc::now(),
+                },
+            })
+            .await
+            .unwrap();
+
+        let (commands, data) = server.await.unwrap();
+        assert!(commands.contains(&"MAIL FROM:<no-reply@example.com>".to_string()));
+        assert!(commands.contains(&"RCPT TO:<user@example.com>".to_string()));
+        assert!(data.contains("Subject: Reset your password"));
+        // 本文は quoted-printable でエンコードされる
+        assert!(data.contains("https://app.example.com/reset-password?token=3Dreset-token"));
+    }
+
+    #[test]
+    fn test_security_from_str() {
+        assert_eq!(
+            "STARTTLS".parse::<SmtpSecurity>().unwrap(),
+            SmtpSecurity::StartTls
+        );
+        assert_eq!("tls".parse::<SmtpSecurity>().unwrap(), SmtpSecurity::Tls);
+        assert!("ssl".parse::<SmtpSecurity>().is_err());
+    }
+}
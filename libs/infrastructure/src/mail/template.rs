@@ -0,0 +1,180 @@
+use chrono::SecondsFormat;
+use std::str::FromStr;
+use usecase::mailer::MailMessage;
+
+/// メールの件名・本文に使用する言語。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MailLocale {
+    #[default]
+    Ja,
+    En,
+}
+
+impl FromStr for MailLocale {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "ja" => Ok(Self::Ja),
+            "en" => Ok(Self::En),
+            other => Err(anyhow::anyhow!("Unsupported mail locale: {}", other)),
+        }
+    }
+}
+
+/// テンプレートから組み立てた件名と本文。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedMail {
+    pub subject: String,
+    pub body: String,
+}
+
+/// `MailMessage` を言語別のテンプレートで件名・本文に変換する。
+///
+/// テンプレートは 1 行目を件名、空行以降を本文とし、`{{name}}` 形式の値を差し込む。
+pub struct MailRenderer {
+    app_base_url: String,
+    locale: MailLocale,
+}
+
+impl MailRenderer {
+    /// `app_base_url` はメール本文のリンク先（フロントエンド）のベース URL。
+    pub fn new(app_base_url: impl Into<String>, locale: MailLocale) -> Self {
+        Self {
+            app_base_url: app_base_url.into().trim_end_matches('/').to_string(),
+            locale,
+        }
+    }
+
+    pub fn render(&self, message: &MailMessage) -> RenderedMail {
+        match message {
+            MailMessage::PasswordReset { token, expires_at } => fill(
+                self.template(TemplateKind::PasswordReset),
+                &[
+                    (
+                        "link",
+                        format!(
+                            "{}/reset-password?token={}",
+                            self.app_base_url,
+                            token.expose_as_str()
+                        ),
+                    ),
+                    (
+                        "expires_at",
+                        expires_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+                    ),
+                ],
+            ),
+            MailMessage::EmailVerification { token, expires_at } => fill(
+                self.template(TemplateKind::EmailVerification),
+                &[
+                    (
+                        "link",
+                        format!(
+                            "{}/verify-email?token={}",
+                            self.app_base_url,
+                            token.expose_as_str()
+                        ),
+                    ),
+                    (
+                        "expires_at",
+                        expires_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+                    ),
+                ],
+            ),
+            MailMessage::SignupAttempted { attempted_at } => fill(
+                self.template(TemplateKind::SignupAttempted),
+                &[
+                    ("link", format!("{}/login", self.app_base_url)),
+                    (
+                        "attempted_at",
+                        attempted_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+                    ),
+                ],
+            ),
+        }
+    }
+
+    fn template(&self, kind: TemplateKind) -> &'static str {
+        match (self.locale, kind) {
+            (MailLocale::Ja, TemplateKind::PasswordReset) => {
+                include_str!("templates/ja/password_reset.txt")
+            }
+            (MailLocale::Ja, TemplateKind::EmailVerification) => {
+                include_str!("templates/ja/email_verification.txt")
+            }
+            (MailLocale::En, TemplateKind::PasswordReset) => {
+                include_str!("templates/en/password_reset.txt")
+            }
+            (MailLocale::En, TemplateKind::EmailVerification) => {
+                include_str!("templates/en/email_verification.txt")
+            }
+            (MailLocale::Ja, TemplateKind::SignupAttempted) => {
+                include_str!("templates/ja/signup_attempted.txt")
+            }
+            (MailLocale::En, TemplateKind::SignupAttempted) => {
+                include_str!("templates/en/signup_attempted.txt")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum TemplateKind {
+    PasswordReset,
+    EmailVerification,
+    SignupAttempted,
+}
+
+fn fill(template: &str, values: &[(&str, String)]) -> RenderedMail {
+    let (subject, body) = template.split_once("\n\n").unwrap_or((template, ""));
+    let body = values.iter().fold(body.to_string(), |body, (name, value)| {
+        body.replace(&format!("{{{{{}}}}}", name), value)
+    });
+    RenderedMail {
+        subject: subject.trim().to_string(),
+        body: body.trim_end().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use domain::models::auth::OpaqueToken;
+
+    fn password_reset() -> MailMessage {
+        MailMessage::PasswordReset {
+            token: OpaqueToken::from("reset-token"),
+            expires_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_render_fills_placeholders_per_locale() {
+        let ja =
+            MailRenderer::new("https://app.example.com/", MailLocale::Ja).render(&password_reset());
+        assert_eq!(ja.subject, "パスワードの再設定");
+        assert!(
+            ja.body
+                .contains("https://app.example.com/reset-password?token=reset-token")
+        );
+        assert!(ja.body.contains("2026-01-01T00:00:00Z"));
+        assert!(!ja.body.contains("{{"));
+
+        let en =
+            MailRenderer::new("https://app.example.com", MailLocale::En).render(&password_reset());
+        assert_eq!(en.subject, "Reset your password");
+        assert!(
+            en.body
+                .contains("https://app.example.com/reset-password?token=reset-token")
+        );
+    }
+
+    #[test]
+    fn test_locale_from_str() {
+        assert_eq!("ja".parse::<MailLocale>().unwrap(), MailLocale::Ja);
+        assert_eq!("EN".parse::<MailLocale>().unwrap(), MailLocale::En);
+        assert!("fr".parse::<MailLocale>().is_err());
+    }
+}
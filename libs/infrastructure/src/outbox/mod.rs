@@ -0,0 +1,21 @@
+pub mod publisher;
+pub mod relay;
+
+pub use publisher::{EventPublisher, LogEventPublisher};
+pub use relay::{OutboxRelay, OutboxRelayConfig, RetryPolicy};
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Outbox から取り出した配信対象のメッセージ。
+#[derive(Debug, Clone)]
+pub struct OutboxMessage {
+    pub id: Uuid,
+    pub aggregate_type: String,
+    pub aggregate_id: Uuid,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub occurred_at: DateTime<Utc>,
+    /// これまでに配信を試みた回数
+    pub attempts: i32,
+}
@@ -0,0 +1,28 @@
+use super::OutboxMessage;
+use async_trait::async_trait;
+
+/// Outbox のメッセージを外部（メッセージブローカー等）へ配信するポート。
+///
+/// 配信は少なくとも 1 回（at-least-once）であるため、受信側は `OutboxMessage::id` で重複を排除すること。
+#[async_trait]
+pub trait EventPublisher: Send + Sync {
+    async fn publish(&self, message: &OutboxMessage) -> anyhow::Result<()>;
+}
+
+/// 配信の代わりにイベントをログへ出力する `EventPublisher` の実装。
+pub struct LogEventPublisher;
+
+#[async_trait]
+impl EventPublisher for LogEventPublisher {
+    async fn publish(&self, message: &OutboxMessage) -> anyhow::Result<()> {
+        tracing::info!(
+            id = %message.id,
+            aggregate_type = %message.aggregate_type,
+            aggregate_id = %message.aggregate_id,
+            event_type = %message.event_type,
+            "Domain event published: {}",
+            message.payload
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+use super::EventPublisher;
+use crate::repository::audit::TxAudit;
+use crate::repository::outbox::SqlxOutboxRepository;
+use chrono::{DateTime, Duration, Utc};
+use domain::AuditContext;
+use domain::clock::Clock;
+use sqlx::{Pool, Postgres};
+use std::sync::Arc;
+
+/// 監査カラムに記録するプログラムコード
+const PROGRAM_CODE: &str = "outbox-relay";
+
+/// 配信失敗時の再試行方針（指数バックオフ）。
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// この回数だけ失敗したメッセージはデッドレターに移す
+    pub max_attempts: i32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 10,
+            initial_backoff: Duration::seconds(1),
+            max_backoff: Duration::hours(1),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// `attempts` 回目の失敗後の次回配信日時。再試行の上限に達した場合は `None`。
+    pub fn next_attempt_at(&self, attempts: i32, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if attempts >= self.max_attempts {
+            return None;
+        }
+        let exponent = attempts.saturating_sub(1).clamp(0, 30) as u32;
+        let backoff = self
+            .initial_backoff
+            .checked_mul(2_i32.pow(exponent))
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff));
+        Some(now + backoff)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OutboxRelayConfig {
+    /// 1 回のポーリングで取り出す最大件数
+    pub batch_size: i64,
+    pub poll_interval: std::time::Duration,
+    pub retry: RetryPolicy,
+}
+
+impl Default for OutboxRelayConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: 100,
+            poll_interval: std::time::Duration::from_secs(1),
+            retry: RetryPolicy::default(),
+        }
+    }
+}
+
+/// Outbox をポーリングし、配信待ちのメッセージを `EventPublisher` へ中継するワーカー。
+///
+/// 取り出しに `FOR UPDATE SKIP LOCKED` を使用するため、複数インスタンスで並行稼働できる。
+pub struct OutboxRelay<C: Clock> {
+    pool: Pool<Postgres>,
+    publisher: Arc<dyn EventPublisher>,
+    clock: Arc<C>,
+    config: OutboxRelayConfig,
+}
+
+impl<C: Clock> OutboxRelay<C> {
+    pub fn new(
+        pool: Pool<Postgres>,
+        publisher: Arc<dyn EventPublisher>,
+        clock: Arc<C>,
+        config: OutboxRelayConfig,
+    ) -> Self {
+        Self {
+            pool,
+            publisher,
+            clock,
+            config,
+        }
+    }
+
+    /// 配信期限を迎えたメッセージを 1 バッチ分処理し、処理した件数を返す。
+    pub async fn run_once(&self) -> anyhow::Result<usize> {
+        let mut tx = self.pool.begin().await?;
+        let audit = TxAudit::new(AuditContext::system(PROGRAM_CODE));
+        let messages =
+            SqlxOutboxRepository::lock_due(&mut *tx, self.clock.now(), self.config.batch_size)
+                .await?;
+
+        for message in &messages {
+            let now = self.clock.now();
+            let stamp = audit.stamp(now);
+            match self.publisher.publish(message).await {
+                Ok(()) => {
+                    SqlxOutboxRepository::mark_published(&mut *tx, message.id, &stamp).await?
+                }
+                Err(e) => {
+                    let attempts = message.attempts + 1;
+                    let next_attempt_at = self.config.retry.next_attempt_at(attempts, now);
+                    if next_attempt_at.is_none() {
+                        tracing::error!(
+                            id = %message.id,
+                            event_type = %message.event_type,
+                            attempts,
+                            error = ?e,
+                            "Outbox message moved to dead letter"
+                        );
+                    } else {
+                        tracing::warn!(
+                            id = %message.id,
+                            event_type = %message.event_type,
+                            attempts,
+                            error = ?e,
+                            "Failed to publish outbox message"
+                        );
+                    }
+                    SqlxOutboxRepository::mark_failed(
+                        &mut *tx,
+                        message.id,
+                        &format!("{:#}", e),
+                        next_attempt_at,
+                        &stamp,
+                    )
+                    .await?;
+                }
+            }
+        }
+
+        tx.commit().await?;
+        Ok(messages.len())
+    }
+
+    /// ポーリングを続ける。バッチが埋まっている間は待たずに次を処理する。
+    pub async fn run(self) {
+        loop {
+            match self.run_once().await {
+                Ok(processed) if processed as i64 >= self.config.batch_size => continue,
+                Ok(_) => {}
+                Err(e) => tracing::error!(error = ?e, "Outbox relay failed"),
+            }
+            tokio::time::sleep(self.config.poll_interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_exponentially_until_cap() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            initial_backoff: Duration::seconds(1),
+            max_backoff: Duration::seconds(60),
+        };
+        let now = Utc::now();
+
+        assert_eq!(
+            policy.next_attempt_at(1, now),
+            Some(now + Duration::seconds(1))
+        );
+        assert_eq!(
+            policy.next_attempt_at(2, now),
+            Some(now + Duration::seconds(2))
+        );
+        assert_eq!(
+            policy.next_attempt_at(4, now),
+            Some(now + Duration::seconds(8))
+        );
+        assert_eq!(
+            policy.next_attempt_at(9, now),
+            Some(now + Duration::seconds(60))
+        );
+    }
+
+    #[test]
+    fn test_gives_up_after_max_attempts() {
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            ..Default::default()
+        };
+        let now = Utc::now();
+
+        assert!(policy.next_attempt_at(2, now).is_some());
+        assert!(policy.next_attempt_at(3, now).is_none());
+    }
+}
@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use domain::AuditContext;
+use uuid::Uuid;
+
+/// トランザクションごとの監査情報。`SqlxTransactionManager::execute` の呼び出しごとに生成する。
+#[derive(Debug, Clone)]
+pub struct TxAudit {
+    context: AuditContext,
+    tx_id: String,
+}
+
+impl TxAudit {
+    /// トランザクション ID を UUIDv7 で採番する。
+    pub fn new(context: AuditContext) -> Self {
+        Self {
+            context,
+            tx_id: Uuid::now_v7().to_string(),
+        }
+    }
+
+    pub fn tx_id(&self) -> &str {
+        &self.tx_id
+    }
+
+    /// `at` 時点の書き込みに記録する監査カラムの値を組み立てる。
+    pub fn stamp(&self, at: DateTime<Utc>) -> AuditStamp {
+        AuditStamp {
+            at,
+            by: self.context.actor().to_string(),
+            pgm_cd: self.context.program_code(),
+            tx_id: self.tx_id.clone(),
+        }
+    }
+}
+
+/// 1 回の書き込みで `*_at` / `*_by` / `*_pgm_cd` / `*_tx_id` に記録する値。
+#[derive(Debug, Clone)]
+pub struct AuditStamp {
+    pub at: DateTime<Utc>,
+    pub by: String,
+    pub pgm_cd: &'static str,
+    pub tx_id: String,
+}
+
+impl AuditStamp {
+    /// ユースケースのトランザクション外で実行するシステム処理用。書き込みごとに新しいトランザクション ID を採番する。
+    pub fn system(program_code: &'static str, at: DateTime<Utc>) -> Self {
+        TxAudit::new(AuditContext::system(program_code)).stamp(at)
+    }
+}
@@ -0,0 +1,88 @@
+use chrono::{DateTime, Utc};
+use domain::models::user::{EmailVerificationDispatch, UserId, UserRepositoryError};
+use sqlx::Postgres;
+use uuid::Uuid;
+
+use crate::repository::audit::AuditStamp;
+
+/// SQLx を使用した確認メール送信記録リポジトリの低レベル操作。
+pub struct SqlxEmailVerificationDispatchRepository;
+
+impl SqlxEmailVerificationDispatchRepository {
+    pub async fn find_by_user_id<'e, E>(
+        executor: E,
+        user_id: UserId,
+    ) -> Result<Option<EmailVerificationDispatch>, UserRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        let row = sqlx::query_as!(
+            EmailVerificationDispatchRow,
+            r#"
+            SELECT user_id, last_sent_at
+            FROM email_verification_dispatches
+            WHERE user_id = $1
+            "#,
+            Uuid::from(user_id)
+        )
+        .fetch_optional(executor)
+        .await
+        .map_err(|e| UserRepositoryError::QueryFailed(e.into()))?;
+
+        Ok(row.map(EmailVerificationDispatch::from))
+    }
+
+    pub async fn save<'e, E>(
+        executor: E,
+        dispatch: &EmailVerificationDispatch,
+        audit: &AuditStamp,
+    ) -> Result<(), UserRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        let now = audit.at;
+        let actor = audit.by.as_str();
+        let pgm_cd = audit.pgm_cd;
+        let tx_id = audit.tx_id.as_str();
+
+        sqlx::query!(
+            r#"
+            INSERT INTO email_verification_dispatches (
+                user_id, last_sent_at,
+                created_at, created_by, created_pgm_cd, created_tx_id,
+                updated_at, updated_by, updated_pgm_cd, updated_tx_id
+            ) VALUES ($1, $2, $3, $4, $5, $6, $3, $4, $5, $6)
+            ON CONFLICT (user_id) DO UPDATE SET
+                last_sent_at = EXCLUDED.last_sent_at,
+                updated_at = $3,
+                updated_by = $4,
+                updated_pgm_cd = $5,
+                updated_tx_id = $6,
+                lock_no = email_verification_dispatches.lock_no + 1
+            "#,
+            Uuid::from(dispatch.user_id()),
+            dispatch.last_sent_at(),
+            now,
+            actor,
+            pgm_cd,
+            tx_id,
+        )
+        .execute(executor)
+        .await
+        .map_err(|e| UserRepositoryError::QueryFailed(e.into()))?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct EmailVerificationDispatchRow {
+    user_id: Uuid,
+    last_sent_at: DateTime<Utc>,
+}
+
+impl From<EmailVerificationDispatchRow> for EmailVerificationDispatch {
+    fn from(row: EmailVerificationDispatchRow) -> Self {
+        EmailVerificationDispatch::reconstruct(UserId::from(row.user_id), row.last_sent_at)
+    }
+}
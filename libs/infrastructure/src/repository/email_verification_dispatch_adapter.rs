@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+use domain::clock::Clock;
+use domain::models::user::{
+    EmailVerificationDispatch, EmailVerificationDispatchRepository, UserId, UserRepositoryError,
+};
+use sqlx::{Postgres, Transaction};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::repository::audit::TxAudit;
+use crate::repository::email_verification_dispatch::SqlxEmailVerificationDispatchRepository;
+
+/// トランザクションを保持し、`EmailVerificationDispatchRepository` トレイトを実装するアダプター。
+pub struct SqlxEmailVerificationDispatchRepoAdapter<'a, C: Clock> {
+    transaction: Arc<Mutex<Option<Transaction<'a, Postgres>>>>,
+    clock: Arc<C>,
+    audit: Arc<TxAudit>,
+}
+
+impl<'a, C: Clock> SqlxEmailVerificationDispatchRepoAdapter<'a, C> {
+    pub fn new(
+        transaction: Arc<Mutex<Option<Transaction<'a, Postgres>>>>,
+        clock: Arc<C>,
+        audit: Arc<TxAudit>,
+    ) -> Self {
+        Self {
+            transaction,
+            clock,
+            audit,
+        }
+    }
+}
+
+#[async_trait]
+impl<'a, C: Clock> EmailVerificationDispatchRepository
+    for SqlxEmailVerificationDispatchRepoAdapter<'a, C>
+{
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<EmailVerificationDispatch>, UserRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            UserRepositoryError::Unexpected(anyhow::anyhow!("Transaction already closed or taken"))
+        })?;
+        SqlxEmailVerificationDispatchRepository::find_by_user_id(&mut **tx, user_id).await
+    }
+
+    async fn save(&self, dispatch: &EmailVerificationDispatch) -> Result<(), UserRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            UserRepositoryError::Unexpected(anyhow::anyhow!("Transaction already closed or taken"))
+        })?;
+        SqlxEmailVerificationDispatchRepository::save(
+            &mut **tx,
+            dispatch,
+            &self.audit.stamp(self.clock.now()),
+        )
+        .await
+    }
+}
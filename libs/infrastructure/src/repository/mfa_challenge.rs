@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+use domain::models::auth::{MfaChallenge, MfaChallengeId, MfaRepositoryError, OpaqueTokenHash};
+use domain::models::user::UserId;
+use sqlx::Postgres;
+use uuid::Uuid;
+
+use crate::repository::audit::AuditStamp;
+
+/// SQLx を使用した MFA チャレンジリポジトリの低レベル操作。
+pub struct SqlxMfaChallengeRepository;
+
+impl SqlxMfaChallengeRepository {
+    pub async fn find_by_token_hash<'e, E>(
+        executor: E,
+        token_hash: &OpaqueTokenHash,
+    ) -> Result<Option<MfaChallenge>, MfaRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        let row = sqlx::query_as!(
+            MfaChallengeRow,
+            r#"
+            SELECT id, user_id, token_hash, expires_at, failed_attempts
+            FROM mfa_challenges
+            WHERE token_hash = $1
+            "#,
+            token_hash.as_ref()
+        )
+        .fetch_optional(executor)
+        .await
+        .map_err(|e| MfaRepositoryError::QueryFailed(e.into()))?;
+
+        row.map(MfaChallenge::try_from).transpose()
+    }
+
+    pub async fn save<'e, E>(
+        executor: E,
+        challenge: &MfaChallenge,
+        audit: &AuditStamp,
+    ) -> Result<(), MfaRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        let now = audit.at;
+        let actor = audit.by.as_str();
+        let pgm_cd = audit.pgm_cd;
+        let tx_id = audit.tx_id.as_str();
+
+        let failed_attempts = i32::try_from(challenge.failed_attempts())
+            .map_err(|e| MfaRepositoryError::MappingFailed(e.into()))?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO mfa_challenges (
+                id, user_id, token_hash, expires_at, failed_attempts,
+                created_at, created_by, created_pgm_cd, created_tx_id,
+                updated_at, updated_by, updated_pgm_cd, updated_tx_id
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $6, $7, $8, $9)
+            ON CONFLICT (id) DO UPDATE SET
+                failed_attempts = EXCLUDED.failed_attempts,
+                updated_at = $6,
+                updated_by = $7,
+                updated_pgm_cd = $8,
+                updated_tx_id = $9,
+                lock_no = mfa_challenges.lock_no + 1
+            "#,
+            Uuid::from(challenge.id()),
+            Uuid::from(challenge.user_id()),
+            challenge.token_hash().as_ref(),
+            challenge.expires_at(),
+            failed_attempts,
+            now,
+            actor,
+            pgm_cd,
+            tx_id,
+        )
+        .execute(executor)
+        .await
+        .map_err(|e| MfaRepositoryError::QueryFailed(e.into()))?;
+
+        Ok(())
+    }
+
+    pub async fn delete<'e, E>(executor: E, id: MfaChallengeId) -> Result<(), MfaRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        sqlx::query!("DELETE FROM mfa_challenges WHERE id = $1", Uuid::from(id))
+            .execute(executor)
+            .await
+            .map_err(|e| MfaRepositoryError::QueryFailed(e.into()))?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct MfaChallengeRow {
+    id: Uuid,
+    user_id: Uuid,
+    token_hash: String,
+    expires_at: DateTime<Utc>,
+    failed_attempts: i32,
+}
+
+impl TryFrom<MfaChallengeRow> for MfaChallenge {
+    type Error = MfaRepositoryError;
+
+    fn try_from(row: MfaChallengeRow) -> Result<Self, Self::Error> {
+        let failed_attempts = u32::try_from(row.failed_attempts)
+            .map_err(|e| MfaRepositoryError::MappingFailed(e.into()))?;
+
+        Ok(MfaChallenge::reconstruct(
+            MfaChallengeId::from(row.id),
+            UserId::from(row.user_id),
+            OpaqueTokenHash::from_str_unchecked(row.token_hash),
+            row.expires_at,
+            failed_attempts,
+        ))
+    }
+}
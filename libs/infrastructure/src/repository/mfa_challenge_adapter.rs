@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use domain::clock::Clock;
+use domain::models::auth::{
+    MfaChallenge, MfaChallengeId, MfaChallengeRepository, MfaRepositoryError, OpaqueTokenHash,
+};
+use sqlx::{Postgres, Transaction};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::repository::audit::TxAudit;
+use crate::repository::mfa_challenge::SqlxMfaChallengeRepository;
+
+/// トランザクションを保持し、`MfaChallengeRepository` トレイトを実装するアダプター。
+pub struct SqlxMfaChallengeRepoAdapter<'a, C: Clock> {
+    transaction: Arc<Mutex<Option<Transaction<'a, Postgres>>>>,
+    clock: Arc<C>,
+    audit: Arc<TxAudit>,
+}
+
+impl<'a, C: Clock> SqlxMfaChallengeRepoAdapter<'a, C> {
+    pub fn new(
+        transaction: Arc<Mutex<Option<Transaction<'a, Postgres>>>>,
+        clock: Arc<C>,
+        audit: Arc<TxAudit>,
+    ) -> Self {
+        Self {
+            transaction,
+            clock,
+            audit,
+        }
+    }
+}
+
+#[async_trait]
+impl<'a, C: Clock> MfaChallengeRepository for SqlxMfaChallengeRepoAdapter<'a, C> {
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &OpaqueTokenHash,
+    ) -> Result<Option<MfaChallenge>, MfaRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            MfaRepositoryError::Unexpected(anyhow::anyhow!("Transaction already closed or taken"))
+        })?;
+        SqlxMfaChallengeRepository::find_by_token_hash(&mut **tx, token_hash).await
+    }
+
+    async fn save(&self, challenge: &MfaChallenge) -> Result<(), MfaRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            MfaRepositoryError::Unexpected(anyhow::anyhow!("Transaction already closed or taken"))
+        })?;
+        SqlxMfaChallengeRepository::save(&mut **tx, challenge, &self.audit.stamp(self.clock.now()))
+            .await
+    }
+
+    async fn delete(&self, id: MfaChallengeId) -> Result<(), MfaRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            MfaRepositoryError::Unexpected(anyhow::anyhow!("Transaction already closed or taken"))
+        })?;
+        SqlxMfaChallengeRepository::delete(&mut **tx, id).await
+    }
+}
@@ -1,5 +1,27 @@
+pub mod audit;
+pub mod email_verification_dispatch;
+pub mod email_verification_dispatch_adapter;
+pub mod mfa_challenge;
+pub mod mfa_challenge_adapter;
+pub mod outbox;
+pub mod outbox_adapter;
+pub mod password_reset_token;
+pub mod password_reset_token_adapter;
+pub mod recovery_code;
+pub mod recovery_code_adapter;
+pub mod refresh_token;
+pub mod refresh_token_adapter;
+pub mod totp_credential;
+pub mod totp_credential_adapter;
 pub mod user;
 
+pub use email_verification_dispatch::SqlxEmailVerificationDispatchRepository;
+pub use mfa_challenge::SqlxMfaChallengeRepository;
+pub use outbox::SqlxOutboxRepository;
+pub use password_reset_token::SqlxPasswordResetTokenRepository;
+pub use recovery_code::SqlxRecoveryCodeRepository;
+pub use refresh_token::SqlxRefreshTokenRepository;
+pub use totp_credential::SqlxTotpCredentialRepository;
 pub use user::SqlxUserRepository;
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,183 @@
+use chrono::{DateTime, Utc};
+use domain::event::{DomainEvent, OutboxRepositoryError};
+use sqlx::Postgres;
+use uuid::Uuid;
+
+use crate::outbox::OutboxMessage;
+use crate::repository::audit::AuditStamp;
+
+/// SQLx を使用した Outbox の低レベル操作。
+///
+/// 追記はユースケースのトランザクション内で、取り出し・状態更新はリレーのトランザクション内で行う。
+pub struct SqlxOutboxRepository;
+
+impl SqlxOutboxRepository {
+    /// イベントを配信待ち（pending）として追記する。
+    pub async fn append<'e, E>(
+        executor: E,
+        events: &[DomainEvent],
+        audit: &AuditStamp,
+    ) -> Result<(), OutboxRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let now = audit.at;
+        let actor = audit.by.as_str();
+        let pgm_cd = audit.pgm_cd;
+        let tx_id = audit.tx_id.as_str();
+
+        let ids: Vec<Uuid> = events.iter().map(|_| Uuid::now_v7()).collect();
+        let aggregate_types: Vec<String> = events
+            .iter()
+            .map(|e| e.aggregate_type().to_string())
+            .collect();
+        let aggregate_ids: Vec<Uuid> = events.iter().map(|e| e.aggregate_id()).collect();
+        let event_types: Vec<String> = events.iter().map(|e| e.event_type().to_string()).collect();
+        let payloads = events
+            .iter()
+            .map(serde_json::to_value)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| OutboxRepositoryError::SerializationFailed(e.into()))?;
+        let occurred_ats: Vec<DateTime<Utc>> = events.iter().map(|e| e.occurred_at()).collect();
+
+        sqlx::query!(
+            r#"
+            INSERT INTO outbox (
+                id, aggregate_type, aggregate_id, event_type, payload, occurred_at,
+                next_attempt_at,
+                created_at, created_by, created_pgm_cd, created_tx_id,
+                updated_at, updated_by, updated_pgm_cd, updated_tx_id
+            )
+            SELECT e.id, e.aggregate_type, e.aggregate_id, e.event_type, e.payload, e.occurred_at,
+                $7, $7, $8, $9, $10, $7, $8, $9, $10
+            FROM UNNEST(
+                $1::UUID[], $2::VARCHAR[], $3::UUID[], $4::VARCHAR[], $5::JSONB[], $6::TIMESTAMPTZ[]
+            ) AS e(id, aggregate_type, aggregate_id, event_type, payload, occurred_at)
+            "#,
+            &ids,
+            &aggregate_types,
+            &aggregate_ids,
+            &event_types,
+            &payloads,
+            &occurred_ats,
+            now,
+            actor,
+            pgm_cd,
+            tx_id,
+        )
+        .execute(executor)
+        .await
+        .map_err(|e| OutboxRepositoryError::QueryFailed(e.into()))?;
+
+        Ok(())
+    }
+
+    /// 配信期限を迎えたメッセージを古い順に取り出し、行ロックを取得する。
+    ///
+    /// `SKIP LOCKED` により、他のリレーが処理中のメッセージは取り出さない。
+    pub async fn lock_due<'e, E>(
+        executor: E,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<OutboxMessage>, OutboxRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        sqlx::query_as!(
+            OutboxMessage,
+            r#"
+            SELECT id, aggregate_type, aggregate_id, event_type, payload, occurred_at, attempts
+            FROM outbox
+            WHERE status = 'pending' AND next_attempt_at <= $1
+            ORDER BY next_attempt_at, id
+            LIMIT $2
+            FOR UPDATE SKIP LOCKED
+            "#,
+            now,
+            limit
+        )
+        .fetch_all(executor)
+        .await
+        .map_err(|e| OutboxRepositoryError::QueryFailed(e.into()))
+    }
+
+    /// 配信済みにする。
+    pub async fn mark_published<'e, E>(
+        executor: E,
+        id: Uuid,
+        audit: &AuditStamp,
+    ) -> Result<(), OutboxRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE outbox SET
+                status = 'published',
+                attempts = attempts + 1,
+                published_at = $2,
+                last_error = NULL,
+                updated_at = $2,
+                updated_by = $3,
+                updated_pgm_cd = $4,
+                updated_tx_id = $5,
+                lock_no = lock_no + 1
+            WHERE id = $1
+            "#,
+            id,
+            audit.at,
+            audit.by,
+            audit.pgm_cd,
+            audit.tx_id,
+        )
+        .execute(executor)
+        .await
+        .map_err(|e| OutboxRepositoryError::QueryFailed(e.into()))?;
+
+        Ok(())
+    }
+
+    /// 配信の失敗を記録する。`next_attempt_at` が `None` の場合はデッドレターに移す。
+    pub async fn mark_failed<'e, E>(
+        executor: E,
+        id: Uuid,
+        error: &str,
+        next_attempt_at: Option<DateTime<Utc>>,
+        audit: &AuditStamp,
+    ) -> Result<(), OutboxRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        sqlx::query!(
+            r#"
+            UPDATE outbox SET
+                status = CASE WHEN $3::TIMESTAMPTZ IS NULL THEN 'dead_letter' ELSE 'pending' END,
+                attempts = attempts + 1,
+                next_attempt_at = COALESCE($3, next_attempt_at),
+                last_error = $2,
+                updated_at = $4,
+                updated_by = $5,
+                updated_pgm_cd = $6,
+                updated_tx_id = $7,
+                lock_no = lock_no + 1
+            WHERE id = $1
+            "#,
+            id,
+            error,
+            next_attempt_at,
+            audit.at,
+            audit.by,
+            audit.pgm_cd,
+            audit.tx_id,
+        )
+        .execute(executor)
+        .await
+        .map_err(|e| OutboxRepositoryError::QueryFailed(e.into()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use domain::clock::Clock;
+use domain::event::{DomainEvent, OutboxRepository, OutboxRepositoryError};
+use sqlx::{Postgres, Transaction};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::repository::audit::TxAudit;
+use crate::repository::outbox::SqlxOutboxRepository;
+
+/// トランザクションを保持し、`OutboxRepository` トレイトを実装するアダプター。
+pub struct SqlxOutboxRepoAdapter<'a, C: Clock> {
+    transaction: Arc<Mutex<Option<Transaction<'a, Postgres>>>>,
+    clock: Arc<C>,
+    audit: Arc<TxAudit>,
+}
+
+impl<'a, C: Clock> SqlxOutboxRepoAdapter<'a, C> {
+    pub fn new(
+        transaction: Arc<Mutex<Option<Transaction<'a, Postgres>>>>,
+        clock: Arc<C>,
+        audit: Arc<TxAudit>,
+    ) -> Self {
+        Self {
+            transaction,
+            clock,
+            audit,
+        }
+    }
+}
+
+#[async_trait]
+impl<'a, C: Clock> OutboxRepository for SqlxOutboxRepoAdapter<'a, C> {
+    async fn append(&self, events: &[DomainEvent]) -> Result<(), OutboxRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            OutboxRepositoryError::Unexpected(anyhow::anyhow!(
+                "Transaction already closed or taken"
+            ))
+        })?;
+        SqlxOutboxRepository::append(&mut **tx, events, &self.audit.stamp(self.clock.now())).await
+    }
+}
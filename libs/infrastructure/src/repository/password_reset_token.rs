@@ -0,0 +1,112 @@
+use chrono::{DateTime, Utc};
+use domain::models::auth::{
+    OpaqueTokenHash, PasswordResetRepositoryError, PasswordResetToken, PasswordResetTokenId,
+};
+use domain::models::user::UserId;
+use sqlx::Postgres;
+use uuid::Uuid;
+
+use crate::repository::audit::AuditStamp;
+
+/// SQLx を使用したパスワードリセットトークンリポジトリの低レベル操作。
+pub struct SqlxPasswordResetTokenRepository;
+
+impl SqlxPasswordResetTokenRepository {
+    pub async fn find_by_token_hash<'e, E>(
+        executor: E,
+        token_hash: &OpaqueTokenHash,
+    ) -> Result<Option<PasswordResetToken>, PasswordResetRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        let row = sqlx::query_as!(
+            PasswordResetTokenRow,
+            r#"
+            SELECT id, user_id, token_hash, expires_at
+            FROM password_reset_tokens
+            WHERE token_hash = $1
+            FOR UPDATE
+            "#,
+            token_hash.as_ref()
+        )
+        .fetch_optional(executor)
+        .await
+        .map_err(|e| PasswordResetRepositoryError::QueryFailed(e.into()))?;
+
+        Ok(row.map(PasswordResetToken::from))
+    }
+
+    pub async fn save<'e, E>(
+        executor: E,
+        token: &PasswordResetToken,
+        audit: &AuditStamp,
+    ) -> Result<(), PasswordResetRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        let now = audit.at;
+        let actor = audit.by.as_str();
+        let pgm_cd = audit.pgm_cd;
+        let tx_id = audit.tx_id.as_str();
+
+        sqlx::query!(
+            r#"
+            INSERT INTO password_reset_tokens (
+                id, user_id, token_hash, expires_at,
+                created_at, created_by, created_pgm_cd, created_tx_id,
+                updated_at, updated_by, updated_pgm_cd, updated_tx_id
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $5, $6, $7, $8)
+            "#,
+            Uuid::from(token.id()),
+            Uuid::from(token.user_id()),
+            token.token_hash().as_ref(),
+            token.expires_at(),
+            now,
+            actor,
+            pgm_cd,
+            tx_id,
+        )
+        .execute(executor)
+        .await
+        .map_err(|e| PasswordResetRepositoryError::QueryFailed(e.into()))?;
+
+        Ok(())
+    }
+
+    pub async fn delete_by_user_id<'e, E>(
+        executor: E,
+        user_id: UserId,
+    ) -> Result<(), PasswordResetRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        sqlx::query!(
+            "DELETE FROM password_reset_tokens WHERE user_id = $1",
+            Uuid::from(user_id)
+        )
+        .execute(executor)
+        .await
+        .map_err(|e| PasswordResetRepositoryError::QueryFailed(e.into()))?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct PasswordResetTokenRow {
+    id: Uuid,
+    user_id: Uuid,
+    token_hash: String,
+    expires_at: DateTime<Utc>,
+}
+
+impl From<PasswordResetTokenRow> for PasswordResetToken {
+    fn from(row: PasswordResetTokenRow) -> Self {
+        PasswordResetToken::reconstruct(
+            PasswordResetTokenId::from(row.id),
+            UserId::from(row.user_id),
+            OpaqueTokenHash::from_str_unchecked(row.token_hash),
+            row.expires_at,
+        )
+    }
+}
@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use domain::clock::Clock;
+use domain::models::auth::{
+    OpaqueTokenHash, PasswordResetRepositoryError, PasswordResetToken, PasswordResetTokenRepository,
+};
+use domain::models::user::UserId;
+use sqlx::{Postgres, Transaction};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::repository::audit::TxAudit;
+use crate::repository::password_reset_token::SqlxPasswordResetTokenRepository;
+
+/// トランザクションを保持し、`PasswordResetTokenRepository` トレイトを実装するアダプター。
+pub struct SqlxPasswordResetTokenRepoAdapter<'a, C: Clock> {
+    transaction: Arc<Mutex<Option<Transaction<'a, Postgres>>>>,
+    clock: Arc<C>,
+    audit: Arc<TxAudit>,
+}
+
+impl<'a, C: Clock> SqlxPasswordResetTokenRepoAdapter<'a, C> {
+    pub fn new(
+        transaction: Arc<Mutex<Option<Transaction<'a, Postgres>>>>,
+        clock: Arc<C>,
+        audit: Arc<TxAudit>,
+    ) -> Self {
+        Self {
+            transaction,
+            clock,
+            audit,
+        }
+    }
+}
+
+#[async_trait]
+impl<'a, C: Clock> PasswordResetTokenRepository for SqlxPasswordResetTokenRepoAdapter<'a, C> {
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &OpaqueTokenHash,
+    ) -> Result<Option<PasswordResetToken>, PasswordResetRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            PasswordResetRepositoryError::Unexpected(anyhow::anyhow!(
+                "Transaction already closed or taken"
+            ))
+        })?;
+        SqlxPasswordResetTokenRepository::find_by_token_hash(&mut **tx, token_hash).await
+    }
+
+    async fn save(&self, token: &PasswordResetToken) -> Result<(), PasswordResetRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            PasswordResetRepositoryError::Unexpected(anyhow::anyhow!(
+                "Transaction already closed or taken"
+            ))
+        })?;
+        SqlxPasswordResetTokenRepository::save(
+            &mut **tx,
+            token,
+            &self.audit.stamp(self.clock.now()),
+        )
+        .await
+    }
+
+    async fn delete_by_user_id(&self, user_id: UserId) -> Result<(), PasswordResetRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            PasswordResetRepositoryError::Unexpected(anyhow::anyhow!(
+                "Transaction already closed or taken"
+            ))
+        })?;
+        SqlxPasswordResetTokenRepository::delete_by_user_id(&mut **tx, user_id).await
+    }
+}
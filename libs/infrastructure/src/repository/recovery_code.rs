@@ -0,0 +1,116 @@
+use chrono::{DateTime, Utc};
+use domain::models::auth::{MfaRepositoryError, RecoveryCodeEntry, RecoveryCodeSet};
+use domain::models::user::{PasswordHash, UserId};
+use sqlx::Postgres;
+use uuid::Uuid;
+
+use crate::repository::audit::AuditStamp;
+
+/// SQLx を使用したリカバリーコードリポジトリの低レベル操作。
+pub struct SqlxRecoveryCodeRepository;
+
+impl SqlxRecoveryCodeRepository {
+    pub async fn find_by_user_id<'e, E>(
+        executor: E,
+        user_id: UserId,
+    ) -> Result<Option<RecoveryCodeSet>, MfaRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        let rows = sqlx::query_as!(
+            RecoveryCodeRow,
+            r#"
+            SELECT code_hash, used_at
+            FROM mfa_recovery_codes
+            WHERE user_id = $1
+            ORDER BY seq
+            "#,
+            Uuid::from(user_id)
+        )
+        .fetch_all(executor)
+        .await
+        .map_err(|e| MfaRepositoryError::QueryFailed(e.into()))?;
+
+        if rows.is_empty() {
+            return Ok(None);
+        }
+        let codes = rows.into_iter().map(RecoveryCodeEntry::from).collect();
+        Ok(Some(RecoveryCodeSet::reconstruct(user_id, codes)))
+    }
+
+    /// 一式を位置（seq）ごとに upsert し、余った古い行を削除する。
+    pub async fn save<'e, E>(
+        executor: E,
+        code_set: &RecoveryCodeSet,
+        audit: &AuditStamp,
+    ) -> Result<(), MfaRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        let now = audit.at;
+        let actor = audit.by.as_str();
+        let pgm_cd = audit.pgm_cd;
+        let tx_id = audit.tx_id.as_str();
+
+        let codes = code_set.codes();
+        let seqs = (0..codes.len())
+            .map(i16::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| MfaRepositoryError::MappingFailed(e.into()))?;
+        let code_hashes: Vec<String> = codes
+            .iter()
+            .map(|c| c.code_hash().as_ref().to_string())
+            .collect();
+        let used_ats: Vec<Option<DateTime<Utc>>> = codes.iter().map(|c| c.used_at()).collect();
+
+        sqlx::query!(
+            r#"
+            WITH removed AS (
+                DELETE FROM mfa_recovery_codes
+                WHERE user_id = $1 AND seq >= cardinality($2::SMALLINT[])
+            )
+            INSERT INTO mfa_recovery_codes (
+                user_id, seq, code_hash, used_at,
+                created_at, created_by, created_pgm_cd, created_tx_id,
+                updated_at, updated_by, updated_pgm_cd, updated_tx_id
+            )
+            SELECT $1, c.seq, c.code_hash, c.used_at, $5, $6, $7, $8, $5, $6, $7, $8
+            FROM UNNEST($2::SMALLINT[], $3::VARCHAR[], $4::TIMESTAMPTZ[])
+                AS c(seq, code_hash, used_at)
+            ON CONFLICT (user_id, seq) DO UPDATE SET
+                code_hash = EXCLUDED.code_hash,
+                used_at = EXCLUDED.used_at,
+                updated_at = $5,
+                updated_by = $6,
+                updated_pgm_cd = $7,
+                updated_tx_id = $8,
+                lock_no = mfa_recovery_codes.lock_no + 1
+            "#,
+            Uuid::from(code_set.user_id()),
+            &seqs,
+            &code_hashes,
+            &used_ats as &[Option<DateTime<Utc>>],
+            now,
+            actor,
+            pgm_cd,
+            tx_id,
+        )
+        .execute(executor)
+        .await
+        .map_err(|e| MfaRepositoryError::QueryFailed(e.into()))?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct RecoveryCodeRow {
+    code_hash: String,
+    used_at: Option<DateTime<Utc>>,
+}
+
+impl From<RecoveryCodeRow> for RecoveryCodeEntry {
+    fn from(row: RecoveryCodeRow) -> Self {
+        RecoveryCodeEntry::reconstruct(PasswordHash::from_str_unchecked(row.code_hash), row.used_at)
+    }
+}
@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use domain::clock::Clock;
+use domain::models::auth::{MfaRepositoryError, RecoveryCodeRepository, RecoveryCodeSet};
+use domain::models::user::UserId;
+use sqlx::{Postgres, Transaction};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::repository::audit::TxAudit;
+use crate::repository::recovery_code::SqlxRecoveryCodeRepository;
+
+/// トランザクションを保持し、`RecoveryCodeRepository` トレイトを実装するアダプター。
+pub struct SqlxRecoveryCodeRepoAdapter<'a, C: Clock> {
+    transaction: Arc<Mutex<Option<Transaction<'a, Postgres>>>>,
+    clock: Arc<C>,
+    audit: Arc<TxAudit>,
+}
+
+impl<'a, C: Clock> SqlxRecoveryCodeRepoAdapter<'a, C> {
+    pub fn new(
+        transaction: Arc<Mutex<Option<Transaction<'a, Postgres>>>>,
+        clock: Arc<C>,
+        audit: Arc<TxAudit>,
+    ) -> Self {
+        Self {
+            transaction,
+            clock,
+            audit,
+        }
+    }
+}
+
+#[async_trait]
+impl<'a, C: Clock> RecoveryCodeRepository for SqlxRecoveryCodeRepoAdapter<'a, C> {
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<RecoveryCodeSet>, MfaRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            MfaRepositoryError::Unexpected(anyhow::anyhow!("Transaction already closed or taken"))
+        })?;
+        SqlxRecoveryCodeRepository::find_by_user_id(&mut **tx, user_id).await
+    }
+
+    async fn save(&self, code_set: &RecoveryCodeSet) -> Result<(), MfaRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            MfaRepositoryError::Unexpected(anyhow::anyhow!("Transaction already closed or taken"))
+        })?;
+        SqlxRecoveryCodeRepository::save(&mut **tx, code_set, &self.audit.stamp(self.clock.now()))
+            .await
+    }
+}
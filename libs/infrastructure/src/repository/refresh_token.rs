@@ -0,0 +1,214 @@
+use chrono::{DateTime, Utc};
+use domain::models::auth::{
+    OpaqueTokenHash, RefreshToken, RefreshTokenFamilyId, RefreshTokenId,
+    RefreshTokenRepositoryError, RefreshTokenStatus,
+};
+use domain::models::user::UserId;
+use sqlx::Postgres;
+use uuid::Uuid;
+
+use crate::repository::audit::AuditStamp;
+
+/// SQLx を使用したリフレッシュトークンリポジトリの低レベル操作。
+pub struct SqlxRefreshTokenRepository;
+
+impl SqlxRefreshTokenRepository {
+    /// 行ロックを取得して読み込む。同じトークンの並行したローテーションはコミットまで待たされ、
+    /// 使用済みの状態を読んで再利用として検知される。
+    pub async fn find_by_token_hash<'e, E>(
+        executor: E,
+        token_hash: &OpaqueTokenHash,
+    ) -> Result<Option<RefreshToken>, RefreshTokenRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        let row = sqlx::query_as!(
+            RefreshTokenRow,
+            r#"
+            SELECT
+                id, family_id, user_id, token_hash,
+                issued_at, expires_at, used_at, revoked_at
+            FROM refresh_tokens
+            WHERE token_hash = $1
+            FOR UPDATE
+            "#,
+            token_hash.as_ref()
+        )
+        .fetch_optional(executor)
+        .await
+        .map_err(|e| RefreshTokenRepositoryError::QueryFailed(e.into()))?;
+
+        Ok(row.map(RefreshToken::from))
+    }
+
+    pub async fn save<'e, E>(
+        executor: E,
+        token: &RefreshToken,
+        audit: &AuditStamp,
+    ) -> Result<(), RefreshTokenRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        let now = audit.at;
+        let actor = audit.by.as_str();
+        let pgm_cd = audit.pgm_cd;
+        let tx_id = audit.tx_id.as_str();
+
+        let (used_at, revoked_at) = match token.status() {
+            RefreshTokenStatus::Active => (None, None),
+            RefreshTokenStatus::Used { used_at } => (Some(used_at), None),
+            RefreshTokenStatus::Revoked { revoked_at } => (None, Some(revoked_at)),
+        };
+
+        sqlx::query!(
+            r#"
+            INSERT INTO refresh_tokens (
+                id, family_id, user_id, token_hash,
+                issued_at, expires_at, used_at, revoked_at,
+                created_at, created_by, created_pgm_cd, created_tx_id,
+                updated_at, updated_by, updated_pgm_cd, updated_tx_id
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+            ON CONFLICT (id) DO UPDATE SET
+                used_at = COALESCE(EXCLUDED.used_at, refresh_tokens.used_at),
+                revoked_at = COALESCE(EXCLUDED.revoked_at, refresh_tokens.revoked_at),
+                updated_at = $13,
+                updated_by = $14,
+                updated_pgm_cd = $15,
+                updated_tx_id = $16,
+                lock_no = refresh_tokens.lock_no + 1
+            "#,
+            Uuid::from(token.id()),
+            Uuid::from(token.family_id()),
+            Uuid::from(token.user_id()),
+            token.token_hash().as_ref(),
+            token.issued_at(),
+            token.expires_at(),
+            used_at,
+            revoked_at,
+            now,
+            actor,
+            pgm_cd,
+            tx_id,
+            now,
+            actor,
+            pgm_cd,
+            tx_id,
+        )
+        .execute(executor)
+        .await
+        .map_err(|e| RefreshTokenRepositoryError::QueryFailed(e.into()))?;
+
+        Ok(())
+    }
+
+    pub async fn revoke_family<'e, E>(
+        executor: E,
+        family_id: RefreshTokenFamilyId,
+        revoked_at: DateTime<Utc>,
+        audit: &AuditStamp,
+    ) -> Result<(), RefreshTokenRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        let now = audit.at;
+        let actor = audit.by.as_str();
+        let pgm_cd = audit.pgm_cd;
+        let tx_id = audit.tx_id.as_str();
+
+        sqlx::query!(
+            r#"
+            UPDATE refresh_tokens SET
+                revoked_at = $2,
+                updated_at = $3,
+                updated_by = $4,
+                updated_pgm_cd = $5,
+                updated_tx_id = $6,
+                lock_no = lock_no + 1
+            WHERE family_id = $1 AND revoked_at IS NULL
+            "#,
+            Uuid::from(family_id),
+            revoked_at,
+            now,
+            actor,
+            pgm_cd,
+            tx_id,
+        )
+        .execute(executor)
+        .await
+        .map_err(|e| RefreshTokenRepositoryError::QueryFailed(e.into()))?;
+
+        Ok(())
+    }
+
+    pub async fn revoke_all_by_user_id<'e, E>(
+        executor: E,
+        user_id: UserId,
+        revoked_at: DateTime<Utc>,
+        audit: &AuditStamp,
+    ) -> Result<(), RefreshTokenRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        let now = audit.at;
+        let actor = audit.by.as_str();
+        let pgm_cd = audit.pgm_cd;
+        let tx_id = audit.tx_id.as_str();
+
+        sqlx::query!(
+            r#"
+            UPDATE refresh_tokens SET
+                revoked_at = $2,
+                updated_at = $3,
+                updated_by = $4,
+                updated_pgm_cd = $5,
+                updated_tx_id = $6,
+                lock_no = lock_no + 1
+            WHERE user_id = $1 AND revoked_at IS NULL
+            "#,
+            Uuid::from(user_id),
+            revoked_at,
+            now,
+            actor,
+            pgm_cd,
+            tx_id,
+        )
+        .execute(executor)
+        .await
+        .map_err(|e| RefreshTokenRepositoryError::QueryFailed(e.into()))?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct RefreshTokenRow {
+    id: Uuid,
+    family_id: Uuid,
+    user_id: Uuid,
+    token_hash: String,
+    issued_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    used_at: Option<DateTime<Utc>>,
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+impl From<RefreshTokenRow> for RefreshToken {
+    fn from(row: RefreshTokenRow) -> Self {
+        // 失効は使用済みよりも優先される
+        let status = match (row.used_at, row.revoked_at) {
+            (_, Some(revoked_at)) => RefreshTokenStatus::Revoked { revoked_at },
+            (Some(used_at), None) => RefreshTokenStatus::Used { used_at },
+            (None, None) => RefreshTokenStatus::Active,
+        };
+
+        RefreshToken::reconstruct(
+            RefreshTokenId::from(row.id),
+            RefreshTokenFamilyId::from(row.family_id),
+            UserId::from(row.user_id),
+            OpaqueTokenHash::from_str_unchecked(row.token_hash),
+            row.issued_at,
+            row.expires_at,
+            status,
+        )
+    }
+}
@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::clock::Clock;
+use domain::models::auth::{
+    OpaqueTokenHash, RefreshToken, RefreshTokenFamilyId, RefreshTokenRepository,
+    RefreshTokenRepositoryError,
+};
+use domain::models::user::UserId;
+use sqlx::{Postgres, Transaction};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::repository::audit::TxAudit;
+use crate::repository::refresh_token::SqlxRefreshTokenRepository;
+
+/// トランザクションを保持し、`RefreshTokenRepository` トレイトを実装するアダプター。
+pub struct SqlxRefreshTokenRepoAdapter<'a, C: Clock> {
+    transaction: Arc<Mutex<Option<Transaction<'a, Postgres>>>>,
+    clock: Arc<C>,
+    audit: Arc<TxAudit>,
+}
+
+impl<'a, C: Clock> SqlxRefreshTokenRepoAdapter<'a, C> {
+    pub fn new(
+        transaction: Arc<Mutex<Option<Transaction<'a, Postgres>>>>,
+        clock: Arc<C>,
+        audit: Arc<TxAudit>,
+    ) -> Self {
+        Self {
+            transaction,
+            clock,
+            audit,
+        }
+    }
+}
+
+#[async_trait]
+impl<'a, C: Clock> RefreshTokenRepository for SqlxRefreshTokenRepoAdapter<'a, C> {
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &OpaqueTokenHash,
+    ) -> Result<Option<RefreshToken>, RefreshTokenRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            RefreshTokenRepositoryError::Unexpected(anyhow::anyhow!(
+                "Transaction already closed or taken"
+            ))
+        })?;
+        SqlxRefreshTokenRepository::find_by_token_hash(&mut **tx, token_hash).await
+    }
+
+    async fn save(&self, token: &RefreshToken) -> Result<(), RefreshTokenRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            RefreshTokenRepositoryError::Unexpected(anyhow::anyhow!(
+                "Transaction already closed or taken"
+            ))
+        })?;
+        SqlxRefreshTokenRepository::save(&mut **tx, token, &self.audit.stamp(self.clock.now()))
+            .await
+    }
+
+    async fn revoke_family(
+        &self,
+        family_id: RefreshTokenFamilyId,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<(), RefreshTokenRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            RefreshTokenRepositoryError::Unexpected(anyhow::anyhow!(
+                "Transaction already closed or taken"
+            ))
+        })?;
+        SqlxRefreshTokenRepository::revoke_family(
+            &mut **tx,
+            family_id,
+            revoked_at,
+            &self.audit.stamp(self.clock.now()),
+        )
+        .await
+    }
+
+    async fn revoke_all_by_user_id(
+        &self,
+        user_id: UserId,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<(), RefreshTokenRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            RefreshTokenRepositoryError::Unexpected(anyhow::anyhow!(
+                "Transaction already closed or taken"
+            ))
+        })?;
+        SqlxRefreshTokenRepository::revoke_all_by_user_id(
+            &mut **tx,
+            user_id,
+            revoked_at,
+            &self.audit.stamp(self.clock.now()),
+        )
+        .await
+    }
+}
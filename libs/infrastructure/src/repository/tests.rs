@@ -1,10 +1,21 @@
 use crate::id::UuidV7Generator;
+use crate::outbox::{OutboxRelay, OutboxRelayConfig, RetryPolicy};
 use crate::repository::tx::SqlxTransactionManager;
 use domain::id::IdGenerator;
+use domain::models::auth::{
+    MfaChallenge, MfaChallengeId, OpaqueTokenHash, PasswordResetToken, RecoveryCodeSet,
+    RefreshToken, RefreshTokenId, RefreshTokenStatus, TotpCredential, TotpSecret,
+};
 use domain::models::user::{
-    Authenticatable, Email, PasswordHash, User, UserId, UserIdentity, UserRepositoryError,
+    AccountStatus, Authenticatable, Email, EmailVerificationDispatch, PasswordHash, Role, User,
+    UserEvent, UserId, UserIdentity, UserRepositoryError, UserSearchCriteria,
 };
 use domain::repository::tx::TransactionManager;
+use domain::{Actor, AuditContext};
+
+fn test_audit() -> AuditContext {
+    AuditContext::system("infra-test")
+}
 
 #[sqlx::test(migrations = "../../migrations")]
 async fn test_save_and_find_user(pool: sqlx::PgPool) {
@@ -19,7 +30,7 @@ async fn test_save_and_find_user(pool: sqlx::PgPool) {
 
     // 1. 新規保存 (INSERT)
     let user_to_save = user.clone();
-    let result: Result<(), domain::error::DomainError> = domain::tx!(tm, |factory| {
+    let result: Result<(), domain::error::DomainError> = domain::tx!(tm, test_audit(), |factory| {
         let repo = factory.user_repository();
         repo.save(&user_to_save).await?;
         Ok::<(), domain::error::DomainError>(())
@@ -29,7 +40,7 @@ async fn test_save_and_find_user(pool: sqlx::PgPool) {
 
     // 2. 検索して検証
     let email_to_find = email.clone();
-    let found_user: Option<User> = domain::tx!(tm, |factory| {
+    let found_user: Option<User> = domain::tx!(tm, test_audit(), |factory| {
         let repo = factory.user_repository();
         let res = repo.find_by_email(&email_to_find).await?;
         Ok::<Option<User>, domain::error::DomainError>(res)
@@ -42,23 +53,22 @@ async fn test_save_and_find_user(pool: sqlx::PgPool) {
     assert_eq!(found.id(), user_id);
     assert_eq!(found.email(), &email);
 
-    // 3. 更新 (UPDATE / ON CONFLICT)
-    let updated_user = User::new(
-        found.id(),
-        found.email().clone(),
+    // 3. 更新 (読み込んだバージョンを条件に UPDATE)
+    let (user_to_update, _) = found.change_password(
         PasswordHash::from_str_unchecked("new_hash"),
+        chrono::Utc::now(),
     );
-    let user_to_update = updated_user.clone();
-    let update_result: Result<(), domain::error::DomainError> = domain::tx!(tm, |factory| {
-        let repo = factory.user_repository();
-        repo.save(&user_to_update).await?;
-        Ok::<(), domain::error::DomainError>(())
-    })
-    .await;
+    let update_result: Result<(), domain::error::DomainError> =
+        domain::tx!(tm, test_audit(), |factory| {
+            let repo = factory.user_repository();
+            repo.save(&user_to_update).await?;
+            Ok::<(), domain::error::DomainError>(())
+        })
+        .await;
     assert!(update_result.is_ok());
 
     // 4. 更新結果の確認
-    let found_after_update: Option<User> = domain::tx!(tm, |factory| {
+    let found_after_update: Option<User> = domain::tx!(tm, test_audit(), |factory| {
         let repo = factory.user_repository();
         let res = repo.find_by_email(&email).await?;
         Ok::<Option<User>, domain::error::DomainError>(res)
@@ -90,7 +100,7 @@ async fn test_duplicate_email_error(pool: sqlx::PgPool) {
     );
 
     // 一人目を保存
-    let res1: Result<(), domain::error::DomainError> = domain::tx!(tm, |factory| {
+    let res1: Result<(), domain::error::DomainError> = domain::tx!(tm, test_audit(), |factory| {
         let repo = factory.user_repository();
         repo.save(&user1).await?;
         Ok::<(), domain::error::DomainError>(())
@@ -99,7 +109,7 @@ async fn test_duplicate_email_error(pool: sqlx::PgPool) {
     assert!(res1.is_ok());
 
     // 二人目を同じメールアドレスで保存（別のID）
-    let res2: Result<(), domain::error::DomainError> = domain::tx!(tm, |factory| {
+    let res2: Result<(), domain::error::DomainError> = domain::tx!(tm, test_audit(), |factory| {
         let repo = factory.user_repository();
         repo.save(&user2).await?;
         Ok::<(), domain::error::DomainError>(())
@@ -136,7 +146,7 @@ async fn test_transaction_rollback(pool: sqlx::PgPool) {
 
     // エラーを返してロールバックを誘発
     let user_to_save = user.clone();
-    let result: Result<(), domain::error::DomainError> = domain::tx!(tm, |factory| {
+    let result: Result<(), domain::error::DomainError> = domain::tx!(tm, test_audit(), |factory| {
         let repo = factory.user_repository();
         repo.save(&user_to_save).await?;
         Err(domain::error::DomainError::LogicViolation(
@@ -148,7 +158,7 @@ async fn test_transaction_rollback(pool: sqlx::PgPool) {
     assert!(result.is_err());
 
     // 保存されていないことを確認
-    let found_user: Option<User> = domain::tx!(tm, |factory| {
+    let found_user: Option<User> = domain::tx!(tm, test_audit(), |factory| {
         let repo = factory.user_repository();
         let res = repo.find_by_email(&email).await?;
         Ok::<Option<User>, domain::error::DomainError>(res)
@@ -158,3 +168,827 @@ async fn test_transaction_rollback(pool: sqlx::PgPool) {
 
     assert!(found_user.is_none());
 }
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_refresh_token_rotation_and_family_revocation(pool: sqlx::PgPool) {
+    let clock = std::sync::Arc::new(crate::clock::RealClock);
+    let tm = SqlxTransactionManager::new(pool, clock);
+    let id_gen = UuidV7Generator::new();
+
+    let user = User::new(
+        id_gen.generate(),
+        Email::try_from("refresh@example.com").unwrap(),
+        PasswordHash::from_str_unchecked("hash"),
+    );
+    let now = chrono::Utc::now();
+    let first = RefreshToken::issue(
+        id_gen.generate(),
+        user.id(),
+        OpaqueTokenHash::from_str_unchecked("hash-1"),
+        now,
+    );
+    let next_id: RefreshTokenId = id_gen.generate();
+    let (used, next) = first
+        .clone()
+        .rotate(next_id, OpaqueTokenHash::from_str_unchecked("hash-2"), now)
+        .unwrap();
+
+    let family_id = next.family_id();
+
+    // 1. 発行とローテーションを保存
+    let result: Result<(), domain::error::DomainError> = domain::tx!(tm, test_audit(), |factory| {
+        factory.user_repository().save(&user).await?;
+        let repo = factory.refresh_token_repository();
+        repo.save(&first).await?;
+        repo.save(&used).await?;
+        repo.save(&next).await?;
+        Ok::<(), domain::error::DomainError>(())
+    })
+    .await;
+    assert!(result.is_ok());
+
+    // 2. ハッシュで検索し、状態が復元されること
+    let found: Option<RefreshToken> = domain::tx!(tm, test_audit(), |factory| {
+        let res = factory
+            .refresh_token_repository()
+            .find_by_token_hash(&OpaqueTokenHash::from_str_unchecked("hash-1"))
+            .await?;
+        Ok::<Option<RefreshToken>, domain::error::DomainError>(res)
+    })
+    .await
+    .unwrap();
+    assert!(matches!(
+        found.unwrap().status(),
+        RefreshTokenStatus::Used { .. }
+    ));
+
+    // 3. ファミリー全体を失効
+    let revoked: Option<RefreshToken> = domain::tx!(tm, test_audit(), |factory| {
+        let repo = factory.refresh_token_repository();
+        repo.revoke_family(family_id, chrono::Utc::now()).await?;
+        let res = repo
+            .find_by_token_hash(&OpaqueTokenHash::from_str_unchecked("hash-2"))
+            .await?;
+        Ok::<Option<RefreshToken>, domain::error::DomainError>(res)
+    })
+    .await
+    .unwrap();
+    assert!(matches!(
+        revoked.unwrap().status(),
+        RefreshTokenStatus::Revoked { .. }
+    ));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_totp_credential_and_mfa_challenge(pool: sqlx::PgPool) {
+    let clock = std::sync::Arc::new(crate::clock::RealClock);
+    let tm = SqlxTransactionManager::new(pool, clock);
+    let id_gen = UuidV7Generator::new();
+
+    let user = User::new(
+        id_gen.generate(),
+        Email::try_from("mfa@example.com").unwrap(),
+        PasswordHash::from_str_unchecked("hash"),
+    );
+    let user_id = user.id();
+    let now = chrono::Utc::now();
+    let credential = TotpCredential::enroll(user_id, TotpSecret::from_str_unchecked("SECRET"));
+    let challenge_id: MfaChallengeId = id_gen.generate();
+    let challenge = MfaChallenge::issue(
+        challenge_id,
+        user_id,
+        OpaqueTokenHash::from_str_unchecked("challenge-hash"),
+        now,
+    );
+
+    // 1. 登録開始 → 確認（同一ユーザーの行を更新）とチャレンジの保存
+    let result: Result<(), domain::error::DomainError> = domain::tx!(tm, test_audit(), |factory| {
+        factory.user_repository().save(&user).await?;
+        let totp_repo = factory.totp_credential_repository();
+        totp_repo.save(&credential).await?;
+        totp_repo.save(&credential.confirm(42, now)?).await?;
+        let challenge_repo = factory.mfa_challenge_repository();
+        challenge_repo.save(&challenge).await?;
+        challenge_repo.save(&challenge.record_failure()).await?;
+        Ok::<(), domain::error::DomainError>(())
+    })
+    .await;
+    assert!(result.is_ok());
+
+    // 2. 状態が復元されること
+    let (found_credential, found_challenge) = domain::tx!(tm, test_audit(), |factory| {
+        let credential = factory
+            .totp_credential_repository()
+            .find_by_user_id(user_id)
+            .await?;
+        let challenge = factory
+            .mfa_challenge_repository()
+            .find_by_token_hash(&OpaqueTokenHash::from_str_unchecked("challenge-hash"))
+            .await?;
+        Ok::<_, domain::error::DomainError>((credential, challenge))
+    })
+    .await
+    .unwrap();
+    let found_credential = found_credential.unwrap();
+    assert!(found_credential.is_enabled());
+    assert_eq!(found_credential.last_used_step(), Some(42));
+    assert_eq!(found_challenge.unwrap().failed_attempts(), 1);
+
+    // 3. チャレンジの削除
+    let deleted: Option<MfaChallenge> = domain::tx!(tm, test_audit(), |factory| {
+        let repo = factory.mfa_challenge_repository();
+        repo.delete(challenge_id).await?;
+        let res = repo
+            .find_by_token_hash(&OpaqueTokenHash::from_str_unchecked("challenge-hash"))
+            .await?;
+        Ok::<Option<MfaChallenge>, domain::error::DomainError>(res)
+    })
+    .await
+    .unwrap();
+    assert!(deleted.is_none());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_save_and_replace_recovery_codes(pool: sqlx::PgPool) {
+    let clock = std::sync::Arc::new(crate::clock::RealClock);
+    let tm = SqlxTransactionManager::new(pool, clock);
+    let id_gen = UuidV7Generator::new();
+
+    let user = User::new(
+        id_gen.generate(),
+        Email::try_from("recovery@example.com").unwrap(),
+        PasswordHash::from_str_unchecked("hash"),
+    );
+    let user_id = user.id();
+    let hashes = |prefix: &str, n: usize| {
+        (0..n)
+            .map(|i| PasswordHash::from_str_unchecked(format!("{prefix}-{i}")))
+            .collect::<Vec<_>>()
+    };
+
+    // 1. 発行 → 1 件使用
+    let issued = RecoveryCodeSet::issue(user_id, hashes("old", 3));
+    let consumed = issued.consume(1, chrono::Utc::now()).unwrap();
+    let found = domain::tx!(tm, test_audit(), |factory| {
+        factory.user_repository().save(&user).await?;
+        let repo = factory.recovery_code_repository();
+        repo.save(&consumed).await?;
+        let res = repo.find_by_user_id(user_id).await?;
+        Ok::<_, domain::error::DomainError>(res)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(found.remaining(), 2);
+    assert!(found.codes()[1].is_used());
+    assert_eq!(found.codes()[2].code_hash().as_ref(), "old-2");
+
+    // 2. 件数の少ない一式で置き換えると、古い行は残らない
+    let regenerated = RecoveryCodeSet::issue(user_id, hashes("new", 2));
+    let found = domain::tx!(tm, test_audit(), |factory| {
+        let repo = factory.recovery_code_repository();
+        repo.save(&regenerated).await?;
+        let res = repo.find_by_user_id(user_id).await?;
+        Ok::<_, domain::error::DomainError>(res)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(found.codes().len(), 2);
+    assert_eq!(found.remaining(), 2);
+    assert_eq!(found.codes()[0].code_hash().as_ref(), "new-0");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_save_find_and_delete_password_reset_tokens(pool: sqlx::PgPool) {
+    let clock = std::sync::Arc::new(crate::clock::RealClock);
+    let tm = SqlxTransactionManager::new(pool, clock);
+    let id_gen = UuidV7Generator::new();
+
+    let user = User::new(
+        id_gen.generate(),
+        Email::try_from("reset@example.com").unwrap(),
+        PasswordHash::from_str_unchecked("hash"),
+    );
+    let user_id = user.id();
+    let now = chrono::Utc::now();
+    let first_id = id_gen.generate();
+    let first = PasswordResetToken::issue(
+        first_id,
+        user_id,
+        OpaqueTokenHash::from_str_unchecked("reset-hash-1"),
+        now,
+    );
+    let second = PasswordResetToken::issue(
+        id_gen.generate(),
+        user_id,
+        OpaqueTokenHash::from_str_unchecked("reset-hash-2"),
+        now,
+    );
+
+    // 1. 保存とハッシュでの検索
+    let found = domain::tx!(tm, test_audit(), |factory| {
+        factory.user_repository().save(&user).await?;
+        let repo = factory.password_reset_token_repository();
+        repo.save(&first).await?;
+        repo.save(&second).await?;
+        let res = repo
+            .find_by_token_hash(&OpaqueTokenHash::from_str_unchecked("reset-hash-1"))
+            .await?;
+        Ok::<_, domain::error::DomainError>(res)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(found.id(), first_id);
+    assert_eq!(found.user_id(), user_id);
+
+    // 2. ユーザー単位で一括削除される
+    let remaining = domain::tx!(tm, test_audit(), |factory| {
+        let repo = factory.password_reset_token_repository();
+        repo.delete_by_user_id(user_id).await?;
+        let res = repo
+            .find_by_token_hash(&OpaqueTokenHash::from_str_unchecked("reset-hash-2"))
+            .await?;
+        Ok::<_, domain::error::DomainError>(res)
+    })
+    .await
+    .unwrap();
+    assert!(remaining.is_none());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_email_verification_status_and_dispatch(pool: sqlx::PgPool) {
+    let clock = std::sync::Arc::new(crate::clock::RealClock);
+    let tm = SqlxTransactionManager::new(pool, clock);
+    let id_gen = UuidV7Generator::new();
+
+    let user = User::new(
+        id_gen.generate(),
+        Email::try_from("verify@example.com").unwrap(),
+        PasswordHash::from_str_unchecked("hash"),
+    );
+    let user_id = user.id();
+    // DB の精度（マイクロ秒）に丸めておく
+    let now =
+        chrono::DateTime::from_timestamp_micros(chrono::Utc::now().timestamp_micros()).unwrap();
+    let later = now + chrono::Duration::minutes(5);
+
+    // 1. 登録時は未確認。送信記録は置き換えで保存される
+    let (found, dispatch) = domain::tx!(tm, test_audit(), |factory| {
+        let user_repo = factory.user_repository();
+        user_repo.save(&user).await?;
+        let dispatch_repo = factory.email_verification_dispatch_repository();
+        dispatch_repo
+            .save(&EmailVerificationDispatch::record(user_id, now))
+            .await?;
+        dispatch_repo
+            .save(&EmailVerificationDispatch::record(user_id, later))
+            .await?;
+        let found = user_repo.find_by_id(user_id).await?;
+        let dispatch = dispatch_repo.find_by_user_id(user_id).await?;
+        Ok::<_, domain::error::DomainError>((found, dispatch))
+    })
+    .await
+    .unwrap();
+    let found = found.unwrap();
+    assert!(!found.email_verification().is_verified());
+    assert_eq!(dispatch.unwrap().last_sent_at(), later);
+
+    // 2. 確認済みの状態が永続化される
+    let (verified, _) = found.verify_email(now);
+    let found = domain::tx!(tm, test_audit(), |factory| {
+        let user_repo = factory.user_repository();
+        user_repo.save(&verified).await?;
+        let res = user_repo.find_by_id(user_id).await?;
+        Ok::<_, domain::error::DomainError>(res)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(found.email_verification().verified_at(), Some(now));
+}
+
+/// 常に配信に失敗するパブリッシャー。
+struct FailingPublisher;
+
+#[async_trait::async_trait]
+impl crate::outbox::EventPublisher for FailingPublisher {
+    async fn publish(&self, _message: &crate::outbox::OutboxMessage) -> anyhow::Result<()> {
+        Err(anyhow::anyhow!("broker unavailable"))
+    }
+}
+
+async fn outbox_state(pool: &sqlx::PgPool) -> Vec<(String, String, i32, Option<String>)> {
+    sqlx::query_as("SELECT event_type, status, attempts, last_error FROM outbox ORDER BY id")
+        .fetch_all(pool)
+        .await
+        .unwrap()
+}
+
+fn registered_event(user: &User) -> domain::event::DomainEvent {
+    UserEvent::UserRegistered {
+        user_id: user.id(),
+        email: user.email().clone(),
+        occurred_at: chrono::Utc::now(),
+    }
+    .into()
+}
+
+fn relay(
+    pool: &sqlx::PgPool,
+    publisher: std::sync::Arc<dyn crate::outbox::EventPublisher>,
+    retry: RetryPolicy,
+) -> OutboxRelay<crate::clock::RealClock> {
+    OutboxRelay::new(
+        pool.clone(),
+        publisher,
+        std::sync::Arc::new(crate::clock::RealClock),
+        OutboxRelayConfig {
+            retry,
+            ..Default::default()
+        },
+    )
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_outbox_append_follows_transaction(pool: sqlx::PgPool) {
+    let clock = std::sync::Arc::new(crate::clock::RealClock);
+    let tm = SqlxTransactionManager::new(pool.clone(), clock);
+    let id_gen = UuidV7Generator::new();
+
+    let committed = User::new(
+        id_gen.generate(),
+        Email::try_from("outbox1@example.com").unwrap(),
+        PasswordHash::from_str_unchecked("hash"),
+    );
+    let rolled_back = User::new(
+        id_gen.generate(),
+        Email::try_from("outbox2@example.com").unwrap(),
+        PasswordHash::from_str_unchecked("hash"),
+    );
+
+    // 1. コミットされたトランザクションのイベントは記録される
+    let committed_id = committed.id();
+    let event = registered_event(&committed);
+    domain::tx!(tm, test_audit(), |factory| {
+        factory.user_repository().save(&committed).await?;
+        factory.outbox_repository().append(&[event]).await?;
+        Ok::<_, domain::error::DomainError>(())
+    })
+    .await
+    .unwrap();
+
+    // 2. ロールバックされたトランザクションのイベントは残らない
+    let event = registered_event(&rolled_back);
+    let result = domain::tx!(tm, test_audit(), |factory| {
+        factory.user_repository().save(&rolled_back).await?;
+        factory.outbox_repository().append(&[event]).await?;
+        Err::<(), _>(domain::error::DomainError::LogicViolation("abort"))
+    })
+    .await;
+    assert!(result.is_err());
+
+    let (aggregate_id, payload): (uuid::Uuid, serde_json::Value) =
+        sqlx::query_as("SELECT aggregate_id, payload FROM outbox")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(aggregate_id, uuid::Uuid::from(committed_id));
+    assert_eq!(payload["type"], "UserRegistered");
+    assert_eq!(payload["email"], "outbox1@example.com");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_outbox_relay_publishes_pending_messages(pool: sqlx::PgPool) {
+    let clock = std::sync::Arc::new(crate::clock::RealClock);
+    let tm = SqlxTransactionManager::new(pool.clone(), clock);
+    let user = User::new(
+        UuidV7Generator::new().generate(),
+        Email::try_from("relay@example.com").unwrap(),
+        PasswordHash::from_str_unchecked("hash"),
+    );
+    let event = registered_event(&user);
+    domain::tx!(tm, test_audit(), |factory| {
+        factory.outbox_repository().append(&[event]).await?;
+        Ok::<_, domain::error::DomainError>(())
+    })
+    .await
+    .unwrap();
+
+    // 1. 他のリレーがロック中のメッセージは取り出さない
+    let mut locking = pool.begin().await.unwrap();
+    let locked =
+        crate::repository::SqlxOutboxRepository::lock_due(&mut *locking, chrono::Utc::now(), 10)
+            .await
+            .unwrap();
+    assert_eq!(locked.len(), 1);
+
+    let relay = relay(
+        &pool,
+        std::sync::Arc::new(crate::outbox::LogEventPublisher),
+        RetryPolicy::default(),
+    );
+    assert_eq!(relay.run_once().await.unwrap(), 0);
+    locking.rollback().await.unwrap();
+
+    // 2. ロック解除後に配信され、配信済みになる
+    assert_eq!(relay.run_once().await.unwrap(), 1);
+    assert_eq!(
+        outbox_state(&pool).await,
+        vec![(
+            "UserRegistered".to_string(),
+            "published".to_string(),
+            1,
+            None
+        )]
+    );
+    assert_eq!(relay.run_once().await.unwrap(), 0);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_outbox_relay_retries_then_dead_letters(pool: sqlx::PgPool) {
+    let clock = std::sync::Arc::new(crate::clock::RealClock);
+    let tm = SqlxTransactionManager::new(pool.clone(), clock);
+    let user = User::new(
+        UuidV7Generator::new().generate(),
+        Email::try_from("poison@example.com").unwrap(),
+        PasswordHash::from_str_unchecked("hash"),
+    );
+    let event = registered_event(&user);
+    domain::tx!(tm, test_audit(), |factory| {
+        factory.outbox_repository().append(&[event]).await?;
+        Ok::<_, domain::error::DomainError>(())
+    })
+    .await
+    .unwrap();
+
+    let retry = RetryPolicy {
+        max_attempts: 2,
+        initial_backoff: chrono::Duration::zero(),
+        max_backoff: chrono::Duration::zero(),
+    };
+    let relay = relay(&pool, std::sync::Arc::new(FailingPublisher), retry);
+
+    // 1. 1 回目の失敗では再試行待ちとして残る
+    assert_eq!(relay.run_once().await.unwrap(), 1);
+    assert_eq!(
+        outbox_state(&pool).await,
+        vec![(
+            "UserRegistered".to_string(),
+            "pending".to_string(),
+            1,
+            Some("broker unavailable".to_string())
+        )]
+    );
+
+    // 2. 上限に達するとデッドレターに移り、以降は取り出されない
+    assert_eq!(relay.run_once().await.unwrap(), 1);
+    assert_eq!(
+        outbox_state(&pool).await,
+        vec![(
+            "UserRegistered".to_string(),
+            "dead_letter".to_string(),
+            2,
+            Some("broker unavailable".to_string())
+        )]
+    );
+    assert_eq!(relay.run_once().await.unwrap(), 0);
+}
+
+type AuditColumns = (String, String, String, String, String, String, i32);
+
+async fn user_audit_columns(pool: &sqlx::PgPool, user_id: UserId) -> AuditColumns {
+    sqlx::query_as(
+        r#"
+        SELECT created_by, created_pgm_cd, created_tx_id,
+               updated_by, updated_pgm_cd, updated_tx_id, lock_no
+        FROM users WHERE id = $1
+        "#,
+    )
+    .bind(uuid::Uuid::from(user_id))
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_audit_columns_follow_context(pool: sqlx::PgPool) {
+    let clock = std::sync::Arc::new(crate::clock::RealClock);
+    let tm = SqlxTransactionManager::new(pool.clone(), clock);
+    let user_id: UserId = UuidV7Generator::new().generate();
+    let user = User::new(
+        user_id,
+        Email::try_from("audit@example.com").unwrap(),
+        PasswordHash::from_str_unchecked("hashed_pw"),
+    );
+
+    domain::tx!(
+        tm,
+        AuditContext::new(Actor::Anonymous, "auth-signup"),
+        |factory| {
+            factory.user_repository().save(&user).await?;
+            Ok::<(), domain::error::DomainError>(())
+        }
+    )
+    .await
+    .unwrap();
+
+    let (created_by, created_pgm_cd, created_tx_id, updated_by, _, updated_tx_id, _) =
+        user_audit_columns(&pool, user_id).await;
+    assert_eq!(created_by, "anonymous");
+    assert_eq!(created_pgm_cd, "auth-signup");
+    assert!(uuid::Uuid::parse_str(&created_tx_id).is_ok());
+    assert_eq!(updated_by, "anonymous");
+    assert_eq!(updated_tx_id, created_tx_id);
+
+    // 更新では created_* を保持し、updated_* のみ書き換える
+    domain::tx!(
+        tm,
+        AuditContext::new(Actor::User(user_id), "auth-password-reset"),
+        |factory| {
+            let repo = factory.user_repository();
+            let loaded = repo.find_by_id(user_id).await?.unwrap();
+            repo.save(&loaded).await?;
+            Ok::<(), domain::error::DomainError>(())
+        }
+    )
+    .await
+    .unwrap();
+
+    let (created_by, created_pgm_cd, first_tx_id, updated_by, updated_pgm_cd, updated_tx_id, _) =
+        user_audit_columns(&pool, user_id).await;
+    assert_eq!(created_by, "anonymous");
+    assert_eq!(created_pgm_cd, "auth-signup");
+    assert_eq!(first_tx_id, created_tx_id);
+    assert_eq!(updated_by, user_id.to_string());
+    assert_eq!(updated_pgm_cd, "auth-password-reset");
+    assert_ne!(updated_tx_id, created_tx_id);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_concurrent_user_update_is_rejected(pool: sqlx::PgPool) {
+    let clock = std::sync::Arc::new(crate::clock::RealClock);
+    let tm = SqlxTransactionManager::new(pool.clone(), clock);
+    let user = User::new(
+        UuidV7Generator::new().generate(),
+        Email::try_from("race@example.com").unwrap(),
+        PasswordHash::from_str_unchecked("hash"),
+    );
+    let user_id = user.id();
+
+    let to_insert = user.clone();
+    domain::tx!(tm, test_audit(), |factory| {
+        factory.user_repository().save(&to_insert).await?;
+        Ok::<(), domain::error::DomainError>(())
+    })
+    .await
+    .unwrap();
+
+    // 同じ ID での再登録は競合として扱う
+    let duplicated = domain::tx!(tm, test_audit(), |factory| {
+        factory.user_repository().save(&user).await?;
+        Ok::<(), domain::error::DomainError>(())
+    })
+    .await;
+    assert!(matches!(
+        duplicated,
+        Err(domain::error::DomainError::User(
+            domain::models::user::UserError::Repository(
+                UserRepositoryError::ConcurrentModification
+            )
+        ))
+    ));
+
+    // 同じバージョンを読み込んだ 2 つの更新のうち、後から保存した方は失敗する
+    let load = || async {
+        domain::tx!(tm, test_audit(), |factory| {
+            let res = factory.user_repository().find_by_id(user_id).await?;
+            Ok::<Option<User>, domain::error::DomainError>(res)
+        })
+        .await
+        .unwrap()
+        .unwrap()
+    };
+    let first = load().await;
+    let second = load().await;
+    assert_eq!(first.version(), domain::Version::from(1));
+
+    let (first, _) = first.change_password(
+        PasswordHash::from_str_unchecked("first"),
+        chrono::Utc::now(),
+    );
+    domain::tx!(tm, test_audit(), |factory| {
+        factory.user_repository().save(&first).await?;
+        Ok::<(), domain::error::DomainError>(())
+    })
+    .await
+    .unwrap();
+
+    let (second, _) = second.change_password(
+        PasswordHash::from_str_unchecked("second"),
+        chrono::Utc::now(),
+    );
+    let result = domain::tx!(tm, test_audit(), |factory| {
+        factory.user_repository().save(&second).await?;
+        Ok::<(), domain::error::DomainError>(())
+    })
+    .await;
+    assert!(matches!(
+        result,
+        Err(domain::error::DomainError::User(
+            domain::models::user::UserError::Repository(
+                UserRepositoryError::ConcurrentModification
+            )
+        ))
+    ));
+
+    let stored = load().await;
+    assert_eq!(stored.version(), domain::Version::from(2));
+    assert_eq!(stored.password_hash().to_string(), "first");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_user_roles_are_persisted(pool: sqlx::PgPool) {
+    let clock = std::sync::Arc::new(crate::clock::RealClock);
+    let tm = SqlxTransactionManager::new(pool.clone(), clock);
+    let user = User::new(
+        UuidV7Generator::new().generate(),
+        Email::try_from("roles@example.com").unwrap(),
+        PasswordHash::from_str_unchecked("hash"),
+    );
+    let user_id = user.id();
+
+    domain::tx!(tm, test_audit(), |factory| {
+        factory.user_repository().save(&user).await?;
+        Ok::<(), domain::error::DomainError>(())
+    })
+    .await
+    .unwrap();
+
+    let load = || async {
+        domain::tx!(tm, test_audit(), |factory| {
+            let res = factory.user_repository().find_by_id(user_id).await?;
+            Ok::<Option<User>, domain::error::DomainError>(res)
+        })
+        .await
+        .unwrap()
+        .unwrap()
+    };
+    let save = |user: User| async {
+        domain::tx!(tm, test_audit(), |factory| {
+            factory.user_repository().save(&user).await?;
+            Ok::<(), domain::error::DomainError>(())
+        })
+        .await
+        .unwrap()
+    };
+
+    // 新規ユーザーには一般ユーザーのロールが付与される
+    assert_eq!(load().await.roles(), &[Role::User]);
+
+    save(load().await.grant_role(Role::Admin)).await;
+    assert_eq!(load().await.roles(), &[Role::User, Role::Admin]);
+
+    save(load().await.revoke_role(Role::User)).await;
+    let stored = load().await;
+    assert_eq!(stored.roles(), &[Role::Admin]);
+    assert_eq!(stored.version(), domain::Version::from(3));
+
+    let count: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM user_roles WHERE user_id = $1")
+        .bind(uuid::Uuid::from(user_id))
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count.0, 1);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_search_users(pool: sqlx::PgPool) {
+    let clock = std::sync::Arc::new(crate::clock::RealClock);
+    let tm = SqlxTransactionManager::new(pool.clone(), clock);
+    let id_generator = UuidV7Generator::new();
+    let users: Vec<User> = ["a_1@example.com", "ab1@example.com", "b@example.com"]
+        .into_iter()
+        .map(|email| {
+            User::new(
+                id_generator.generate(),
+                Email::try_from(email).unwrap(),
+                PasswordHash::from_str_unchecked("hash"),
+            )
+        })
+        .collect();
+    let (suspended, _) = users[2].clone().suspend(chrono::Utc::now()).unwrap();
+
+    let to_insert = vec![users[0].clone(), users[1].clone(), suspended];
+    domain::tx!(tm, test_audit(), |factory| {
+        for user in &to_insert {
+            factory.user_repository().save(user).await?;
+        }
+        Ok::<(), domain::error::DomainError>(())
+    })
+    .await
+    .unwrap();
+
+    let search = |criteria: UserSearchCriteria| async {
+        domain::tx!(tm, test_audit(), |factory| {
+            let res = factory.user_repository().search(&criteria).await?;
+            Ok::<Vec<User>, domain::error::DomainError>(res)
+        })
+        .await
+        .unwrap()
+        .iter()
+        .map(|u| u.id())
+        .collect::<Vec<_>>()
+    };
+    let all = UserSearchCriteria {
+        limit: 10,
+        ..Default::default()
+    };
+
+    // ID（登録順）の昇順で、カーソルより後のユーザーを取得する
+    assert_eq!(
+        search(all.clone()).await,
+        users.iter().map(|u| u.id()).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        search(UserSearchCriteria {
+            after: Some(users[0].id()),
+            limit: 1,
+            ..all.clone()
+        })
+        .await,
+        vec![users[1].id()]
+    );
+    // ワイルドカードはリテラルとして扱う
+    assert_eq!(
+        search(UserSearchCriteria {
+            email_prefix: Some("a_".to_string()),
+            ..all.clone()
+        })
+        .await,
+        vec![users[0].id()]
+    );
+    assert_eq!(
+        search(UserSearchCriteria {
+            status: Some(AccountStatus::Suspended),
+            ..all.clone()
+        })
+        .await,
+        vec![users[2].id()]
+    );
+
+    assert!(
+        search(UserSearchCriteria {
+            status: Some(AccountStatus::Deleted),
+            ..all
+        })
+        .await
+        .is_empty()
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_account_state_is_persisted(pool: sqlx::PgPool) {
+    let clock = std::sync::Arc::new(crate::clock::RealClock);
+    let tm = SqlxTransactionManager::new(pool.clone(), clock);
+    let user = User::new(
+        UuidV7Generator::new().generate(),
+        Email::try_from("state@example.com").unwrap(),
+        PasswordHash::from_str_unchecked("hash"),
+    );
+    let user_id = user.id();
+
+    let save = |user: User| async {
+        domain::tx!(tm, test_audit(), |factory| {
+            factory.user_repository().save(&user).await?;
+            Ok::<(), domain::error::DomainError>(())
+        })
+        .await
+        .unwrap();
+    };
+    let load = || async {
+        domain::tx!(tm, test_audit(), |factory| {
+            let user = factory.user_repository().find_by_id(user_id).await?;
+            Ok::<Option<User>, domain::error::DomainError>(user)
+        })
+        .await
+        .unwrap()
+        .unwrap()
+    };
+
+    // マイクロ秒未満は保存時に切り捨てられるため、比較できる精度に揃える
+    let now = chrono::DateTime::from_timestamp(chrono::Utc::now().timestamp(), 0).unwrap();
+    let until = now + chrono::Duration::minutes(15);
+    save(user).await;
+    let (locked, _) = load().await.lock(until, now).unwrap();
+    save(locked.clone()).await;
+    assert_eq!(load().await.state(), locked.state());
+
+    let (deleted, _) = load().await.delete(now).unwrap();
+    save(deleted).await;
+    let stored = load().await;
+    assert_eq!(stored.status(), AccountStatus::Deleted);
+    assert_eq!(stored.state().changed_at(), Some(now));
+    assert_eq!(stored.state().locked_until(), None);
+}
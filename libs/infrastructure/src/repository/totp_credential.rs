@@ -0,0 +1,114 @@
+use chrono::{DateTime, Utc};
+use domain::models::auth::{MfaRepositoryError, TotpCredential, TotpSecret};
+use domain::models::user::UserId;
+use sqlx::Postgres;
+use uuid::Uuid;
+
+use crate::repository::audit::AuditStamp;
+
+/// SQLx を使用した TOTP 登録情報リポジトリの低レベル操作。
+pub struct SqlxTotpCredentialRepository;
+
+impl SqlxTotpCredentialRepository {
+    pub async fn find_by_user_id<'e, E>(
+        executor: E,
+        user_id: UserId,
+    ) -> Result<Option<TotpCredential>, MfaRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        let row = sqlx::query_as!(
+            TotpCredentialRow,
+            r#"
+            SELECT user_id, secret, confirmed_at, last_used_step
+            FROM user_totp_credentials
+            WHERE user_id = $1
+            "#,
+            Uuid::from(user_id)
+        )
+        .fetch_optional(executor)
+        .await
+        .map_err(|e| MfaRepositoryError::QueryFailed(e.into()))?;
+
+        row.map(TotpCredential::try_from).transpose()
+    }
+
+    pub async fn save<'e, E>(
+        executor: E,
+        credential: &TotpCredential,
+        audit: &AuditStamp,
+    ) -> Result<(), MfaRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        let now = audit.at;
+        let actor = audit.by.as_str();
+        let pgm_cd = audit.pgm_cd;
+        let tx_id = audit.tx_id.as_str();
+
+        let last_used_step = credential
+            .last_used_step()
+            .map(i64::try_from)
+            .transpose()
+            .map_err(|e| MfaRepositoryError::MappingFailed(e.into()))?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO user_totp_credentials (
+                user_id, secret, confirmed_at, last_used_step,
+                created_at, created_by, created_pgm_cd, created_tx_id,
+                updated_at, updated_by, updated_pgm_cd, updated_tx_id
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $5, $6, $7, $8)
+            ON CONFLICT (user_id) DO UPDATE SET
+                secret = EXCLUDED.secret,
+                confirmed_at = EXCLUDED.confirmed_at,
+                last_used_step = EXCLUDED.last_used_step,
+                updated_at = $5,
+                updated_by = $6,
+                updated_pgm_cd = $7,
+                updated_tx_id = $8,
+                lock_no = user_totp_credentials.lock_no + 1
+            "#,
+            Uuid::from(credential.user_id()),
+            credential.secret().expose_as_str(),
+            credential.confirmed_at(),
+            last_used_step,
+            now,
+            actor,
+            pgm_cd,
+            tx_id,
+        )
+        .execute(executor)
+        .await
+        .map_err(|e| MfaRepositoryError::QueryFailed(e.into()))?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct TotpCredentialRow {
+    user_id: Uuid,
+    secret: String,
+    confirmed_at: Option<DateTime<Utc>>,
+    last_used_step: Option<i64>,
+}
+
+impl TryFrom<TotpCredentialRow> for TotpCredential {
+    type Error = MfaRepositoryError;
+
+    fn try_from(row: TotpCredentialRow) -> Result<Self, Self::Error> {
+        let last_used_step = row
+            .last_used_step
+            .map(u64::try_from)
+            .transpose()
+            .map_err(|e| MfaRepositoryError::MappingFailed(e.into()))?;
+
+        Ok(TotpCredential::reconstruct(
+            UserId::from(row.user_id),
+            TotpSecret::from_str_unchecked(row.secret),
+            row.confirmed_at,
+            last_used_step,
+        ))
+    }
+}
@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use domain::clock::Clock;
+use domain::models::auth::{MfaRepositoryError, TotpCredential, TotpCredentialRepository};
+use domain::models::user::UserId;
+use sqlx::{Postgres, Transaction};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::repository::audit::TxAudit;
+use crate::repository::totp_credential::SqlxTotpCredentialRepository;
+
+/// トランザクションを保持し、`TotpCredentialRepository` トレイトを実装するアダプター。
+pub struct SqlxTotpCredentialRepoAdapter<'a, C: Clock> {
+    transaction: Arc<Mutex<Option<Transaction<'a, Postgres>>>>,
+    clock: Arc<C>,
+    audit: Arc<TxAudit>,
+}
+
+impl<'a, C: Clock> SqlxTotpCredentialRepoAdapter<'a, C> {
+    pub fn new(
+        transaction: Arc<Mutex<Option<Transaction<'a, Postgres>>>>,
+        clock: Arc<C>,
+        audit: Arc<TxAudit>,
+    ) -> Self {
+        Self {
+            transaction,
+            clock,
+            audit,
+        }
+    }
+}
+
+#[async_trait]
+impl<'a, C: Clock> TotpCredentialRepository for SqlxTotpCredentialRepoAdapter<'a, C> {
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<TotpCredential>, MfaRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            MfaRepositoryError::Unexpected(anyhow::anyhow!("Transaction already closed or taken"))
+        })?;
+        SqlxTotpCredentialRepository::find_by_user_id(&mut **tx, user_id).await
+    }
+
+    async fn save(&self, credential: &TotpCredential) -> Result<(), MfaRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            MfaRepositoryError::Unexpected(anyhow::anyhow!("Transaction already closed or taken"))
+        })?;
+        SqlxTotpCredentialRepository::save(
+            &mut **tx,
+            credential,
+            &self.audit.stamp(self.clock.now()),
+        )
+        .await
+    }
+}
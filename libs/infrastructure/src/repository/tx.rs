@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use domain::AuditContext;
 use domain::clock::Clock;
 use domain::repository::tx::{IntoTxError, RepositoryFactory, TransactionManager};
 use futures_util::future::BoxFuture;
@@ -7,11 +8,20 @@ use std::fmt::Debug;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
+use crate::repository::audit::TxAudit;
+use crate::repository::email_verification_dispatch_adapter::SqlxEmailVerificationDispatchRepoAdapter;
+use crate::repository::mfa_challenge_adapter::SqlxMfaChallengeRepoAdapter;
+use crate::repository::outbox_adapter::SqlxOutboxRepoAdapter;
+use crate::repository::password_reset_token_adapter::SqlxPasswordResetTokenRepoAdapter;
+use crate::repository::recovery_code_adapter::SqlxRecoveryCodeRepoAdapter;
+use crate::repository::refresh_token_adapter::SqlxRefreshTokenRepoAdapter;
+use crate::repository::totp_credential_adapter::SqlxTotpCredentialRepoAdapter;
 use crate::repository::user_adapter::SqlxUserRepoAdapter;
 
 pub struct SqlxRepositoryFactory<'a, C: Clock> {
     transaction: Arc<Mutex<Option<Transaction<'a, Postgres>>>>,
     clock: Arc<C>,
+    audit: Arc<TxAudit>,
 }
 
 impl<'a, C: Clock> RepositoryFactory for SqlxRepositoryFactory<'a, C> {
@@ -19,6 +29,75 @@ impl<'a, C: Clock> RepositoryFactory for SqlxRepositoryFactory<'a, C> {
         Arc::new(SqlxUserRepoAdapter::new(
             Arc::clone(&self.transaction),
             Arc::clone(&self.clock),
+            Arc::clone(&self.audit),
+        ))
+    }
+
+    fn refresh_token_repository(
+        &self,
+    ) -> Arc<dyn domain::models::auth::RefreshTokenRepository + '_> {
+        Arc::new(SqlxRefreshTokenRepoAdapter::new(
+            Arc::clone(&self.transaction),
+            Arc::clone(&self.clock),
+            Arc::clone(&self.audit),
+        ))
+    }
+
+    fn totp_credential_repository(
+        &self,
+    ) -> Arc<dyn domain::models::auth::TotpCredentialRepository + '_> {
+        Arc::new(SqlxTotpCredentialRepoAdapter::new(
+            Arc::clone(&self.transaction),
+            Arc::clone(&self.clock),
+            Arc::clone(&self.audit),
+        ))
+    }
+
+    fn mfa_challenge_repository(
+        &self,
+    ) -> Arc<dyn domain::models::auth::MfaChallengeRepository + '_> {
+        Arc::new(SqlxMfaChallengeRepoAdapter::new(
+            Arc::clone(&self.transaction),
+            Arc::clone(&self.clock),
+            Arc::clone(&self.audit),
+        ))
+    }
+
+    fn recovery_code_repository(
+        &self,
+    ) -> Arc<dyn domain::models::auth::RecoveryCodeRepository + '_> {
+        Arc::new(SqlxRecoveryCodeRepoAdapter::new(
+            Arc::clone(&self.transaction),
+            Arc::clone(&self.clock),
+            Arc::clone(&self.audit),
+        ))
+    }
+
+    fn password_reset_token_repository(
+        &self,
+    ) -> Arc<dyn domain::models::auth::PasswordResetTokenRepository + '_> {
+        Arc::new(SqlxPasswordResetTokenRepoAdapter::new(
+            Arc::clone(&self.transaction),
+            Arc::clone(&self.clock),
+            Arc::clone(&self.audit),
+        ))
+    }
+
+    fn email_verification_dispatch_repository(
+        &self,
+    ) -> Arc<dyn domain::models::user::EmailVerificationDispatchRepository + '_> {
+        Arc::new(SqlxEmailVerificationDispatchRepoAdapter::new(
+            Arc::clone(&self.transaction),
+            Arc::clone(&self.clock),
+            Arc::clone(&self.audit),
+        ))
+    }
+
+    fn outbox_repository(&self) -> Arc<dyn domain::event::OutboxRepository + '_> {
+        Arc::new(SqlxOutboxRepoAdapter::new(
+            Arc::clone(&self.transaction),
+            Arc::clone(&self.clock),
+            Arc::clone(&self.audit),
         ))
     }
 }
@@ -36,7 +115,7 @@ impl<C: Clock> SqlxTransactionManager<C> {
 
 #[async_trait]
 impl<C: Clock> TransactionManager for SqlxTransactionManager<C> {
-    async fn execute<T, E, F>(&self, f: F) -> Result<T, E>
+    async fn execute<T, E, F>(&self, context: AuditContext, f: F) -> Result<T, E>
     where
         T: Send,
         E: IntoTxError + Debug + Send + Sync,
@@ -48,6 +127,7 @@ impl<C: Clock> TransactionManager for SqlxTransactionManager<C> {
         let factory = SqlxRepositoryFactory {
             transaction: Arc::clone(&transaction),
             clock: Arc::clone(&self.clock),
+            audit: Arc::new(TxAudit::new(context)),
         };
 
         let result = f(&factory).await;
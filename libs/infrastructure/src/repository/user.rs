@@ -1,14 +1,55 @@
 use chrono::{DateTime, Utc};
+use domain::Version;
 use domain::models::user::{
-    Authenticatable, Email, PasswordHash, User, UserId, UserIdentity, UserRepositoryError,
+    AccountState, AccountStatus, Authenticatable, Email, EmailVerificationStatus, PasswordHash,
+    Role, User, UserId, UserIdentity, UserRepositoryError, UserSearchCriteria,
 };
 use sqlx::Postgres;
 use uuid::Uuid;
 
+use crate::repository::audit::AuditStamp;
+
 /// SQLx を使用したユーザーリポジトリの低レベル操作。
 pub struct SqlxUserRepository;
 
 impl SqlxUserRepository {
+    pub async fn find_by_id<'e, E>(
+        executor: E,
+        id: UserId,
+    ) -> Result<Option<User>, UserRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        let row = sqlx::query_as!(
+            UserRow,
+            r#"
+            SELECT
+                id, email, password_hash, email_verified_at,
+                status, status_changed_at, locked_until,
+                credentials_changed_at, password_reset_required_at,
+                created_at, created_by, created_pgm_cd, created_tx_id,
+                updated_at, updated_by, updated_pgm_cd, updated_tx_id,
+                lock_no,
+                ARRAY(
+                    SELECT role FROM user_roles
+                    WHERE user_roles.user_id = users.id
+                    ORDER BY role
+                ) AS "roles!"
+            FROM users
+            WHERE id = $1
+            "#,
+            Uuid::from(id)
+        )
+        .fetch_optional(executor)
+        .await
+        .map_err(|e| UserRepositoryError::QueryFailed(e.into()))?;
+
+        match row {
+            Some(row) => Ok(Some(User::try_from(row)?)),
+            None => Ok(None),
+        }
+    }
+
     pub async fn find_by_email<'e, E>(
         executor: E,
         email: &Email,
@@ -20,10 +61,17 @@ impl SqlxUserRepository {
             UserRow,
             r#"
             SELECT
-                id, email, password_hash,
+                id, email, password_hash, email_verified_at,
+                status, status_changed_at, locked_until,
+                credentials_changed_at, password_reset_required_at,
                 created_at, created_by, created_pgm_cd, created_tx_id,
                 updated_at, updated_by, updated_pgm_cd, updated_tx_id,
-                lock_no
+                lock_no,
+                ARRAY(
+                    SELECT role FROM user_roles
+                    WHERE user_roles.user_id = users.id
+                    ORDER BY role
+                ) AS "roles!"
             FROM users
             WHERE email = $1
             "#,
@@ -39,65 +87,196 @@ impl SqlxUserRepository {
         }
     }
 
-    pub async fn save<'e, E, C>(
+    pub async fn save<'e, E>(
         executor: E,
         user: &User,
-        clock: &C,
+        audit: &AuditStamp,
     ) -> Result<(), UserRepositoryError>
     where
         E: sqlx::Executor<'e, Database = Postgres>,
-        C: domain::clock::Clock,
     {
-        let now = clock.now();
-        let system_name = "auth-system";
-        let pgm_cd = "auth-user-mgmt";
-        let tx_id = "tx-none";
+        let now = audit.at;
+        let actor = audit.by.as_str();
+        let pgm_cd = audit.pgm_cd;
+        let tx_id = audit.tx_id.as_str();
+
+        let roles: Vec<String> = user
+            .roles()
+            .iter()
+            .map(|r| r.as_str().to_string())
+            .collect();
+
+        // ユーザー本体とロールを 1 文で保存し、保存できた行数で競合を判定する
+        let saved = if user.version().is_new() {
+            // 同じ ID が既に登録されている場合は何もせず、競合として扱う
+            sqlx::query_scalar!(
+                r#"
+                WITH saved AS (
+                    INSERT INTO users (
+                        id, email, password_hash, email_verified_at,
+                        status, status_changed_at, locked_until,
+                        credentials_changed_at, password_reset_required_at,
+                        created_at, created_by, created_pgm_cd, created_tx_id,
+                        updated_at, updated_by, updated_pgm_cd, updated_tx_id
+                    ) VALUES (
+                        $1, $2, $3, $4, $10, $11, $12, $13, $14,
+                        $5, $6, $7, $8, $5, $6, $7, $8
+                    )
+                    ON CONFLICT (id) DO NOTHING
+                    RETURNING id
+                ), granted AS (
+                    INSERT INTO user_roles (
+                        user_id, role,
+                        created_at, created_by, created_pgm_cd, created_tx_id,
+                        updated_at, updated_by, updated_pgm_cd, updated_tx_id
+                    )
+                    SELECT saved.id, r.role, $5, $6, $7, $8, $5, $6, $7, $8
+                    FROM saved CROSS JOIN UNNEST($9::VARCHAR[]) AS r(role)
+                )
+                SELECT COUNT(*) AS "saved!" FROM saved
+                "#,
+                Uuid::from(user.id()),
+                user.email().as_ref(),
+                user.password_hash().as_ref(),
+                user.email_verification().verified_at(),
+                now,
+                actor,
+                pgm_cd,
+                tx_id,
+                &roles,
+                user.status().as_str(),
+                user.state().changed_at(),
+                user.state().locked_until(),
+                user.credentials_changed_at(),
+                user.password_reset_required_at(),
+            )
+            .fetch_one(executor)
+            .await
+        } else {
+            sqlx::query_scalar!(
+                r#"
+                WITH saved AS (
+                    UPDATE users SET
+                        email = $2,
+                        password_hash = $3,
+                        email_verified_at = $4,
+                        status = $11,
+                        status_changed_at = $12,
+                        locked_until = $13,
+                        credentials_changed_at = $14,
+                        password_reset_required_at = $15,
+                        updated_at = $5,
+                        updated_by = $6,
+                        updated_pgm_cd = $7,
+                        updated_tx_id = $8,
+                        lock_no = lock_no + 1
+                    WHERE id = $1 AND lock_no = $9
+                    RETURNING id
+                ), revoked AS (
+                    DELETE FROM user_roles
+                    WHERE user_id IN (SELECT id FROM saved) AND NOT (role = ANY($10::VARCHAR[]))
+                ), granted AS (
+                    INSERT INTO user_roles (
+                        user_id, role,
+                        created_at, created_by, created_pgm_cd, created_tx_id,
+                        updated_at, updated_by, updated_pgm_cd, updated_tx_id
+                    )
+                    SELECT saved.id, r.role, $5, $6, $7, $8, $5, $6, $7, $8
+                    FROM saved CROSS JOIN UNNEST($10::VARCHAR[]) AS r(role)
+                    ON CONFLICT (user_id, role) DO NOTHING
+                )
+                SELECT COUNT(*) AS "saved!" FROM saved
+                "#,
+                Uuid::from(user.id()),
+                user.email().as_ref(),
+                user.password_hash().as_ref(),
+                user.email_verification().verified_at(),
+                now,
+                actor,
+                pgm_cd,
+                tx_id,
+                i32::from(user.version()),
+                &roles,
+                user.status().as_str(),
+                user.state().changed_at(),
+                user.state().locked_until(),
+                user.credentials_changed_at(),
+                user.password_reset_required_at(),
+            )
+            .fetch_one(executor)
+            .await
+        }
+        .map_err(|e| UserRepositoryError::QueryFailed(e.into()))?;
+
+        if saved == 0 {
+            return Err(UserRepositoryError::ConcurrentModification);
+        }
+
+        Ok(())
+    }
+
+    pub async fn search<'e, E>(
+        executor: E,
+        criteria: &UserSearchCriteria,
+    ) -> Result<Vec<User>, UserRepositoryError>
+    where
+        E: sqlx::Executor<'e, Database = Postgres>,
+    {
+        let email_pattern = criteria
+            .email_prefix
+            .as_deref()
+            .map(|prefix| format!("{}%", escape_like(prefix)));
 
-        sqlx::query!(
+        let rows = sqlx::query_as!(
+            UserRow,
             r#"
-            INSERT INTO users (
-                id, email, password_hash,
+            SELECT
+                id, email, password_hash, email_verified_at,
+                status, status_changed_at, locked_until,
+                credentials_changed_at, password_reset_required_at,
                 created_at, created_by, created_pgm_cd, created_tx_id,
                 updated_at, updated_by, updated_pgm_cd, updated_tx_id,
-                lock_no
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
-            ON CONFLICT (id) DO UPDATE SET
-                email = EXCLUDED.email,
-                password_hash = EXCLUDED.password_hash,
-                updated_at = $8,
-                updated_by = $9,
-                updated_pgm_cd = $10,
-                updated_tx_id = $11,
-                lock_no = users.lock_no + 1
-            WHERE users.lock_no = $12
+                lock_no,
+                ARRAY(
+                    SELECT role FROM user_roles
+                    WHERE user_roles.user_id = users.id
+                    ORDER BY role
+                ) AS "roles!"
+            FROM users
+            WHERE ($1::UUID IS NULL OR id > $1)
+                AND ($2::TEXT IS NULL OR email LIKE $2 ESCAPE '\')
+                AND ($3::VARCHAR IS NULL OR status = $3)
+            ORDER BY id
+            LIMIT $4
             "#,
-            Uuid::from(user.id()),
-            user.email().as_ref(),
-            user.password_hash().as_ref(),
-            now,
-            system_name,
-            pgm_cd,
-            tx_id,
-            now,
-            system_name,
-            pgm_cd,
-            tx_id,
-            1
+            criteria.after.map(Uuid::from),
+            email_pattern,
+            criteria.status.map(|status| status.as_str()),
+            i64::from(criteria.limit),
         )
-        .execute(executor)
+        .fetch_all(executor)
         .await
         .map_err(|e| UserRepositoryError::QueryFailed(e.into()))?;
 
-        Ok(())
+        rows.into_iter().map(User::try_from).collect()
     }
 }
 
+/// LIKE のワイルドカードを含む入力をリテラルとして扱うためにエスケープする。
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
 #[allow(dead_code)]
 #[derive(Debug, sqlx::FromRow)]
 struct UserRow {
     id: Uuid,
     email: String,
     password_hash: String,
+    email_verified_at: Option<DateTime<Utc>>,
     created_at: DateTime<Utc>,
     created_by: String,
     created_pgm_cd: String,
@@ -106,7 +285,13 @@ struct UserRow {
     updated_by: String,
     updated_pgm_cd: String,
     updated_tx_id: String,
+    status: String,
+    status_changed_at: Option<DateTime<Utc>>,
+    locked_until: Option<DateTime<Utc>>,
+    credentials_changed_at: Option<DateTime<Utc>>,
+    password_reset_required_at: Option<DateTime<Utc>>,
     lock_no: i32,
+    roles: Vec<String>,
 }
 
 impl TryFrom<UserRow> for User {
@@ -115,11 +300,29 @@ impl TryFrom<UserRow> for User {
     fn try_from(row: UserRow) -> Result<Self, Self::Error> {
         let email =
             Email::try_from(row.email).map_err(|e| UserRepositoryError::MappingFailed(e.into()))?;
+        let roles = row
+            .roles
+            .iter()
+            .map(|role| role.parse::<Role>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| UserRepositoryError::MappingFailed(e.into()))?;
+        let status = row
+            .status
+            .parse::<AccountStatus>()
+            .map_err(|e| UserRepositoryError::MappingFailed(e.into()))?;
+        let state = AccountState::reconstruct(status, row.status_changed_at, row.locked_until)
+            .map_err(|e| UserRepositoryError::MappingFailed(e.into()))?;
 
-        Ok(User::new(
+        Ok(User::reconstruct(
             UserId::from(row.id),
             email,
             PasswordHash::from_str_unchecked(row.password_hash),
+            EmailVerificationStatus::from_verified_at(row.email_verified_at),
+            roles,
+            state,
+            row.credentials_changed_at,
+            row.password_reset_required_at,
+            Version::from(row.lock_no),
         ))
     }
 }
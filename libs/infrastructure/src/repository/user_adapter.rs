@@ -1,26 +1,46 @@
 use async_trait::async_trait;
 use domain::clock::Clock;
-use domain::models::user::{Email, User, UserRepository, UserRepositoryError};
+use domain::models::user::{
+    Email, User, UserId, UserRepository, UserRepositoryError, UserSearchCriteria,
+};
 use sqlx::{Postgres, Transaction};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
+use crate::repository::audit::TxAudit;
 use crate::repository::user::SqlxUserRepository;
 
 /// トランザクションを保持し、`UserRepository` トレイトを実装するアダプター。
 pub struct SqlxUserRepoAdapter<'a, C: Clock> {
     transaction: Arc<Mutex<Option<Transaction<'a, Postgres>>>>,
     clock: Arc<C>,
+    audit: Arc<TxAudit>,
 }
 
 impl<'a, C: Clock> SqlxUserRepoAdapter<'a, C> {
-    pub fn new(transaction: Arc<Mutex<Option<Transaction<'a, Postgres>>>>, clock: Arc<C>) -> Self {
-        Self { transaction, clock }
+    pub fn new(
+        transaction: Arc<Mutex<Option<Transaction<'a, Postgres>>>>,
+        clock: Arc<C>,
+        audit: Arc<TxAudit>,
+    ) -> Self {
+        Self {
+            transaction,
+            clock,
+            audit,
+        }
     }
 }
 
 #[async_trait]
 impl<'a, C: Clock> UserRepository for SqlxUserRepoAdapter<'a, C> {
+    async fn find_by_id(&self, id: UserId) -> Result<Option<User>, UserRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            UserRepositoryError::Unexpected(anyhow::anyhow!("Transaction already closed or taken"))
+        })?;
+        SqlxUserRepository::find_by_id(&mut **tx, id).await
+    }
+
     async fn find_by_email(&self, email: &Email) -> Result<Option<User>, UserRepositoryError> {
         let mut guard = self.transaction.lock().await;
         let tx = guard.as_mut().ok_or_else(|| {
@@ -34,6 +54,17 @@ impl<'a, C: Clock> UserRepository for SqlxUserRepoAdapter<'a, C> {
         let tx = guard.as_mut().ok_or_else(|| {
             UserRepositoryError::Unexpected(anyhow::anyhow!("Transaction already closed or taken"))
         })?;
-        SqlxUserRepository::save(&mut **tx, user, &*self.clock).await
+        SqlxUserRepository::save(&mut **tx, user, &self.audit.stamp(self.clock.now())).await
+    }
+
+    async fn search(
+        &self,
+        criteria: &UserSearchCriteria,
+    ) -> Result<Vec<User>, UserRepositoryError> {
+        let mut guard = self.transaction.lock().await;
+        let tx = guard.as_mut().ok_or_else(|| {
+            UserRepositoryError::Unexpected(anyhow::anyhow!("Transaction already closed or taken"))
+        })?;
+        SqlxUserRepository::search(&mut **tx, criteria).await
     }
 }
@@ -0,0 +1,266 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+use crate::auth::{AuthService, AuthToken, Claims, TokenRevocationStore};
+use crate::error::{AuthServiceError, UseCaseResult};
+use domain::error::DomainError;
+use domain::models::auth::AuthError;
+use domain::models::user::User;
+use domain::repository::tx::TransactionManager;
+use domain::{Actor, AuditContext, Clock};
+
+/// 監査カラムに記録するプログラムコード
+const PROGRAM_CODE: &str = "auth-authenticate";
+
+/// リクエストに付与されたアクセストークンを検証するユースケース。
+#[async_trait]
+pub trait AuthenticateUseCase: Send + Sync {
+    async fn authenticate(&self, token: &AuthToken) -> UseCaseResult<Claims>;
+}
+
+pub struct AuthenticateUseCaseImpl<TM, C>
+where
+    TM: TransactionManager,
+    C: Clock,
+{
+    transaction_manager: Arc<TM>,
+    auth_service: Arc<dyn AuthService>,
+    revocation_store: Arc<dyn TokenRevocationStore>,
+    clock: Arc<C>,
+}
+
+impl<TM, C> AuthenticateUseCaseImpl<TM, C>
+where
+    TM: TransactionManager,
+    C: Clock,
+{
+    pub fn new(
+        transaction_manager: Arc<TM>,
+        auth_service: Arc<dyn AuthService>,
+        revocation_store: Arc<dyn TokenRevocationStore>,
+        clock: Arc<C>,
+    ) -> Self {
+        Self {
+            transaction_manager,
+            auth_service,
+            revocation_store,
+            clock,
+        }
+    }
+}
+
+#[async_trait]
+impl<TM, C> AuthenticateUseCase for AuthenticateUseCaseImpl<TM, C>
+where
+    TM: TransactionManager,
+    C: Clock + 'static,
+{
+    async fn authenticate(&self, token: &AuthToken) -> UseCaseResult<Claims> {
+        // 署名と有効期限の検証
+        let claims = self.auth_service.verify_token(token)?;
+
+        // ログアウト等によるサーバー側での失効の検証
+        if self.revocation_store.is_revoked(claims.jti).await? {
+            return Err(AuthServiceError::TokenRevoked.into());
+        }
+
+        // 発行後に停止・ロック・削除されたアカウントのトークンを拒否する
+        let now = self.clock.now();
+        let audit = AuditContext::new(Actor::User(claims.sub), PROGRAM_CODE);
+        let user = domain::tx!(self.transaction_manager, audit, |factory| {
+            let user = factory
+                .user_repository()
+                .find_by_id(claims.sub)
+                .await?
+                .ok_or(AuthError::InvalidCredentials)?;
+            user.ensure_active(now)?;
+            Ok::<User, DomainError>(user)
+        })
+        .await?;
+
+        // パスワードの変更前に発行されたトークン（他の端末のセッション）を拒否する
+        let issued_at = i64::try_from(claims.iat)
+            .ok()
+            .and_then(|iat| DateTime::from_timestamp(iat, 0))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+        if user.is_token_issued_before_credentials_change(issued_at) {
+            return Err(AuthServiceError::TokenRevoked.into());
+        }
+
+        // 発行後のロールの変更を即座に反映するため、認可には現在のロールから導出した権限を使用する
+        Ok(Claims {
+            roles: user.roles().to_vec(),
+            permissions: user.permissions(),
+            ..claims
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::test_utils::utils::*;
+    use crate::error::UseCaseError;
+    use domain::models::user::{Email, PasswordHash, Permission, Role, User, UserId};
+    use domain::test_utils::FixedClock;
+    use rstest::*;
+
+    #[fixture]
+    fn claims() -> Claims {
+        Claims {
+            sub: UserId::from(uuid::Uuid::now_v7()),
+            iat: 0,
+            exp: usize::MAX,
+            jti: uuid::Uuid::new_v4(),
+            roles: vec![],
+            permissions: vec![],
+            custom: Default::default(),
+        }
+    }
+
+    fn build_usecase(
+        claims: Claims,
+        store: Arc<StubTokenRevocationStore>,
+        repo: StubUserRepository,
+    ) -> AuthenticateUseCaseImpl<StubTransactionManager, FixedClock> {
+        let auth_service = Arc::new(StubAuthService {
+            issue_token_result: Arc::new(|| unreachable!()),
+            verify_token_result: Arc::new(move || Ok(claims.clone())),
+        });
+        let tm = Arc::new(StubTransactionManager {
+            factory: Arc::new(StubRepositoryFactory::new(Arc::new(repo))),
+        });
+        AuthenticateUseCaseImpl::new(
+            tm,
+            auth_service,
+            store,
+            Arc::new(FixedClock::new(chrono::Utc::now())),
+        )
+    }
+
+    fn user_of(claims: &Claims, email: Email) -> User {
+        User::new(claims.sub, email, PasswordHash::from_str_unchecked("hash"))
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_authenticate_valid_token(claims: Claims, valid_email: Email) {
+        let usecase = build_usecase(
+            claims.clone(),
+            Arc::new(StubTokenRevocationStore::default()),
+            StubUserRepository::with_user(user_of(&claims, valid_email)),
+        );
+
+        let result = usecase.authenticate(&AuthToken::from("token")).await;
+        assert_eq!(result.unwrap().jti, claims.jti);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_authenticate_revoked_token(claims: Claims, valid_email: Email) {
+        let store = Arc::new(StubTokenRevocationStore::default());
+        store.revoke(claims.jti, claims.exp).await.unwrap();
+        let user = user_of(&claims, valid_email);
+        let usecase = build_usecase(claims, store, StubUserRepository::with_user(user));
+
+        let result = usecase.authenticate(&AuthToken::from("token")).await;
+        assert!(matches!(result, Err(UseCaseError::Authentication(_))));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_authenticate_refuses_non_active_account(claims: Claims, valid_email: Email) {
+        let now = chrono::Utc::now();
+        let user = user_of(&claims, valid_email);
+
+        let (suspended, _) = user.clone().suspend(now).unwrap();
+        let usecase = build_usecase(
+            claims.clone(),
+            Arc::new(StubTokenRevocationStore::default()),
+            StubUserRepository::with_user(suspended),
+        );
+        let result = usecase.authenticate(&AuthToken::from("token")).await;
+        assert!(matches!(result, Err(UseCaseError::Forbidden(_))));
+
+        let (deleted, _) = user.delete(now).unwrap();
+        let usecase = build_usecase(
+            claims.clone(),
+            Arc::new(StubTokenRevocationStore::default()),
+            StubUserRepository::with_user(deleted),
+        );
+        let result = usecase.authenticate(&AuthToken::from("token")).await;
+        assert!(matches!(result, Err(UseCaseError::Authentication(_))));
+
+        let usecase = build_usecase(
+            claims,
+            Arc::new(StubTokenRevocationStore::default()),
+            StubUserRepository::default(),
+        );
+        let result = usecase.authenticate(&AuthToken::from("token")).await;
+        assert!(matches!(result, Err(UseCaseError::Authentication(_))));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_authenticate_rejects_token_issued_before_password_change(
+        claims: Claims,
+        valid_email: Email,
+    ) {
+        let changed_at = chrono::Utc::now();
+        let (user, _) = user_of(&claims, valid_email)
+            .change_password(PasswordHash::from_str_unchecked("new-hash"), changed_at);
+
+        let stale = Claims {
+            iat: (changed_at.timestamp() - 60) as usize,
+            ..claims.clone()
+        };
+        let usecase = build_usecase(
+            stale,
+            Arc::new(StubTokenRevocationStore::default()),
+            StubUserRepository::with_user(user.clone()),
+        );
+        let result = usecase.authenticate(&AuthToken::from("token")).await;
+        assert!(matches!(result, Err(UseCaseError::Authentication(_))));
+
+        // 変更後に発行されたトークンは受け付ける
+        let fresh = Claims {
+            iat: changed_at.timestamp() as usize,
+            ..claims
+        };
+        let usecase = build_usecase(
+            fresh,
+            Arc::new(StubTokenRevocationStore::default()),
+            StubUserRepository::with_user(user),
+        );
+        assert!(
+            usecase
+                .authenticate(&AuthToken::from("token"))
+                .await
+                .is_ok()
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_authenticate_uses_current_roles(claims: Claims, valid_email: Email) {
+        // 管理者として発行された後に降格されたユーザー
+        let issued_as_admin = Claims {
+            roles: vec![Role::User, Role::Admin],
+            permissions: Role::permissions_of(&[Role::User, Role::Admin]),
+            ..claims.clone()
+        };
+        let usecase = build_usecase(
+            issued_as_admin,
+            Arc::new(StubTokenRevocationStore::default()),
+            StubUserRepository::with_user(user_of(&claims, valid_email)),
+        );
+
+        let authenticated = usecase
+            .authenticate(&AuthToken::from("token"))
+            .await
+            .unwrap();
+        assert_eq!(authenticated.roles, vec![Role::User]);
+        assert!(!authenticated.has_permission(Permission::UsersWrite));
+    }
+}
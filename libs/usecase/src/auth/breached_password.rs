@@ -0,0 +1,26 @@
+use crate::error::{BreachedPasswordError, UseCaseResult};
+use async_trait::async_trait;
+use domain::models::auth::RawPassword;
+use domain::models::user::PasswordError;
+
+/// 過去に漏洩したパスワードの集合を照会するポート。
+///
+/// 実装はパスワードそのものを外部へ渡さず、ハッシュの接頭辞による範囲照会（k-匿名性）で判定すること。
+#[async_trait]
+pub trait BreachedPasswordChecker: Send + Sync {
+    /// 漏洩済みのパスワードに含まれるか
+    async fn is_breached(&self, password: &RawPassword) -> Result<bool, BreachedPasswordError>;
+}
+
+/// 新しく設定するパスワードが漏洩済みでないことを検証する。
+///
+/// 照合を設定時と揃えるため、正規化済みのパスワードを渡すこと。
+pub async fn ensure_not_breached(
+    checker: &dyn BreachedPasswordChecker,
+    password: &RawPassword,
+) -> UseCaseResult<()> {
+    if checker.is_breached(password).await? {
+        return Err(PasswordError::Breached.into());
+    }
+    Ok(())
+}
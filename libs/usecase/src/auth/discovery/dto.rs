@@ -0,0 +1,15 @@
+use crate::auth::JsonWebKey;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JwksDto {
+    pub keys: Vec<JsonWebKey>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DiscoveryDto {
+    /// トークンの発行者（API の公開 URL）
+    pub issuer: String,
+    /// 公開鍵で検証できる署名アルゴリズム
+    pub signing_algorithms: Vec<String>,
+}
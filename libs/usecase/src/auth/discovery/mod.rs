@@ -0,0 +1,101 @@
+pub mod dto;
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use self::dto::{DiscoveryDto, JwksDto};
+use crate::auth::AuthService;
+
+/// トークンを検証する外部サービス向けに、発行者と公開鍵を公開するユースケース。
+#[async_trait]
+pub trait DiscoveryUseCase: Send + Sync {
+    /// 署名の検証に使用できる公開鍵の一覧
+    async fn get_jwks(&self) -> JwksDto;
+
+    /// 発行者と対応する署名アルゴリズム
+    async fn get_discovery(&self) -> DiscoveryDto;
+}
+
+pub struct DiscoveryUseCaseImpl {
+    auth_service: Arc<dyn AuthService>,
+    issuer: String,
+}
+
+impl DiscoveryUseCaseImpl {
+    pub fn new(auth_service: Arc<dyn AuthService>, issuer: impl Into<String>) -> Self {
+        Self {
+            auth_service,
+            issuer: issuer.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl DiscoveryUseCase for DiscoveryUseCaseImpl {
+    async fn get_jwks(&self) -> JwksDto {
+        JwksDto {
+            keys: self.auth_service.verification_keys(),
+        }
+    }
+
+    async fn get_discovery(&self) -> DiscoveryDto {
+        let mut signing_algorithms: Vec<String> = Vec::new();
+        for key in self.auth_service.verification_keys() {
+            if !signing_algorithms.contains(&key.alg) {
+                signing_algorithms.push(key.alg);
+            }
+        }
+        DiscoveryDto {
+            issuer: self.issuer.clone(),
+            signing_algorithms,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::{AuthToken, Claims, JsonWebKey};
+    use crate::error::AuthServiceError;
+    use domain::models::user::{Role, UserId};
+
+    struct KeysOnlyAuthService(Vec<JsonWebKey>);
+
+    impl AuthService for KeysOnlyAuthService {
+        fn issue_token(&self, _: UserId, _: &[Role]) -> Result<AuthToken, AuthServiceError> {
+            unreachable!()
+        }
+        fn verify_token(&self, _: &AuthToken) -> Result<Claims, AuthServiceError> {
+            unreachable!()
+        }
+        fn verification_keys(&self) -> Vec<JsonWebKey> {
+            self.0.clone()
+        }
+    }
+
+    fn key(kid: &str, alg: &str) -> JsonWebKey {
+        JsonWebKey {
+            kty: if alg == "EdDSA" { "OKP" } else { "RSA" }.to_string(),
+            kid: kid.to_string(),
+            alg: alg.to_string(),
+            crv: None,
+            x: None,
+            n: None,
+            e: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_lists_each_signing_algorithm_once() {
+        let keys = vec![key("a", "RS256"), key("b", "EdDSA"), key("c", "EdDSA")];
+        let usecase = DiscoveryUseCaseImpl::new(
+            Arc::new(KeysOnlyAuthService(keys.clone())),
+            "https://auth.example.com",
+        );
+
+        assert_eq!(usecase.get_jwks().await.keys, keys);
+        let discovery = usecase.get_discovery().await;
+        assert_eq!(discovery.issuer, "https://auth.example.com");
+        assert_eq!(discovery.signing_algorithms, vec!["RS256", "EdDSA"]);
+    }
+}
@@ -0,0 +1,13 @@
+use sensitive_data::{EmailRule, SecretRule, Sensitive};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifyEmailCommand {
+    /// 確認メールで送付されたトークン
+    pub token: Sensitive<String, SecretRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResendVerificationEmailCommand {
+    pub email: Sensitive<String, EmailRule>,
+}
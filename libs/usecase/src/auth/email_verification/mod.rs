@@ -0,0 +1,469 @@
+pub mod command;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+pub use self::command::{ResendVerificationEmailCommand, VerifyEmailCommand};
+use crate::auth::{EmailVerificationToken, EmailVerificationTokenService};
+use crate::error::{AuthServiceError, UseCaseResult};
+use crate::event::{EventHandler, EventSink};
+use crate::mailer::{Mail, MailMessage, Mailer};
+use domain::models::user::{
+    EMAIL_VERIFICATION_TOKEN_TTL, Email, EmailVerificationDispatch, EmailVerificationError, User,
+    UserEvent, UserId, UserIdentity,
+};
+use domain::repository::tx::TransactionManager;
+use domain::{Actor, AuditContext, Clock, DomainEvent};
+
+/// 監査カラムに記録するプログラムコード
+const PROGRAM_CODE: &str = "auth-email-verify";
+
+/// メールアドレスの確認と、確認メールの再送を行うユースケース。
+#[async_trait]
+pub trait EmailVerificationUseCase: Send + Sync {
+    /// トークンを検証してメールアドレスを確認済みにする
+    async fn verify_email(&self, command: VerifyEmailCommand) -> UseCaseResult<()>;
+
+    /// 確認メールを再送する
+    ///
+    /// アカウントの有無や状態を推測されないよう、未登録・確認済み・クールダウン中の場合も
+    /// 送信せずに成功として扱う。
+    async fn resend_verification_email(
+        &self,
+        command: ResendVerificationEmailCommand,
+    ) -> UseCaseResult<()>;
+}
+
+/// 確認用トークンを発行し、確認メールを送信する。
+///
+/// 新規登録と再送で共通して使用する。
+async fn send_verification_email(
+    token_service: &dyn EmailVerificationTokenService,
+    mailer: &dyn Mailer,
+    user_id: UserId,
+    email: &Email,
+    now: DateTime<Utc>,
+) -> UseCaseResult<()> {
+    let expires_at = now + EMAIL_VERIFICATION_TOKEN_TTL;
+    let token = token_service.issue(user_id, email, expires_at)?;
+    mailer
+        .send(Mail {
+            to: email.clone(),
+            message: MailMessage::EmailVerification { token, expires_at },
+        })
+        .await?;
+    Ok(())
+}
+
+/// 新規登録（`UserRegistered`）を受けて確認メールを送信するイベントハンドラー。
+pub struct VerificationMailHandler {
+    token_service: Arc<dyn EmailVerificationTokenService>,
+    mailer: Arc<dyn Mailer>,
+}
+
+impl VerificationMailHandler {
+    pub fn new(
+        token_service: Arc<dyn EmailVerificationTokenService>,
+        mailer: Arc<dyn Mailer>,
+    ) -> Self {
+        Self {
+            token_service,
+            mailer,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler for VerificationMailHandler {
+    async fn handle(&self, event: &DomainEvent) -> UseCaseResult<()> {
+        match event {
+            DomainEvent::User(UserEvent::UserRegistered {
+                user_id,
+                email,
+                occurred_at,
+            }) => {
+                send_verification_email(
+                    &*self.token_service,
+                    &*self.mailer,
+                    *user_id,
+                    email,
+                    *occurred_at,
+                )
+                .await
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+pub struct EmailVerificationUseCaseImpl<TM, C>
+where
+    TM: TransactionManager,
+    C: Clock,
+{
+    transaction_manager: Arc<TM>,
+    token_service: Arc<dyn EmailVerificationTokenService>,
+    mailer: Arc<dyn Mailer>,
+    event_sink: Arc<dyn EventSink>,
+    clock: Arc<C>,
+}
+
+impl<TM, C> EmailVerificationUseCaseImpl<TM, C>
+where
+    TM: TransactionManager,
+    C: Clock,
+{
+    pub fn new(
+        transaction_manager: Arc<TM>,
+        token_service: Arc<dyn EmailVerificationTokenService>,
+        mailer: Arc<dyn Mailer>,
+        event_sink: Arc<dyn EventSink>,
+        clock: Arc<C>,
+    ) -> Self {
+        Self {
+            transaction_manager,
+            token_service,
+            mailer,
+            event_sink,
+            clock,
+        }
+    }
+}
+
+#[async_trait]
+impl<TM, C> EmailVerificationUseCase for EmailVerificationUseCaseImpl<TM, C>
+where
+    TM: TransactionManager,
+    C: Clock + 'static,
+{
+    async fn verify_email(&self, command: VerifyEmailCommand) -> UseCaseResult<()> {
+        let claims = self
+            .token_service
+            .verify(&EmailVerificationToken::from(command.token.into_inner()))
+            .map_err(|e| match e {
+                AuthServiceError::TokenExpired | AuthServiceError::InvalidToken => {
+                    EmailVerificationError::InvalidToken.into()
+                }
+                e => crate::error::UseCaseError::from(e),
+            })?;
+        let now = self.clock.now();
+
+        let audit = AuditContext::new(Actor::User(claims.sub), PROGRAM_CODE);
+        let events = domain::tx!(self.transaction_manager, audit, |factory| {
+            let user_repo = factory.user_repository();
+            let user = user_repo
+                .find_by_id(claims.sub)
+                .await?
+                .ok_or(EmailVerificationError::InvalidToken)?;
+
+            // 発行後にメールアドレスが変更された場合、古いアドレス宛てのトークンは無効
+            if user.email().as_ref() != &claims.email {
+                return Err(EmailVerificationError::InvalidToken.into());
+            }
+
+            let (user, events) = user.verify_email(now);
+            // 確認済みの場合はイベントが発生せず、更新も不要
+            if events.is_empty() {
+                return Ok(vec![]);
+            }
+            user_repo.save(&user).await?;
+
+            let events: Vec<DomainEvent> = events.into_iter().map(DomainEvent::from).collect();
+            factory.outbox_repository().append(&events).await?;
+
+            Ok::<Vec<DomainEvent>, domain::error::DomainError>(events)
+        })
+        .await?;
+
+        self.event_sink.publish(&events).await;
+
+        Ok(())
+    }
+
+    async fn resend_verification_email(
+        &self,
+        command: ResendVerificationEmailCommand,
+    ) -> UseCaseResult<()> {
+        let email = Email::try_from(command.email.into_inner())?;
+        let now = self.clock.now();
+
+        let audit = AuditContext::new(Actor::Anonymous, PROGRAM_CODE);
+        let target = domain::tx!(self.transaction_manager, audit, |factory| {
+            let Some(user) = factory.user_repository().find_by_email(&email).await? else {
+                return Ok(None);
+            };
+            if user.email_verification().is_verified() {
+                return Ok(None);
+            }
+
+            let dispatch_repo = factory.email_verification_dispatch_repository();
+            let in_cooldown = dispatch_repo
+                .find_by_user_id(user.id())
+                .await?
+                .is_some_and(|dispatch| !dispatch.can_resend(now));
+            if in_cooldown {
+                return Ok(None);
+            }
+            dispatch_repo
+                .save(&EmailVerificationDispatch::record(user.id(), now))
+                .await?;
+
+            Ok::<Option<User>, domain::error::DomainError>(Some(user))
+        })
+        .await?;
+
+        if let Some(user) = target {
+            send_verification_email(
+                &*self.token_service,
+                &*self.mailer,
+                user.id(),
+                user.email(),
+                now,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::test_utils::utils::*;
+    use crate::error::UseCaseError;
+    use domain::models::user::{EMAIL_VERIFICATION_RESEND_COOLDOWN, PasswordHash};
+    use domain::test_utils::FixedClock;
+    use rstest::*;
+
+    struct Fixture {
+        usecase: EmailVerificationUseCaseImpl<StubTransactionManager, FixedClock>,
+        factory: Arc<StubRepositoryFactory>,
+        mailer: Arc<StubMailer>,
+        event_sink: Arc<StubEventSink>,
+    }
+
+    fn build(factory: StubRepositoryFactory, now: DateTime<Utc>) -> Fixture {
+        let factory = Arc::new(factory);
+        let tm = Arc::new(StubTransactionManager {
+            factory: factory.clone(),
+        });
+        let mailer = Arc::new(StubMailer::default());
+        let event_sink = Arc::new(StubEventSink::default());
+        let usecase = EmailVerificationUseCaseImpl::new(
+            tm,
+            Arc::new(StubEmailVerificationTokenService),
+            mailer.clone(),
+            event_sink.clone(),
+            Arc::new(FixedClock::new(now)),
+        );
+        Fixture {
+            usecase,
+            factory,
+            mailer,
+            event_sink,
+        }
+    }
+
+    #[fixture]
+    fn user(valid_email: Email) -> User {
+        User::new(
+            UserId::from(uuid::Uuid::now_v7()),
+            valid_email,
+            PasswordHash::from_str_unchecked("hash"),
+        )
+    }
+
+    fn token_for(user_id: UserId, email: &str, now: DateTime<Utc>) -> String {
+        StubEmailVerificationTokenService
+            .issue(user_id, &Email::try_from(email).unwrap(), now)
+            .unwrap()
+            .expose_as_str()
+            .to_string()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_verify_email(user: User) {
+        let now = Utc::now();
+        let user_id = user.id();
+        let token = token_for(user.id(), user.email().as_ref(), now);
+        let fixture = build(
+            StubRepositoryFactory::new(Arc::new(StubUserRepository::with_user(user))),
+            now,
+        );
+
+        fixture
+            .usecase
+            .verify_email(VerifyEmailCommand {
+                token: token.into(),
+            })
+            .await
+            .unwrap();
+
+        let saved = fixture.factory.repo.saved_users();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].email_verification().verified_at(), Some(now));
+
+        // 確認イベントが Outbox に記録され、EventSink へ引き渡されること
+        let expected: Vec<DomainEvent> = vec![
+            UserEvent::EmailVerified {
+                user_id,
+                occurred_at: now,
+            }
+            .into(),
+        ];
+        assert_eq!(fixture.factory.outbox_repo.events(), expected);
+        assert_eq!(fixture.event_sink.published(), expected);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_verify_email_is_idempotent(user: User) {
+        let now = Utc::now();
+        let token = token_for(user.id(), user.email().as_ref(), now);
+        let (verified, _) = user.verify_email(now);
+        let fixture = build(
+            StubRepositoryFactory::new(Arc::new(StubUserRepository::with_user(verified))),
+            now,
+        );
+
+        fixture
+            .usecase
+            .verify_email(VerifyEmailCommand {
+                token: token.into(),
+            })
+            .await
+            .unwrap();
+
+        assert!(fixture.factory.repo.saved_users().is_empty());
+        assert!(fixture.event_sink.published().is_empty());
+    }
+
+    #[rstest]
+    #[case::other_email("changed@example.com")]
+    #[case::malformed("")]
+    #[tokio::test]
+    async fn test_verify_email_rejects_invalid_token(user: User, #[case] email: &str) {
+        let now = Utc::now();
+        let token = if email.is_empty() {
+            "malformed".to_string()
+        } else {
+            token_for(user.id(), email, now)
+        };
+        let fixture = build(
+            StubRepositoryFactory::new(Arc::new(StubUserRepository::with_user(user))),
+            now,
+        );
+
+        let result = fixture
+            .usecase
+            .verify_email(VerifyEmailCommand {
+                token: token.into(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::InvalidInput(_))));
+        assert!(fixture.factory.repo.saved_users().is_empty());
+    }
+
+    #[rstest]
+    #[case::in_cooldown(EMAIL_VERIFICATION_RESEND_COOLDOWN - chrono::Duration::seconds(1), false)]
+    #[case::after_cooldown(EMAIL_VERIFICATION_RESEND_COOLDOWN, true)]
+    #[tokio::test]
+    async fn test_resend_respects_cooldown(
+        user: User,
+        #[case] elapsed: chrono::Duration,
+        #[case] expected_sent: bool,
+    ) {
+        let now = Utc::now();
+        let email = user.email().to_string();
+        let dispatch_repo = Arc::new(StubEmailVerificationDispatchRepository::with_dispatch(
+            EmailVerificationDispatch::record(user.id(), now - elapsed),
+        ));
+        let fixture = build(
+            StubRepositoryFactory {
+                email_verification_dispatch_repo: dispatch_repo.clone(),
+                ..StubRepositoryFactory::new(Arc::new(StubUserRepository::with_user(user)))
+            },
+            now,
+        );
+
+        fixture
+            .usecase
+            .resend_verification_email(ResendVerificationEmailCommand {
+                email: email.into(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(fixture.mailer.sent().len(), usize::from(expected_sent));
+        let last_sent_at = dispatch_repo.dispatches()[0].last_sent_at();
+        assert_eq!(last_sent_at == now, expected_sent);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_resend_is_silent_for_verified_user(user: User) {
+        let now = Utc::now();
+        let email = user.email().to_string();
+        let fixture = build(
+            StubRepositoryFactory::new(Arc::new(StubUserRepository::with_user(
+                user.verify_email(now).0,
+            ))),
+            now,
+        );
+
+        fixture
+            .usecase
+            .resend_verification_email(ResendVerificationEmailCommand {
+                email: email.into(),
+            })
+            .await
+            .unwrap();
+
+        assert!(fixture.mailer.sent().is_empty());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler_sends_mail_on_registration(user: User) {
+        let now = Utc::now();
+        let mailer = Arc::new(StubMailer::default());
+        let handler = VerificationMailHandler::new(
+            Arc::new(StubEmailVerificationTokenService),
+            mailer.clone(),
+        );
+
+        handler
+            .handle(
+                &UserEvent::UserRegistered {
+                    user_id: user.id(),
+                    email: user.email().clone(),
+                    occurred_at: now,
+                }
+                .into(),
+            )
+            .await
+            .unwrap();
+        handler
+            .handle(
+                &UserEvent::PasswordChanged {
+                    user_id: user.id(),
+                    occurred_at: now,
+                }
+                .into(),
+            )
+            .await
+            .unwrap();
+
+        let sent = mailer.sent();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(&sent[0].to, user.email());
+        let MailMessage::EmailVerification { expires_at, .. } = &sent[0].message else {
+            panic!("expected a verification mail");
+        };
+        assert_eq!(*expires_at, now + EMAIL_VERIFICATION_TOKEN_TTL);
+    }
+}
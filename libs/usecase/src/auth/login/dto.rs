@@ -1,4 +1,6 @@
 use crate::auth::AuthToken;
+use chrono::{DateTime, Utc};
+use domain::models::auth::OpaqueToken;
 use domain::models::user::{User, UserIdentity};
 use serde::{Deserialize, Serialize};
 
@@ -7,14 +9,30 @@ pub struct LoginResponseDto {
     pub id: uuid::Uuid,
     pub email: String,
     pub token: AuthToken,
+    pub refresh_token: OpaqueToken,
 }
 
 impl LoginResponseDto {
-    pub fn new(user: &User, token: AuthToken) -> Self {
+    pub fn new(user: &User, token: AuthToken, refresh_token: OpaqueToken) -> Self {
         Self {
             id: user.id().into(),
             email: user.email().to_string(),
             token,
+            refresh_token,
         }
     }
 }
+
+/// MFA が有効なユーザーに対して、第二要素の入力を求めるチャレンジ。
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MfaChallengeDto {
+    pub mfa_token: OpaqueToken,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// ログインの結果。MFA が有効な場合はトークンの代わりにチャレンジを返す。
+#[derive(Debug, Serialize, Deserialize)]
+pub enum LoginOutcomeDto {
+    Authenticated(LoginResponseDto),
+    MfaRequired(MfaChallengeDto),
+}
@@ -4,91 +4,251 @@ pub mod query;
 use async_trait::async_trait;
 use std::sync::Arc;
 
-use self::dto::LoginResponseDto;
+use self::dto::{LoginOutcomeDto, LoginResponseDto, MfaChallengeDto};
 pub use self::query::LoginQuery;
-use crate::auth::AuthService;
+use crate::auth::login_attempt::LoginThrottle;
+use crate::auth::{AuthService, LoginAttemptStore};
 use crate::error::UseCaseResult;
-use domain::Clock;
-use domain::models::auth::{AuthError, PasswordService, RawPassword};
-use domain::models::user::{Authenticatable, Email, User, UserIdentity};
+use crate::event::EventSink;
+use domain::error::DomainError;
+use domain::id::IdGenerator;
+use domain::models::auth::{
+    AuthError, LoginThrottleConfig, MfaChallenge, MfaChallengeId, OpaqueTokenService,
+    PasswordService, RawPassword, RefreshToken, RefreshTokenId, ThrottleKey,
+};
+use domain::models::user::{
+    Authenticatable, Email, EmailVerificationPolicy, User, UserIdentity, UserRepositoryError,
+};
 use domain::repository::tx::TransactionManager;
+use domain::{Actor, AuditContext, Clock};
+
+/// 監査カラムに記録するプログラムコード
+const PROGRAM_CODE: &str = "auth-login";
 
 #[async_trait]
 pub trait AuthQueryUseCase: Send + Sync {
-    async fn login(&self, query: LoginQuery) -> UseCaseResult<LoginResponseDto>;
+    async fn login(&self, query: LoginQuery) -> UseCaseResult<LoginOutcomeDto>;
+}
+
+/// パスワード認証に成功した後の結果。
+enum PasswordOutcome {
+    Authenticated(User),
+    /// 第二要素の入力が必要
+    MfaRequired(MfaChallenge),
 }
 
-pub struct AuthQueryUseCaseImpl<TM, PS, C>
+pub struct AuthQueryUseCaseImpl<TM, PS, TS, C, IG>
 where
     TM: TransactionManager,
     PS: PasswordService,
+    TS: OpaqueTokenService,
     C: Clock,
+    IG: IdGenerator<RefreshTokenId> + IdGenerator<MfaChallengeId>,
 {
     transaction_manager: Arc<TM>,
     password_service: Arc<PS>,
+    token_service: Arc<TS>,
     auth_service: Arc<dyn AuthService>,
-    _clock: Arc<C>,
+    event_sink: Arc<dyn EventSink>,
+    clock: Arc<C>,
+    id_generator: Arc<IG>,
+    email_verification_policy: EmailVerificationPolicy,
+    throttle: LoginThrottle,
 }
 
-impl<TM, PS, C> AuthQueryUseCaseImpl<TM, PS, C>
+impl<TM, PS, TS, C, IG> AuthQueryUseCaseImpl<TM, PS, TS, C, IG>
 where
     TM: TransactionManager,
     PS: PasswordService,
+    TS: OpaqueTokenService,
     C: Clock,
+    IG: IdGenerator<RefreshTokenId> + IdGenerator<MfaChallengeId>,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         transaction_manager: Arc<TM>,
         password_service: Arc<PS>,
+        token_service: Arc<TS>,
         auth_service: Arc<dyn AuthService>,
+        event_sink: Arc<dyn EventSink>,
         clock: Arc<C>,
+        id_generator: Arc<IG>,
+        email_verification_policy: EmailVerificationPolicy,
+        attempt_store: Arc<dyn LoginAttemptStore>,
+        throttle: LoginThrottleConfig,
     ) -> Self {
         Self {
             transaction_manager,
             password_service,
+            token_service,
             auth_service,
-            _clock: clock,
+            event_sink,
+            clock,
+            id_generator,
+            email_verification_policy,
+            throttle: LoginThrottle::new(attempt_store, throttle),
         }
     }
 }
 
 #[async_trait]
-impl<TM, PS, C> AuthQueryUseCase for AuthQueryUseCaseImpl<TM, PS, C>
+impl<TM, PS, TS, C, IG> AuthQueryUseCase for AuthQueryUseCaseImpl<TM, PS, TS, C, IG>
 where
     TM: TransactionManager,
     PS: PasswordService + 'static,
+    TS: OpaqueTokenService + 'static,
     C: Clock + 'static,
+    IG: IdGenerator<RefreshTokenId> + IdGenerator<MfaChallengeId> + 'static,
 {
-    async fn login(&self, query: LoginQuery) -> UseCaseResult<LoginResponseDto> {
+    async fn login(&self, query: LoginQuery) -> UseCaseResult<LoginOutcomeDto> {
         let email = Email::try_from(query.email.into_inner())?;
-        let password_service = Arc::clone(&self.password_service);
 
-        let user = domain::tx!(self.transaction_manager, |factory| {
-            let user_repo = factory.user_repository();
+        let refresh_token = self.token_service.generate();
+        let refresh_token_hash = self.token_service.hash(&refresh_token);
+        let refresh_token_id: RefreshTokenId = self.id_generator.generate();
+        let mfa_token = self.token_service.generate();
+        let mfa_token_hash = self.token_service.hash(&mfa_token);
+        let mfa_challenge_id: MfaChallengeId = self.id_generator.generate();
+        let now = self.clock.now();
+        let email_verification_policy = self.email_verification_policy;
 
-            let user = user_repo
-                .find_by_email(&email)
-                .await?
-                .ok_or(AuthError::InvalidCredentials)?;
+        // 未登録のメールアドレスも同じく集計し、ロックアウトの有無で存在を推測させない
+        let account_key = ThrottleKey::Account(email.clone());
+        let client_ip_key = query.client_ip.map(ThrottleKey::ClientIp);
 
-            let is_valid = password_service
-                .verify(
-                    &RawPassword::from(query.password.into_inner()),
-                    user.password_hash(),
-                )
-                .await?;
+        // ハッシュの照合・計算は時間がかかるため、接続を保持しないようトランザクションの外で行う
+        let audit = AuditContext::new(Actor::Anonymous, PROGRAM_CODE);
+        let user = domain::tx!(self.transaction_manager, audit, |factory| {
+            let user = factory.user_repository().find_by_email(&email).await?;
+            Ok::<Option<User>, DomainError>(user)
+        })
+        .await?;
+
+        self.throttle
+            .begin_attempt(&account_key, client_ip_key.as_ref(), now)
+            .await?;
 
-            if !is_valid {
+        // 設定時と同じく正規化してから照合する
+        let password = RawPassword::from(query.password.into_inner()).normalized();
+        let verified = match &user {
+            Some(user) => {
+                self.password_service
+                    .verify(&password, user.password_hash())
+                    .await
+            }
+            None => {
+                // 未登録でも同等の照合を行い、応答時間からアカウントの有無を推測させない
+                self.password_service
+                    .verify(&password, self.password_service.dummy_hash())
+                    .await
+                    .map(|_| false)
+            }
+        };
+        let is_valid = match verified {
+            Ok(is_valid) => is_valid,
+            Err(e) => {
+                self.throttle
+                    .cancel(&account_key, client_ip_key.as_ref(), now)
+                    .await?;
+                return Err(e.into());
+            }
+        };
+        // 失敗した場合は、照合の前の記録がそのまま失敗の記録となる
+        let user = match user {
+            Some(user) if is_valid => user,
+            user => {
+                if let Some(user) = user {
+                    let audit = AuditContext::new(Actor::Anonymous, PROGRAM_CODE);
+                    self.throttle
+                        .fail(
+                            &*self.transaction_manager,
+                            &*self.event_sink,
+                            audit,
+                            &account_key,
+                            user.id(),
+                            now,
+                        )
+                        .await?;
+                }
                 return Err(AuthError::InvalidCredentials.into());
             }
+        };
+        self.throttle
+            .succeed(&account_key, client_ip_key.as_ref(), now)
+            .await?;
+
+        // パスワードの照合後に判定し、停止中・未確認であることを第三者に推測させない
+        user.ensure_active(now)?;
+        user.ensure_password_usable()?;
+        email_verification_policy.ensure_login_allowed(&user)?;
+
+        // 平文を扱える照合の直後に、古い設定のハッシュを現在の設定で置き換える
+        let rehashed = if self.password_service.needs_rehash(user.password_hash()) {
+            Some(self.password_service.hash(&password).await?)
+        } else {
+            None
+        };
+
+        let audit = AuditContext::new(Actor::Anonymous, PROGRAM_CODE);
+        let outcome = domain::tx!(self.transaction_manager, audit, |factory| {
+            let user = match rehashed {
+                Some(password_hash) => {
+                    let rehashed = user.clone().rehash_password(password_hash);
+                    match factory.user_repository().save(&rehashed).await {
+                        Ok(()) => rehashed,
+                        // 置き換えは次回のログインでも行えるため、並行して更新された場合は読み込んだ状態で続ける
+                        Err(UserRepositoryError::ConcurrentModification) => {
+                            tracing::info!(
+                                user_id = %user.id(),
+                                "Skipped password rehash due to a concurrent modification"
+                            );
+                            user
+                        }
+                        Err(e) => return Err(e.into()),
+                    }
+                }
+                None => user,
+            };
+
+            // MFA が有効な場合はトークンを発行せず、チャレンジを返す
+            let mfa_enabled = factory
+                .totp_credential_repository()
+                .find_by_user_id(user.id())
+                .await?
+                .is_some_and(|credential| credential.is_enabled());
+            if mfa_enabled {
+                let challenge =
+                    MfaChallenge::issue(mfa_challenge_id, user.id(), mfa_token_hash, now);
+                factory.mfa_challenge_repository().save(&challenge).await?;
+                return Ok(PasswordOutcome::MfaRequired(challenge));
+            }
+
+            // 新しいファミリーの先頭となるリフレッシュトークンを永続化
+            let record = RefreshToken::issue(refresh_token_id, user.id(), refresh_token_hash, now);
+            factory.refresh_token_repository().save(&record).await?;
 
-            Ok::<User, domain::error::DomainError>(user)
+            Ok::<PasswordOutcome, DomainError>(PasswordOutcome::Authenticated(user))
         })
         .await?;
 
+        let user = match outcome {
+            PasswordOutcome::Authenticated(user) => user,
+            PasswordOutcome::MfaRequired(challenge) => {
+                return Ok(LoginOutcomeDto::MfaRequired(MfaChallengeDto {
+                    mfa_token,
+                    expires_at: challenge.expires_at(),
+                }));
+            }
+        };
+
         // ユースケース内でトークンを発行
-        let token = self.auth_service.issue_token(user.id())?;
+        let token = self.auth_service.issue_token(user.id(), user.roles())?;
 
-        Ok(LoginResponseDto::new(&user, token))
+        Ok(LoginOutcomeDto::Authenticated(LoginResponseDto::new(
+            &user,
+            token,
+            refresh_token,
+        )))
     }
 }
 
@@ -98,10 +258,59 @@ mod tests {
     use crate::auth::AuthToken;
     use crate::auth::test_utils::utils::*;
     use crate::error::UseCaseError;
+    use chrono::{DateTime, Utc};
     use domain::id::IdGenerator;
-    use domain::models::user::UserId;
+    use domain::models::auth::{LoginThrottlePolicy, TotpCredential, TotpSecret};
+    use domain::models::user::{AccountStatus, UserId};
     use domain::test_utils::{FixedClock, MockIdGenerator};
     use rstest::*;
+    use std::net::IpAddr;
+
+    type TestUseCase = AuthQueryUseCaseImpl<
+        StubTransactionManager,
+        StubPasswordService,
+        StubOpaqueTokenService,
+        FixedClock,
+        StubUuidGenerator,
+    >;
+
+    fn build_usecase(
+        tm: Arc<StubTransactionManager>,
+        ps: Arc<StubPasswordService>,
+        auth_service: Arc<StubAuthService>,
+        email_verification_policy: EmailVerificationPolicy,
+    ) -> TestUseCase {
+        build_usecase_with(
+            tm,
+            ps,
+            auth_service,
+            email_verification_policy,
+            Arc::new(StubLoginAttemptStore::default()),
+            chrono::Utc::now(),
+        )
+    }
+
+    fn build_usecase_with(
+        tm: Arc<StubTransactionManager>,
+        ps: Arc<StubPasswordService>,
+        auth_service: Arc<StubAuthService>,
+        email_verification_policy: EmailVerificationPolicy,
+        attempt_store: Arc<StubLoginAttemptStore>,
+        now: DateTime<Utc>,
+    ) -> TestUseCase {
+        AuthQueryUseCaseImpl::new(
+            tm,
+            ps,
+            Arc::new(StubOpaqueTokenService::default()),
+            auth_service,
+            Arc::new(StubEventSink::default()),
+            Arc::new(FixedClock::new(now)),
+            Arc::new(StubUuidGenerator),
+            email_verification_policy,
+            attempt_store,
+            LoginThrottleConfig::default(),
+        )
+    }
 
     #[rstest]
     #[tokio::test]
@@ -119,8 +328,10 @@ mod tests {
         let repo = Arc::new(StubUserRepository {
             found_user: Some(user),
             save_error: None,
+            ..Default::default()
         });
-        let factory = Arc::new(StubRepositoryFactory { repo });
+        let factory = Arc::new(StubRepositoryFactory::new(repo));
+        let refresh_token_repo = factory.refresh_token_repo.clone();
         let tm = Arc::new(StubTransactionManager { factory });
         let ps = Arc::new(StubPasswordService {
             verify_result: Arc::new(|| Ok(true)),
@@ -130,19 +341,114 @@ mod tests {
             issue_token_result: Arc::new(|| Ok(AuthToken::from("test-token".to_string()))),
             verify_token_result: Arc::new(|| unreachable!()),
         });
-        let clock = Arc::new(FixedClock::new(chrono::Utc::now()));
-
-        let usecase = AuthQueryUseCaseImpl::new(tm, ps, auth_service, clock);
+        let usecase = build_usecase(tm, ps, auth_service, EmailVerificationPolicy::Optional);
         let result = usecase
             .login(LoginQuery {
                 email: valid_email.to_string().into(),
                 password: valid_password.into(),
+                client_ip: None,
             })
             .await;
-        assert!(result.is_ok());
-        let response = result.unwrap();
+        let Ok(LoginOutcomeDto::Authenticated(response)) = result else {
+            panic!("expected tokens to be issued");
+        };
         assert_eq!(response.email, valid_email.to_string());
         assert_eq!(response.token.expose_as_str(), "test-token");
+        // リフレッシュトークンはハッシュのみが永続化される
+        let stored = refresh_token_repo.tokens();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(
+            stored[0].token_hash().as_ref(),
+            &format!("hashed:{}", response.refresh_token.expose_as_str())
+        );
+    }
+
+    #[rstest]
+    #[case::outdated(format!("{OUTDATED_HASH_PREFIX}hash"), true)]
+    #[case::current("hashed".to_string(), false)]
+    #[tokio::test]
+    async fn test_login_rehashes_outdated_password_hash(
+        valid_email: Email,
+        valid_password: String,
+        #[case] stored_hash: String,
+        #[case] rehashed: bool,
+    ) {
+        let user = User::new(
+            UserId::from(uuid::Uuid::now_v7()),
+            valid_email.clone(),
+            domain::models::user::PasswordHash::from_str_unchecked(stored_hash),
+        );
+        let repo = Arc::new(StubUserRepository::with_user(user));
+        let tm = Arc::new(StubTransactionManager {
+            factory: Arc::new(StubRepositoryFactory::new(repo.clone())),
+        });
+        let ps = Arc::new(StubPasswordService {
+            verify_result: Arc::new(|| Ok(true)),
+            hash_result: Arc::new(|| {
+                Ok(domain::models::user::PasswordHash::from_str_unchecked(
+                    "rehashed",
+                ))
+            }),
+        });
+        let auth_service = Arc::new(StubAuthService {
+            issue_token_result: Arc::new(|| Ok(AuthToken::from("test-token".to_string()))),
+            verify_token_result: Arc::new(|| unreachable!()),
+        });
+        let usecase = build_usecase(tm, ps, auth_service, EmailVerificationPolicy::Optional);
+
+        let result = usecase
+            .login(login_query(&valid_email, &valid_password, None))
+            .await;
+
+        assert!(matches!(result, Ok(LoginOutcomeDto::Authenticated(_))));
+        // 古い設定のハッシュのみ、トークンの発行と同じトランザクションで置き換えられる
+        let saved = repo.saved_users();
+        assert_eq!(saved.len(), usize::from(rehashed));
+        if rehashed {
+            assert_eq!(saved[0].password_hash().as_ref(), "rehashed");
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_login_succeeds_when_rehash_conflicts(valid_email: Email, valid_password: String) {
+        let user = User::new(
+            UserId::from(uuid::Uuid::now_v7()),
+            valid_email.clone(),
+            domain::models::user::PasswordHash::from_str_unchecked(format!(
+                "{OUTDATED_HASH_PREFIX}hash"
+            )),
+        );
+        // 照合の間に別の要求でユーザーが更新された
+        let repo = Arc::new(StubUserRepository {
+            save_error: Some(|| UserRepositoryError::ConcurrentModification),
+            ..StubUserRepository::with_user(user)
+        });
+        let factory = Arc::new(StubRepositoryFactory::new(repo.clone()));
+        let refresh_token_repo = factory.refresh_token_repo.clone();
+        let tm = Arc::new(StubTransactionManager { factory });
+        let ps = Arc::new(StubPasswordService {
+            verify_result: Arc::new(|| Ok(true)),
+            hash_result: Arc::new(|| {
+                Ok(domain::models::user::PasswordHash::from_str_unchecked(
+                    "rehashed",
+                ))
+            }),
+        });
+        let auth_service = Arc::new(StubAuthService {
+            issue_token_result: Arc::new(|| Ok(AuthToken::from("test-token".to_string()))),
+            verify_token_result: Arc::new(|| unreachable!()),
+        });
+        let usecase = build_usecase(tm, ps, auth_service, EmailVerificationPolicy::Optional);
+
+        let result = usecase
+            .login(login_query(&valid_email, &valid_password, None))
+            .await;
+
+        // ハッシュの置き換えは見送られ、ログイン自体は成功する
+        assert!(matches!(result, Ok(LoginOutcomeDto::Authenticated(_))));
+        assert!(repo.saved_users().is_empty());
+        assert_eq!(refresh_token_repo.tokens().len(), 1);
     }
 
     #[rstest]
@@ -157,8 +463,9 @@ mod tests {
         let repo = Arc::new(StubUserRepository {
             found_user: Some(user),
             save_error: None,
+            ..Default::default()
         });
-        let factory = Arc::new(StubRepositoryFactory { repo });
+        let factory = Arc::new(StubRepositoryFactory::new(repo));
         let tm = Arc::new(StubTransactionManager { factory });
         let ps = Arc::new(StubPasswordService {
             verify_result: Arc::new(|| Ok(false)), // Password mismatch
@@ -172,16 +479,408 @@ mod tests {
             issue_token_result: Arc::new(|| unreachable!()),
             verify_token_result: Arc::new(|| unreachable!()),
         });
-        let clock = Arc::new(FixedClock::new(chrono::Utc::now()));
+        let usecase = build_usecase(tm, ps, auth_service, EmailVerificationPolicy::Optional);
+        let result = usecase
+            .login(LoginQuery {
+                email: valid_email.to_string().into(),
+                password: valid_password.into(),
+                client_ip: None,
+            })
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::Authentication(_))));
+    }
+
+    /// 未登録のアカウントでも登録済みと同じく照合を 1 回行い、応答時間に差を生じさせないこと
+    #[rstest]
+    #[case::unknown_account(false)]
+    #[case::wrong_password(true)]
+    #[tokio::test]
+    async fn test_login_failure_performs_comparable_work(
+        valid_email: Email,
+        #[case] registered: bool,
+    ) {
+        let user = registered.then(|| {
+            User::new(
+                UserId::from(uuid::Uuid::now_v7()),
+                valid_email.clone(),
+                domain::models::user::PasswordHash::from_str_unchecked("hashed"),
+            )
+        });
+        let repo = Arc::new(StubUserRepository {
+            found_user: user,
+            ..Default::default()
+        });
+        let tm = Arc::new(StubTransactionManager {
+            factory: Arc::new(StubRepositoryFactory::new(repo)),
+        });
+        let (ps, verify_calls) = counting_password_service();
+        let auth_service = Arc::new(StubAuthService {
+            issue_token_result: Arc::new(|| unreachable!()),
+            verify_token_result: Arc::new(|| unreachable!()),
+        });
+        let usecase = build_usecase(tm, ps, auth_service, EmailVerificationPolicy::Optional);
+
+        let result = usecase
+            .login(login_query(&valid_email, "wrong", None))
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::Authentication(_))));
+        assert_eq!(verify_calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_login_with_mfa_returns_challenge(
+        valid_email: Email,
+        valid_password: String,
+        valid_password_hash: domain::models::user::PasswordHash,
+    ) {
+        let user = User::new(
+            UserId::from(uuid::Uuid::now_v7()),
+            valid_email.clone(),
+            valid_password_hash.clone(),
+        );
+        let credential = TotpCredential::enroll(
+            user.id(),
+            TotpSecret::from_str_unchecked(StubTotpService::SECRET),
+        )
+        .confirm(1, chrono::Utc::now())
+        .unwrap();
+        let repo = Arc::new(StubUserRepository {
+            found_user: Some(user),
+            save_error: None,
+            ..Default::default()
+        });
+        let factory = Arc::new(StubRepositoryFactory {
+            totp_credential_repo: Arc::new(StubTotpCredentialRepository::with_credential(
+                credential,
+            )),
+            ..StubRepositoryFactory::new(repo)
+        });
+        let refresh_token_repo = factory.refresh_token_repo.clone();
+        let mfa_challenge_repo = factory.mfa_challenge_repo.clone();
+        let tm = Arc::new(StubTransactionManager { factory });
+        let ps = Arc::new(StubPasswordService {
+            verify_result: Arc::new(|| Ok(true)),
+            hash_result: Arc::new(move || Ok(valid_password_hash.clone())),
+        });
+        let auth_service = Arc::new(StubAuthService {
+            issue_token_result: Arc::new(|| unreachable!()),
+            verify_token_result: Arc::new(|| unreachable!()),
+        });
+        let usecase = build_usecase(tm, ps, auth_service, EmailVerificationPolicy::Optional);
+
+        let result = usecase
+            .login(LoginQuery {
+                email: valid_email.to_string().into(),
+                password: valid_password.into(),
+                client_ip: None,
+            })
+            .await;
+
+        let Ok(LoginOutcomeDto::MfaRequired(challenge)) = result else {
+            panic!("expected an MFA challenge");
+        };
+        // トークンは発行されず、チャレンジのハッシュのみが永続化される
+        assert!(refresh_token_repo.tokens().is_empty());
+        let stored = mfa_challenge_repo.challenges();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(
+            stored[0].token_hash().as_ref(),
+            &format!("hashed:{}", challenge.mfa_token.expose_as_str())
+        );
+    }
+
+    #[rstest]
+    #[case::unverified(false, false)]
+    #[case::verified(true, true)]
+    #[tokio::test]
+    async fn test_login_with_required_email_verification(
+        valid_email: Email,
+        valid_password: String,
+        valid_password_hash: domain::models::user::PasswordHash,
+        #[case] verified: bool,
+        #[case] expected_ok: bool,
+    ) {
+        let user = User::new(
+            UserId::from(uuid::Uuid::now_v7()),
+            valid_email.clone(),
+            valid_password_hash.clone(),
+        );
+        let user = if verified {
+            user.verify_email(chrono::Utc::now()).0
+        } else {
+            user
+        };
+        let repo = Arc::new(StubUserRepository::with_user(user));
+        let factory = Arc::new(StubRepositoryFactory::new(repo));
+        let refresh_token_repo = factory.refresh_token_repo.clone();
+        let tm = Arc::new(StubTransactionManager { factory });
+        let ps = Arc::new(StubPasswordService {
+            verify_result: Arc::new(|| Ok(true)),
+            hash_result: Arc::new(move || Ok(valid_password_hash.clone())),
+        });
+        let auth_service = Arc::new(StubAuthService {
+            issue_token_result: Arc::new(|| Ok(AuthToken::from("test-token".to_string()))),
+            verify_token_result: Arc::new(|| unreachable!()),
+        });
+        let usecase = build_usecase(tm, ps, auth_service, EmailVerificationPolicy::Required);
 
-        let usecase = AuthQueryUseCaseImpl::new(tm, ps, auth_service, clock);
         let result = usecase
             .login(LoginQuery {
                 email: valid_email.to_string().into(),
                 password: valid_password.into(),
+                client_ip: None,
             })
             .await;
 
+        if expected_ok {
+            assert!(matches!(result, Ok(LoginOutcomeDto::Authenticated(_))));
+        } else {
+            assert!(matches!(result, Err(UseCaseError::Forbidden(_))));
+            assert!(refresh_token_repo.tokens().is_empty());
+        }
+    }
+
+    #[rstest]
+    #[case::suspended(AccountStatus::Suspended)]
+    #[case::locked(AccountStatus::Locked)]
+    #[case::deleted(AccountStatus::Deleted)]
+    #[tokio::test]
+    async fn test_login_refuses_non_active_account(
+        valid_email: Email,
+        valid_password: String,
+        valid_password_hash: domain::models::user::PasswordHash,
+        #[case] status: AccountStatus,
+    ) {
+        let now = chrono::Utc::now();
+        let user = User::new(
+            UserId::from(uuid::Uuid::now_v7()),
+            valid_email.clone(),
+            valid_password_hash.clone(),
+        );
+        let user = match status {
+            AccountStatus::Suspended => user.suspend(now).unwrap().0,
+            AccountStatus::Locked => user.lock(now + chrono::Duration::hours(1), now).unwrap().0,
+            _ => user.delete(now).unwrap().0,
+        };
+        let repo = Arc::new(StubUserRepository::with_user(user));
+        let factory = Arc::new(StubRepositoryFactory::new(repo));
+        let refresh_token_repo = factory.refresh_token_repo.clone();
+        let tm = Arc::new(StubTransactionManager { factory });
+        let ps = Arc::new(StubPasswordService {
+            verify_result: Arc::new(|| Ok(true)),
+            hash_result: Arc::new(move || Ok(valid_password_hash.clone())),
+        });
+        let auth_service = Arc::new(StubAuthService {
+            issue_token_result: Arc::new(|| unreachable!()),
+            verify_token_result: Arc::new(|| unreachable!()),
+        });
+        let usecase = build_usecase(tm, ps, auth_service, EmailVerificationPolicy::Optional);
+
+        let result = usecase
+            .login(LoginQuery {
+                email: valid_email.to_string().into(),
+                password: valid_password.into(),
+                client_ip: None,
+            })
+            .await;
+
+        // 停止は 403、ロックは 423、削除済みは存在しないアカウントと同じく 401
+        match status {
+            AccountStatus::Suspended => assert!(matches!(result, Err(UseCaseError::Forbidden(_)))),
+            AccountStatus::Locked => assert!(matches!(result, Err(UseCaseError::Locked { .. }))),
+            _ => assert!(matches!(result, Err(UseCaseError::Authentication(_)))),
+        }
+        assert!(refresh_token_repo.tokens().is_empty());
+    }
+
+    fn login_query(email: &Email, password: &str, client_ip: Option<IpAddr>) -> LoginQuery {
+        LoginQuery {
+            email: email.to_string().into(),
+            password: password.to_string().into(),
+            client_ip,
+        }
+    }
+
+    fn throttle_fixture(
+        verify: bool,
+    ) -> (
+        Arc<StubTransactionManager>,
+        Arc<StubPasswordService>,
+        Arc<StubAuthService>,
+    ) {
+        let user = User::new(
+            UserId::from(uuid::Uuid::now_v7()),
+            Email::try_from("throttle@example.com").unwrap(),
+            domain::models::user::PasswordHash::from_str_unchecked("hashed"),
+        );
+        let repo = Arc::new(StubUserRepository::with_user(user));
+        let tm = Arc::new(StubTransactionManager {
+            factory: Arc::new(StubRepositoryFactory::new(repo)),
+        });
+        let ps = Arc::new(StubPasswordService {
+            verify_result: Arc::new(move || Ok(verify)),
+            hash_result: Arc::new(|| {
+                Ok(domain::models::user::PasswordHash::from_str_unchecked(
+                    "hashed",
+                ))
+            }),
+        });
+        let auth_service = Arc::new(StubAuthService {
+            issue_token_result: Arc::new(|| Ok(AuthToken::from("test-token".to_string()))),
+            verify_token_result: Arc::new(|| unreachable!()),
+        });
+        (tm, ps, auth_service)
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_login_locks_account_with_exponential_backoff(valid_email: Email) {
+        let now = chrono::Utc::now();
+        let store = Arc::new(StubLoginAttemptStore::default());
+        let (tm, ps, auth_service) = throttle_fixture(false);
+        let at = |now| {
+            build_usecase_with(
+                tm.clone(),
+                ps.clone(),
+                auth_service.clone(),
+                EmailVerificationPolicy::Optional,
+                store.clone(),
+                now,
+            )
+        };
+
+        let usecase = at(now);
+        for _ in 0..LoginThrottlePolicy::ACCOUNT.max_failures {
+            let result = usecase
+                .login(login_query(&valid_email, "wrong", None))
+                .await;
+            assert!(matches!(result, Err(UseCaseError::Authentication(_))));
+        }
+        // 上限に達した後はパスワードを照合せずに拒否する
+        let result = usecase
+            .login(login_query(&valid_email, "wrong", None))
+            .await;
+        assert!(matches!(
+            result,
+            Err(UseCaseError::Locked {
+                retry_after: Some(30),
+                ..
+            })
+        ));
+        assert_eq!(
+            store.failure_count(&ThrottleKey::Account(valid_email.clone())),
+            5
+        );
+        // ロックアウトは管理者からも確認できるようユーザーの状態に反映される
+        let saved = tm.factory.repo.saved_users();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(
+            saved[0].state().locked_until(),
+            Some(now + chrono::Duration::seconds(30))
+        );
+
+        // ロックアウトの終了後に再び失敗すると、次のロックアウトは倍になる
+        let usecase = at(now + chrono::Duration::seconds(30));
+        let result = usecase
+            .login(login_query(&valid_email, "wrong", None))
+            .await;
+        assert!(matches!(result, Err(UseCaseError::Authentication(_))));
+        let result = usecase
+            .login(login_query(&valid_email, "wrong", None))
+            .await;
+        assert!(matches!(
+            result,
+            Err(UseCaseError::Locked {
+                retry_after: Some(60),
+                ..
+            })
+        ));
+        let saved = tm.factory.repo.saved_users();
+        assert_eq!(
+            saved.last().unwrap().state().locked_until(),
+            Some(now + chrono::Duration::seconds(90))
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_login_throttles_client_ip_across_accounts() {
+        let now = chrono::Utc::now();
+        let store = Arc::new(StubLoginAttemptStore::default());
+        let (tm, ps, auth_service) = throttle_fixture(false);
+        let usecase = build_usecase_with(
+            tm,
+            ps,
+            auth_service,
+            EmailVerificationPolicy::Optional,
+            store,
+            now,
+        );
+        let client_ip: IpAddr = "203.0.113.7".parse().unwrap();
+
+        for i in 0..LoginThrottlePolicy::CLIENT_IP.max_failures {
+            let email = Email::try_from(format!("user{}@example.com", i)).unwrap();
+            let result = usecase
+                .login(login_query(&email, "wrong", Some(client_ip)))
+                .await;
+            assert!(matches!(result, Err(UseCaseError::Authentication(_))));
+        }
+
+        let email = Email::try_from("another@example.com").unwrap();
+        let result = usecase
+            .login(login_query(&email, "wrong", Some(client_ip)))
+            .await;
+        assert!(matches!(
+            result,
+            Err(UseCaseError::TooManyRequests {
+                retry_after: Some(30),
+                ..
+            })
+        ));
+        // 他の接続元からは制限されない
+        let result = usecase
+            .login(login_query(&email, "wrong", "198.51.100.1".parse().ok()))
+            .await;
         assert!(matches!(result, Err(UseCaseError::Authentication(_))));
     }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_successful_login_clears_account_failures(valid_email: Email) {
+        let now = chrono::Utc::now();
+        let store = Arc::new(StubLoginAttemptStore::default());
+        let (tm, ps, auth_service) = throttle_fixture(false);
+        let failing = build_usecase_with(
+            tm.clone(),
+            ps,
+            auth_service.clone(),
+            EmailVerificationPolicy::Optional,
+            store.clone(),
+            now,
+        );
+        for _ in 0..3 {
+            let _ = failing
+                .login(login_query(&valid_email, "wrong", None))
+                .await;
+        }
+
+        let (_, ps, _) = throttle_fixture(true);
+        let succeeding = build_usecase_with(
+            tm,
+            ps,
+            auth_service,
+            EmailVerificationPolicy::Optional,
+            store.clone(),
+            now,
+        );
+        let result = succeeding
+            .login(login_query(&valid_email, "correct", None))
+            .await;
+
+        assert!(matches!(result, Ok(LoginOutcomeDto::Authenticated(_))));
+        assert_eq!(store.failure_count(&ThrottleKey::Account(valid_email)), 0);
+    }
 }
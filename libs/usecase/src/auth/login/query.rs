@@ -1,8 +1,11 @@
 use sensitive_data::{EmailRule, SecretRule, Sensitive};
 use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoginQuery {
     pub email: Sensitive<String, EmailRule>,
     pub password: Sensitive<String, SecretRule>,
+    /// 接続元の IP アドレス（取得できない場合は接続元単位の制限を行わない）
+    pub client_ip: Option<IpAddr>,
 }
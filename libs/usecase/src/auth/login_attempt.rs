@@ -0,0 +1,207 @@
+use crate::error::{LoginAttemptError, UseCaseError, UseCaseResult};
+use crate::event::EventSink;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::error::DomainError;
+use domain::models::auth::{LoginThrottleConfig, LoginThrottlePolicy, ThrottleKey};
+use domain::models::user::{UserId, UserRepositoryError};
+use domain::repository::tx::TransactionManager;
+use domain::{AuditContext, DomainEvent};
+use std::sync::Arc;
+
+/// ログインに失敗した試行をアカウント・接続元ごとに記録するポート。
+///
+/// 判定は集計期間内の記録のみで行うため、実装は期間外の記録を破棄してよい。
+#[async_trait]
+pub trait LoginAttemptStore: Send + Sync {
+    /// 失敗した試行を記録する。`retain_since` より前の記録（キーを問わない）は破棄してよい。
+    async fn record_failure(
+        &self,
+        key: &ThrottleKey,
+        attempted_at: DateTime<Utc>,
+        retain_since: DateTime<Utc>,
+    ) -> Result<(), LoginAttemptError>;
+
+    /// `since` 以降に記録された失敗の日時を古い順に返す
+    async fn failures_since(
+        &self,
+        key: &ThrottleKey,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<DateTime<Utc>>, LoginAttemptError>;
+
+    /// `attempted_at` に記録した失敗を 1 件取り消す
+    async fn remove_failure(
+        &self,
+        key: &ThrottleKey,
+        attempted_at: DateTime<Utc>,
+    ) -> Result<(), LoginAttemptError>;
+
+    /// 指定したキーの記録を消去する
+    async fn clear(&self, key: &ThrottleKey) -> Result<(), LoginAttemptError>;
+}
+
+/// 失敗した試行の記録に基づき、パスワードの照合を制限する。
+///
+/// ログインと、ログイン中の現在のパスワードの確認とで同じ記録を共有する。
+/// 試行は照合の前に失敗として記録し、失敗でなかった場合に取り消す。
+/// 判定には並行して照合中の試行も含まれるため、同時に送られた試行が上限を超えて照合されることはない。
+/// 判定は記録に基づいて行い、アカウントのロックアウトはユーザーの状態（ロック中）にも反映する。
+pub struct LoginThrottle {
+    store: Arc<dyn LoginAttemptStore>,
+    config: LoginThrottleConfig,
+}
+
+impl LoginThrottle {
+    pub fn new(store: Arc<dyn LoginAttemptStore>, config: LoginThrottleConfig) -> Self {
+        Self { store, config }
+    }
+
+    /// 試行を失敗として記録したうえで、接続元・アカウントがロックアウト中でないことを検証する。
+    ///
+    /// ロックアウト中の場合は記録を取り消し、ロックアウトを延長しない。
+    pub async fn begin_attempt(
+        &self,
+        account: &ThrottleKey,
+        client_ip: Option<&ThrottleKey>,
+        now: DateTime<Utc>,
+    ) -> UseCaseResult<()> {
+        let retain_since = self.config.retention_start(now);
+        for key in std::iter::once(account).chain(client_ip) {
+            self.store.record_failure(key, now, retain_since).await?;
+        }
+
+        let throttled = self.check(account, client_ip, now).await;
+        if throttled.is_err() {
+            self.cancel(account, client_ip, now).await?;
+        }
+        throttled
+    }
+
+    /// 照合に成功したため、アカウントの失敗の記録を消去し、接続元の記録を取り消す。
+    pub async fn succeed(
+        &self,
+        account: &ThrottleKey,
+        client_ip: Option<&ThrottleKey>,
+        now: DateTime<Utc>,
+    ) -> UseCaseResult<()> {
+        self.store.clear(account).await?;
+        if let Some(client_ip) = client_ip {
+            self.store.remove_failure(client_ip, now).await?;
+        }
+        Ok(())
+    }
+
+    /// 照合に失敗した。記録は失敗としてそのまま残し、アカウントがロックアウトに達した場合は
+    /// 管理者からも確認できるようユーザーをロック中にする。
+    ///
+    /// 利用中（期限切れのロックを含む）でない場合や、並行する更新と競合した場合は状態を変更しない。
+    pub async fn fail<TM: TransactionManager>(
+        &self,
+        transaction_manager: &TM,
+        event_sink: &dyn EventSink,
+        audit: AuditContext,
+        account: &ThrottleKey,
+        user_id: UserId,
+        now: DateTime<Utc>,
+    ) -> UseCaseResult<()> {
+        let policy = &self.config.account;
+        let failures = self
+            .store
+            .failures_since(account, policy.window_start(now))
+            .await?;
+        let Some(until) = policy.locked_until(&failures).filter(|until| *until > now) else {
+            return Ok(());
+        };
+
+        let events = domain::tx!(transaction_manager, audit, |factory| {
+            let user_repo = factory.user_repository();
+            let Some(user) = user_repo.find_by_id(user_id).await? else {
+                return Ok(vec![]);
+            };
+            if user.ensure_active(now).is_err() {
+                return Ok(vec![]);
+            }
+            let (user, events) = user.lock(until, now)?;
+            match user_repo.save(&user).await {
+                Ok(()) => {}
+                Err(UserRepositoryError::ConcurrentModification) => {
+                    tracing::info!(user_id = %user_id, "Skipped locking a concurrently updated account");
+                    return Ok(vec![]);
+                }
+                Err(e) => return Err(e.into()),
+            }
+
+            let events: Vec<DomainEvent> = events.into_iter().map(DomainEvent::from).collect();
+            factory.outbox_repository().append(&events).await?;
+
+            Ok::<Vec<DomainEvent>, DomainError>(events)
+        })
+        .await?;
+
+        event_sink.publish(&events).await;
+
+        Ok(())
+    }
+
+    /// 照合できなかった試行の記録を取り消す。
+    pub async fn cancel(
+        &self,
+        account: &ThrottleKey,
+        client_ip: Option<&ThrottleKey>,
+        now: DateTime<Utc>,
+    ) -> UseCaseResult<()> {
+        for key in std::iter::once(account).chain(client_ip) {
+            self.store.remove_failure(key, now).await?;
+        }
+        Ok(())
+    }
+
+    async fn check(
+        &self,
+        account: &ThrottleKey,
+        client_ip: Option<&ThrottleKey>,
+        now: DateTime<Utc>,
+    ) -> UseCaseResult<()> {
+        if let Some(client_ip) = client_ip
+            && let Some(retry_after) = self
+                .retry_after(client_ip, &self.config.client_ip, now)
+                .await?
+        {
+            return Err(UseCaseError::TooManyRequests {
+                message: "Too many failed login attempts from this client".into(),
+                retry_after: Some(retry_after),
+            });
+        }
+        if let Some(retry_after) = self.retry_after(account, &self.config.account, now).await? {
+            return Err(UseCaseError::Locked {
+                message: "Account is temporarily locked due to too many failed login attempts"
+                    .into(),
+                retry_after: Some(retry_after),
+            });
+        }
+        Ok(())
+    }
+
+    /// ロックアウト中であれば、解除までの秒数（切り上げ）を返す。
+    ///
+    /// 今回の試行の記録（最新の 1 件）を除き、それ以前と照合中の試行で判定する。
+    async fn retry_after(
+        &self,
+        key: &ThrottleKey,
+        policy: &LoginThrottlePolicy,
+        now: DateTime<Utc>,
+    ) -> UseCaseResult<Option<u64>> {
+        let mut failures = self
+            .store
+            .failures_since(key, policy.window_start(now))
+            .await?;
+        failures.pop();
+        Ok(policy
+            .ensure_allowed(&failures, now)
+            .err()
+            .map(|throttled| {
+                let millis = (throttled.until - now).num_milliseconds().max(1) as u64;
+                millis.div_ceil(1000)
+            }))
+    }
+}
@@ -0,0 +1,16 @@
+use domain::models::user::UserId;
+use sensitive_data::{SecretRule, Sensitive};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogoutCommand {
+    /// ログアウトするユーザー（アクセストークンの `sub`）
+    pub user_id: UserId,
+    /// 失効させるアクセストークンの `jti`
+    pub jti: Uuid,
+    /// 失効させるアクセストークンの `exp`
+    pub exp: usize,
+    /// 併せて失効させるリフレッシュトークン（任意）
+    pub refresh_token: Option<Sensitive<String, SecretRule>>,
+}
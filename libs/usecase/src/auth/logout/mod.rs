@@ -0,0 +1,191 @@
+pub mod command;
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub use self::command::LogoutCommand;
+use crate::auth::TokenRevocationStore;
+use crate::error::UseCaseResult;
+use domain::models::auth::{OpaqueToken, OpaqueTokenService};
+use domain::repository::tx::TransactionManager;
+use domain::{Actor, AuditContext, Clock};
+
+/// 監査カラムに記録するプログラムコード
+const PROGRAM_CODE: &str = "auth-logout";
+
+#[async_trait]
+pub trait LogoutUseCase: Send + Sync {
+    async fn logout(&self, command: LogoutCommand) -> UseCaseResult<()>;
+}
+
+pub struct LogoutUseCaseImpl<TM, TS, C>
+where
+    TM: TransactionManager,
+    TS: OpaqueTokenService,
+    C: Clock,
+{
+    transaction_manager: Arc<TM>,
+    token_service: Arc<TS>,
+    revocation_store: Arc<dyn TokenRevocationStore>,
+    clock: Arc<C>,
+}
+
+impl<TM, TS, C> LogoutUseCaseImpl<TM, TS, C>
+where
+    TM: TransactionManager,
+    TS: OpaqueTokenService,
+    C: Clock,
+{
+    pub fn new(
+        transaction_manager: Arc<TM>,
+        token_service: Arc<TS>,
+        revocation_store: Arc<dyn TokenRevocationStore>,
+        clock: Arc<C>,
+    ) -> Self {
+        Self {
+            transaction_manager,
+            token_service,
+            revocation_store,
+            clock,
+        }
+    }
+}
+
+#[async_trait]
+impl<TM, TS, C> LogoutUseCase for LogoutUseCaseImpl<TM, TS, C>
+where
+    TM: TransactionManager,
+    TS: OpaqueTokenService + 'static,
+    C: Clock + 'static,
+{
+    async fn logout(&self, command: LogoutCommand) -> UseCaseResult<()> {
+        self.revocation_store
+            .revoke(command.jti, command.exp)
+            .await?;
+
+        let Some(refresh_token) = command.refresh_token else {
+            return Ok(());
+        };
+
+        let token_hash = self
+            .token_service
+            .hash(&OpaqueToken::from(refresh_token.into_inner()));
+        let user_id = command.user_id;
+        let now = self.clock.now();
+
+        let audit = AuditContext::new(Actor::User(user_id), PROGRAM_CODE);
+        domain::tx!(self.transaction_manager, audit, |factory| {
+            let refresh_token_repo = factory.refresh_token_repository();
+
+            // 未知のトークンや他ユーザーのトークンは無視する（ログアウトは冪等）
+            if let Some(token) = refresh_token_repo
+                .find_by_token_hash(&token_hash)
+                .await?
+                .filter(|t| t.user_id() == user_id)
+            {
+                refresh_token_repo
+                    .revoke_family(token.family_id(), now)
+                    .await?;
+            }
+
+            Ok::<(), domain::error::DomainError>(())
+        })
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::test_utils::utils::*;
+    use domain::models::auth::{OpaqueTokenHash, RefreshToken, RefreshTokenId};
+    use domain::models::user::UserId;
+    use domain::test_utils::FixedClock;
+    use rstest::*;
+
+    fn build_usecase(
+        token_repo: Arc<StubRefreshTokenRepository>,
+        store: Arc<StubTokenRevocationStore>,
+    ) -> LogoutUseCaseImpl<StubTransactionManager, StubOpaqueTokenService, FixedClock> {
+        let repo = Arc::new(StubUserRepository {
+            found_user: None,
+            save_error: None,
+            ..Default::default()
+        });
+        let factory = Arc::new(StubRepositoryFactory {
+            refresh_token_repo: token_repo,
+            ..StubRepositoryFactory::new(repo)
+        });
+        let tm = Arc::new(StubTransactionManager { factory });
+        LogoutUseCaseImpl::new(
+            tm,
+            Arc::new(StubOpaqueTokenService::default()),
+            store,
+            Arc::new(FixedClock::new(chrono::Utc::now())),
+        )
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_logout_revokes_access_and_refresh_tokens() {
+        let user_id = UserId::from(uuid::Uuid::now_v7());
+        let refresh_token = RefreshToken::issue(
+            RefreshTokenId::from(uuid::Uuid::now_v7()),
+            user_id,
+            OpaqueTokenHash::from_str_unchecked("hashed:presented"),
+            chrono::Utc::now(),
+        );
+        let token_repo = Arc::new(StubRefreshTokenRepository::with_tokens(vec![
+            refresh_token.clone(),
+        ]));
+        let store = Arc::new(StubTokenRevocationStore::default());
+        let usecase = build_usecase(token_repo.clone(), store.clone());
+
+        let jti = uuid::Uuid::new_v4();
+        usecase
+            .logout(LogoutCommand {
+                user_id,
+                jti,
+                exp: usize::MAX,
+                refresh_token: Some("presented".to_string().into()),
+            })
+            .await
+            .unwrap();
+
+        assert!(store.is_revoked(jti).await.unwrap());
+        assert_eq!(
+            token_repo.revoked_families(),
+            vec![refresh_token.family_id()]
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_logout_ignores_refresh_token_of_other_user() {
+        let refresh_token = RefreshToken::issue(
+            RefreshTokenId::from(uuid::Uuid::now_v7()),
+            UserId::from(uuid::Uuid::now_v7()),
+            OpaqueTokenHash::from_str_unchecked("hashed:presented"),
+            chrono::Utc::now(),
+        );
+        let token_repo = Arc::new(StubRefreshTokenRepository::with_tokens(vec![refresh_token]));
+        let usecase = build_usecase(
+            token_repo.clone(),
+            Arc::new(StubTokenRevocationStore::default()),
+        );
+
+        let result = usecase
+            .logout(LogoutCommand {
+                user_id: UserId::from(uuid::Uuid::now_v7()),
+                jti: uuid::Uuid::new_v4(),
+                exp: usize::MAX,
+                refresh_token: Some("presented".to_string().into()),
+            })
+            .await;
+
+        assert!(result.is_ok());
+        assert!(token_repo.revoked_families().is_empty());
+    }
+}
@@ -0,0 +1,27 @@
+use domain::Version;
+use domain::models::user::UserId;
+use sensitive_data::{SecretRule, Sensitive};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartTotpEnrollmentCommand {
+    pub user_id: UserId,
+    /// クライアントが参照したユーザーのバージョン（`If-Match`）。`None` の場合は検証しない
+    pub expected_version: Option<Version>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfirmTotpEnrollmentCommand {
+    pub user_id: UserId,
+    /// 認証アプリに表示された最初のコード
+    pub code: Sensitive<String, SecretRule>,
+    /// クライアントが参照したユーザーのバージョン（`If-Match`）。`None` の場合は検証しない
+    pub expected_version: Option<Version>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegenerateRecoveryCodesCommand {
+    pub user_id: UserId,
+    /// クライアントが参照したユーザーのバージョン（`If-Match`）。`None` の場合は検証しない
+    pub expected_version: Option<Version>,
+}
@@ -0,0 +1,22 @@
+use domain::models::auth::{RecoveryCode, TotpSecret};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TotpEnrollmentDto {
+    /// 手入力用のシークレット（Base32）
+    pub secret: TotpSecret,
+    /// 認証アプリに登録するための `otpauth://` URI（QR コード化して表示する）
+    pub otpauth_uri: String,
+}
+
+/// 発行したリカバリーコード（平文を返すのはこの一度のみ）
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecoveryCodesDto {
+    pub codes: Vec<RecoveryCode>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecoveryCodeStatusDto {
+    /// 未使用のリカバリーコードの残数
+    pub remaining: usize,
+}
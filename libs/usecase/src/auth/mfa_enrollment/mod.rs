@@ -0,0 +1,484 @@
+pub mod command;
+pub mod dto;
+pub mod query;
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub use self::command::{
+    ConfirmTotpEnrollmentCommand, RegenerateRecoveryCodesCommand, StartTotpEnrollmentCommand,
+};
+use self::dto::{RecoveryCodeStatusDto, RecoveryCodesDto, TotpEnrollmentDto};
+pub use self::query::RecoveryCodeStatusQuery;
+use crate::error::UseCaseResult;
+use domain::error::DomainError;
+use domain::models::auth::{
+    MfaError, PasswordService, RECOVERY_CODE_COUNT, RecoveryCode, RecoveryCodeGenerator,
+    RecoveryCodeSet, TotpCode, TotpCredential, TotpService,
+};
+use domain::models::user::{Email, PasswordHash, UserError, UserId, UserIdentity};
+use domain::repository::tx::{RepositoryFactory, TransactionManager};
+use domain::{Actor, AuditContext, Clock, Version};
+
+/// 監査カラムに記録するプログラムコード
+const PROGRAM_CODE: &str = "auth-mfa-enroll";
+
+/// TOTP による多要素認証の登録を行うユースケース。
+#[async_trait]
+pub trait MfaEnrollmentUseCase: Send + Sync {
+    /// 新しいシークレットを発行して登録を開始する
+    async fn start_totp_enrollment(
+        &self,
+        command: StartTotpEnrollmentCommand,
+    ) -> UseCaseResult<TotpEnrollmentDto>;
+
+    /// 最初のコードを照合して登録を確定し、MFA を有効にする（リカバリーコードを発行する）
+    async fn confirm_totp_enrollment(
+        &self,
+        command: ConfirmTotpEnrollmentCommand,
+    ) -> UseCaseResult<RecoveryCodesDto>;
+
+    /// リカバリーコードを再発行する（以前のコードはすべて無効になる）
+    async fn regenerate_recovery_codes(
+        &self,
+        command: RegenerateRecoveryCodesCommand,
+    ) -> UseCaseResult<RecoveryCodesDto>;
+
+    /// 未使用のリカバリーコードの残数を取得する
+    async fn get_recovery_code_status(
+        &self,
+        query: RecoveryCodeStatusQuery,
+    ) -> UseCaseResult<RecoveryCodeStatusDto>;
+}
+
+pub struct MfaEnrollmentUseCaseImpl<TM, TOTP, PS, RG, C>
+where
+    TM: TransactionManager,
+    TOTP: TotpService,
+    PS: PasswordService,
+    RG: RecoveryCodeGenerator,
+    C: Clock,
+{
+    transaction_manager: Arc<TM>,
+    totp_service: Arc<TOTP>,
+    password_service: Arc<PS>,
+    recovery_code_generator: Arc<RG>,
+    clock: Arc<C>,
+}
+
+impl<TM, TOTP, PS, RG, C> MfaEnrollmentUseCaseImpl<TM, TOTP, PS, RG, C>
+where
+    TM: TransactionManager,
+    TOTP: TotpService,
+    PS: PasswordService,
+    RG: RecoveryCodeGenerator,
+    C: Clock,
+{
+    pub fn new(
+        transaction_manager: Arc<TM>,
+        totp_service: Arc<TOTP>,
+        password_service: Arc<PS>,
+        recovery_code_generator: Arc<RG>,
+        clock: Arc<C>,
+    ) -> Self {
+        Self {
+            transaction_manager,
+            totp_service,
+            password_service,
+            recovery_code_generator,
+            clock,
+        }
+    }
+
+    /// リカバリーコードの平文を生成し、保存用にハッシュ化する。
+    async fn generate_recovery_codes(
+        &self,
+    ) -> UseCaseResult<(Vec<RecoveryCode>, Vec<PasswordHash>)> {
+        let codes: Vec<RecoveryCode> = (0..RECOVERY_CODE_COUNT)
+            .map(|_| self.recovery_code_generator.generate())
+            .collect();
+        let mut hashes = Vec::with_capacity(codes.len());
+        for code in &codes {
+            hashes.push(self.password_service.hash(&code.to_raw_password()).await?);
+        }
+        Ok((codes, hashes))
+    }
+}
+
+/// `expected` が指定されている場合、ユーザーの現在のバージョンと一致することを確認する。
+async fn ensure_user_version(
+    factory: &dyn RepositoryFactory,
+    user_id: UserId,
+    expected: Option<Version>,
+) -> Result<(), DomainError> {
+    let Some(expected) = expected else {
+        return Ok(());
+    };
+    let user = factory
+        .user_repository()
+        .find_by_id(user_id)
+        .await?
+        .ok_or(UserError::NotFound)?;
+    user.ensure_version(expected)?;
+    Ok(())
+}
+
+#[async_trait]
+impl<TM, TOTP, PS, RG, C> MfaEnrollmentUseCase for MfaEnrollmentUseCaseImpl<TM, TOTP, PS, RG, C>
+where
+    TM: TransactionManager,
+    TOTP: TotpService + 'static,
+    PS: PasswordService + 'static,
+    RG: RecoveryCodeGenerator + 'static,
+    C: Clock + 'static,
+{
+    async fn start_totp_enrollment(
+        &self,
+        command: StartTotpEnrollmentCommand,
+    ) -> UseCaseResult<TotpEnrollmentDto> {
+        let secret = self.totp_service.generate_secret();
+        let credential = TotpCredential::enroll(command.user_id, secret.clone());
+
+        let audit = AuditContext::new(Actor::User(command.user_id), PROGRAM_CODE);
+        let email = domain::tx!(self.transaction_manager, audit, |factory| {
+            let user = factory
+                .user_repository()
+                .find_by_id(command.user_id)
+                .await?
+                .ok_or(UserError::NotFound)?;
+            if let Some(expected) = command.expected_version {
+                user.ensure_version(expected)?;
+            }
+
+            // 確認前の登録はやり直しを許容し、有効化済みの場合のみ拒否する
+            let totp_repo = factory.totp_credential_repository();
+            if let Some(existing) = totp_repo.find_by_user_id(user.id()).await?
+                && existing.is_enabled()
+            {
+                return Err(MfaError::AlreadyEnabled.into());
+            }
+            totp_repo.save(&credential).await?;
+
+            Ok::<Email, domain::error::DomainError>(user.email().clone())
+        })
+        .await?;
+
+        let otpauth_uri = self.totp_service.provisioning_uri(&secret, email.as_ref());
+
+        Ok(TotpEnrollmentDto {
+            secret,
+            otpauth_uri,
+        })
+    }
+
+    async fn confirm_totp_enrollment(
+        &self,
+        command: ConfirmTotpEnrollmentCommand,
+    ) -> UseCaseResult<RecoveryCodesDto> {
+        let code = TotpCode::try_from(command.code.into_inner()).map_err(MfaError::from)?;
+        let totp_service = Arc::clone(&self.totp_service);
+        let now = self.clock.now();
+        let (recovery_codes, recovery_code_hashes) = self.generate_recovery_codes().await?;
+        let recovery_code_set = RecoveryCodeSet::issue(command.user_id, recovery_code_hashes);
+
+        let audit = AuditContext::new(Actor::User(command.user_id), PROGRAM_CODE);
+        domain::tx!(self.transaction_manager, audit, |factory| {
+            ensure_user_version(factory, command.user_id, command.expected_version).await?;
+            let totp_repo = factory.totp_credential_repository();
+
+            let credential = totp_repo
+                .find_by_user_id(command.user_id)
+                .await?
+                .ok_or(MfaError::NotEnrolled)?;
+
+            let matched_step = totp_service
+                .verify(credential.secret(), &code, now)
+                .ok_or(MfaError::InvalidCode)?;
+            let confirmed = credential.confirm(matched_step, now)?;
+            totp_repo.save(&confirmed).await?;
+            factory
+                .recovery_code_repository()
+                .save(&recovery_code_set)
+                .await?;
+
+            Ok::<(), domain::error::DomainError>(())
+        })
+        .await?;
+
+        Ok(RecoveryCodesDto {
+            codes: recovery_codes,
+        })
+    }
+
+    async fn regenerate_recovery_codes(
+        &self,
+        command: RegenerateRecoveryCodesCommand,
+    ) -> UseCaseResult<RecoveryCodesDto> {
+        let (recovery_codes, recovery_code_hashes) = self.generate_recovery_codes().await?;
+        let recovery_code_set = RecoveryCodeSet::issue(command.user_id, recovery_code_hashes);
+
+        let audit = AuditContext::new(Actor::User(command.user_id), PROGRAM_CODE);
+        domain::tx!(self.transaction_manager, audit, |factory| {
+            ensure_user_version(factory, command.user_id, command.expected_version).await?;
+            // MFA が有効なユーザーにのみ発行する
+            let enabled = factory
+                .totp_credential_repository()
+                .find_by_user_id(command.user_id)
+                .await?
+                .is_some_and(|credential| credential.is_enabled());
+            if !enabled {
+                return Err(MfaError::NotEnrolled.into());
+            }
+            factory
+                .recovery_code_repository()
+                .save(&recovery_code_set)
+                .await?;
+
+            Ok::<(), domain::error::DomainError>(())
+        })
+        .await?;
+
+        Ok(RecoveryCodesDto {
+            codes: recovery_codes,
+        })
+    }
+
+    async fn get_recovery_code_status(
+        &self,
+        query: RecoveryCodeStatusQuery,
+    ) -> UseCaseResult<RecoveryCodeStatusDto> {
+        let audit = AuditContext::new(Actor::User(query.user_id), PROGRAM_CODE);
+        let code_set = domain::tx!(self.transaction_manager, audit, |factory| {
+            factory
+                .recovery_code_repository()
+                .find_by_user_id(query.user_id)
+                .await
+                .map_err(domain::error::DomainError::from)
+        })
+        .await?;
+
+        Ok(RecoveryCodeStatusDto {
+            remaining: code_set.map_or(0, |set| set.remaining()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::test_utils::utils::*;
+    use crate::error::UseCaseError;
+    use domain::models::auth::{RecoveryCodeRepository, TotpSecret};
+    use domain::models::user::{PasswordHash, User, UserId};
+    use domain::test_utils::FixedClock;
+    use rstest::*;
+
+    type TestUseCase = MfaEnrollmentUseCaseImpl<
+        StubTransactionManager,
+        StubTotpService,
+        StubPrefixPasswordService,
+        StubRecoveryCodeGenerator,
+        FixedClock,
+    >;
+
+    fn build_usecase(
+        user: Option<User>,
+        totp_repo: Arc<StubTotpCredentialRepository>,
+    ) -> (TestUseCase, Arc<StubRepositoryFactory>) {
+        let repo = Arc::new(StubUserRepository {
+            found_user: user,
+            save_error: None,
+            ..Default::default()
+        });
+        let factory = Arc::new(StubRepositoryFactory {
+            totp_credential_repo: totp_repo,
+            ..StubRepositoryFactory::new(repo)
+        });
+        let tm = Arc::new(StubTransactionManager {
+            factory: factory.clone(),
+        });
+        let usecase = MfaEnrollmentUseCaseImpl::new(
+            tm,
+            Arc::new(StubTotpService),
+            Arc::new(StubPrefixPasswordService),
+            Arc::new(StubRecoveryCodeGenerator::default()),
+            Arc::new(FixedClock::new(chrono::Utc::now())),
+        );
+        (usecase, factory)
+    }
+
+    #[fixture]
+    fn user(valid_email: Email) -> User {
+        User::new(
+            UserId::from(uuid::Uuid::now_v7()),
+            valid_email,
+            PasswordHash::from_str_unchecked("hash"),
+        )
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_enrollment_flow(user: User) {
+        let user_id = user.id();
+        let totp_repo = Arc::new(StubTotpCredentialRepository::default());
+        let (usecase, factory) = build_usecase(Some(user), totp_repo.clone());
+
+        let enrollment = usecase
+            .start_totp_enrollment(StartTotpEnrollmentCommand {
+                user_id,
+                expected_version: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(enrollment.secret.expose_as_str(), StubTotpService::SECRET);
+        assert!(enrollment.otpauth_uri.contains("test@example.com"));
+        assert!(!totp_repo.credentials()[0].is_enabled());
+
+        let recovery = usecase
+            .confirm_totp_enrollment(ConfirmTotpEnrollmentCommand {
+                user_id,
+                code: StubTotpService::VALID_CODE.to_string().into(),
+                expected_version: None,
+            })
+            .await
+            .unwrap();
+        assert!(totp_repo.credentials()[0].is_enabled());
+
+        // 確定と同時にリカバリーコードが発行され、ハッシュのみが保存される
+        assert_eq!(recovery.codes.len(), RECOVERY_CODE_COUNT);
+        let stored = &factory.recovery_code_repo.code_sets()[0];
+        assert_eq!(stored.remaining(), RECOVERY_CODE_COUNT);
+        assert_eq!(
+            stored.codes()[0].code_hash(),
+            &StubPrefixPasswordService::hash_of("CODE000000")
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_regenerate_recovery_codes(user: User) {
+        let user_id = user.id();
+        let enabled = TotpCredential::enroll(user_id, TotpSecret::from_str_unchecked("S"))
+            .confirm(1, chrono::Utc::now())
+            .unwrap();
+        let (usecase, factory) = build_usecase(
+            Some(user),
+            Arc::new(StubTotpCredentialRepository::with_credential(enabled)),
+        );
+        let used = RecoveryCodeSet::issue(user_id, vec![PasswordHash::from_str_unchecked("old")])
+            .consume(0, chrono::Utc::now())
+            .unwrap();
+        factory.recovery_code_repo.save(&used).await.unwrap();
+
+        let status = usecase
+            .get_recovery_code_status(RecoveryCodeStatusQuery { user_id })
+            .await
+            .unwrap();
+        assert_eq!(status.remaining, 0);
+
+        let recovery = usecase
+            .regenerate_recovery_codes(RegenerateRecoveryCodesCommand {
+                user_id,
+                expected_version: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(recovery.codes.len(), RECOVERY_CODE_COUNT);
+
+        // 以前の一式は置き換えられる
+        let status = usecase
+            .get_recovery_code_status(RecoveryCodeStatusQuery { user_id })
+            .await
+            .unwrap();
+        assert_eq!(status.remaining, RECOVERY_CODE_COUNT);
+        assert_eq!(factory.recovery_code_repo.code_sets().len(), 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_regenerate_requires_enabled_mfa(user: User) {
+        let user_id = user.id();
+        let (usecase, factory) = build_usecase(
+            Some(user),
+            Arc::new(StubTotpCredentialRepository::with_credential(
+                TotpCredential::enroll(user_id, TotpSecret::from_str_unchecked("S")),
+            )),
+        );
+
+        let result = usecase
+            .regenerate_recovery_codes(RegenerateRecoveryCodesCommand {
+                user_id,
+                expected_version: None,
+            })
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::InvalidInput(_))));
+        assert!(factory.recovery_code_repo.code_sets().is_empty());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_confirm_with_wrong_code(user: User) {
+        let user_id = user.id();
+        let totp_repo = Arc::new(StubTotpCredentialRepository::with_credential(
+            TotpCredential::enroll(user_id, TotpSecret::from_str_unchecked("S")),
+        ));
+        let (usecase, factory) = build_usecase(Some(user), totp_repo.clone());
+
+        let result = usecase
+            .confirm_totp_enrollment(ConfirmTotpEnrollmentCommand {
+                user_id,
+                code: "000000".to_string().into(),
+                expected_version: None,
+            })
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::Authentication(_))));
+        assert!(!totp_repo.credentials()[0].is_enabled());
+        assert!(factory.recovery_code_repo.code_sets().is_empty());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_start_when_already_enabled(user: User) {
+        let user_id = user.id();
+        let enabled = TotpCredential::enroll(user_id, TotpSecret::from_str_unchecked("S"))
+            .confirm(1, chrono::Utc::now())
+            .unwrap();
+        let (usecase, _) = build_usecase(
+            Some(user),
+            Arc::new(StubTotpCredentialRepository::with_credential(enabled)),
+        );
+
+        let result = usecase
+            .start_totp_enrollment(StartTotpEnrollmentCommand {
+                user_id,
+                expected_version: None,
+            })
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::Conflict(_))));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_stale_expected_version_is_rejected(user: User) {
+        let user_id = user.id();
+        let totp_repo = Arc::new(StubTotpCredentialRepository::with_credential(
+            TotpCredential::enroll(user_id, TotpSecret::from_str_unchecked("S")),
+        ));
+        let (usecase, factory) = build_usecase(Some(user), totp_repo.clone());
+
+        let result = usecase
+            .confirm_totp_enrollment(ConfirmTotpEnrollmentCommand {
+                user_id,
+                code: StubTotpService::VALID_CODE.to_string().into(),
+                expected_version: Some(Version::from(7)),
+            })
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::PreconditionFailed(_))));
+        assert!(!totp_repo.credentials()[0].is_enabled());
+        assert!(factory.recovery_code_repo.code_sets().is_empty());
+    }
+}
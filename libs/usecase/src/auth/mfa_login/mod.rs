@@ -0,0 +1,391 @@
+pub mod query;
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub use self::query::MfaLoginQuery;
+use crate::auth::AuthService;
+use crate::auth::login::dto::LoginResponseDto;
+use crate::error::{UseCaseError, UseCaseResult};
+use domain::id::IdGenerator;
+use domain::models::auth::{
+    AuthError, MfaError, OpaqueToken, OpaqueTokenService, PasswordService, RecoveryCode,
+    RecoveryCodeSet, RefreshToken, RefreshTokenId, TotpCode, TotpCredential, TotpService,
+};
+use domain::models::user::{User, UserError, UserIdentity};
+use domain::repository::tx::TransactionManager;
+use domain::{Actor, AuditContext, Clock};
+
+/// 監査カラムに記録するプログラムコード
+const PROGRAM_CODE: &str = "auth-mfa-login";
+
+/// MFA チャレンジに回答してログインを完了するユースケース。
+#[async_trait]
+pub trait MfaLoginUseCase: Send + Sync {
+    async fn verify(&self, query: MfaLoginQuery) -> UseCaseResult<LoginResponseDto>;
+}
+
+/// チャレンジへの回答として受け付ける第二要素。
+enum SecondFactor {
+    Totp(TotpCode),
+    /// 認証アプリを利用できない場合の代替手段
+    Recovery(RecoveryCode),
+}
+
+impl TryFrom<String> for SecondFactor {
+    type Error = MfaError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        match TotpCode::try_from(s.clone()) {
+            Ok(code) => Ok(Self::Totp(code)),
+            Err(e) => RecoveryCode::try_from(s)
+                .map(Self::Recovery)
+                .map_err(|_| e.into()),
+        }
+    }
+}
+
+/// 照合に成功し、使用済みとして記録すべき第二要素。
+enum AcceptedFactor {
+    Totp(TotpCredential),
+    Recovery(RecoveryCodeSet),
+}
+
+/// トランザクション内でのコード照合の結果。
+enum VerificationOutcome {
+    Verified(User),
+    /// 誤入力を記録した（記録を確実にコミットするため、エラーではなく結果として返す）
+    Rejected(MfaError),
+}
+
+pub struct MfaLoginUseCaseImpl<TM, TS, TOTP, PS, C, IG>
+where
+    TM: TransactionManager,
+    TS: OpaqueTokenService,
+    TOTP: TotpService,
+    PS: PasswordService,
+    C: Clock,
+    IG: IdGenerator<RefreshTokenId>,
+{
+    transaction_manager: Arc<TM>,
+    token_service: Arc<TS>,
+    totp_service: Arc<TOTP>,
+    password_service: Arc<PS>,
+    auth_service: Arc<dyn AuthService>,
+    clock: Arc<C>,
+    id_generator: Arc<IG>,
+}
+
+impl<TM, TS, TOTP, PS, C, IG> MfaLoginUseCaseImpl<TM, TS, TOTP, PS, C, IG>
+where
+    TM: TransactionManager,
+    TS: OpaqueTokenService,
+    TOTP: TotpService,
+    PS: PasswordService,
+    C: Clock,
+    IG: IdGenerator<RefreshTokenId>,
+{
+    pub fn new(
+        transaction_manager: Arc<TM>,
+        token_service: Arc<TS>,
+        totp_service: Arc<TOTP>,
+        password_service: Arc<PS>,
+        auth_service: Arc<dyn AuthService>,
+        clock: Arc<C>,
+        id_generator: Arc<IG>,
+    ) -> Self {
+        Self {
+            transaction_manager,
+            token_service,
+            totp_service,
+            password_service,
+            auth_service,
+            clock,
+            id_generator,
+        }
+    }
+}
+
+#[async_trait]
+impl<TM, TS, TOTP, PS, C, IG> MfaLoginUseCase for MfaLoginUseCaseImpl<TM, TS, TOTP, PS, C, IG>
+where
+    TM: TransactionManager,
+    TS: OpaqueTokenService + 'static,
+    TOTP: TotpService + 'static,
+    PS: PasswordService + 'static,
+    C: Clock + 'static,
+    IG: IdGenerator<RefreshTokenId> + 'static,
+{
+    async fn verify(&self, query: MfaLoginQuery) -> UseCaseResult<LoginResponseDto> {
+        let second_factor = SecondFactor::try_from(query.code.into_inner())?;
+        let challenge_hash = self
+            .token_service
+            .hash(&OpaqueToken::from(query.mfa_token.into_inner()));
+        let totp_service = Arc::clone(&self.totp_service);
+        let password_service = Arc::clone(&self.password_service);
+
+        let refresh_token = self.token_service.generate();
+        let refresh_token_hash = self.token_service.hash(&refresh_token);
+        let refresh_token_id = self.id_generator.generate();
+        let now = self.clock.now();
+
+        let audit = AuditContext::new(Actor::Anonymous, PROGRAM_CODE);
+        let outcome = domain::tx!(self.transaction_manager, audit, |factory| {
+            let challenge_repo = factory.mfa_challenge_repository();
+            let totp_repo = factory.totp_credential_repository();
+            let recovery_repo = factory.recovery_code_repository();
+
+            let challenge = challenge_repo
+                .find_by_token_hash(&challenge_hash)
+                .await?
+                .ok_or(MfaError::InvalidChallenge)?;
+            challenge.ensure_usable(now)?;
+
+            let credential = totp_repo
+                .find_by_user_id(challenge.user_id())
+                .await?
+                .ok_or(MfaError::InvalidChallenge)?;
+
+            let accepted = match &second_factor {
+                SecondFactor::Totp(code) => totp_service
+                    .verify(credential.secret(), code, now)
+                    .ok_or(MfaError::InvalidCode)
+                    .and_then(|step| credential.accept_step(step))
+                    .map(AcceptedFactor::Totp),
+                SecondFactor::Recovery(code) => {
+                    let code_set = recovery_repo
+                        .find_by_user_id(challenge.user_id())
+                        .await?
+                        .unwrap_or_else(|| RecoveryCodeSet::issue(challenge.user_id(), vec![]));
+
+                    // ハッシュはソルト付きのため、未使用のコードと 1 件ずつ照合する
+                    let raw = code.to_raw_password();
+                    let mut matched = None;
+                    for (index, hash) in code_set.unused() {
+                        if password_service.verify(&raw, hash).await? {
+                            matched = Some(index);
+                            break;
+                        }
+                    }
+                    matched
+                        .ok_or(MfaError::InvalidCode)
+                        .and_then(|index| code_set.consume(index, now))
+                        .map(AcceptedFactor::Recovery)
+                }
+            };
+            match accepted {
+                Ok(AcceptedFactor::Totp(credential)) => totp_repo.save(&credential).await?,
+                Ok(AcceptedFactor::Recovery(code_set)) => recovery_repo.save(&code_set).await?,
+                Err(e) => {
+                    challenge_repo.save(&challenge.record_failure()).await?;
+                    return Ok(VerificationOutcome::Rejected(e));
+                }
+            }
+            challenge_repo.delete(challenge.id()).await?;
+
+            let user = factory
+                .user_repository()
+                .find_by_id(challenge.user_id())
+                .await?
+                .ok_or(UserError::NotFound)?;
+            // チャレンジの発行後に停止・削除・再設定の要求をされた場合はトークンを発行しない
+            user.ensure_active(now)?;
+            user.ensure_password_usable()?;
+
+            // 新しいファミリーの先頭となるリフレッシュトークンを永続化
+            let record = RefreshToken::issue(refresh_token_id, user.id(), refresh_token_hash, now);
+            factory.refresh_token_repository().save(&record).await?;
+
+            Ok::<VerificationOutcome, domain::error::DomainError>(VerificationOutcome::Verified(
+                user,
+            ))
+        })
+        .await?;
+
+        let user = match outcome {
+            VerificationOutcome::Verified(user) => user,
+            VerificationOutcome::Rejected(e) => {
+                return Err(UseCaseError::from(AuthError::from(e)));
+            }
+        };
+
+        let token = self.auth_service.issue_token(user.id(), user.roles())?;
+
+        Ok(LoginResponseDto::new(&user, token, refresh_token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthToken;
+    use crate::auth::test_utils::utils::*;
+    use domain::models::auth::{
+        MfaChallenge, MfaChallengeId, MfaChallengeRepository, OpaqueTokenHash, TotpCredential,
+        TotpSecret,
+    };
+    use domain::models::user::{Email, PasswordHash, UserId};
+    use domain::test_utils::FixedClock;
+    use rstest::*;
+
+    struct Fixture {
+        usecase: MfaLoginUseCaseImpl<
+            StubTransactionManager,
+            StubOpaqueTokenService,
+            StubTotpService,
+            StubPrefixPasswordService,
+            FixedClock,
+            StubUuidGenerator,
+        >,
+        factory: Arc<StubRepositoryFactory>,
+        challenge: MfaChallenge,
+    }
+
+    #[fixture]
+    fn fixture(valid_email: Email) -> Fixture {
+        let now = chrono::Utc::now();
+        let user = User::new(
+            UserId::from(uuid::Uuid::now_v7()),
+            valid_email,
+            PasswordHash::from_str_unchecked("hash"),
+        );
+        let credential = TotpCredential::enroll(
+            user.id(),
+            TotpSecret::from_str_unchecked(StubTotpService::SECRET),
+        )
+        .confirm(1, now)
+        .unwrap();
+        let challenge = MfaChallenge::issue(
+            MfaChallengeId::from(uuid::Uuid::now_v7()),
+            user.id(),
+            OpaqueTokenHash::from_str_unchecked("hashed:challenge"),
+            now,
+        );
+        let recovery_codes = RecoveryCodeSet::issue(
+            user.id(),
+            vec![
+                StubPrefixPasswordService::hash_of("RECOVERY00"),
+                StubPrefixPasswordService::hash_of("RECOVERY01"),
+            ],
+        );
+        let repo = Arc::new(StubUserRepository {
+            found_user: Some(user),
+            save_error: None,
+            ..Default::default()
+        });
+        let factory = Arc::new(StubRepositoryFactory {
+            recovery_code_repo: Arc::new(StubRecoveryCodeRepository::with_code_set(recovery_codes)),
+            totp_credential_repo: Arc::new(StubTotpCredentialRepository::with_credential(
+                credential,
+            )),
+            mfa_challenge_repo: Arc::new(StubMfaChallengeRepository::with_challenge(
+                challenge.clone(),
+            )),
+            ..StubRepositoryFactory::new(repo)
+        });
+        let tm = Arc::new(StubTransactionManager {
+            factory: factory.clone(),
+        });
+        let auth_service = Arc::new(StubAuthService {
+            issue_token_result: Arc::new(|| Ok(AuthToken::from("access-token"))),
+            verify_token_result: Arc::new(|| unreachable!()),
+        });
+        let usecase = MfaLoginUseCaseImpl::new(
+            tm,
+            Arc::new(StubOpaqueTokenService::default()),
+            Arc::new(StubTotpService),
+            Arc::new(StubPrefixPasswordService),
+            auth_service,
+            Arc::new(FixedClock::new(now)),
+            Arc::new(StubUuidGenerator),
+        );
+        Fixture {
+            usecase,
+            factory,
+            challenge,
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_verify_issues_tokens_and_consumes_challenge(fixture: Fixture) {
+        let result = fixture
+            .usecase
+            .verify(MfaLoginQuery {
+                mfa_token: "challenge".to_string().into(),
+                code: StubTotpService::VALID_CODE.to_string().into(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.token.expose_as_str(), "access-token");
+        assert_eq!(fixture.factory.refresh_token_repo.tokens().len(), 1);
+        assert!(fixture.factory.mfa_challenge_repo.challenges().is_empty());
+
+        // 回答済みのチャレンジは再利用できない
+        let replay = fixture
+            .usecase
+            .verify(MfaLoginQuery {
+                mfa_token: "challenge".to_string().into(),
+                code: StubTotpService::VALID_CODE.to_string().into(),
+            })
+            .await;
+        assert!(matches!(replay, Err(UseCaseError::Authentication(_))));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_verify_wrong_code_records_failure(fixture: Fixture) {
+        let result = fixture
+            .usecase
+            .verify(MfaLoginQuery {
+                mfa_token: "challenge".to_string().into(),
+                code: "000000".to_string().into(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::Authentication(_))));
+        assert!(fixture.factory.refresh_token_repo.tokens().is_empty());
+        let challenges = fixture.factory.mfa_challenge_repo.challenges();
+        assert_eq!(challenges[0].id(), fixture.challenge.id());
+        assert_eq!(challenges[0].failed_attempts(), 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_verify_with_recovery_code_is_single_use(fixture: Fixture) {
+        let result = fixture
+            .usecase
+            .verify(MfaLoginQuery {
+                mfa_token: "challenge".to_string().into(),
+                code: "recov-ery01".to_string().into(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.token.expose_as_str(), "access-token");
+        let code_set = &fixture.factory.recovery_code_repo.code_sets()[0];
+        assert_eq!(code_set.remaining(), 1);
+        assert!(code_set.codes()[1].is_used());
+
+        // 使用済みのコードは次のチャレンジでは受け付けない
+        fixture
+            .factory
+            .mfa_challenge_repo
+            .save(&fixture.challenge)
+            .await
+            .unwrap();
+        let replay = fixture
+            .usecase
+            .verify(MfaLoginQuery {
+                mfa_token: "challenge".to_string().into(),
+                code: "RECOVERY01".to_string().into(),
+            })
+            .await;
+        assert!(matches!(replay, Err(UseCaseError::Authentication(_))));
+        assert_eq!(
+            fixture.factory.mfa_challenge_repo.challenges()[0].failed_attempts(),
+            1
+        );
+    }
+}
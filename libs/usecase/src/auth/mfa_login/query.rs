@@ -0,0 +1,10 @@
+use sensitive_data::{SecretRule, Sensitive};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MfaLoginQuery {
+    /// パスワード認証時に返却されたチャレンジトークン
+    pub mfa_token: Sensitive<String, SecretRule>,
+    /// 認証アプリに表示されたコード、またはリカバリーコード
+    pub code: Sensitive<String, SecretRule>,
+}
@@ -1,10 +1,43 @@
+pub mod authenticate;
+pub mod breached_password;
+pub mod discovery;
+pub mod email_verification;
 pub mod login;
+pub mod login_attempt;
+pub mod logout;
+pub mod mfa_enrollment;
+pub mod mfa_login;
+pub mod password_change;
+pub mod password_reset;
+pub mod refresh;
+pub mod revocation;
 pub mod service;
 pub mod signup;
+pub mod verification_token;
 
 #[cfg(test)]
 pub mod test_utils;
 
+pub use authenticate::{AuthenticateUseCase, AuthenticateUseCaseImpl};
+pub use breached_password::BreachedPasswordChecker;
+pub use discovery::{DiscoveryUseCase, DiscoveryUseCaseImpl};
+pub use domain::models::user::{Permission, Role};
+pub use email_verification::{
+    EmailVerificationUseCase, EmailVerificationUseCaseImpl, VerificationMailHandler,
+};
 pub use login::{AuthQueryUseCase, AuthQueryUseCaseImpl};
-pub use service::{AuthService, AuthToken, Claims};
-pub use signup::{AuthCommandUseCase, AuthCommandUseCaseImpl};
+pub use login_attempt::{LoginAttemptStore, LoginThrottle};
+pub use logout::{LogoutUseCase, LogoutUseCaseImpl};
+pub use mfa_enrollment::{MfaEnrollmentUseCase, MfaEnrollmentUseCaseImpl};
+pub use mfa_login::{MfaLoginUseCase, MfaLoginUseCaseImpl};
+pub use password_change::{PasswordChangeUseCase, PasswordChangeUseCaseImpl};
+pub use password_reset::{PasswordResetUseCase, PasswordResetUseCaseImpl};
+pub use refresh::{TokenRefreshUseCase, TokenRefreshUseCaseImpl};
+pub use revocation::TokenRevocationStore;
+pub use service::{
+    AuthService, AuthToken, Claims, ClaimsEnricher, CustomClaim, CustomClaims, JsonWebKey,
+};
+pub use signup::{AuthCommandUseCase, AuthCommandUseCaseImpl, SignupAttemptMailHandler};
+pub use verification_token::{
+    EmailVerificationClaims, EmailVerificationToken, EmailVerificationTokenService,
+};
@@ -0,0 +1,19 @@
+use domain::Version;
+use domain::models::user::UserId;
+use sensitive_data::{SecretRule, Sensitive};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangePasswordCommand {
+    /// パスワードを変更するユーザー（アクセストークンの `sub`）
+    pub user_id: UserId,
+    /// 変更を要求したアクセストークンの `jti`（新しいトークンと引き換えに失効させる）
+    pub jti: Uuid,
+    /// 変更を要求したアクセストークンの `exp`
+    pub exp: usize,
+    pub current_password: Sensitive<String, SecretRule>,
+    pub new_password: Sensitive<String, SecretRule>,
+    /// クライアントが参照したユーザーのバージョン（`If-Match`）。`None` の場合は検証しない
+    pub expected_version: Option<Version>,
+}
@@ -0,0 +1,446 @@
+pub mod command;
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub use self::command::ChangePasswordCommand;
+use crate::auth::breached_password::{BreachedPasswordChecker, ensure_not_breached};
+use crate::auth::login::dto::LoginResponseDto;
+use crate::auth::{AuthService, LoginAttemptStore, LoginThrottle, TokenRevocationStore};
+use crate::error::UseCaseResult;
+use crate::event::EventSink;
+use domain::id::IdGenerator;
+use domain::models::auth::{
+    AuthError, LoginThrottleConfig, OpaqueTokenService, PasswordPolicy, PasswordService,
+    RawPassword, RefreshToken, RefreshTokenId, ThrottleKey,
+};
+use domain::models::user::{Authenticatable, User, UserError, UserIdentity};
+use domain::repository::tx::TransactionManager;
+use domain::{Actor, AuditContext, Clock, DomainEvent};
+
+/// 監査カラムに記録するプログラムコード
+const PROGRAM_CODE: &str = "auth-password-change";
+
+/// ログイン中のユーザーが現在のパスワードを確認したうえで新しいパスワードを設定するユースケース。
+#[async_trait]
+pub trait PasswordChangeUseCase: Send + Sync {
+    /// パスワードを変更し、他のセッションをすべて失効させる
+    ///
+    /// 要求したクライアントのトークンも失効させるため、引き換えに新しいセッションを返す。
+    async fn change_password(
+        &self,
+        command: ChangePasswordCommand,
+    ) -> UseCaseResult<LoginResponseDto>;
+}
+
+pub struct PasswordChangeUseCaseImpl<TM, PS, TS, C, IG>
+where
+    TM: TransactionManager,
+    PS: PasswordService,
+    TS: OpaqueTokenService,
+    C: Clock,
+    IG: IdGenerator<RefreshTokenId>,
+{
+    transaction_manager: Arc<TM>,
+    password_service: Arc<PS>,
+    breached_password_checker: Arc<dyn BreachedPasswordChecker>,
+    token_service: Arc<TS>,
+    auth_service: Arc<dyn AuthService>,
+    revocation_store: Arc<dyn TokenRevocationStore>,
+    event_sink: Arc<dyn EventSink>,
+    clock: Arc<C>,
+    id_generator: Arc<IG>,
+    password_policy: PasswordPolicy,
+    throttle: LoginThrottle,
+}
+
+impl<TM, PS, TS, C, IG> PasswordChangeUseCaseImpl<TM, PS, TS, C, IG>
+where
+    TM: TransactionManager,
+    PS: PasswordService,
+    TS: OpaqueTokenService,
+    C: Clock,
+    IG: IdGenerator<RefreshTokenId>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        transaction_manager: Arc<TM>,
+        password_service: Arc<PS>,
+        breached_password_checker: Arc<dyn BreachedPasswordChecker>,
+        token_service: Arc<TS>,
+        auth_service: Arc<dyn AuthService>,
+        revocation_store: Arc<dyn TokenRevocationStore>,
+        event_sink: Arc<dyn EventSink>,
+        clock: Arc<C>,
+        id_generator: Arc<IG>,
+        password_policy: PasswordPolicy,
+        attempt_store: Arc<dyn LoginAttemptStore>,
+        throttle: LoginThrottleConfig,
+    ) -> Self {
+        Self {
+            transaction_manager,
+            password_service,
+            breached_password_checker,
+            token_service,
+            auth_service,
+            revocation_store,
+            event_sink,
+            clock,
+            id_generator,
+            password_policy,
+            throttle: LoginThrottle::new(attempt_store, throttle),
+        }
+    }
+}
+
+#[async_trait]
+impl<TM, PS, TS, C, IG> PasswordChangeUseCase for PasswordChangeUseCaseImpl<TM, PS, TS, C, IG>
+where
+    TM: TransactionManager,
+    PS: PasswordService + 'static,
+    TS: OpaqueTokenService + 'static,
+    C: Clock + 'static,
+    IG: IdGenerator<RefreshTokenId> + 'static,
+{
+    async fn change_password(
+        &self,
+        command: ChangePasswordCommand,
+    ) -> UseCaseResult<LoginResponseDto> {
+        let current_password =
+            RawPassword::from(command.current_password.into_inner()).normalized();
+        let new_password = RawPassword::from(command.new_password.into_inner());
+        // 照会はパスワードのみで行えるため、トランザクションの外で先に済ませる
+        ensure_not_breached(&*self.breached_password_checker, &new_password.normalized()).await?;
+
+        let refresh_token = self.token_service.generate();
+        let refresh_token_hash = self.token_service.hash(&refresh_token);
+        let refresh_token_id: RefreshTokenId = self.id_generator.generate();
+        let user_id = command.user_id;
+        let expected_version = command.expected_version;
+        let now = self.clock.now();
+
+        // ハッシュの照合・計算は時間がかかるため、接続を保持しないようトランザクションの外で行う
+        let audit = AuditContext::new(Actor::User(user_id), PROGRAM_CODE);
+        let user = domain::tx!(self.transaction_manager, audit, |factory| {
+            let user = factory
+                .user_repository()
+                .find_by_id(user_id)
+                .await?
+                .ok_or(UserError::NotFound)?;
+            Ok::<User, domain::error::DomainError>(user)
+        })
+        .await?;
+        if let Some(expected) = expected_version {
+            user.ensure_version(expected)?;
+        }
+        user.ensure_active(now)?;
+        // 管理者が再設定を要求したパスワードは漏洩を疑われているため、変更の確認にも使わせない
+        user.ensure_password_usable()?;
+
+        // 奪われたセッションからの総当たりを防ぐため、ログインと同じ記録で試行を制限する
+        let account_key = ThrottleKey::Account(user.email().clone());
+        self.throttle.begin_attempt(&account_key, None, now).await?;
+        let is_valid = match self
+            .password_service
+            .verify(&current_password, user.password_hash())
+            .await
+        {
+            Ok(is_valid) => is_valid,
+            Err(e) => {
+                self.throttle.cancel(&account_key, None, now).await?;
+                return Err(e.into());
+            }
+        };
+        if !is_valid {
+            let audit = AuditContext::new(Actor::User(user_id), PROGRAM_CODE);
+            self.throttle
+                .fail(
+                    &*self.transaction_manager,
+                    &*self.event_sink,
+                    audit,
+                    &account_key,
+                    user_id,
+                    now,
+                )
+                .await?;
+            return Err(AuthError::InvalidCredentials.into());
+        }
+        self.throttle.succeed(&account_key, None, now).await?;
+
+        let new_password = self.password_policy.validate(&new_password, user.email())?;
+        let password_hash = self.password_service.hash(new_password.as_raw()).await?;
+
+        let audit = AuditContext::new(Actor::User(user_id), PROGRAM_CODE);
+        let (user, events) = domain::tx!(self.transaction_manager, audit, |factory| {
+            // 読み込み後に更新されていれば、保存時のバージョンの照合で検知される
+            let (user, events) = user.change_password(password_hash, now);
+            factory.user_repository().save(&user).await?;
+
+            // 他の端末のセッションをすべて失効させ、要求元には新しいファミリーを発行する
+            let refresh_token_repo = factory.refresh_token_repository();
+            refresh_token_repo
+                .revoke_all_by_user_id(user.id(), now)
+                .await?;
+            let record = RefreshToken::issue(refresh_token_id, user.id(), refresh_token_hash, now);
+            refresh_token_repo.save(&record).await?;
+
+            let events: Vec<DomainEvent> = events.into_iter().map(DomainEvent::from).collect();
+            factory.outbox_repository().append(&events).await?;
+
+            Ok::<(User, Vec<DomainEvent>), domain::error::DomainError>((user, events))
+        })
+        .await?;
+
+        // 他の端末のアクセストークンは変更日時より前の発行として認証時に拒否される。
+        // 発行日時は秒単位で比較するため、要求元のトークンは同じ秒でも使えないよう個別に失効させる。
+        self.revocation_store
+            .revoke(command.jti, command.exp)
+            .await?;
+        self.event_sink.publish(&events).await;
+
+        let token = self.auth_service.issue_token(user.id(), user.roles())?;
+
+        Ok(LoginResponseDto::new(&user, token, refresh_token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthToken;
+    use crate::auth::test_utils::utils::*;
+    use crate::error::UseCaseError;
+    use domain::Version;
+    use domain::models::auth::{LoginThrottlePolicy, OpaqueTokenHash, RefreshTokenStatus};
+    use domain::models::user::{AccountStatus, Email, UserId};
+    use domain::test_utils::FixedClock;
+    use rstest::*;
+
+    struct Fixture {
+        usecase: PasswordChangeUseCaseImpl<
+            StubTransactionManager,
+            StubPrefixPasswordService,
+            StubOpaqueTokenService,
+            FixedClock,
+            StubUuidGenerator,
+        >,
+        factory: Arc<StubRepositoryFactory>,
+        revocation_store: Arc<StubTokenRevocationStore>,
+        event_sink: Arc<StubEventSink>,
+        attempt_store: Arc<StubLoginAttemptStore>,
+    }
+
+    #[fixture]
+    fn user(valid_email: Email) -> User {
+        User::new(
+            UserId::from(uuid::Uuid::now_v7()),
+            valid_email,
+            StubPrefixPasswordService::hash_of("Current-Pass1"),
+        )
+    }
+
+    fn build(user: &User) -> Fixture {
+        let session = RefreshToken::issue(
+            RefreshTokenId::from(uuid::Uuid::now_v7()),
+            user.id(),
+            OpaqueTokenHash::from_str_unchecked("hashed:other-device"),
+            chrono::Utc::now(),
+        );
+        let factory = Arc::new(StubRepositoryFactory {
+            refresh_token_repo: Arc::new(StubRefreshTokenRepository::with_tokens(vec![session])),
+            ..StubRepositoryFactory::new(Arc::new(StubUserRepository::with_user(user.clone())))
+        });
+        let tm = Arc::new(StubTransactionManager {
+            factory: factory.clone(),
+        });
+        let revocation_store = Arc::new(StubTokenRevocationStore::default());
+        let event_sink = Arc::new(StubEventSink::default());
+        let attempt_store = Arc::new(StubLoginAttemptStore::default());
+        let usecase = PasswordChangeUseCaseImpl::new(
+            tm,
+            Arc::new(StubPrefixPasswordService),
+            Arc::new(StubBreachedPasswordChecker::with_breached(["Password123!"])),
+            Arc::new(StubOpaqueTokenService::default()),
+            Arc::new(StubAuthService {
+                issue_token_result: Arc::new(|| Ok(AuthToken::from("new-access-token"))),
+                verify_token_result: Arc::new(|| unreachable!()),
+            }),
+            revocation_store.clone(),
+            event_sink.clone(),
+            Arc::new(FixedClock::new(chrono::Utc::now())),
+            Arc::new(StubUuidGenerator),
+            PasswordPolicy::default(),
+            attempt_store.clone(),
+            LoginThrottleConfig::default(),
+        );
+        Fixture {
+            usecase,
+            factory,
+            revocation_store,
+            event_sink,
+            attempt_store,
+        }
+    }
+
+    fn command(user: &User, current: &str, new: &str) -> ChangePasswordCommand {
+        ChangePasswordCommand {
+            user_id: user.id(),
+            jti: uuid::Uuid::now_v7(),
+            exp: 0,
+            current_password: current.to_string().into(),
+            new_password: new.to_string().into(),
+            expected_version: None,
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_change_password_revokes_other_sessions(user: User) {
+        let fixture = build(&user);
+        let command = command(&user, "Current-Pass1", "NewPassword123!");
+        let jti = command.jti;
+
+        let response = fixture.usecase.change_password(command).await.unwrap();
+
+        let saved = fixture.factory.repo.saved_users();
+        assert_eq!(
+            saved[0].password_hash(),
+            &StubPrefixPasswordService::hash_of("NewPassword123!")
+        );
+        // 既存のセッションは失効し、要求元に発行した新しいセッションのみが有効
+        let tokens = fixture.factory.refresh_token_repo.tokens();
+        let active: Vec<_> = tokens
+            .iter()
+            .filter(|t| !matches!(t.status(), RefreshTokenStatus::Revoked { .. }))
+            .collect();
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(active.len(), 1);
+        assert_eq!(
+            active[0].token_hash(),
+            &OpaqueTokenHash::from_str_unchecked(format!(
+                "hashed:{}",
+                response.refresh_token.expose_as_str()
+            ))
+        );
+        assert!(fixture.revocation_store.is_revoked(jti).await.unwrap());
+
+        let published = fixture.event_sink.published();
+        assert_eq!(published.len(), 1);
+        assert_eq!(published[0].event_type(), "PasswordChanged");
+        assert_eq!(fixture.factory.outbox_repo.events(), published);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_change_password_requires_current_password(user: User) {
+        let fixture = build(&user);
+        let command = command(&user, "Wrong-Pass1", "NewPassword123!");
+        let jti = command.jti;
+
+        let result = fixture.usecase.change_password(command).await;
+
+        assert!(matches!(result, Err(UseCaseError::Authentication(_))));
+        assert!(fixture.factory.repo.saved_users().is_empty());
+        assert!(
+            fixture
+                .factory
+                .refresh_token_repo
+                .tokens()
+                .iter()
+                .all(|t| !matches!(t.status(), RefreshTokenStatus::Revoked { .. }))
+        );
+        assert!(!fixture.revocation_store.is_revoked(jti).await.unwrap());
+        assert!(fixture.event_sink.published().is_empty());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_change_password_locks_account_after_repeated_failures(user: User) {
+        let fixture = build(&user);
+        for _ in 0..LoginThrottlePolicy::ACCOUNT.max_failures {
+            let result = fixture
+                .usecase
+                .change_password(command(&user, "Wrong-Pass1", "NewPassword123!"))
+                .await;
+            assert!(matches!(result, Err(UseCaseError::Authentication(_))));
+        }
+        assert_eq!(
+            fixture
+                .attempt_store
+                .failure_count(&ThrottleKey::Account(user.email().clone())),
+            5
+        );
+
+        // ログインと同じくロックアウトされ、正しいパスワードでも照合しない
+        let result = fixture
+            .usecase
+            .change_password(command(&user, "Current-Pass1", "NewPassword123!"))
+            .await;
+        assert!(matches!(
+            result,
+            Err(UseCaseError::Locked {
+                retry_after: Some(30),
+                ..
+            })
+        ));
+        // ユーザーはロック中になるだけで、パスワードは変更されない
+        let saved = fixture.factory.repo.saved_users();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].status(), AccountStatus::Locked);
+        assert_eq!(saved[0].password_hash(), user.password_hash());
+    }
+
+    #[rstest]
+    #[case::policy_violation("short", "too_short")]
+    #[case::contains_email("Test-Password1", "contains_email")]
+    #[tokio::test]
+    async fn test_change_password_validates_new_password(
+        user: User,
+        #[case] new_password: &str,
+        #[case] expected_code: &str,
+    ) {
+        let fixture = build(&user);
+
+        let result = fixture
+            .usecase
+            .change_password(command(&user, "Current-Pass1", new_password))
+            .await;
+
+        let Err(UseCaseError::Validation { violations, .. }) = result else {
+            panic!("expected a policy violation, got {:?}", result);
+        };
+        assert!(violations.iter().any(|v| v.code == expected_code));
+        assert!(fixture.factory.repo.saved_users().is_empty());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_change_password_rejects_breached_password(user: User) {
+        let fixture = build(&user);
+
+        let result = fixture
+            .usecase
+            .change_password(command(&user, "Current-Pass1", "Password123!"))
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::InvalidInput(_))));
+        assert!(fixture.factory.repo.saved_users().is_empty());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_change_password_checks_version(user: User) {
+        let fixture = build(&user);
+
+        let result = fixture
+            .usecase
+            .change_password(ChangePasswordCommand {
+                expected_version: Some(Version::from(7)),
+                ..command(&user, "Current-Pass1", "NewPassword123!")
+            })
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::PreconditionFailed(_))));
+        assert!(fixture.factory.repo.saved_users().is_empty());
+    }
+}
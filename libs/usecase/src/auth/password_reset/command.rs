@@ -0,0 +1,14 @@
+use sensitive_data::{EmailRule, SecretRule, Sensitive};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestPasswordResetCommand {
+    pub email: Sensitive<String, EmailRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResetPasswordCommand {
+    /// メールで送付されたリセットトークン
+    pub token: Sensitive<String, SecretRule>,
+    pub new_password: Sensitive<String, SecretRule>,
+}
@@ -0,0 +1,563 @@
+pub mod command;
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub use self::command::{RequestPasswordResetCommand, ResetPasswordCommand};
+use crate::auth::breached_password::{BreachedPasswordChecker, ensure_not_breached};
+use crate::error::UseCaseResult;
+use crate::event::EventSink;
+use crate::mailer::{Mail, MailMessage, Mailer};
+use domain::id::IdGenerator;
+use domain::models::auth::{
+    OpaqueToken, OpaqueTokenService, PasswordPolicy, PasswordResetError, PasswordResetToken,
+    PasswordResetTokenId, PasswordService, RawPassword,
+};
+use domain::models::user::{Email, User, UserIdentity};
+use domain::repository::tx::TransactionManager;
+use domain::{Actor, AuditContext, Clock, DomainEvent};
+
+/// 監査カラムに記録するプログラムコード
+const PROGRAM_CODE: &str = "auth-password-reset";
+
+/// メールで送付する使い捨てトークンによるパスワードリセットのユースケース。
+#[async_trait]
+pub trait PasswordResetUseCase: Send + Sync {
+    /// リセット用のトークンを発行してメールで送付する
+    ///
+    /// メールアドレスの登録有無を推測されないよう、未登録の場合も送信に失敗した場合も成功として扱う。
+    /// 応答時間からも推測されないよう、`Mailer` には送信を待たずに返す実装を渡すこと。
+    async fn request_password_reset(
+        &self,
+        command: RequestPasswordResetCommand,
+    ) -> UseCaseResult<()>;
+
+    /// トークンを検証して新しいパスワードを設定する
+    async fn reset_password(&self, command: ResetPasswordCommand) -> UseCaseResult<()>;
+}
+
+pub struct PasswordResetUseCaseImpl<TM, PS, TS, C, IG>
+where
+    TM: TransactionManager,
+    PS: PasswordService,
+    TS: OpaqueTokenService,
+    C: Clock,
+    IG: IdGenerator<PasswordResetTokenId>,
+{
+    transaction_manager: Arc<TM>,
+    password_service: Arc<PS>,
+    breached_password_checker: Arc<dyn BreachedPasswordChecker>,
+    token_service: Arc<TS>,
+    mailer: Arc<dyn Mailer>,
+    event_sink: Arc<dyn EventSink>,
+    clock: Arc<C>,
+    id_generator: Arc<IG>,
+    password_policy: PasswordPolicy,
+}
+
+impl<TM, PS, TS, C, IG> PasswordResetUseCaseImpl<TM, PS, TS, C, IG>
+where
+    TM: TransactionManager,
+    PS: PasswordService,
+    TS: OpaqueTokenService,
+    C: Clock,
+    IG: IdGenerator<PasswordResetTokenId>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        transaction_manager: Arc<TM>,
+        password_service: Arc<PS>,
+        breached_password_checker: Arc<dyn BreachedPasswordChecker>,
+        token_service: Arc<TS>,
+        mailer: Arc<dyn Mailer>,
+        event_sink: Arc<dyn EventSink>,
+        clock: Arc<C>,
+        id_generator: Arc<IG>,
+        password_policy: PasswordPolicy,
+    ) -> Self {
+        Self {
+            transaction_manager,
+            password_service,
+            breached_password_checker,
+            token_service,
+            mailer,
+            event_sink,
+            clock,
+            id_generator,
+            password_policy,
+        }
+    }
+}
+
+#[async_trait]
+impl<TM, PS, TS, C, IG> PasswordResetUseCase for PasswordResetUseCaseImpl<TM, PS, TS, C, IG>
+where
+    TM: TransactionManager,
+    PS: PasswordService + 'static,
+    TS: OpaqueTokenService + 'static,
+    C: Clock + 'static,
+    IG: IdGenerator<PasswordResetTokenId> + 'static,
+{
+    async fn request_password_reset(
+        &self,
+        command: RequestPasswordResetCommand,
+    ) -> UseCaseResult<()> {
+        let email = Email::try_from(command.email.into_inner())?;
+
+        let token = self.token_service.generate();
+        let token_hash = self.token_service.hash(&token);
+        let token_id = self.id_generator.generate();
+        let now = self.clock.now();
+
+        let audit = AuditContext::new(Actor::Anonymous, PROGRAM_CODE);
+        let issued = domain::tx!(self.transaction_manager, audit, |factory| {
+            let Some(user) = factory.user_repository().find_by_email(&email).await? else {
+                return Ok(None);
+            };
+            // 停止中・削除済みのアカウントにも未登録と同じく何もせず、状態を推測させない
+            if user.ensure_active(now).is_err() {
+                return Ok(None);
+            }
+
+            // 以前に発行した未使用のトークンは無効化し、最新の 1 件のみを有効とする
+            let record = PasswordResetToken::issue(token_id, user.id(), token_hash, now);
+            let reset_repo = factory.password_reset_token_repository();
+            reset_repo.delete_by_user_id(user.id()).await?;
+            reset_repo.save(&record).await?;
+
+            Ok::<Option<(User, PasswordResetToken)>, domain::error::DomainError>(Some((
+                user, record,
+            )))
+        })
+        .await?;
+
+        if let Some((user, record)) = issued
+            && let Err(e) = self
+                .mailer
+                .send(Mail {
+                    to: user.email().clone(),
+                    message: MailMessage::PasswordReset {
+                        token,
+                        expires_at: record.expires_at(),
+                    },
+                })
+                .await
+        {
+            // 登録済みの場合にのみ失敗を返すと、登録有無の推測に使われる
+            tracing::warn!(error = ?e, user_id = %user.id(), "Failed to send password reset mail");
+        }
+
+        Ok(())
+    }
+
+    async fn reset_password(&self, command: ResetPasswordCommand) -> UseCaseResult<()> {
+        let token_hash = self
+            .token_service
+            .hash(&OpaqueToken::from(command.token.into_inner()));
+        let new_password = RawPassword::from(command.new_password.into_inner());
+        // 照会はパスワードのみで行えるため、トランザクションの外で先に済ませる
+        ensure_not_breached(&*self.breached_password_checker, &new_password.normalized()).await?;
+        let now = self.clock.now();
+
+        // ハッシュの計算は時間がかかるため、接続を保持しないようトランザクションの外で行う
+        let audit = AuditContext::new(Actor::Anonymous, PROGRAM_CODE);
+        let lookup_hash = token_hash.clone();
+        let user = domain::tx!(self.transaction_manager, audit, |factory| {
+            let record = factory
+                .password_reset_token_repository()
+                .find_by_token_hash(&lookup_hash)
+                .await?
+                .ok_or(PasswordResetError::InvalidToken)?;
+            record.ensure_usable(now)?;
+
+            let user = factory
+                .user_repository()
+                .find_by_id(record.user_id())
+                .await?
+                .ok_or(PasswordResetError::InvalidToken)?;
+            Ok::<User, domain::error::DomainError>(user)
+        })
+        .await?;
+        // 発行後に停止・削除されたアカウントは、パスワードを設定しても利用を再開させない
+        user.ensure_active(now)?;
+        // メールアドレスとの照合が必要なため、ユーザーを特定してから検証する
+        let new_password = self.password_policy.validate(&new_password, user.email())?;
+        let password_hash = self.password_service.hash(new_password.as_raw()).await?;
+
+        let audit = AuditContext::new(Actor::Anonymous, PROGRAM_CODE);
+        let events = domain::tx!(self.transaction_manager, audit, |factory| {
+            // 読み込み後に同じトークンが使用・再発行されていれば無効とする。
+            // 行を確定までロックし、同時に使用された場合は後の要求が削除後の状態を読む。
+            let reset_repo = factory.password_reset_token_repository();
+            let record = reset_repo
+                .find_by_token_hash(&token_hash)
+                .await?
+                .ok_or(PasswordResetError::InvalidToken)?;
+            record.ensure_usable(now)?;
+
+            let (user, events) = user.change_password(password_hash, now);
+            factory.user_repository().save(&user).await?;
+
+            // 使用したトークンを含め、ユーザーのトークンをすべて無効化する
+            reset_repo.delete_by_user_id(user.id()).await?;
+            // 以前のパスワードで確立されたセッションもすべて失効させる
+            factory
+                .refresh_token_repository()
+                .revoke_all_by_user_id(user.id(), now)
+                .await?;
+
+            let events: Vec<DomainEvent> = events.into_iter().map(DomainEvent::from).collect();
+            factory.outbox_repository().append(&events).await?;
+
+            Ok::<Vec<DomainEvent>, domain::error::DomainError>(events)
+        })
+        .await?;
+
+        self.event_sink.publish(&events).await;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::test_utils::utils::*;
+    use crate::error::UseCaseError;
+    use domain::models::auth::{
+        OpaqueTokenHash, PASSWORD_RESET_TOKEN_TTL, PasswordResetTokenRepository, RefreshToken,
+        RefreshTokenId, RefreshTokenRepository, RefreshTokenStatus,
+    };
+    use domain::models::user::{Authenticatable, PasswordHash, UserId};
+    use domain::test_utils::FixedClock;
+    use rstest::*;
+
+    struct Fixture {
+        usecase: PasswordResetUseCaseImpl<
+            StubTransactionManager,
+            StubPrefixPasswordService,
+            StubOpaqueTokenService,
+            FixedClock,
+            StubUuidGenerator,
+        >,
+        factory: Arc<StubRepositoryFactory>,
+        mailer: Arc<StubMailer>,
+        event_sink: Arc<StubEventSink>,
+    }
+
+    fn build(repo: StubUserRepository, now: chrono::DateTime<chrono::Utc>) -> Fixture {
+        build_with_mailer(repo, now, StubMailer::default())
+    }
+
+    fn build_with_mailer(
+        repo: StubUserRepository,
+        now: chrono::DateTime<chrono::Utc>,
+        mailer: StubMailer,
+    ) -> Fixture {
+        let repo = Arc::new(repo);
+        let factory = Arc::new(StubRepositoryFactory::new(repo));
+        let tm = Arc::new(StubTransactionManager {
+            factory: factory.clone(),
+        });
+        let mailer = Arc::new(mailer);
+        let event_sink = Arc::new(StubEventSink::default());
+        let usecase = PasswordResetUseCaseImpl::new(
+            tm,
+            Arc::new(StubPrefixPasswordService),
+            Arc::new(StubBreachedPasswordChecker::with_breached(["Password123!"])),
+            Arc::new(StubOpaqueTokenService::default()),
+            mailer.clone(),
+            event_sink.clone(),
+            Arc::new(FixedClock::new(now)),
+            Arc::new(StubUuidGenerator),
+            PasswordPolicy::default(),
+        );
+        Fixture {
+            usecase,
+            factory,
+            mailer,
+            event_sink,
+        }
+    }
+
+    #[fixture]
+    fn user(valid_email: Email) -> User {
+        User::new(
+            UserId::from(uuid::Uuid::now_v7()),
+            valid_email,
+            PasswordHash::from_str_unchecked("old-hash"),
+        )
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_reset_flow(user: User) {
+        let email = user.email().clone();
+        let session = RefreshToken::issue(
+            RefreshTokenId::from(uuid::Uuid::now_v7()),
+            user.id(),
+            OpaqueTokenHash::from_str_unchecked("hashed:session"),
+            chrono::Utc::now(),
+        );
+        let fixture = build(StubUserRepository::with_user(user), chrono::Utc::now());
+        fixture
+            .factory
+            .refresh_token_repo
+            .save(&session)
+            .await
+            .unwrap();
+
+        fixture
+            .usecase
+            .request_password_reset(RequestPasswordResetCommand {
+                email: "test@example.com".to_string().into(),
+            })
+            .await
+            .unwrap();
+
+        // トークンの平文はメールでのみ送付され、保存されるのはハッシュ値
+        let sent = fixture.mailer.sent();
+        assert_eq!(sent.len(), 1);
+        let MailMessage::PasswordReset { token, .. } = &sent[0].message else {
+            panic!("expected a password reset mail");
+        };
+        assert_eq!(sent[0].to, email);
+        let stored = fixture.factory.password_reset_token_repo.tokens();
+        assert_eq!(
+            stored[0].token_hash(),
+            &OpaqueTokenHash::from_str_unchecked(format!("hashed:{}", token.expose_as_str()))
+        );
+
+        fixture
+            .usecase
+            .reset_password(ResetPasswordCommand {
+                token: token.expose_as_str().to_string().into(),
+                new_password: "NewPassword123!".to_string().into(),
+            })
+            .await
+            .unwrap();
+
+        let saved = fixture.factory.repo.saved_users();
+        assert_eq!(
+            saved[0].password_hash(),
+            &StubPrefixPasswordService::hash_of("NewPassword123!")
+        );
+        assert!(
+            fixture
+                .factory
+                .password_reset_token_repo
+                .tokens()
+                .is_empty()
+        );
+        let events = fixture.factory.outbox_repo.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), "PasswordChanged");
+        assert_eq!(fixture.event_sink.published(), events);
+        // 既存のセッションはすべて失効する
+        assert!(matches!(
+            fixture.factory.refresh_token_repo.tokens()[0].status(),
+            RefreshTokenStatus::Revoked { .. }
+        ));
+
+        // 使用済みのトークンは再利用できない
+        let replay = fixture
+            .usecase
+            .reset_password(ResetPasswordCommand {
+                token: token.expose_as_str().to_string().into(),
+                new_password: "Another123!".to_string().into(),
+            })
+            .await;
+        assert!(matches!(replay, Err(UseCaseError::InvalidInput(_))));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_reset_rejects_password_violating_policy(user: User) {
+        let fixture = build(StubUserRepository::with_user(user), chrono::Utc::now());
+        fixture
+            .usecase
+            .request_password_reset(RequestPasswordResetCommand {
+                email: "test@example.com".to_string().into(),
+            })
+            .await
+            .unwrap();
+        let MailMessage::PasswordReset { token, .. } = &fixture.mailer.sent()[0].message else {
+            panic!("expected a password reset mail");
+        };
+
+        // メールアドレスのローカル部を含むパスワードは設定できず、トークンも消費されない
+        let result = fixture
+            .usecase
+            .reset_password(ResetPasswordCommand {
+                token: token.expose_as_str().to_string().into(),
+                new_password: "Test-Password1".to_string().into(),
+            })
+            .await;
+
+        let Err(UseCaseError::Validation { violations, .. }) = result else {
+            panic!("expected a policy violation, got {:?}", result);
+        };
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, "contains_email");
+        assert!(fixture.factory.repo.saved_users().is_empty());
+        assert_eq!(fixture.factory.password_reset_token_repo.tokens().len(), 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_reset_rejects_breached_password(user: User) {
+        let fixture = build(StubUserRepository::with_user(user), chrono::Utc::now());
+        fixture
+            .usecase
+            .request_password_reset(RequestPasswordResetCommand {
+                email: "test@example.com".to_string().into(),
+            })
+            .await
+            .unwrap();
+        let MailMessage::PasswordReset { token, .. } = &fixture.mailer.sent()[0].message else {
+            panic!("expected a password reset mail");
+        };
+
+        let result = fixture
+            .usecase
+            .reset_password(ResetPasswordCommand {
+                token: token.expose_as_str().to_string().into(),
+                new_password: "Password123!".to_string().into(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::InvalidInput(_))));
+        assert!(fixture.factory.repo.saved_users().is_empty());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_request_for_unknown_email_is_silent() {
+        let fixture = build(StubUserRepository::default(), chrono::Utc::now());
+
+        let result = fixture
+            .usecase
+            .request_password_reset(RequestPasswordResetCommand {
+                email: "unknown@example.com".to_string().into(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+        assert!(fixture.mailer.sent().is_empty());
+        assert!(
+            fixture
+                .factory
+                .password_reset_token_repo
+                .tokens()
+                .is_empty()
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_request_succeeds_even_if_mail_fails(user: User) {
+        let fixture = build_with_mailer(
+            StubUserRepository::with_user(user),
+            chrono::Utc::now(),
+            StubMailer::failing(),
+        );
+
+        // 未登録の場合と同じく成功として扱い、登録有無を推測させない
+        let result = fixture
+            .usecase
+            .request_password_reset(RequestPasswordResetCommand {
+                email: "test@example.com".to_string().into(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(fixture.factory.password_reset_token_repo.tokens().len(), 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_request_for_suspended_account_is_silent(user: User) {
+        let (user, _) = user.suspend(chrono::Utc::now()).unwrap();
+        let fixture = build(StubUserRepository::with_user(user), chrono::Utc::now());
+
+        let result = fixture
+            .usecase
+            .request_password_reset(RequestPasswordResetCommand {
+                email: "test@example.com".to_string().into(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+        assert!(fixture.mailer.sent().is_empty());
+        assert!(
+            fixture
+                .factory
+                .password_reset_token_repo
+                .tokens()
+                .is_empty()
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_reset_rejects_suspended_account(user: User) {
+        let now = chrono::Utc::now();
+        // トークンの発行後に停止されたアカウント
+        let token = PasswordResetToken::issue(
+            PasswordResetTokenId::from(uuid::Uuid::now_v7()),
+            user.id(),
+            OpaqueTokenHash::from_str_unchecked("hashed:reset"),
+            now,
+        );
+        let (user, _) = user.suspend(now).unwrap();
+        let fixture = build(StubUserRepository::with_user(user), now);
+        fixture
+            .factory
+            .password_reset_token_repo
+            .save(&token)
+            .await
+            .unwrap();
+
+        let result = fixture
+            .usecase
+            .reset_password(ResetPasswordCommand {
+                token: "reset".to_string().into(),
+                new_password: "NewPassword123!".to_string().into(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::Forbidden(_))));
+        assert!(fixture.factory.repo.saved_users().is_empty());
+        assert_eq!(fixture.factory.password_reset_token_repo.tokens().len(), 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_reset_with_expired_token(user: User) {
+        let now = chrono::Utc::now();
+        let fixture = build(StubUserRepository::with_user(user.clone()), now);
+        let expired = PasswordResetToken::issue(
+            PasswordResetTokenId::from(uuid::Uuid::now_v7()),
+            user.id(),
+            OpaqueTokenHash::from_str_unchecked("hashed:reset"),
+            now - PASSWORD_RESET_TOKEN_TTL,
+        );
+        fixture
+            .factory
+            .password_reset_token_repo
+            .save(&expired)
+            .await
+            .unwrap();
+
+        let result = fixture
+            .usecase
+            .reset_password(ResetPasswordCommand {
+                token: "reset".to_string().into(),
+                new_password: "NewPassword123!".to_string().into(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::InvalidInput(_))));
+        assert!(fixture.factory.repo.saved_users().is_empty());
+    }
+}
@@ -0,0 +1,7 @@
+use sensitive_data::{SecretRule, Sensitive};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshTokenCommand {
+    pub refresh_token: Sensitive<String, SecretRule>,
+}
@@ -0,0 +1,9 @@
+use crate::auth::AuthToken;
+use domain::models::auth::OpaqueToken;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RefreshResponseDto {
+    pub token: AuthToken,
+    pub refresh_token: OpaqueToken,
+}
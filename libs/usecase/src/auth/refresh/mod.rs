@@ -0,0 +1,262 @@
+pub mod command;
+pub mod dto;
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub use self::command::RefreshTokenCommand;
+use self::dto::RefreshResponseDto;
+use crate::auth::AuthService;
+use crate::error::{UseCaseError, UseCaseResult};
+use domain::id::IdGenerator;
+use domain::models::auth::{
+    AuthError, OpaqueToken, OpaqueTokenService, RefreshTokenError, RefreshTokenId,
+};
+use domain::models::user::{User, UserIdentity};
+use domain::repository::tx::TransactionManager;
+use domain::{Actor, AuditContext, Clock};
+
+/// 監査カラムに記録するプログラムコード
+const PROGRAM_CODE: &str = "auth-refresh";
+
+#[async_trait]
+pub trait TokenRefreshUseCase: Send + Sync {
+    async fn refresh(&self, command: RefreshTokenCommand) -> UseCaseResult<RefreshResponseDto>;
+}
+
+/// トランザクション内でのローテーション結果。
+enum RotationOutcome {
+    /// ローテーションに成功した。アクセストークンには最新のロールを載せる
+    Rotated(User),
+    /// 再利用を検知してファミリーを失効させた
+    ReuseDetected(RefreshTokenError),
+}
+
+pub struct TokenRefreshUseCaseImpl<TM, TS, C, IG>
+where
+    TM: TransactionManager,
+    TS: OpaqueTokenService,
+    C: Clock,
+    IG: IdGenerator<RefreshTokenId>,
+{
+    transaction_manager: Arc<TM>,
+    token_service: Arc<TS>,
+    auth_service: Arc<dyn AuthService>,
+    clock: Arc<C>,
+    id_generator: Arc<IG>,
+}
+
+impl<TM, TS, C, IG> TokenRefreshUseCaseImpl<TM, TS, C, IG>
+where
+    TM: TransactionManager,
+    TS: OpaqueTokenService,
+    C: Clock,
+    IG: IdGenerator<RefreshTokenId>,
+{
+    pub fn new(
+        transaction_manager: Arc<TM>,
+        token_service: Arc<TS>,
+        auth_service: Arc<dyn AuthService>,
+        clock: Arc<C>,
+        id_generator: Arc<IG>,
+    ) -> Self {
+        Self {
+            transaction_manager,
+            token_service,
+            auth_service,
+            clock,
+            id_generator,
+        }
+    }
+}
+
+#[async_trait]
+impl<TM, TS, C, IG> TokenRefreshUseCase for TokenRefreshUseCaseImpl<TM, TS, C, IG>
+where
+    TM: TransactionManager,
+    TS: OpaqueTokenService + 'static,
+    C: Clock + 'static,
+    IG: IdGenerator<RefreshTokenId> + 'static,
+{
+    async fn refresh(&self, command: RefreshTokenCommand) -> UseCaseResult<RefreshResponseDto> {
+        let presented_hash = self
+            .token_service
+            .hash(&OpaqueToken::from(command.refresh_token.into_inner()));
+
+        let next_token = self.token_service.generate();
+        let next_hash = self.token_service.hash(&next_token);
+        let next_id = self.id_generator.generate();
+        let now = self.clock.now();
+
+        let audit = AuditContext::new(Actor::Anonymous, PROGRAM_CODE);
+        let outcome = domain::tx!(self.transaction_manager, audit, |factory| {
+            let refresh_token_repo = factory.refresh_token_repository();
+
+            let current = refresh_token_repo
+                .find_by_token_hash(&presented_hash)
+                .await?
+                .ok_or(AuthError::InvalidRefreshToken)?;
+
+            match current.rotate(next_id, next_hash, now) {
+                Ok((used, next)) => {
+                    refresh_token_repo.save(&used).await?;
+                    refresh_token_repo.save(&next).await?;
+                    let user = factory
+                        .user_repository()
+                        .find_by_id(next.user_id())
+                        .await?
+                        .ok_or(AuthError::InvalidRefreshToken)?;
+                    user.ensure_active(now)?;
+                    Ok::<RotationOutcome, domain::error::DomainError>(RotationOutcome::Rotated(
+                        user,
+                    ))
+                }
+                Err(e @ RefreshTokenError::ReuseDetected { family_id }) => {
+                    // 失効処理を確実にコミットするため、エラーではなく結果として返す
+                    refresh_token_repo.revoke_family(family_id, now).await?;
+                    Ok(RotationOutcome::ReuseDetected(e))
+                }
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await?;
+
+        let user = match outcome {
+            RotationOutcome::Rotated(user) => user,
+            RotationOutcome::ReuseDetected(e) => {
+                return Err(UseCaseError::from(AuthError::from(e)));
+            }
+        };
+
+        let token = self.auth_service.issue_token(user.id(), user.roles())?;
+
+        Ok(RefreshResponseDto {
+            token,
+            refresh_token: next_token,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthToken;
+    use crate::auth::test_utils::utils::*;
+    use domain::models::auth::{OpaqueTokenHash, RefreshToken, RefreshTokenStatus};
+    use domain::models::user::UserId;
+    use domain::test_utils::{FixedClock, MockIdGenerator};
+    use rstest::*;
+
+    fn setup(
+        token_repo: Arc<StubRefreshTokenRepository>,
+    ) -> TokenRefreshUseCaseImpl<
+        StubTransactionManager,
+        StubOpaqueTokenService,
+        FixedClock,
+        MockIdGenerator<RefreshTokenId>,
+    > {
+        let repo = Arc::new(StubUserRepository::with_user(User::new(
+            UserId::from(uuid::Uuid::now_v7()),
+            valid_email(),
+            valid_password_hash(),
+        )));
+        let factory = Arc::new(StubRepositoryFactory {
+            refresh_token_repo: token_repo,
+            ..StubRepositoryFactory::new(repo)
+        });
+        let tm = Arc::new(StubTransactionManager { factory });
+        let auth_service = Arc::new(StubAuthService {
+            issue_token_result: Arc::new(|| Ok(AuthToken::from("access-token"))),
+            verify_token_result: Arc::new(|| unreachable!()),
+        });
+        TokenRefreshUseCaseImpl::new(
+            tm,
+            Arc::new(StubOpaqueTokenService::default()),
+            auth_service,
+            Arc::new(FixedClock::new(chrono::Utc::now())),
+            Arc::new(MockIdGenerator::with_generated_ids(1)),
+        )
+    }
+
+    fn stored_token(status: RefreshTokenStatus) -> RefreshToken {
+        let issued = RefreshToken::issue(
+            RefreshTokenId::from(uuid::Uuid::now_v7()),
+            UserId::from(uuid::Uuid::now_v7()),
+            OpaqueTokenHash::from_str_unchecked("hashed:presented"),
+            chrono::Utc::now(),
+        );
+        RefreshToken::reconstruct(
+            issued.id(),
+            issued.family_id(),
+            issued.user_id(),
+            issued.token_hash().clone(),
+            issued.issued_at(),
+            issued.expires_at(),
+            status,
+        )
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_refresh_rotates_token() {
+        let current = stored_token(RefreshTokenStatus::Active);
+        let token_repo = Arc::new(StubRefreshTokenRepository::with_tokens(vec![
+            current.clone(),
+        ]));
+        let usecase = setup(token_repo.clone());
+
+        let result = usecase
+            .refresh(RefreshTokenCommand {
+                refresh_token: "presented".to_string().into(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.token.expose_as_str(), "access-token");
+        let tokens = token_repo.tokens();
+        assert_eq!(tokens.len(), 2);
+        let used = tokens.iter().find(|t| t.id() == current.id()).unwrap();
+        assert!(matches!(used.status(), RefreshTokenStatus::Used { .. }));
+        let next = tokens.iter().find(|t| t.id() != current.id()).unwrap();
+        assert_eq!(next.family_id(), current.family_id());
+        assert_eq!(
+            next.token_hash().as_ref(),
+            &format!("hashed:{}", result.refresh_token.expose_as_str())
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_refresh_reuse_revokes_family() {
+        let current = stored_token(RefreshTokenStatus::Used {
+            used_at: chrono::Utc::now(),
+        });
+        let token_repo = Arc::new(StubRefreshTokenRepository::with_tokens(vec![
+            current.clone(),
+        ]));
+        let usecase = setup(token_repo.clone());
+
+        let result = usecase
+            .refresh(RefreshTokenCommand {
+                refresh_token: "presented".to_string().into(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::Authentication(_))));
+        assert_eq!(token_repo.revoked_families(), vec![current.family_id()]);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_refresh_unknown_token() {
+        let usecase = setup(Arc::new(StubRefreshTokenRepository::default()));
+
+        let result = usecase
+            .refresh(RefreshTokenCommand {
+                refresh_token: "unknown".to_string().into(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::Authentication(_))));
+    }
+}
@@ -0,0 +1,16 @@
+use crate::error::TokenRevocationError;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// 有効期限前に失効させたアクセストークン（`jti`）を管理するポート。
+///
+/// 失効情報はトークンの有効期限（`exp`）を過ぎれば不要になるため、
+/// 実装は期限切れのエントリを破棄してよい。
+#[async_trait]
+pub trait TokenRevocationStore: Send + Sync {
+    /// 指定したトークンを失効させる
+    async fn revoke(&self, jti: Uuid, exp: usize) -> Result<(), TokenRevocationError>;
+
+    /// 指定したトークンが失効済みかどうかを返す
+    async fn is_revoked(&self, jti: Uuid) -> Result<bool, TokenRevocationError>;
+}
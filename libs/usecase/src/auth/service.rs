@@ -1,16 +1,116 @@
-use crate::error::AuthServiceError;
+use crate::error::{AuthServiceError, UseCaseResult};
 use async_trait::async_trait;
 use derive_more::{Display, From};
 use domain::SensitiveDebug;
-use domain::models::user::UserId;
+use domain::models::auth::AuthError;
+use domain::models::user::{Permission, Role, UserId};
 use sensitive_data::{SecretRule, SensitiveData};
-use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+/// 予約済みのクレーム名。カスタムクレームには使用できない
+pub const RESERVED_CLAIM_NAMES: &[&str] = &[
+    "sub",
+    "iat",
+    "exp",
+    "nbf",
+    "jti",
+    "iss",
+    "aud",
+    "roles",
+    "permissions",
+];
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: UserId,
     pub iat: usize,
     pub exp: usize,
+    /// トークン固有のID。サーバー側での失効管理に使用する
+    pub jti: Uuid,
+    /// 発行時点のロール（認証後は現在のロールに置き換わる）
+    #[serde(default)]
+    pub roles: Vec<Role>,
+    /// ロールから導出した権限。認証時に現在のロールから導出し直し、ハンドラーはこの値で認可する
+    #[serde(default)]
+    pub permissions: Vec<Permission>,
+    /// アプリケーションが追加したクレーム（`ClaimsEnricher` を参照）
+    #[serde(flatten)]
+    pub custom: CustomClaims,
+}
+
+impl Claims {
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.permissions.contains(&permission)
+    }
+
+    /// 権限を持たない場合は `AuthError::Forbidden` を返す
+    pub fn authorize(&self, permission: Permission) -> UseCaseResult<()> {
+        if self.has_permission(permission) {
+            Ok(())
+        } else {
+            Err(AuthError::Forbidden.into())
+        }
+    }
+}
+
+/// アプリケーションが追加する型付きのクレーム（テナント ID、セッション ID 等）。
+pub trait CustomClaim: Serialize + DeserializeOwned {
+    /// JWT のクレーム名（`RESERVED_CLAIM_NAMES` 以外）
+    const NAME: &'static str;
+}
+
+/// クレーム名をキーとするカスタムクレームの集合。
+///
+/// 予約済みのクレーム名は保持しない。
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct CustomClaims(BTreeMap<String, serde_json::Value>);
+
+impl CustomClaims {
+    /// クレームを設定する。予約済みの名前やシリアライズできない値は `IssuanceFailed`
+    pub fn insert<T: CustomClaim>(&mut self, value: &T) -> Result<(), AuthServiceError> {
+        if RESERVED_CLAIM_NAMES.contains(&T::NAME) {
+            return Err(AuthServiceError::IssuanceFailed(anyhow::anyhow!(
+                "claim name {} is reserved",
+                T::NAME
+            )));
+        }
+        let value = serde_json::to_value(value)
+            .map_err(|e| AuthServiceError::IssuanceFailed(anyhow::Error::from(e)))?;
+        self.0.insert(T::NAME.to_string(), value);
+        Ok(())
+    }
+
+    /// クレームを取得する。値の形式が異なる場合は `InvalidToken`
+    pub fn get<T: CustomClaim>(&self) -> Result<Option<T>, AuthServiceError> {
+        self.0
+            .get(T::NAME)
+            .map(|value| {
+                serde_json::from_value(value.clone()).map_err(|_| AuthServiceError::InvalidToken)
+            })
+            .transpose()
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomClaims {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // iss / aud 等、Claims のフィールド以外の登録済みクレームを取り除く
+        let mut claims = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
+        claims.retain(|name, _| !RESERVED_CLAIM_NAMES.contains(&name.as_str()));
+        Ok(Self(claims))
+    }
+}
+
+/// アクセストークンの発行時にカスタムクレームを追加する拡張ポイント。
+pub trait ClaimsEnricher: Send + Sync {
+    fn enrich(
+        &self,
+        user_id: UserId,
+        roles: &[Role],
+        claims: &mut CustomClaims,
+    ) -> Result<(), AuthServiceError>;
 }
 
 /// 認証用トークン（JWT等）を表現する値オブジェクト。
@@ -39,12 +139,94 @@ impl SensitiveData for AuthToken {
     }
 }
 
+/// トークンの署名を検証するための公開鍵（RFC 7517 の JWK）。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonWebKey {
+    /// 鍵の種類（`OKP` / `RSA`）
+    pub kty: String,
+    /// トークンのヘッダーの `kid` に対応する鍵 ID
+    pub kid: String,
+    /// 署名アルゴリズム（`EdDSA` / `RS256`）
+    pub alg: String,
+    /// 曲線名（OKP のみ）
+    pub crv: Option<String>,
+    /// 公開鍵（OKP のみ、Base64URL）
+    pub x: Option<String>,
+    /// モジュラス（RSA のみ、Base64URL）
+    pub n: Option<String>,
+    /// 公開指数（RSA のみ、Base64URL）
+    pub e: Option<String>,
+}
+
 /// 認証・認可に関する外部サービス（JWT発行等）との境界を定義するポート。
 #[async_trait]
 pub trait AuthService: Send + Sync {
-    /// ユーザーIDから認証トークンを発行する
-    fn issue_token(&self, user_id: UserId) -> Result<AuthToken, AuthServiceError>;
+    /// ユーザーIDとロールから認証トークンを発行する
+    fn issue_token(&self, user_id: UserId, roles: &[Role]) -> Result<AuthToken, AuthServiceError>;
 
     /// 認証トークンを検証し、Claimsを返す
     fn verify_token(&self, token: &AuthToken) -> Result<Claims, AuthServiceError>;
+
+    /// 署名の検証に使用できる公開鍵の一覧（共有鍵は含めない）
+    fn verification_keys(&self) -> Vec<JsonWebKey>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct TenantId(String);
+
+    impl CustomClaim for TenantId {
+        const NAME: &'static str = "tenant";
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Issuer(String);
+
+    impl CustomClaim for Issuer {
+        const NAME: &'static str = "iss";
+    }
+
+    #[test]
+    fn test_custom_claims_round_trip_through_claims() {
+        let mut custom = CustomClaims::default();
+        custom.insert(&TenantId("acme".to_string())).unwrap();
+        assert!(matches!(
+            custom.insert(&Issuer("spoofed".to_string())),
+            Err(AuthServiceError::IssuanceFailed(_))
+        ));
+
+        let claims = Claims {
+            sub: UserId::from(Uuid::now_v7()),
+            iat: 0,
+            exp: 1,
+            jti: Uuid::new_v4(),
+            roles: vec![Role::User],
+            permissions: Role::permissions_of(&[Role::User]),
+            custom,
+        };
+        let mut json = serde_json::to_value(&claims).unwrap();
+        assert_eq!(json["tenant"], "acme");
+
+        // 登録済みのクレームはカスタムクレームに含めない
+        json["iss"] = "https://auth.example.com".into();
+        json["aud"] = serde_json::json!(["api"]);
+        let decoded: Claims = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.custom, claims.custom);
+        assert_eq!(
+            decoded.custom.get::<TenantId>().unwrap(),
+            Some(TenantId("acme".to_string()))
+        );
+
+        let mut malformed = CustomClaims::default();
+        malformed
+            .0
+            .insert("tenant".to_string(), serde_json::json!(1));
+        assert!(matches!(
+            malformed.get::<TenantId>(),
+            Err(AuthServiceError::InvalidToken)
+        ));
+    }
 }
@@ -16,3 +16,12 @@ impl From<User> for SignupResponseDTO {
         }
     }
 }
+
+/// 新規登録の結果。
+#[derive(Debug, Clone)]
+pub enum SignupOutcomeDto {
+    /// 登録が完了した
+    Registered(SignupResponseDTO),
+    /// 受け付けた（登録の有無を明かさない）
+    Accepted,
+}
@@ -5,17 +5,59 @@ use async_trait::async_trait;
 use std::sync::Arc;
 
 pub use self::command::SignupCommand;
-use self::dto::SignupResponseDTO;
+use self::dto::{SignupOutcomeDto, SignupResponseDTO};
+use crate::auth::breached_password::{BreachedPasswordChecker, ensure_not_breached};
 use crate::error::UseCaseResult;
-use domain::Clock;
+use crate::event::{EventHandler, EventSink};
+use crate::mailer::{Mail, MailMessage, Mailer};
 use domain::id::IdGenerator;
-use domain::models::auth::{PasswordService, RawPassword};
-use domain::models::user::{Email, User, UserId, UserUniquenessChecker};
+use domain::models::auth::{PasswordPolicy, PasswordService, RawPassword};
+use domain::models::user::{
+    AccountStatus, Email, EmailVerificationDispatch, SignupDisclosurePolicy, User, UserEvent,
+    UserId, UserIdentity, UserUniquenessChecker,
+};
 use domain::repository::tx::TransactionManager;
+use domain::{Actor, AuditContext, Clock, DomainEvent};
+
+/// 監査カラムに記録するプログラムコード
+const PROGRAM_CODE: &str = "auth-signup";
 
 #[async_trait]
 pub trait AuthCommandUseCase: Send + Sync {
-    async fn signup(&self, command: SignupCommand) -> UseCaseResult<SignupResponseDTO>;
+    async fn signup(&self, command: SignupCommand) -> UseCaseResult<SignupOutcomeDto>;
+}
+
+/// 登録済みのメールアドレスでの新規登録（`SignupAttempted`）を受けて所有者へ通知するイベントハンドラー。
+pub struct SignupAttemptMailHandler {
+    mailer: Arc<dyn Mailer>,
+}
+
+impl SignupAttemptMailHandler {
+    pub fn new(mailer: Arc<dyn Mailer>) -> Self {
+        Self { mailer }
+    }
+}
+
+#[async_trait]
+impl EventHandler for SignupAttemptMailHandler {
+    async fn handle(&self, event: &DomainEvent) -> UseCaseResult<()> {
+        match event {
+            DomainEvent::User(UserEvent::SignupAttempted {
+                email, occurred_at, ..
+            }) => {
+                self.mailer
+                    .send(Mail {
+                        to: email.clone(),
+                        message: MailMessage::SignupAttempted {
+                            attempted_at: *occurred_at,
+                        },
+                    })
+                    .await?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 pub struct AuthCommandUseCaseImpl<TM, UC, PS, C, IG>
@@ -29,8 +71,12 @@ where
     transaction_manager: Arc<TM>,
     user_uniqueness_checker: Arc<UC>,
     password_service: Arc<PS>,
-    _clock: Arc<C>,
+    breached_password_checker: Arc<dyn BreachedPasswordChecker>,
+    event_sink: Arc<dyn EventSink>,
+    clock: Arc<C>,
     id_generator: Arc<IG>,
+    password_policy: PasswordPolicy,
+    disclosure_policy: SignupDisclosurePolicy,
 }
 
 impl<TM, UC, PS, C, IG> AuthCommandUseCaseImpl<TM, UC, PS, C, IG>
@@ -41,19 +87,28 @@ where
     C: Clock,
     IG: IdGenerator<UserId>,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         transaction_manager: Arc<TM>,
         user_uniqueness_checker: Arc<UC>,
         password_service: Arc<PS>,
+        breached_password_checker: Arc<dyn BreachedPasswordChecker>,
+        event_sink: Arc<dyn EventSink>,
         clock: Arc<C>,
         id_generator: Arc<IG>,
+        password_policy: PasswordPolicy,
+        disclosure_policy: SignupDisclosurePolicy,
     ) -> Self {
         Self {
             transaction_manager,
             user_uniqueness_checker,
             password_service,
-            _clock: clock,
+            breached_password_checker,
+            event_sink,
+            clock,
             id_generator,
+            password_policy,
+            disclosure_policy,
         }
     }
 }
@@ -67,31 +122,71 @@ where
     C: Clock + 'static,
     IG: IdGenerator<UserId> + 'static,
 {
-    async fn signup(&self, command: SignupCommand) -> UseCaseResult<SignupResponseDTO> {
+    async fn signup(&self, command: SignupCommand) -> UseCaseResult<SignupOutcomeDto> {
         let email = Email::try_from(command.email.into_inner())?;
 
         let checker = Arc::clone(&self.user_uniqueness_checker);
         let password_service = Arc::clone(&self.password_service);
         let id_generator = Arc::clone(&self.id_generator);
 
-        let password_hash = password_service
-            .hash(&RawPassword::from(command.password.into_inner()))
-            .await?;
+        let password = self
+            .password_policy
+            .validate(&RawPassword::from(command.password.into_inner()), &email)?;
+        ensure_not_breached(&*self.breached_password_checker, password.as_raw()).await?;
+        let password_hash = password_service.hash(password.as_raw()).await?;
+        let now = self.clock.now();
+        let disclosure_policy = self.disclosure_policy;
 
-        let user = domain::tx!(self.transaction_manager, |factory| {
+        // ハッシュ化は登録の有無にかかわらず行い、応答時間からアカウントの有無を推測させない
+        let audit = AuditContext::new(Actor::Anonymous, PROGRAM_CODE);
+        let (registered, events) = domain::tx!(self.transaction_manager, audit, |factory| {
             let user_repo = factory.user_repository();
 
+            if disclosure_policy == SignupDisclosurePolicy::Conceal
+                && let Some(owner) = user_repo.find_by_email(&email).await?
+            {
+                // 削除済みのアカウントには通知しない
+                let events: Vec<DomainEvent> = if owner.status() == AccountStatus::Deleted {
+                    Vec::new()
+                } else {
+                    owner
+                        .signup_attempted(now)
+                        .into_iter()
+                        .map(DomainEvent::from)
+                        .collect()
+                };
+                factory.outbox_repository().append(&events).await?;
+                // 登録済みのため登録せず、所有者への通知のみを行う
+                return Ok((None, events));
+            }
+
             checker.check_email_uniqueness(&*user_repo, &email).await?;
 
-            let user = User::new(id_generator.generate(), email, password_hash);
+            let (user, events) = User::register(id_generator.generate(), email, password_hash, now);
 
             user_repo.save(&user).await?;
+            // 確認メールは UserRegistered を受けて送信される。直後の再送をクールダウンの対象にする
+            factory
+                .email_verification_dispatch_repository()
+                .save(&EmailVerificationDispatch::record(user.id(), now))
+                .await?;
+
+            let events: Vec<DomainEvent> = events.into_iter().map(DomainEvent::from).collect();
+            factory.outbox_repository().append(&events).await?;
 
-            Ok::<User, domain::error::DomainError>(user)
+            Ok::<_, domain::error::DomainError>((Some(user), events))
         })
         .await?;
 
-        Ok(SignupResponseDTO::from(user))
+        // 登録自体は完了しているため、後続の副作用（確認メール等）の失敗は成功として扱う
+        self.event_sink.publish(&events).await;
+
+        Ok(match (disclosure_policy, registered) {
+            (SignupDisclosurePolicy::Disclose, Some(user)) => {
+                SignupOutcomeDto::Registered(SignupResponseDTO::from(user))
+            }
+            _ => SignupOutcomeDto::Accepted,
+        })
     }
 }
 
@@ -114,9 +209,12 @@ mod tests {
         let repo = Arc::new(StubUserRepository {
             found_user: None,
             save_error: None,
+            ..Default::default()
+        });
+        let factory = Arc::new(StubRepositoryFactory::new(repo));
+        let tm = Arc::new(StubTransactionManager {
+            factory: factory.clone(),
         });
-        let factory = Arc::new(StubRepositoryFactory { repo });
-        let tm = Arc::new(StubTransactionManager { factory });
         let checker = Arc::new(StubUserUniquenessChecker {
             error_factory: None,
         });
@@ -124,22 +222,48 @@ mod tests {
             verify_result: Arc::new(|| Ok(true)),
             hash_result: Arc::new(move || Ok(valid_password_hash.clone())),
         });
-        let clock = Arc::new(FixedClock::new(chrono::Utc::now()));
+        let clock_now = chrono::Utc::now();
+        let clock = Arc::new(FixedClock::new(clock_now));
         let id_generator = Arc::new(MockIdGenerator::<UserId>::with_generated_ids(1));
         let expected_id = id_generator.expected_ids()[0];
 
-        let usecase = AuthCommandUseCaseImpl::new(tm, checker, ps, clock, id_generator);
+        let event_sink = Arc::new(StubEventSink::default());
+
+        let usecase = AuthCommandUseCaseImpl::new(
+            tm,
+            checker,
+            ps,
+            Arc::new(StubBreachedPasswordChecker::default()),
+            event_sink.clone(),
+            clock,
+            id_generator,
+            PasswordPolicy::default(),
+            SignupDisclosurePolicy::Disclose,
+        );
         let command = SignupCommand {
             email: valid_email.to_string().into(),
             password: valid_password.into(),
         };
 
         let result = usecase.signup(command).await;
-        assert!(result.is_ok());
-        let response = result.unwrap();
+        let Ok(SignupOutcomeDto::Registered(response)) = result else {
+            panic!("expected registration, got {:?}", result);
+        };
         assert_eq!(response.email, valid_email.to_string());
         let expected_uuid: uuid::Uuid = expected_id.into();
         assert_eq!(response.id, expected_uuid);
+
+        // 登録イベントが Outbox に記録され、EventSink へ引き渡されること
+        let expected: Vec<DomainEvent> = vec![
+            domain::models::user::UserEvent::UserRegistered {
+                user_id: expected_id,
+                email: valid_email,
+                occurred_at: clock_now,
+            }
+            .into(),
+        ];
+        assert_eq!(factory.outbox_repo.events(), expected);
+        assert_eq!(event_sink.published(), expected);
     }
 
     #[rstest]
@@ -148,8 +272,9 @@ mod tests {
         let repo = Arc::new(StubUserRepository {
             found_user: None,
             save_error: None,
+            ..Default::default()
         });
-        let factory = Arc::new(StubRepositoryFactory { repo });
+        let factory = Arc::new(StubRepositoryFactory::new(repo));
         let tm = Arc::new(StubTransactionManager { factory });
         let checker = Arc::new(StubUserUniquenessChecker {
             error_factory: Some(|| {
@@ -169,7 +294,19 @@ mod tests {
         let clock = Arc::new(FixedClock::new(chrono::Utc::now()));
         let id_generator = Arc::new(MockIdGenerator::<UserId>::with_generated_ids(1));
 
-        let usecase = AuthCommandUseCaseImpl::new(tm, checker, ps, clock, id_generator);
+        let event_sink = Arc::new(StubEventSink::default());
+
+        let usecase = AuthCommandUseCaseImpl::new(
+            tm,
+            checker,
+            ps,
+            Arc::new(StubBreachedPasswordChecker::default()),
+            event_sink.clone(),
+            clock,
+            id_generator,
+            PasswordPolicy::default(),
+            SignupDisclosurePolicy::Disclose,
+        );
         let command = SignupCommand {
             email: valid_email.to_string().into(),
             password: valid_password.into(),
@@ -177,5 +314,172 @@ mod tests {
 
         let result = usecase.signup(command).await;
         assert!(matches!(result, Err(UseCaseError::Conflict(_))));
+        assert!(event_sink.published().is_empty());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_signup_reports_all_password_violations(valid_email: Email) {
+        let repo = Arc::new(StubUserRepository::default());
+        let event_sink = Arc::new(StubEventSink::default());
+        let usecase = conceal_usecase(repo.clone(), event_sink.clone(), chrono::Utc::now());
+
+        let result = usecase
+            .signup(SignupCommand {
+                email: valid_email.to_string().into(),
+                password: "test".to_string().into(),
+            })
+            .await;
+
+        let Err(UseCaseError::Validation { violations, .. }) = result else {
+            panic!("expected a policy violation, got {:?}", result);
+        };
+        let codes: Vec<_> = violations.iter().map(|v| v.code.as_str()).collect();
+        assert_eq!(codes, ["too_short", "too_weak", "contains_email"]);
+        assert!(repo.saved_users().is_empty());
+        assert!(event_sink.published().is_empty());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_signup_rejects_breached_password(valid_email: Email) {
+        let repo = Arc::new(StubUserRepository::default());
+        let (ps, _) = counting_password_service();
+        let usecase = AuthCommandUseCaseImpl::new(
+            Arc::new(StubTransactionManager {
+                factory: Arc::new(StubRepositoryFactory::new(repo.clone())),
+            }),
+            Arc::new(StubUserUniquenessChecker {
+                error_factory: None,
+            }),
+            ps,
+            Arc::new(StubBreachedPasswordChecker::with_breached(["Summer2024!"])),
+            Arc::new(StubEventSink::default()),
+            Arc::new(FixedClock::new(chrono::Utc::now())),
+            Arc::new(MockIdGenerator::<UserId>::with_generated_ids(1)),
+            PasswordPolicy::default(),
+            SignupDisclosurePolicy::Disclose,
+        );
+
+        // 全角で入力しても正規化後の値で照会される
+        let result = usecase
+            .signup(SignupCommand {
+                email: valid_email.to_string().into(),
+                password: "Ｓｕｍｍｅｒ2024!".to_string().into(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::InvalidInput(_))));
+        assert!(repo.saved_users().is_empty());
+    }
+
+    fn conceal_usecase(
+        repo: Arc<StubUserRepository>,
+        event_sink: Arc<StubEventSink>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> impl AuthCommandUseCase {
+        let tm = Arc::new(StubTransactionManager {
+            factory: Arc::new(StubRepositoryFactory::new(repo)),
+        });
+        let checker = Arc::new(StubUserUniquenessChecker {
+            error_factory: None,
+        });
+        let (ps, _) = counting_password_service();
+        let breached_password_checker = Arc::new(StubBreachedPasswordChecker::default());
+        AuthCommandUseCaseImpl::new(
+            tm,
+            checker,
+            ps,
+            breached_password_checker,
+            event_sink,
+            Arc::new(FixedClock::new(now)),
+            Arc::new(MockIdGenerator::<UserId>::with_generated_ids(1)),
+            PasswordPolicy::default(),
+            SignupDisclosurePolicy::Conceal,
+        )
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_signup_conceal_accepts_new_email(valid_email: Email, valid_password: String) {
+        let repo = Arc::new(StubUserRepository::default());
+        let event_sink = Arc::new(StubEventSink::default());
+        let usecase = conceal_usecase(repo.clone(), event_sink.clone(), chrono::Utc::now());
+
+        let result = usecase
+            .signup(SignupCommand {
+                email: valid_email.to_string().into(),
+                password: valid_password.into(),
+            })
+            .await;
+
+        assert!(matches!(result, Ok(SignupOutcomeDto::Accepted)));
+        assert_eq!(repo.saved_users().len(), 1);
+        assert_eq!(event_sink.published()[0].event_type(), "UserRegistered");
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_signup_conceal_notifies_existing_owner(
+        valid_email: Email,
+        valid_password: String,
+    ) {
+        let now = chrono::Utc::now();
+        let owner = User::new(
+            UserId::from(uuid::Uuid::now_v7()),
+            valid_email.clone(),
+            domain::models::user::PasswordHash::from_str_unchecked("hashed"),
+        );
+        let repo = Arc::new(StubUserRepository::with_user(owner.clone()));
+        let event_sink = Arc::new(StubEventSink::default());
+        let usecase = conceal_usecase(repo.clone(), event_sink.clone(), now);
+
+        let result = usecase
+            .signup(SignupCommand {
+                email: valid_email.to_string().into(),
+                password: valid_password.into(),
+            })
+            .await;
+
+        // 新規登録と同じ応答を返し、登録はせずに所有者へ通知する
+        assert!(matches!(result, Ok(SignupOutcomeDto::Accepted)));
+        assert!(repo.saved_users().is_empty());
+        let expected: Vec<DomainEvent> = vec![
+            UserEvent::SignupAttempted {
+                user_id: owner.id(),
+                email: valid_email,
+                occurred_at: now,
+            }
+            .into(),
+        ];
+        assert_eq!(event_sink.published(), expected);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_signup_attempt_mail_handler_notifies_owner(valid_email: Email) {
+        let now = chrono::Utc::now();
+        let mailer = Arc::new(StubMailer::default());
+        let handler = SignupAttemptMailHandler::new(mailer.clone());
+
+        handler
+            .handle(
+                &UserEvent::SignupAttempted {
+                    user_id: UserId::from(uuid::Uuid::now_v7()),
+                    email: valid_email.clone(),
+                    occurred_at: now,
+                }
+                .into(),
+            )
+            .await
+            .unwrap();
+
+        let sent = mailer.sent();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].to, valid_email);
+        assert!(matches!(
+            sent[0].message,
+            MailMessage::SignupAttempted { attempted_at } if attempted_at == now
+        ));
     }
 }
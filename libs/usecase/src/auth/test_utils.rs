@@ -1,46 +1,442 @@
 #[cfg(test)]
 pub mod utils {
-    use crate::auth::{AuthService, AuthToken, Claims};
-    use crate::error::AuthServiceError;
+    use crate::auth::{
+        AuthService, AuthToken, BreachedPasswordChecker, Claims, EmailVerificationClaims,
+        EmailVerificationToken, EmailVerificationTokenService, JsonWebKey, LoginAttemptStore,
+        TokenRevocationStore,
+    };
+    use crate::error::{
+        AuthServiceError, BreachedPasswordError, LoginAttemptError, MailerError,
+        TokenRevocationError,
+    };
+    use crate::event::EventSink;
+    use crate::mailer::{Mail, Mailer};
     use async_trait::async_trait;
-    use domain::models::auth::{PasswordService, PasswordServiceError, RawPassword};
+    use chrono::{DateTime, Utc};
+    use domain::AuditContext;
+    use domain::event::{DomainEvent, OutboxRepository, OutboxRepositoryError};
+    use domain::id::IdGenerator;
+    use domain::models::auth::{
+        MfaChallenge, MfaChallengeId, MfaChallengeRepository, MfaRepositoryError, OpaqueToken,
+        OpaqueTokenHash, OpaqueTokenService, PasswordResetRepositoryError, PasswordResetToken,
+        PasswordResetTokenRepository, PasswordService, PasswordServiceError, RawPassword,
+        RecoveryCode, RecoveryCodeGenerator, RecoveryCodeRepository, RecoveryCodeSet, RefreshToken,
+        RefreshTokenFamilyId, RefreshTokenRepository, RefreshTokenRepositoryError,
+        RefreshTokenStatus, ThrottleKey, TotpCode, TotpCredential, TotpCredentialRepository,
+        TotpSecret, TotpService,
+    };
     use domain::models::user::{
-        Email, PasswordHash, User, UserId, UserRepository, UserRepositoryError,
+        Email, EmailVerificationDispatch, EmailVerificationDispatchRepository, PasswordHash, Role,
+        User, UserId, UserIdentity, UserRepository, UserRepositoryError, UserSearchCriteria,
         UserUniquenessChecker, UserUniquenessViolation,
     };
     use domain::repository::tx::{IntoTxError, RepositoryFactory, TransactionManager};
     use futures_util::future::BoxFuture;
     use rstest::*;
+    use std::collections::{HashMap, HashSet};
     use std::fmt::Debug;
-    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, LazyLock, Mutex};
 
     // --- Stubs ---
 
+    #[derive(Default)]
     pub struct StubUserRepository {
         pub found_user: Option<User>,
         pub save_error: Option<fn() -> UserRepositoryError>,
+        pub saved: Mutex<Vec<User>>,
+        /// `search` の対象（ID の昇順で保持する）
+        pub users: Vec<User>,
+    }
+    impl StubUserRepository {
+        pub fn with_user(user: User) -> Self {
+            Self {
+                found_user: Some(user),
+                ..Default::default()
+            }
+        }
+
+        pub fn saved_users(&self) -> Vec<User> {
+            self.saved.lock().unwrap().clone()
+        }
     }
     #[async_trait]
     impl UserRepository for StubUserRepository {
+        async fn find_by_id(&self, _id: UserId) -> Result<Option<User>, UserRepositoryError> {
+            Ok(self.found_user.clone())
+        }
         async fn find_by_email(&self, _email: &Email) -> Result<Option<User>, UserRepositoryError> {
             Ok(self.found_user.clone())
         }
-        async fn save(&self, _user: &User) -> Result<(), UserRepositoryError> {
+        async fn save(&self, user: &User) -> Result<(), UserRepositoryError> {
             if let Some(err_fn) = self.save_error {
                 Err(err_fn())
             } else {
+                self.saved.lock().unwrap().push(user.clone());
                 Ok(())
             }
         }
+        async fn search(
+            &self,
+            criteria: &UserSearchCriteria,
+        ) -> Result<Vec<User>, UserRepositoryError> {
+            Ok(self
+                .users
+                .iter()
+                .filter(|u| criteria.after.is_none_or(|after| u.id() > after))
+                .filter(|u| criteria.status.is_none_or(|status| u.status() == status))
+                .filter(|u| {
+                    criteria
+                        .email_prefix
+                        .as_ref()
+                        .is_none_or(|prefix| u.email().as_ref().starts_with(prefix.as_str()))
+                })
+                .take(criteria.limit as usize)
+                .cloned()
+                .collect())
+        }
+    }
+
+    /// メモリ上でリフレッシュトークンを保持するスタブ。
+    #[derive(Default)]
+    pub struct StubRefreshTokenRepository {
+        tokens: Mutex<Vec<RefreshToken>>,
+        revoked_families: Mutex<Vec<RefreshTokenFamilyId>>,
+    }
+    impl StubRefreshTokenRepository {
+        pub fn with_tokens(tokens: Vec<RefreshToken>) -> Self {
+            Self {
+                tokens: Mutex::new(tokens),
+                revoked_families: Mutex::new(Vec::new()),
+            }
+        }
+
+        pub fn tokens(&self) -> Vec<RefreshToken> {
+            self.tokens.lock().unwrap().clone()
+        }
+
+        pub fn revoked_families(&self) -> Vec<RefreshTokenFamilyId> {
+            self.revoked_families.lock().unwrap().clone()
+        }
+    }
+    #[async_trait]
+    impl RefreshTokenRepository for StubRefreshTokenRepository {
+        async fn find_by_token_hash(
+            &self,
+            token_hash: &OpaqueTokenHash,
+        ) -> Result<Option<RefreshToken>, RefreshTokenRepositoryError> {
+            let tokens = self.tokens.lock().unwrap();
+            Ok(tokens
+                .iter()
+                .find(|t| t.token_hash() == token_hash)
+                .cloned())
+        }
+        async fn save(&self, token: &RefreshToken) -> Result<(), RefreshTokenRepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            tokens.retain(|t| t.id() != token.id());
+            tokens.push(token.clone());
+            Ok(())
+        }
+        async fn revoke_family(
+            &self,
+            family_id: RefreshTokenFamilyId,
+            revoked_at: DateTime<Utc>,
+        ) -> Result<(), RefreshTokenRepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            for token in tokens.iter_mut().filter(|t| t.family_id() == family_id) {
+                *token = RefreshToken::reconstruct(
+                    token.id(),
+                    token.family_id(),
+                    token.user_id(),
+                    token.token_hash().clone(),
+                    token.issued_at(),
+                    token.expires_at(),
+                    RefreshTokenStatus::Revoked { revoked_at },
+                );
+            }
+            self.revoked_families.lock().unwrap().push(family_id);
+            Ok(())
+        }
+        async fn revoke_all_by_user_id(
+            &self,
+            user_id: UserId,
+            revoked_at: DateTime<Utc>,
+        ) -> Result<(), RefreshTokenRepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            for token in tokens.iter_mut().filter(|t| {
+                t.user_id() == user_id && !matches!(t.status(), RefreshTokenStatus::Revoked { .. })
+            }) {
+                *token = RefreshToken::reconstruct(
+                    token.id(),
+                    token.family_id(),
+                    token.user_id(),
+                    token.token_hash().clone(),
+                    token.issued_at(),
+                    token.expires_at(),
+                    RefreshTokenStatus::Revoked { revoked_at },
+                );
+            }
+            Ok(())
+        }
+    }
+
+    /// メモリ上で TOTP 登録情報を保持するスタブ。
+    #[derive(Default)]
+    pub struct StubTotpCredentialRepository {
+        credentials: Mutex<Vec<TotpCredential>>,
+    }
+    impl StubTotpCredentialRepository {
+        pub fn with_credential(credential: TotpCredential) -> Self {
+            Self {
+                credentials: Mutex::new(vec![credential]),
+            }
+        }
+
+        pub fn credentials(&self) -> Vec<TotpCredential> {
+            self.credentials.lock().unwrap().clone()
+        }
+    }
+    #[async_trait]
+    impl TotpCredentialRepository for StubTotpCredentialRepository {
+        async fn find_by_user_id(
+            &self,
+            user_id: UserId,
+        ) -> Result<Option<TotpCredential>, MfaRepositoryError> {
+            let credentials = self.credentials.lock().unwrap();
+            Ok(credentials.iter().find(|c| c.user_id() == user_id).cloned())
+        }
+        async fn save(&self, credential: &TotpCredential) -> Result<(), MfaRepositoryError> {
+            let mut credentials = self.credentials.lock().unwrap();
+            credentials.retain(|c| c.user_id() != credential.user_id());
+            credentials.push(credential.clone());
+            Ok(())
+        }
+    }
+
+    /// メモリ上で MFA チャレンジを保持するスタブ。
+    #[derive(Default)]
+    pub struct StubMfaChallengeRepository {
+        challenges: Mutex<Vec<MfaChallenge>>,
+    }
+    impl StubMfaChallengeRepository {
+        pub fn with_challenge(challenge: MfaChallenge) -> Self {
+            Self {
+                challenges: Mutex::new(vec![challenge]),
+            }
+        }
+
+        pub fn challenges(&self) -> Vec<MfaChallenge> {
+            self.challenges.lock().unwrap().clone()
+        }
+    }
+    #[async_trait]
+    impl MfaChallengeRepository for StubMfaChallengeRepository {
+        async fn find_by_token_hash(
+            &self,
+            token_hash: &OpaqueTokenHash,
+        ) -> Result<Option<MfaChallenge>, MfaRepositoryError> {
+            let challenges = self.challenges.lock().unwrap();
+            Ok(challenges
+                .iter()
+                .find(|c| c.token_hash() == token_hash)
+                .cloned())
+        }
+        async fn save(&self, challenge: &MfaChallenge) -> Result<(), MfaRepositoryError> {
+            let mut challenges = self.challenges.lock().unwrap();
+            challenges.retain(|c| c.id() != challenge.id());
+            challenges.push(challenge.clone());
+            Ok(())
+        }
+        async fn delete(&self, id: MfaChallengeId) -> Result<(), MfaRepositoryError> {
+            self.challenges.lock().unwrap().retain(|c| c.id() != id);
+            Ok(())
+        }
+    }
+
+    /// メモリ上でリカバリーコードを保持するスタブ。
+    #[derive(Default)]
+    pub struct StubRecoveryCodeRepository {
+        code_sets: Mutex<Vec<RecoveryCodeSet>>,
+    }
+    impl StubRecoveryCodeRepository {
+        pub fn with_code_set(code_set: RecoveryCodeSet) -> Self {
+            Self {
+                code_sets: Mutex::new(vec![code_set]),
+            }
+        }
+
+        pub fn code_sets(&self) -> Vec<RecoveryCodeSet> {
+            self.code_sets.lock().unwrap().clone()
+        }
+    }
+    #[async_trait]
+    impl RecoveryCodeRepository for StubRecoveryCodeRepository {
+        async fn find_by_user_id(
+            &self,
+            user_id: UserId,
+        ) -> Result<Option<RecoveryCodeSet>, MfaRepositoryError> {
+            let code_sets = self.code_sets.lock().unwrap();
+            Ok(code_sets.iter().find(|s| s.user_id() == user_id).cloned())
+        }
+        async fn save(&self, code_set: &RecoveryCodeSet) -> Result<(), MfaRepositoryError> {
+            let mut code_sets = self.code_sets.lock().unwrap();
+            code_sets.retain(|s| s.user_id() != code_set.user_id());
+            code_sets.push(code_set.clone());
+            Ok(())
+        }
+    }
+
+    /// メモリ上でパスワードリセットトークンを保持するスタブ。
+    #[derive(Default)]
+    pub struct StubPasswordResetTokenRepository {
+        tokens: Mutex<Vec<PasswordResetToken>>,
+    }
+    impl StubPasswordResetTokenRepository {
+        pub fn tokens(&self) -> Vec<PasswordResetToken> {
+            self.tokens.lock().unwrap().clone()
+        }
+    }
+    #[async_trait]
+    impl PasswordResetTokenRepository for StubPasswordResetTokenRepository {
+        async fn find_by_token_hash(
+            &self,
+            token_hash: &OpaqueTokenHash,
+        ) -> Result<Option<PasswordResetToken>, PasswordResetRepositoryError> {
+            let tokens = self.tokens.lock().unwrap();
+            Ok(tokens
+                .iter()
+                .find(|t| t.token_hash() == token_hash)
+                .cloned())
+        }
+        async fn save(
+            &self,
+            token: &PasswordResetToken,
+        ) -> Result<(), PasswordResetRepositoryError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            tokens.retain(|t| t.id() != token.id());
+            tokens.push(token.clone());
+            Ok(())
+        }
+        async fn delete_by_user_id(
+            &self,
+            user_id: UserId,
+        ) -> Result<(), PasswordResetRepositoryError> {
+            self.tokens
+                .lock()
+                .unwrap()
+                .retain(|t| t.user_id() != user_id);
+            Ok(())
+        }
+    }
+
+    /// メモリ上で確認メールの送信記録を保持するスタブ。
+    #[derive(Default)]
+    pub struct StubEmailVerificationDispatchRepository {
+        dispatches: Mutex<Vec<EmailVerificationDispatch>>,
+    }
+    impl StubEmailVerificationDispatchRepository {
+        pub fn with_dispatch(dispatch: EmailVerificationDispatch) -> Self {
+            Self {
+                dispatches: Mutex::new(vec![dispatch]),
+            }
+        }
+
+        pub fn dispatches(&self) -> Vec<EmailVerificationDispatch> {
+            self.dispatches.lock().unwrap().clone()
+        }
+    }
+    #[async_trait]
+    impl EmailVerificationDispatchRepository for StubEmailVerificationDispatchRepository {
+        async fn find_by_user_id(
+            &self,
+            user_id: UserId,
+        ) -> Result<Option<EmailVerificationDispatch>, UserRepositoryError> {
+            let dispatches = self.dispatches.lock().unwrap();
+            Ok(dispatches.iter().find(|d| d.user_id() == user_id).cloned())
+        }
+        async fn save(
+            &self,
+            dispatch: &EmailVerificationDispatch,
+        ) -> Result<(), UserRepositoryError> {
+            let mut dispatches = self.dispatches.lock().unwrap();
+            dispatches.retain(|d| d.user_id() != dispatch.user_id());
+            dispatches.push(dispatch.clone());
+            Ok(())
+        }
+    }
+
+    /// 追記されたドメインイベントを保持するスタブ。
+    #[derive(Default)]
+    pub struct StubOutboxRepository {
+        events: Mutex<Vec<DomainEvent>>,
+    }
+    impl StubOutboxRepository {
+        pub fn events(&self) -> Vec<DomainEvent> {
+            self.events.lock().unwrap().clone()
+        }
+    }
+    #[async_trait]
+    impl OutboxRepository for StubOutboxRepository {
+        async fn append(&self, events: &[DomainEvent]) -> Result<(), OutboxRepositoryError> {
+            self.events.lock().unwrap().extend_from_slice(events);
+            Ok(())
+        }
     }
 
     pub struct StubRepositoryFactory {
         pub repo: Arc<StubUserRepository>,
+        pub refresh_token_repo: Arc<StubRefreshTokenRepository>,
+        pub totp_credential_repo: Arc<StubTotpCredentialRepository>,
+        pub mfa_challenge_repo: Arc<StubMfaChallengeRepository>,
+        pub recovery_code_repo: Arc<StubRecoveryCodeRepository>,
+        pub password_reset_token_repo: Arc<StubPasswordResetTokenRepository>,
+        pub email_verification_dispatch_repo: Arc<StubEmailVerificationDispatchRepository>,
+        pub outbox_repo: Arc<StubOutboxRepository>,
+    }
+    impl StubRepositoryFactory {
+        /// ユーザーリポジトリ以外を空のスタブで初期化する。
+        pub fn new(repo: Arc<StubUserRepository>) -> Self {
+            Self {
+                repo,
+                refresh_token_repo: Arc::new(StubRefreshTokenRepository::default()),
+                totp_credential_repo: Arc::new(StubTotpCredentialRepository::default()),
+                mfa_challenge_repo: Arc::new(StubMfaChallengeRepository::default()),
+                recovery_code_repo: Arc::new(StubRecoveryCodeRepository::default()),
+                password_reset_token_repo: Arc::new(StubPasswordResetTokenRepository::default()),
+                email_verification_dispatch_repo: Arc::new(
+                    StubEmailVerificationDispatchRepository::default(),
+                ),
+                outbox_repo: Arc::new(StubOutboxRepository::default()),
+            }
+        }
     }
     impl RepositoryFactory for StubRepositoryFactory {
         fn user_repository(&self) -> Arc<dyn UserRepository> {
             self.repo.clone()
         }
+        fn refresh_token_repository(&self) -> Arc<dyn RefreshTokenRepository> {
+            self.refresh_token_repo.clone()
+        }
+        fn totp_credential_repository(&self) -> Arc<dyn TotpCredentialRepository> {
+            self.totp_credential_repo.clone()
+        }
+        fn mfa_challenge_repository(&self) -> Arc<dyn MfaChallengeRepository> {
+            self.mfa_challenge_repo.clone()
+        }
+        fn recovery_code_repository(&self) -> Arc<dyn RecoveryCodeRepository> {
+            self.recovery_code_repo.clone()
+        }
+        fn password_reset_token_repository(&self) -> Arc<dyn PasswordResetTokenRepository> {
+            self.password_reset_token_repo.clone()
+        }
+        fn email_verification_dispatch_repository(
+            &self,
+        ) -> Arc<dyn EmailVerificationDispatchRepository> {
+            self.email_verification_dispatch_repo.clone()
+        }
+        fn outbox_repository(&self) -> Arc<dyn OutboxRepository> {
+            self.outbox_repo.clone()
+        }
     }
 
     pub struct StubTransactionManager {
@@ -48,7 +444,7 @@ pub mod utils {
     }
     #[async_trait]
     impl TransactionManager for StubTransactionManager {
-        async fn execute<T, E, F>(&self, f: F) -> Result<T, E>
+        async fn execute<T, E, F>(&self, _context: AuditContext, f: F) -> Result<T, E>
         where
             T: Send,
             E: IntoTxError + Debug + Send + Sync,
@@ -78,6 +474,13 @@ pub mod utils {
 
     pub type TestResult<T, E> = Arc<dyn Fn() -> Result<T, E> + Send + Sync>;
 
+    /// スタブが古い設定によるものとみなし、再ハッシュを要求するハッシュの接頭辞
+    pub const OUTDATED_HASH_PREFIX: &str = "outdated:";
+
+    /// スタブが返すダミーのハッシュ
+    pub static STUB_DUMMY_HASH: LazyLock<PasswordHash> =
+        LazyLock::new(|| PasswordHash::from_str_unchecked("dummy"));
+
     pub struct StubPasswordService {
         pub verify_result: TestResult<bool, PasswordServiceError>,
         pub hash_result: TestResult<PasswordHash, PasswordServiceError>,
@@ -94,6 +497,55 @@ pub mod utils {
         async fn hash(&self, _pw: &RawPassword) -> Result<PasswordHash, PasswordServiceError> {
             (self.hash_result)()
         }
+        fn dummy_hash(&self) -> &PasswordHash {
+            &STUB_DUMMY_HASH
+        }
+        fn needs_rehash(&self, hash: &PasswordHash) -> bool {
+            hash.as_ref().starts_with(OUTDATED_HASH_PREFIX)
+        }
+    }
+
+    /// 照合の呼び出し回数を数える `StubPasswordService` を返す。照合は常に不一致とする。
+    ///
+    /// 分岐ごとに同等の処理（照合 1 回）が行われることを検証するために使用する。
+    pub fn counting_password_service() -> (Arc<StubPasswordService>, Arc<AtomicUsize>) {
+        let verify_calls = Arc::new(AtomicUsize::new(0));
+        let calls = Arc::clone(&verify_calls);
+        let service = StubPasswordService {
+            verify_result: Arc::new(move || {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok(false)
+            }),
+            hash_result: Arc::new(|| Ok(STUB_DUMMY_HASH.clone())),
+        };
+        (Arc::new(service), verify_calls)
+    }
+
+    /// 平文に接頭辞を付けただけの値をハッシュとし、入力に応じて照合するスタブ。
+    pub struct StubPrefixPasswordService;
+    impl StubPrefixPasswordService {
+        pub fn hash_of(raw: &str) -> PasswordHash {
+            PasswordHash::from_str_unchecked(format!("hashed:{}", raw))
+        }
+    }
+    #[async_trait]
+    impl PasswordService for StubPrefixPasswordService {
+        async fn verify(
+            &self,
+            pw: &RawPassword,
+            hash: &PasswordHash,
+        ) -> Result<bool, PasswordServiceError> {
+            Ok(Self::hash_of(pw.expose_as_str()) == *hash)
+        }
+        async fn hash(&self, pw: &RawPassword) -> Result<PasswordHash, PasswordServiceError> {
+            Ok(Self::hash_of(pw.expose_as_str()))
+        }
+        fn dummy_hash(&self) -> &PasswordHash {
+            &STUB_DUMMY_HASH
+        }
+        fn needs_rehash(&self, hash: &PasswordHash) -> bool {
+            hash.as_ref().starts_with(OUTDATED_HASH_PREFIX)
+        }
     }
 
     pub struct StubAuthService {
@@ -102,12 +554,252 @@ pub mod utils {
     }
     #[async_trait]
     impl AuthService for StubAuthService {
-        fn issue_token(&self, _user_id: UserId) -> Result<AuthToken, AuthServiceError> {
+        fn issue_token(
+            &self,
+            _user_id: UserId,
+            _roles: &[Role],
+        ) -> Result<AuthToken, AuthServiceError> {
             (self.issue_token_result)()
         }
         fn verify_token(&self, _token: &AuthToken) -> Result<Claims, AuthServiceError> {
             (self.verify_token_result)()
         }
+        fn verification_keys(&self) -> Vec<JsonWebKey> {
+            Vec::new()
+        }
+    }
+
+    /// ユーザー ID とメールアドレスを連結しただけのトークンを発行するスタブ。
+    ///
+    /// 署名も有効期限も検証せず、形式が不正な場合のみ `InvalidToken` を返す。
+    pub struct StubEmailVerificationTokenService;
+    impl EmailVerificationTokenService for StubEmailVerificationTokenService {
+        fn issue(
+            &self,
+            user_id: UserId,
+            email: &Email,
+            expires_at: DateTime<Utc>,
+        ) -> Result<EmailVerificationToken, AuthServiceError> {
+            Ok(EmailVerificationToken::from(
+                format!("{}|{}|{}", user_id, email.as_ref(), expires_at.timestamp()).as_str(),
+            ))
+        }
+        fn verify(
+            &self,
+            token: &EmailVerificationToken,
+        ) -> Result<EmailVerificationClaims, AuthServiceError> {
+            let mut parts = token.expose_as_str().split('|');
+            let (Some(sub), Some(email), Some(exp)) = (parts.next(), parts.next(), parts.next())
+            else {
+                return Err(AuthServiceError::InvalidToken);
+            };
+            Ok(EmailVerificationClaims {
+                sub: UserId::from(
+                    uuid::Uuid::parse_str(sub).map_err(|_| AuthServiceError::InvalidToken)?,
+                ),
+                email: email.to_string(),
+                exp: exp.parse().map_err(|_| AuthServiceError::InvalidToken)?,
+            })
+        }
+    }
+
+    /// 連番のトークンを生成し、接頭辞を付けただけの値をハッシュとして返すスタブ。
+    #[derive(Default)]
+    pub struct StubOpaqueTokenService {
+        counter: AtomicUsize,
+    }
+    impl OpaqueTokenService for StubOpaqueTokenService {
+        fn generate(&self) -> OpaqueToken {
+            let n = self.counter.fetch_add(1, Ordering::SeqCst);
+            OpaqueToken::from(format!("token-{}", n))
+        }
+        fn hash(&self, token: &OpaqueToken) -> OpaqueTokenHash {
+            OpaqueTokenHash::from_str_unchecked(format!("hashed:{}", token.expose_as_str()))
+        }
+    }
+
+    /// 固定のコードのみを受理し、時刻から求めたタイムステップを返すスタブ。
+    pub struct StubTotpService;
+    impl StubTotpService {
+        pub const SECRET: &'static str = "STUBSECRET";
+        pub const VALID_CODE: &'static str = "123456";
+    }
+    impl TotpService for StubTotpService {
+        fn generate_secret(&self) -> TotpSecret {
+            TotpSecret::from_str_unchecked(Self::SECRET)
+        }
+        fn provisioning_uri(&self, secret: &TotpSecret, account_name: &str) -> String {
+            format!(
+                "otpauth://totp/Test:{}?secret={}",
+                account_name,
+                secret.expose_as_str()
+            )
+        }
+        fn verify(&self, _secret: &TotpSecret, code: &TotpCode, now: DateTime<Utc>) -> Option<u64> {
+            (code.expose_as_str() == Self::VALID_CODE).then(|| now.timestamp() as u64 / 30)
+        }
+    }
+
+    /// 連番のリカバリーコード（`CODE000000`, `CODE000001`, ...）を生成するスタブ。
+    #[derive(Default)]
+    pub struct StubRecoveryCodeGenerator {
+        counter: AtomicUsize,
+    }
+    impl RecoveryCodeGenerator for StubRecoveryCodeGenerator {
+        fn generate(&self) -> RecoveryCode {
+            let n = self.counter.fetch_add(1, Ordering::SeqCst);
+            RecoveryCode::try_from(format!("CODE{:06}", n)).unwrap()
+        }
+    }
+
+    /// 任意の UUID ベースの ID 型を生成するスタブ。
+    pub struct StubUuidGenerator;
+    impl<T: From<uuid::Uuid>> IdGenerator<T> for StubUuidGenerator {
+        fn generate(&self) -> T {
+            uuid::Uuid::now_v7().into()
+        }
+    }
+
+    /// 指定したパスワードのみを漏洩済みとして扱うスタブ。
+    #[derive(Default)]
+    pub struct StubBreachedPasswordChecker {
+        breached: HashSet<String>,
+    }
+    impl StubBreachedPasswordChecker {
+        pub fn with_breached<'a>(passwords: impl IntoIterator<Item = &'a str>) -> Self {
+            Self {
+                breached: passwords.into_iter().map(str::to_string).collect(),
+            }
+        }
+    }
+    #[async_trait]
+    impl BreachedPasswordChecker for StubBreachedPasswordChecker {
+        async fn is_breached(&self, password: &RawPassword) -> Result<bool, BreachedPasswordError> {
+            Ok(self.breached.contains(password.expose_as_str()))
+        }
+    }
+
+    /// 送信したメールを記録するスタブ。
+    #[derive(Default)]
+    pub struct StubMailer {
+        sent: Mutex<Vec<Mail>>,
+        fail: bool,
+    }
+    impl StubMailer {
+        /// 常に送信に失敗するスタブ
+        pub fn failing() -> Self {
+            Self {
+                fail: true,
+                ..Default::default()
+            }
+        }
+
+        pub fn sent(&self) -> Vec<Mail> {
+            self.sent.lock().unwrap().clone()
+        }
+    }
+    #[async_trait]
+    impl Mailer for StubMailer {
+        async fn send(&self, mail: Mail) -> Result<(), MailerError> {
+            if self.fail {
+                return Err(MailerError::DeliveryFailed(anyhow::anyhow!("mail failed")));
+            }
+            self.sent.lock().unwrap().push(mail);
+            Ok(())
+        }
+    }
+
+    /// 引き渡されたドメインイベントを記録するスタブ。
+    #[derive(Default)]
+    pub struct StubEventSink {
+        published: Mutex<Vec<DomainEvent>>,
+    }
+    impl StubEventSink {
+        pub fn published(&self) -> Vec<DomainEvent> {
+            self.published.lock().unwrap().clone()
+        }
+    }
+    #[async_trait]
+    impl EventSink for StubEventSink {
+        async fn publish(&self, events: &[DomainEvent]) {
+            self.published.lock().unwrap().extend_from_slice(events);
+        }
+    }
+
+    #[derive(Default)]
+    pub struct StubTokenRevocationStore {
+        revoked: Mutex<HashSet<uuid::Uuid>>,
+    }
+    #[async_trait]
+    impl TokenRevocationStore for StubTokenRevocationStore {
+        async fn revoke(&self, jti: uuid::Uuid, _exp: usize) -> Result<(), TokenRevocationError> {
+            self.revoked.lock().unwrap().insert(jti);
+            Ok(())
+        }
+        async fn is_revoked(&self, jti: uuid::Uuid) -> Result<bool, TokenRevocationError> {
+            Ok(self.revoked.lock().unwrap().contains(&jti))
+        }
+    }
+
+    /// キー（`ThrottleKey` の文字列表現）ごとに失敗の日時を保持する。
+    #[derive(Default)]
+    pub struct StubLoginAttemptStore {
+        failures: Mutex<HashMap<String, Vec<DateTime<Utc>>>>,
+    }
+    impl StubLoginAttemptStore {
+        pub fn failure_count(&self, key: &ThrottleKey) -> usize {
+            self.failures
+                .lock()
+                .unwrap()
+                .get(&key.to_string())
+                .map_or(0, Vec::len)
+        }
+    }
+    #[async_trait]
+    impl LoginAttemptStore for StubLoginAttemptStore {
+        async fn record_failure(
+            &self,
+            key: &ThrottleKey,
+            attempted_at: DateTime<Utc>,
+            _retain_since: DateTime<Utc>,
+        ) -> Result<(), LoginAttemptError> {
+            self.failures
+                .lock()
+                .unwrap()
+                .entry(key.to_string())
+                .or_default()
+                .push(attempted_at);
+            Ok(())
+        }
+        async fn failures_since(
+            &self,
+            key: &ThrottleKey,
+            since: DateTime<Utc>,
+        ) -> Result<Vec<DateTime<Utc>>, LoginAttemptError> {
+            Ok(self
+                .failures
+                .lock()
+                .unwrap()
+                .get(&key.to_string())
+                .map(|at| at.iter().copied().filter(|at| *at >= since).collect())
+                .unwrap_or_default())
+        }
+        async fn remove_failure(
+            &self,
+            key: &ThrottleKey,
+            attempted_at: DateTime<Utc>,
+        ) -> Result<(), LoginAttemptError> {
+            if let Some(at) = self.failures.lock().unwrap().get_mut(&key.to_string())
+                && let Some(index) = at.iter().rposition(|at| *at == attempted_at)
+            {
+                at.remove(index);
+            }
+            Ok(())
+        }
+        async fn clear(&self, key: &ThrottleKey) -> Result<(), LoginAttemptError> {
+            self.failures.lock().unwrap().remove(&key.to_string());
+            Ok(())
+        }
     }
 
     // --- Fixtures ---
@@ -119,7 +811,7 @@ pub mod utils {
 
     #[fixture]
     pub fn valid_password() -> String {
-        "password123".to_string()
+        "Password123!".to_string()
     }
 
     #[fixture]
@@ -0,0 +1,61 @@
+use crate::error::AuthServiceError;
+use chrono::{DateTime, Utc};
+use derive_more::{Display, From};
+use domain::SensitiveDebug;
+use domain::models::user::{Email, UserId};
+use sensitive_data::{SecretRule, SensitiveData};
+use serde::{Deserialize, Serialize};
+
+/// メールアドレス確認用トークンに含める情報。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailVerificationClaims {
+    pub sub: UserId,
+    /// 発行時点のメールアドレス。変更後のアドレスに古いトークンを使わせないために照合する
+    pub email: String,
+    pub exp: usize,
+}
+
+/// 確認メールで送付する署名付きトークン。
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Display, From, SensitiveDebug)]
+pub struct EmailVerificationToken(String);
+
+impl EmailVerificationToken {
+    pub fn expose_as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for EmailVerificationToken {
+    fn from(s: &str) -> Self {
+        Self(s.to_string())
+    }
+}
+
+impl SensitiveData for EmailVerificationToken {
+    fn to_masked_string(&self) -> String {
+        Self::mask_raw(&self.0)
+    }
+
+    fn mask_raw(input: &str) -> String {
+        SecretRule::mask_raw(input)
+    }
+}
+
+/// メールアドレス確認用トークンの発行・検証を行うポート。
+///
+/// トークンは署名によって改ざんを検出するため、サーバー側に状態を保持しない。
+pub trait EmailVerificationTokenService: Send + Sync {
+    /// ユーザーとメールアドレスに紐づくトークンを発行する
+    fn issue(
+        &self,
+        user_id: UserId,
+        email: &Email,
+        expires_at: DateTime<Utc>,
+    ) -> Result<EmailVerificationToken, AuthServiceError>;
+
+    /// 署名と有効期限を検証し、Claims を返す
+    fn verify(
+        &self,
+        token: &EmailVerificationToken,
+    ) -> Result<EmailVerificationClaims, AuthServiceError>;
+}
@@ -1,8 +1,12 @@
 use domain::error::DomainError;
-use domain::models::auth::PasswordServiceError;
 use domain::models::auth::error::AuthError;
+use domain::models::auth::{
+    MfaError, MfaRepositoryError, PasswordResetError, PasswordResetRepositoryError,
+    PasswordServiceError, RefreshTokenError, RefreshTokenRepositoryError,
+};
 use domain::models::user::{
-    EmailError, PasswordError, UserError, UserRepositoryError, UserUniquenessViolation,
+    AccountStatusError, EmailError, EmailVerificationError, PasswordError, UserError,
+    UserRepositoryError, UserUniquenessViolation,
 };
 use thiserror::Error;
 
@@ -13,6 +17,13 @@ pub enum UseCaseError {
     #[error("Invalid input: {0}")]
     InvalidInput(String),
 
+    /// 入力が複数の規則に違反している（違反した規則をすべて列挙する）
+    #[error("Invalid input: {message}")]
+    Validation {
+        message: String,
+        violations: Vec<Violation>,
+    },
+
     #[error("Authentication failed: {0}")]
     Authentication(String),
 
@@ -25,12 +36,45 @@ pub enum UseCaseError {
     #[error("Conflict: {0}")]
     Conflict(String),
 
+    /// クライアントが指定した事前条件（参照したバージョン等）を満たさない
+    #[error("Precondition failed: {0}")]
+    PreconditionFailed(String),
+
+    /// アカウントが一時的にロックされている（`retry_after` 秒後に再試行できる）
+    #[error("Locked: {message}")]
+    Locked {
+        message: String,
+        retry_after: Option<u64>,
+    },
+
+    /// 短時間に試行が集中している（`retry_after` 秒後に再試行できる）
+    #[error("Too many requests: {message}")]
+    TooManyRequests {
+        message: String,
+        retry_after: Option<u64>,
+    },
+
+    /// 処理能力を超える要求が集中している（`retry_after` 秒後に再試行できる）
+    #[error("Service unavailable: {message}")]
+    Unavailable {
+        message: String,
+        retry_after: Option<u64>,
+    },
+
     #[error("Internal system error")]
     Internal(#[from] anyhow::Error),
 }
 
 pub type UseCaseResult<T> = Result<T, UseCaseError>;
 
+/// 入力が違反した個々の規則。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// クライアントが違反を識別するためのコード
+    pub code: String,
+    pub message: String,
+}
+
 /// 認証サービス（トークン発行・検証等）に関連するエラー。
 /// ユースケース層のポート（AuthService）で使用されます。
 #[derive(Debug, Error)]
@@ -46,6 +90,41 @@ pub enum AuthServiceError {
 
     #[error("Invalid token")]
     InvalidToken,
+
+    #[error("Token has been revoked")]
+    TokenRevoked,
+}
+
+/// アクセストークンの失効ストアに関連するエラー。
+/// ユースケース層のポート（TokenRevocationStore）で使用されます。
+#[derive(Debug, Error)]
+pub enum TokenRevocationError {
+    #[error("Token revocation store failure: {0}")]
+    StoreFailed(#[source] anyhow::Error),
+}
+
+/// ログイン試行の記録に関連するエラー。
+/// ユースケース層のポート（LoginAttemptStore）で使用されます。
+#[derive(Debug, Error)]
+pub enum LoginAttemptError {
+    #[error("Login attempt store failure: {0}")]
+    StoreFailed(#[source] anyhow::Error),
+}
+
+/// 漏洩済みパスワードの照会に関連するエラー。
+/// ユースケース層のポート（BreachedPasswordChecker）で使用されます。
+#[derive(Debug, Error)]
+pub enum BreachedPasswordError {
+    #[error("Breached password lookup failed: {0}")]
+    LookupFailed(#[source] anyhow::Error),
+}
+
+/// メール送信に関連するエラー。
+/// ユースケース層のポート（Mailer）で使用されます。
+#[derive(Debug, Error)]
+pub enum MailerError {
+    #[error("Failed to deliver mail: {0}")]
+    DeliveryFailed(#[source] anyhow::Error),
 }
 
 // --- 階層的な From 実装 (カプセル化の維持) ---
@@ -69,8 +148,30 @@ impl From<UserError> for UseCaseError {
             UserError::Email(e) => e.into(),
             UserError::Password(e) => e.into(),
             UserError::Uniqueness(e) => e.into(),
+            UserError::EmailVerification(e) => e.into(),
             UserError::Repository(e) => e.into(),
             UserError::NotFound => UseCaseError::NotFound("User not found".into()),
+            UserError::VersionMismatch { .. } => UseCaseError::PreconditionFailed(
+                "User has been modified since it was retrieved".into(),
+            ),
+            UserError::AccountStatus(e) => e.into(),
+            UserError::PasswordResetRequired => UseCaseError::Forbidden(error.to_string()),
+        }
+    }
+}
+
+impl From<AccountStatusError> for UseCaseError {
+    fn from(error: AccountStatusError) -> Self {
+        match error {
+            AccountStatusError::Suspended => UseCaseError::Forbidden(error.to_string()),
+            AccountStatusError::Locked { .. } => UseCaseError::Locked {
+                message: error.to_string(),
+                retry_after: None,
+            },
+            AccountStatusError::Deleted => UseCaseError::Authentication(error.to_string()),
+            AccountStatusError::InvalidTransition { .. } => {
+                UseCaseError::Conflict(error.to_string())
+            }
         }
     }
 }
@@ -83,7 +184,30 @@ impl From<EmailError> for UseCaseError {
 
 impl From<PasswordError> for UseCaseError {
     fn from(error: PasswordError) -> Self {
-        UseCaseError::InvalidInput(format!("Invalid password: {}", error))
+        match error {
+            PasswordError::PolicyViolation(violations) => UseCaseError::Validation {
+                message: "Password does not satisfy the policy".into(),
+                violations: violations
+                    .iter()
+                    .map(|v| Violation {
+                        code: v.code().into(),
+                        message: v.to_string(),
+                    })
+                    .collect(),
+            },
+            PasswordError::Breached | PasswordError::InvalidFormat { .. } => {
+                UseCaseError::InvalidInput(format!("Invalid password: {}", error))
+            }
+        }
+    }
+}
+
+impl From<EmailVerificationError> for UseCaseError {
+    fn from(error: EmailVerificationError) -> Self {
+        match error {
+            EmailVerificationError::InvalidToken => UseCaseError::InvalidInput(error.to_string()),
+            EmailVerificationError::NotVerified => UseCaseError::Forbidden(error.to_string()),
+        }
     }
 }
 
@@ -106,6 +230,9 @@ impl From<UserRepositoryError> for UseCaseError {
             }
             UserRepositoryError::QueryFailed(e) => UseCaseError::Internal(e),
             UserRepositoryError::MappingFailed(e) => UseCaseError::Internal(e),
+            UserRepositoryError::ConcurrentModification => {
+                UseCaseError::Conflict("User was modified concurrently, please retry".into())
+            }
             UserRepositoryError::Unexpected(e) => UseCaseError::Internal(e),
         }
     }
@@ -121,6 +248,71 @@ impl From<AuthError> for UseCaseError {
                 UseCaseError::Forbidden("Access denied: insufficient permissions".into())
             }
             AuthError::PasswordService(e) => e.into(),
+            AuthError::InvalidRefreshToken => {
+                UseCaseError::Authentication("Invalid refresh token".into())
+            }
+            AuthError::RefreshToken(e) => e.into(),
+            AuthError::RefreshTokenRepository(e) => e.into(),
+            AuthError::Mfa(e) => e.into(),
+            AuthError::MfaRepository(e) => e.into(),
+            AuthError::PasswordReset(e) => e.into(),
+            AuthError::PasswordResetRepository(e) => e.into(),
+        }
+    }
+}
+
+impl From<MfaError> for UseCaseError {
+    fn from(error: MfaError) -> Self {
+        match error {
+            MfaError::AlreadyEnabled => UseCaseError::Conflict(error.to_string()),
+            MfaError::NotEnrolled | MfaError::MalformedCode(_) => {
+                UseCaseError::InvalidInput(error.to_string())
+            }
+            MfaError::InvalidCode | MfaError::InvalidChallenge => {
+                UseCaseError::Authentication(error.to_string())
+            }
+        }
+    }
+}
+
+impl From<MfaRepositoryError> for UseCaseError {
+    fn from(error: MfaRepositoryError) -> Self {
+        match error {
+            MfaRepositoryError::QueryFailed(e) => UseCaseError::Internal(e),
+            MfaRepositoryError::MappingFailed(e) => UseCaseError::Internal(e),
+            MfaRepositoryError::Unexpected(e) => UseCaseError::Internal(e),
+        }
+    }
+}
+
+impl From<PasswordResetError> for UseCaseError {
+    fn from(error: PasswordResetError) -> Self {
+        UseCaseError::InvalidInput(error.to_string())
+    }
+}
+
+impl From<PasswordResetRepositoryError> for UseCaseError {
+    fn from(error: PasswordResetRepositoryError) -> Self {
+        match error {
+            PasswordResetRepositoryError::QueryFailed(e) => UseCaseError::Internal(e),
+            PasswordResetRepositoryError::MappingFailed(e) => UseCaseError::Internal(e),
+            PasswordResetRepositoryError::Unexpected(e) => UseCaseError::Internal(e),
+        }
+    }
+}
+
+impl From<RefreshTokenError> for UseCaseError {
+    fn from(error: RefreshTokenError) -> Self {
+        UseCaseError::Authentication(error.to_string())
+    }
+}
+
+impl From<RefreshTokenRepositoryError> for UseCaseError {
+    fn from(error: RefreshTokenRepositoryError) -> Self {
+        match error {
+            RefreshTokenRepositoryError::QueryFailed(e) => UseCaseError::Internal(e),
+            RefreshTokenRepositoryError::MappingFailed(e) => UseCaseError::Internal(e),
+            RefreshTokenRepositoryError::Unexpected(e) => UseCaseError::Internal(e),
         }
     }
 }
@@ -134,6 +326,33 @@ impl From<AuthServiceError> for UseCaseError {
                 UseCaseError::Authentication("Token has expired".into())
             }
             AuthServiceError::InvalidToken => UseCaseError::Authentication("Invalid token".into()),
+            AuthServiceError::TokenRevoked => {
+                UseCaseError::Authentication("Token has been revoked".into())
+            }
+        }
+    }
+}
+
+impl From<TokenRevocationError> for UseCaseError {
+    fn from(error: TokenRevocationError) -> Self {
+        match error {
+            TokenRevocationError::StoreFailed(e) => UseCaseError::Internal(e),
+        }
+    }
+}
+
+impl From<LoginAttemptError> for UseCaseError {
+    fn from(error: LoginAttemptError) -> Self {
+        match error {
+            LoginAttemptError::StoreFailed(e) => UseCaseError::Internal(e),
+        }
+    }
+}
+
+impl From<BreachedPasswordError> for UseCaseError {
+    fn from(error: BreachedPasswordError) -> Self {
+        match error {
+            BreachedPasswordError::LookupFailed(e) => UseCaseError::Internal(e),
         }
     }
 }
@@ -143,6 +362,18 @@ impl From<PasswordServiceError> for UseCaseError {
         match error {
             PasswordServiceError::HashingFailed(e) => UseCaseError::Internal(e),
             PasswordServiceError::VerificationFailed(e) => UseCaseError::Internal(e),
+            PasswordServiceError::Overloaded => UseCaseError::Unavailable {
+                message: "Server is busy, please retry later".into(),
+                retry_after: Some(1),
+            },
+        }
+    }
+}
+
+impl From<MailerError> for UseCaseError {
+    fn from(error: MailerError) -> Self {
+        match error {
+            MailerError::DeliveryFailed(e) => UseCaseError::Internal(e),
         }
     }
 }
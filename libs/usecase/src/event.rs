@@ -0,0 +1,109 @@
+use crate::error::UseCaseResult;
+use async_trait::async_trait;
+use domain::DomainEvent;
+use std::sync::Arc;
+
+/// コミット済みのドメインイベントを受け取り、後続の副作用へ引き渡す外部ポート。
+///
+/// 業務データはコミット済みのため、副作用の失敗はユースケースの失敗として扱わない。
+/// 他サービスへの確実な配信が必要な場合は Outbox を使用すること。
+#[async_trait]
+pub trait EventSink: Send + Sync {
+    async fn publish(&self, events: &[DomainEvent]);
+}
+
+/// ドメインイベントに反応して副作用（メール送信等）を実行するハンドラー。
+#[async_trait]
+pub trait EventHandler: Send + Sync {
+    async fn handle(&self, event: &DomainEvent) -> UseCaseResult<()>;
+}
+
+/// 登録されたハンドラーへイベントを順に配送する `EventSink` の実装。
+///
+/// ハンドラーの失敗はログに記録し、他のハンドラーやイベントの処理は継続する。
+pub struct InProcessEventSink {
+    handlers: Vec<Arc<dyn EventHandler>>,
+}
+
+impl InProcessEventSink {
+    pub fn new(handlers: Vec<Arc<dyn EventHandler>>) -> Self {
+        Self { handlers }
+    }
+}
+
+#[async_trait]
+impl EventSink for InProcessEventSink {
+    async fn publish(&self, events: &[DomainEvent]) {
+        for event in events {
+            for handler in &self.handlers {
+                if let Err(e) = handler.handle(event).await {
+                    tracing::warn!(
+                        error = ?e,
+                        event_type = event.event_type(),
+                        "Failed to handle domain event"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::UseCaseError;
+    use chrono::Utc;
+    use domain::models::user::{UserEvent, UserId};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingHandler {
+        handled: Mutex<Vec<&'static str>>,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl EventHandler for RecordingHandler {
+        async fn handle(&self, event: &DomainEvent) -> UseCaseResult<()> {
+            self.handled.lock().unwrap().push(event.event_type());
+            if self.fail {
+                return Err(UseCaseError::Internal(anyhow::anyhow!("handler failed")));
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delivers_every_event_even_if_a_handler_fails() {
+        let failing = Arc::new(RecordingHandler {
+            fail: true,
+            ..Default::default()
+        });
+        let recording = Arc::new(RecordingHandler::default());
+        let sink = InProcessEventSink::new(vec![failing.clone(), recording.clone()]);
+        let user_id = UserId::from(uuid::Uuid::now_v7());
+
+        sink.publish(&[
+            UserEvent::PasswordChanged {
+                user_id,
+                occurred_at: Utc::now(),
+            }
+            .into(),
+            UserEvent::EmailVerified {
+                user_id,
+                occurred_at: Utc::now(),
+            }
+            .into(),
+        ])
+        .await;
+
+        assert_eq!(
+            *failing.handled.lock().unwrap(),
+            vec!["PasswordChanged", "EmailVerified"]
+        );
+        assert_eq!(
+            *recording.handled.lock().unwrap(),
+            vec!["PasswordChanged", "EmailVerified"]
+        );
+    }
+}
@@ -1,4 +1,7 @@
 pub mod auth;
 pub mod error;
+pub mod event;
+pub mod mailer;
+pub mod user;
 
 pub use error::UseCaseError;
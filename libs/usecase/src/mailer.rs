@@ -0,0 +1,38 @@
+use crate::auth::EmailVerificationToken;
+use crate::error::MailerError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::models::auth::OpaqueToken;
+use domain::models::user::Email;
+
+/// 送信するメールの種類と、本文に差し込む値。
+///
+/// 件名や本文の組み立て（テンプレート・リンク先 URL 等）はアダプター側の責務とする。
+#[derive(Debug, Clone)]
+pub enum MailMessage {
+    /// パスワードリセット用のトークンを通知する
+    PasswordReset {
+        token: OpaqueToken,
+        expires_at: DateTime<Utc>,
+    },
+    /// メールアドレス確認用のトークンを通知する
+    EmailVerification {
+        token: EmailVerificationToken,
+        expires_at: DateTime<Utc>,
+    },
+    /// 登録済みのメールアドレスで新規登録が試みられたことを所有者へ通知する
+    SignupAttempted { attempted_at: DateTime<Utc> },
+}
+
+/// 宛先付きのメール。
+#[derive(Debug, Clone)]
+pub struct Mail {
+    pub to: Email,
+    pub message: MailMessage,
+}
+
+/// メールを送信する外部ポート。
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, mail: Mail) -> Result<(), MailerError>;
+}
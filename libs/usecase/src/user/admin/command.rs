@@ -0,0 +1,35 @@
+use domain::Version;
+use domain::models::user::UserId;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuspendUserCommand {
+    pub admin_id: UserId,
+    pub user_id: UserId,
+    /// クライアントが参照したユーザーのバージョン（`If-Match`）。`None` の場合は検証しない
+    pub expected_version: Option<Version>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReactivateUserCommand {
+    pub admin_id: UserId,
+    pub user_id: UserId,
+    /// クライアントが参照したユーザーのバージョン（`If-Match`）。`None` の場合は検証しない
+    pub expected_version: Option<Version>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForcePasswordResetCommand {
+    pub admin_id: UserId,
+    pub user_id: UserId,
+    /// クライアントが参照したユーザーのバージョン（`If-Match`）。`None` の場合は検証しない
+    pub expected_version: Option<Version>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteUserCommand {
+    pub admin_id: UserId,
+    pub user_id: UserId,
+    /// クライアントが参照したユーザーのバージョン（`If-Match`）。`None` の場合は検証しない
+    pub expected_version: Option<Version>,
+}
@@ -0,0 +1,34 @@
+use domain::Version;
+use domain::models::user::{AccountStatus, Email, Role, User, UserId, UserIdentity};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdminUserDto {
+    pub user_id: UserId,
+    pub email: Email,
+    pub email_verified: bool,
+    pub status: AccountStatus,
+    pub roles: Vec<Role>,
+    /// 楽観ロック用のバージョン（HTTP の `ETag` として公開する）
+    pub version: Version,
+}
+
+impl From<&User> for AdminUserDto {
+    fn from(user: &User) -> Self {
+        Self {
+            user_id: user.id(),
+            email: user.email().clone(),
+            email_verified: user.email_verification().is_verified(),
+            status: user.status(),
+            roles: user.roles().to_vec(),
+            version: user.version(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserPageDto {
+    pub users: Vec<AdminUserDto>,
+    /// 次のページを取得するためのカーソル。最後のページの場合は `None`
+    pub next_cursor: Option<UserId>,
+}
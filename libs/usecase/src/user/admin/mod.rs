@@ -0,0 +1,677 @@
+pub mod command;
+pub mod dto;
+pub mod query;
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub use self::command::{
+    DeleteUserCommand, ForcePasswordResetCommand, ReactivateUserCommand, SuspendUserCommand,
+};
+use self::dto::{AdminUserDto, UserPageDto};
+pub use self::query::{GetUserQuery, ListUsersQuery};
+use crate::auth::LoginAttemptStore;
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::event::EventSink;
+use crate::mailer::{Mail, MailMessage, Mailer};
+use domain::error::DomainError;
+use domain::id::IdGenerator;
+use domain::models::auth::{
+    OpaqueTokenService, PasswordResetToken, PasswordResetTokenId, ThrottleKey,
+};
+use domain::models::user::{
+    AccountStatus, User, UserError, UserId, UserIdentity, UserSearchCriteria,
+};
+use domain::repository::tx::{RepositoryFactory, TransactionManager};
+use domain::{Actor, AuditContext, Clock, DomainEvent, Version};
+
+/// 監査カラムに記録するプログラムコード
+const PROGRAM_CODE: &str = "user-admin";
+
+/// 一覧の 1 ページの既定件数
+pub const DEFAULT_PAGE_SIZE: u32 = 50;
+/// 一覧の 1 ページの最大件数
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// 管理者によるユーザー管理のユースケース。
+#[async_trait]
+pub trait UserAdminUseCase: Send + Sync {
+    /// ユーザーを登録順に一覧する（カーソルによるページング）
+    async fn list_users(&self, query: ListUsersQuery) -> UseCaseResult<UserPageDto>;
+
+    async fn get_user(&self, query: GetUserQuery) -> UseCaseResult<AdminUserDto>;
+
+    /// 利用を停止し、発行済みのリフレッシュトークンを失効させる
+    async fn suspend_user(&self, command: SuspendUserCommand) -> UseCaseResult<()>;
+
+    /// 停止中・ロック中のアカウントを再開する。ロック中の場合はログインの失敗の記録も消去する。
+    async fn reactivate_user(&self, command: ReactivateUserCommand) -> UseCaseResult<()>;
+
+    /// 現在のパスワードとセッションを無効にし、パスワードリセット用のメールを送付する
+    ///
+    /// 再設定されるまでパスワードでのログインを拒否する。メールの送信失敗は要求の失敗としない。
+    async fn force_password_reset(&self, command: ForcePasswordResetCommand) -> UseCaseResult<()>;
+
+    async fn delete_user(&self, command: DeleteUserCommand) -> UseCaseResult<()>;
+}
+
+pub struct UserAdminUseCaseImpl<TM, TS, C, IG>
+where
+    TM: TransactionManager,
+    TS: OpaqueTokenService,
+    C: Clock,
+    IG: IdGenerator<PasswordResetTokenId>,
+{
+    transaction_manager: Arc<TM>,
+    token_service: Arc<TS>,
+    mailer: Arc<dyn Mailer>,
+    event_sink: Arc<dyn EventSink>,
+    attempt_store: Arc<dyn LoginAttemptStore>,
+    clock: Arc<C>,
+    id_generator: Arc<IG>,
+}
+
+impl<TM, TS, C, IG> UserAdminUseCaseImpl<TM, TS, C, IG>
+where
+    TM: TransactionManager,
+    TS: OpaqueTokenService,
+    C: Clock,
+    IG: IdGenerator<PasswordResetTokenId>,
+{
+    pub fn new(
+        transaction_manager: Arc<TM>,
+        token_service: Arc<TS>,
+        mailer: Arc<dyn Mailer>,
+        event_sink: Arc<dyn EventSink>,
+        attempt_store: Arc<dyn LoginAttemptStore>,
+        clock: Arc<C>,
+        id_generator: Arc<IG>,
+    ) -> Self {
+        Self {
+            transaction_manager,
+            token_service,
+            mailer,
+            event_sink,
+            attempt_store,
+            clock,
+            id_generator,
+        }
+    }
+}
+
+/// 管理者が自分自身を停止・削除して管理できなくなることを防ぐ
+fn ensure_not_self(admin_id: UserId, user_id: UserId) -> UseCaseResult<()> {
+    if admin_id == user_id {
+        return Err(UseCaseError::InvalidInput(
+            "Administrators cannot suspend or delete their own account".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// 対象のユーザーを読み込み、`If-Match` で指定されたバージョンと一致することを確認する
+async fn load_user(
+    factory: &dyn RepositoryFactory,
+    user_id: UserId,
+    expected: Option<Version>,
+) -> Result<User, DomainError> {
+    let user = factory
+        .user_repository()
+        .find_by_id(user_id)
+        .await?
+        .ok_or(UserError::NotFound)?;
+    if let Some(expected) = expected {
+        user.ensure_version(expected)?;
+    }
+    Ok(user)
+}
+
+#[async_trait]
+impl<TM, TS, C, IG> UserAdminUseCase for UserAdminUseCaseImpl<TM, TS, C, IG>
+where
+    TM: TransactionManager,
+    TS: OpaqueTokenService + 'static,
+    C: Clock + 'static,
+    IG: IdGenerator<PasswordResetTokenId> + 'static,
+{
+    async fn list_users(&self, query: ListUsersQuery) -> UseCaseResult<UserPageDto> {
+        let limit = query.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+        if !(1..=MAX_PAGE_SIZE).contains(&limit) {
+            return Err(UseCaseError::InvalidInput(format!(
+                "limit must be between 1 and {}",
+                MAX_PAGE_SIZE
+            )));
+        }
+        // 次のページの有無を判定するため 1 件多く取得する
+        let criteria = UserSearchCriteria {
+            email_prefix: query.email_prefix.filter(|prefix| !prefix.is_empty()),
+            status: query.status,
+            after: query.cursor,
+            limit: limit + 1,
+        };
+
+        let audit = AuditContext::new(Actor::User(query.admin_id), PROGRAM_CODE);
+        let mut users = domain::tx!(self.transaction_manager, audit, |factory| {
+            factory
+                .user_repository()
+                .search(&criteria)
+                .await
+                .map_err(DomainError::from)
+        })
+        .await?;
+
+        let has_more = users.len() > limit as usize;
+        users.truncate(limit as usize);
+        let next_cursor = has_more.then(|| users.last().map(|u| u.id())).flatten();
+
+        Ok(UserPageDto {
+            users: users.iter().map(AdminUserDto::from).collect(),
+            next_cursor,
+        })
+    }
+
+    async fn get_user(&self, query: GetUserQuery) -> UseCaseResult<AdminUserDto> {
+        let audit = AuditContext::new(Actor::User(query.admin_id), PROGRAM_CODE);
+        let user = domain::tx!(self.transaction_manager, audit, |factory| {
+            load_user(factory, query.user_id, None).await
+        })
+        .await?;
+
+        Ok(AdminUserDto::from(&user))
+    }
+
+    async fn suspend_user(&self, command: SuspendUserCommand) -> UseCaseResult<()> {
+        ensure_not_self(command.admin_id, command.user_id)?;
+        let now = self.clock.now();
+
+        let audit = AuditContext::new(Actor::User(command.admin_id), PROGRAM_CODE);
+        let events = domain::tx!(self.transaction_manager, audit, |factory| {
+            let user = load_user(factory, command.user_id, command.expected_version).await?;
+            let (user, events) = user.suspend(now)?;
+            factory.user_repository().save(&user).await?;
+            factory
+                .refresh_token_repository()
+                .revoke_all_by_user_id(user.id(), now)
+                .await?;
+
+            let events: Vec<DomainEvent> = events.into_iter().map(DomainEvent::from).collect();
+            factory.outbox_repository().append(&events).await?;
+
+            Ok::<Vec<DomainEvent>, DomainError>(events)
+        })
+        .await?;
+
+        self.event_sink.publish(&events).await;
+
+        Ok(())
+    }
+
+    async fn reactivate_user(&self, command: ReactivateUserCommand) -> UseCaseResult<()> {
+        let now = self.clock.now();
+
+        let audit = AuditContext::new(Actor::User(command.admin_id), PROGRAM_CODE);
+        let (user, was_locked, events) = domain::tx!(self.transaction_manager, audit, |factory| {
+            let user = load_user(factory, command.user_id, command.expected_version).await?;
+            let was_locked = user.status() == AccountStatus::Locked;
+            let (user, events) = user.reactivate(now)?;
+            factory.user_repository().save(&user).await?;
+
+            let events: Vec<DomainEvent> = events.into_iter().map(DomainEvent::from).collect();
+            factory.outbox_repository().append(&events).await?;
+
+            Ok::<(User, bool, Vec<DomainEvent>), DomainError>((user, was_locked, events))
+        })
+        .await?;
+
+        // ロックは失敗の記録に基づいて判定されるため、記録も消去して解除する
+        if was_locked {
+            self.attempt_store
+                .clear(&ThrottleKey::Account(user.email().clone()))
+                .await?;
+        }
+        self.event_sink.publish(&events).await;
+
+        Ok(())
+    }
+
+    async fn force_password_reset(&self, command: ForcePasswordResetCommand) -> UseCaseResult<()> {
+        let token = self.token_service.generate();
+        let token_hash = self.token_service.hash(&token);
+        let token_id = self.id_generator.generate();
+        let now = self.clock.now();
+
+        let audit = AuditContext::new(Actor::User(command.admin_id), PROGRAM_CODE);
+        let (user, record, events) = domain::tx!(self.transaction_manager, audit, |factory| {
+            let user = load_user(factory, command.user_id, command.expected_version).await?;
+            let (user, events) = user.require_password_reset(now)?;
+            factory.user_repository().save(&user).await?;
+
+            // 以前に発行した未使用のトークンは無効化し、最新の 1 件のみを有効とする
+            let record = PasswordResetToken::issue(token_id, user.id(), token_hash, now);
+            let reset_repo = factory.password_reset_token_repository();
+            reset_repo.delete_by_user_id(user.id()).await?;
+            reset_repo.save(&record).await?;
+            factory
+                .refresh_token_repository()
+                .revoke_all_by_user_id(user.id(), now)
+                .await?;
+
+            let events: Vec<DomainEvent> = events.into_iter().map(DomainEvent::from).collect();
+            factory.outbox_repository().append(&events).await?;
+
+            Ok::<(User, PasswordResetToken, Vec<DomainEvent>), DomainError>((user, record, events))
+        })
+        .await?;
+
+        self.event_sink.publish(&events).await;
+
+        // 再設定の要求は確定しているため、送信の失敗は記録のみとし、再度の要求で送り直す
+        if let Err(e) = self
+            .mailer
+            .send(Mail {
+                to: user.email().clone(),
+                message: MailMessage::PasswordReset {
+                    token,
+                    expires_at: record.expires_at(),
+                },
+            })
+            .await
+        {
+            tracing::warn!(error = ?e, user_id = %user.id(), "Failed to send password reset mail");
+        }
+
+        Ok(())
+    }
+
+    async fn delete_user(&self, command: DeleteUserCommand) -> UseCaseResult<()> {
+        ensure_not_self(command.admin_id, command.user_id)?;
+        let now = self.clock.now();
+
+        let audit = AuditContext::new(Actor::User(command.admin_id), PROGRAM_CODE);
+        let events = domain::tx!(self.transaction_manager, audit, |factory| {
+            let user = load_user(factory, command.user_id, command.expected_version).await?;
+            let (user, events) = user.delete(now)?;
+            factory.user_repository().save(&user).await?;
+            factory
+                .refresh_token_repository()
+                .revoke_all_by_user_id(user.id(), now)
+                .await?;
+
+            let events: Vec<DomainEvent> = events.into_iter().map(DomainEvent::from).collect();
+            factory.outbox_repository().append(&events).await?;
+
+            Ok::<Vec<DomainEvent>, DomainError>(events)
+        })
+        .await?;
+
+        self.event_sink.publish(&events).await;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::test_utils::utils::*;
+    use domain::models::auth::OpaqueTokenHash;
+    use domain::models::auth::{RefreshToken, RefreshTokenId, RefreshTokenStatus};
+    use domain::models::user::{Email, PasswordHash};
+    use domain::test_utils::FixedClock;
+    use rstest::*;
+
+    struct Fixture {
+        usecase: UserAdminUseCaseImpl<
+            StubTransactionManager,
+            StubOpaqueTokenService,
+            FixedClock,
+            StubUuidGenerator,
+        >,
+        factory: Arc<StubRepositoryFactory>,
+        mailer: Arc<StubMailer>,
+        event_sink: Arc<StubEventSink>,
+        attempt_store: Arc<StubLoginAttemptStore>,
+    }
+
+    fn build(repo: StubUserRepository) -> Fixture {
+        build_with(StubRepositoryFactory::new(Arc::new(repo)))
+    }
+
+    fn build_with(factory: StubRepositoryFactory) -> Fixture {
+        build_with_mailer(factory, StubMailer::default())
+    }
+
+    fn build_with_mailer(factory: StubRepositoryFactory, mailer: StubMailer) -> Fixture {
+        let factory = Arc::new(factory);
+        let tm = Arc::new(StubTransactionManager {
+            factory: factory.clone(),
+        });
+        let mailer = Arc::new(mailer);
+        let event_sink = Arc::new(StubEventSink::default());
+        let attempt_store = Arc::new(StubLoginAttemptStore::default());
+        let usecase = UserAdminUseCaseImpl::new(
+            tm,
+            Arc::new(StubOpaqueTokenService::default()),
+            mailer.clone(),
+            event_sink.clone(),
+            attempt_store.clone(),
+            Arc::new(FixedClock::new(chrono::Utc::now())),
+            Arc::new(StubUuidGenerator),
+        );
+        Fixture {
+            usecase,
+            factory,
+            mailer,
+            event_sink,
+            attempt_store,
+        }
+    }
+
+    fn user(email: &str) -> User {
+        User::new(
+            UserId::from(uuid::Uuid::now_v7()),
+            Email::try_from(email).unwrap(),
+            PasswordHash::from_str_unchecked("hash"),
+        )
+    }
+
+    #[fixture]
+    fn admin_id() -> UserId {
+        UserId::from(uuid::Uuid::now_v7())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_list_users_pages_with_cursor(admin_id: UserId) {
+        let users: Vec<User> = (0..3)
+            .map(|i| user(&format!("u{}@example.com", i)))
+            .collect();
+        let fixture = build(StubUserRepository {
+            users: users.clone(),
+            ..Default::default()
+        });
+        let query = |cursor| ListUsersQuery {
+            admin_id,
+            email_prefix: None,
+            status: None,
+            cursor,
+            limit: Some(2),
+        };
+
+        let first = fixture.usecase.list_users(query(None)).await.unwrap();
+        assert_eq!(first.users.len(), 2);
+        assert_eq!(first.next_cursor, Some(users[1].id()));
+
+        let second = fixture
+            .usecase
+            .list_users(query(first.next_cursor))
+            .await
+            .unwrap();
+        assert_eq!(second.users.len(), 1);
+        assert_eq!(second.users[0].user_id, users[2].id());
+        assert_eq!(second.next_cursor, None);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(MAX_PAGE_SIZE + 1)]
+    #[tokio::test]
+    async fn test_list_users_rejects_invalid_limit(admin_id: UserId, #[case] limit: u32) {
+        let fixture = build(StubUserRepository::default());
+
+        let result = fixture
+            .usecase
+            .list_users(ListUsersQuery {
+                admin_id,
+                email_prefix: None,
+                status: None,
+                cursor: None,
+                limit: Some(limit),
+            })
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::InvalidInput(_))));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_suspend_revokes_sessions_and_emits_event(admin_id: UserId) {
+        let target = user("target@example.com");
+        let session = RefreshToken::issue(
+            RefreshTokenId::from(uuid::Uuid::now_v7()),
+            target.id(),
+            OpaqueTokenHash::from_str_unchecked("hashed:session"),
+            chrono::Utc::now(),
+        );
+        let fixture = build_with(StubRepositoryFactory {
+            refresh_token_repo: Arc::new(StubRefreshTokenRepository::with_tokens(vec![session])),
+            ..StubRepositoryFactory::new(Arc::new(StubUserRepository::with_user(target.clone())))
+        });
+
+        fixture
+            .usecase
+            .suspend_user(SuspendUserCommand {
+                admin_id,
+                user_id: target.id(),
+                expected_version: None,
+            })
+            .await
+            .unwrap();
+
+        let saved = fixture.factory.repo.saved_users();
+        assert_eq!(saved[0].status(), AccountStatus::Suspended);
+        assert!(
+            fixture
+                .factory
+                .refresh_token_repo
+                .tokens()
+                .iter()
+                .all(|t| matches!(t.status(), RefreshTokenStatus::Revoked { .. }))
+        );
+        let published = fixture.event_sink.published();
+        assert_eq!(published.len(), 1);
+        assert_eq!(published[0].event_type(), "UserSuspended");
+        assert_eq!(fixture.factory.outbox_repo.events(), published);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_reactivate_active_user_conflicts(admin_id: UserId) {
+        let target = user("active@example.com");
+        let fixture = build(StubUserRepository::with_user(target.clone()));
+
+        let result = fixture
+            .usecase
+            .reactivate_user(ReactivateUserCommand {
+                admin_id,
+                user_id: target.id(),
+                expected_version: None,
+            })
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::Conflict(_))));
+        assert!(fixture.factory.repo.saved_users().is_empty());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_reactivate_locked_user_clears_login_failures(admin_id: UserId) {
+        let now = chrono::Utc::now();
+        let (target, _) = user("locked@example.com")
+            .lock(now + chrono::Duration::minutes(15), now)
+            .unwrap();
+        let fixture = build(StubUserRepository::with_user(target.clone()));
+        let key = ThrottleKey::Account(target.email().clone());
+        for _ in 0..5 {
+            fixture
+                .attempt_store
+                .record_failure(&key, now, now - chrono::Duration::hours(1))
+                .await
+                .unwrap();
+        }
+
+        fixture
+            .usecase
+            .reactivate_user(ReactivateUserCommand {
+                admin_id,
+                user_id: target.id(),
+                expected_version: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            fixture.factory.repo.saved_users()[0].status(),
+            AccountStatus::Active
+        );
+        assert_eq!(fixture.attempt_store.failure_count(&key), 0);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_admin_cannot_delete_self(admin_id: UserId) {
+        let fixture = build(StubUserRepository::default());
+
+        let result = fixture
+            .usecase
+            .delete_user(DeleteUserCommand {
+                admin_id,
+                user_id: admin_id,
+                expected_version: None,
+            })
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::InvalidInput(_))));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_delete_checks_version(admin_id: UserId) {
+        let target = user("delete@example.com");
+        let fixture = build(StubUserRepository::with_user(target.clone()));
+
+        let stale = fixture
+            .usecase
+            .delete_user(DeleteUserCommand {
+                admin_id,
+                user_id: target.id(),
+                expected_version: Some(Version::from(7)),
+            })
+            .await;
+        assert!(matches!(stale, Err(UseCaseError::PreconditionFailed(_))));
+        assert!(fixture.factory.repo.saved_users().is_empty());
+
+        fixture
+            .usecase
+            .delete_user(DeleteUserCommand {
+                admin_id,
+                user_id: target.id(),
+                expected_version: Some(target.version()),
+            })
+            .await
+            .unwrap();
+        let saved = fixture.factory.repo.saved_users();
+        assert_eq!(saved[0].id(), target.id());
+        assert_eq!(saved[0].status(), AccountStatus::Deleted);
+        assert_eq!(
+            fixture.event_sink.published()[0].event_type(),
+            "UserDeleted"
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_force_password_reset_invalidates_password_and_sends_mail(admin_id: UserId) {
+        let target = user("reset@example.com");
+        let fixture = build(StubUserRepository::with_user(target.clone()));
+
+        fixture
+            .usecase
+            .force_password_reset(ForcePasswordResetCommand {
+                admin_id,
+                user_id: target.id(),
+                expected_version: Some(target.version()),
+            })
+            .await
+            .unwrap();
+
+        let sent = fixture.mailer.sent();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(&sent[0].to, target.email());
+        assert!(matches!(sent[0].message, MailMessage::PasswordReset { .. }));
+        assert_eq!(fixture.factory.password_reset_token_repo.tokens().len(), 1);
+
+        // 再設定されるまで現在のパスワードは使用できない
+        let saved = fixture.factory.repo.saved_users();
+        assert!(saved[0].ensure_password_usable().is_err());
+        let published = fixture.event_sink.published();
+        assert_eq!(published.len(), 1);
+        assert_eq!(published[0].event_type(), "PasswordResetRequired");
+        assert_eq!(fixture.factory.outbox_repo.events(), published);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_force_password_reset_succeeds_even_if_mail_fails(admin_id: UserId) {
+        let target = user("reset@example.com");
+        let fixture = build_with_mailer(
+            StubRepositoryFactory::new(Arc::new(StubUserRepository::with_user(target.clone()))),
+            StubMailer::failing(),
+        );
+
+        fixture
+            .usecase
+            .force_password_reset(ForcePasswordResetCommand {
+                admin_id,
+                user_id: target.id(),
+                expected_version: None,
+            })
+            .await
+            .unwrap();
+
+        assert!(
+            fixture.factory.repo.saved_users()[0]
+                .ensure_password_usable()
+                .is_err()
+        );
+        assert_eq!(fixture.factory.password_reset_token_repo.tokens().len(), 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_force_password_reset_checks_version_and_state(admin_id: UserId) {
+        let target = user("reset@example.com");
+        let fixture = build(StubUserRepository::with_user(target.clone()));
+
+        let result = fixture
+            .usecase
+            .force_password_reset(ForcePasswordResetCommand {
+                admin_id,
+                user_id: target.id(),
+                expected_version: Some(Version::from(7)),
+            })
+            .await;
+        assert!(matches!(result, Err(UseCaseError::PreconditionFailed(_))));
+
+        // 削除済みのアカウントにはトークンを発行しない
+        let (deleted, _) = target.delete(chrono::Utc::now()).unwrap();
+        let fixture = build(StubUserRepository::with_user(deleted.clone()));
+        let result = fixture
+            .usecase
+            .force_password_reset(ForcePasswordResetCommand {
+                admin_id,
+                user_id: deleted.id(),
+                expected_version: None,
+            })
+            .await;
+        assert!(matches!(result, Err(UseCaseError::Conflict(_))));
+        assert!(fixture.mailer.sent().is_empty());
+        assert!(
+            fixture
+                .factory
+                .password_reset_token_repo
+                .tokens()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use domain::models::user::{AccountStatus, UserId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListUsersQuery {
+    pub admin_id: UserId,
+    /// メールアドレスの前方一致
+    pub email_prefix: Option<String>,
+    pub status: Option<AccountStatus>,
+    /// 前のページの `next_cursor`
+    pub cursor: Option<UserId>,
+    /// 1 ページの件数。`None` の場合は既定値
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetUserQuery {
+    pub admin_id: UserId,
+    pub user_id: UserId,
+}
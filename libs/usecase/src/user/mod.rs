@@ -0,0 +1,6 @@
+pub mod admin;
+pub mod profile;
+
+pub use admin::{UserAdminUseCase, UserAdminUseCaseImpl};
+pub use domain::models::user::{AccountStatus, UserId};
+pub use profile::{UserProfileUseCase, UserProfileUseCaseImpl};
@@ -0,0 +1,12 @@
+use domain::Version;
+use domain::models::user::{Email, UserId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserProfileDto {
+    pub user_id: UserId,
+    pub email: Email,
+    pub email_verified: bool,
+    /// 楽観ロック用のバージョン（HTTP の `ETag` として公開する）
+    pub version: Version,
+}
@@ -0,0 +1,128 @@
+pub mod dto;
+pub mod query;
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use self::dto::UserProfileDto;
+pub use self::query::UserProfileQuery;
+use crate::error::UseCaseResult;
+use domain::models::user::{UserError, UserIdentity};
+use domain::repository::tx::TransactionManager;
+use domain::{Actor, AuditContext};
+
+/// 監査カラムに記録するプログラムコード
+const PROGRAM_CODE: &str = "user-profile";
+
+/// ログイン中のユーザー自身のプロフィールを取得するユースケース。
+#[async_trait]
+pub trait UserProfileUseCase: Send + Sync {
+    async fn get_profile(&self, query: UserProfileQuery) -> UseCaseResult<UserProfileDto>;
+}
+
+pub struct UserProfileUseCaseImpl<TM>
+where
+    TM: TransactionManager,
+{
+    transaction_manager: Arc<TM>,
+}
+
+impl<TM> UserProfileUseCaseImpl<TM>
+where
+    TM: TransactionManager,
+{
+    pub fn new(transaction_manager: Arc<TM>) -> Self {
+        Self {
+            transaction_manager,
+        }
+    }
+}
+
+#[async_trait]
+impl<TM> UserProfileUseCase for UserProfileUseCaseImpl<TM>
+where
+    TM: TransactionManager,
+{
+    async fn get_profile(&self, query: UserProfileQuery) -> UseCaseResult<UserProfileDto> {
+        let audit = AuditContext::new(Actor::User(query.user_id), PROGRAM_CODE);
+        let user = domain::tx!(self.transaction_manager, audit, |factory| {
+            factory
+                .user_repository()
+                .find_by_id(query.user_id)
+                .await?
+                .ok_or(UserError::NotFound)
+                .map_err(domain::error::DomainError::from)
+        })
+        .await?;
+
+        Ok(UserProfileDto {
+            user_id: user.id(),
+            email_verified: user.email_verification().is_verified(),
+            version: user.version(),
+            email: user.email().clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::test_utils::utils::*;
+    use crate::error::UseCaseError;
+    use domain::Version;
+    use domain::models::user::{
+        AccountState, Email, EmailVerificationStatus, PasswordHash, Role, User, UserId,
+    };
+    use rstest::*;
+
+    fn build_usecase(
+        repo: Arc<StubUserRepository>,
+    ) -> UserProfileUseCaseImpl<StubTransactionManager> {
+        let tm = Arc::new(StubTransactionManager {
+            factory: Arc::new(StubRepositoryFactory::new(repo)),
+        });
+        UserProfileUseCaseImpl::new(tm)
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_get_profile_returns_version(valid_email: Email) {
+        let user = User::reconstruct(
+            UserId::from(uuid::Uuid::now_v7()),
+            valid_email.clone(),
+            PasswordHash::from_str_unchecked("hash"),
+            EmailVerificationStatus::Unverified,
+            vec![Role::User],
+            AccountState::new(),
+            None,
+            None,
+            Version::from(4),
+        );
+        let user_id = user.id();
+        let usecase = build_usecase(Arc::new(StubUserRepository::with_user(user)));
+
+        let profile = usecase
+            .get_profile(UserProfileQuery { user_id })
+            .await
+            .unwrap();
+
+        assert_eq!(profile.user_id, user_id);
+        assert_eq!(profile.email, valid_email);
+        assert!(!profile.email_verified);
+        assert_eq!(profile.version, Version::from(4));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_get_profile_of_missing_user() {
+        let usecase = build_usecase(Arc::new(StubUserRepository::default()));
+
+        let result = usecase
+            .get_profile(UserProfileQuery {
+                user_id: UserId::from(uuid::Uuid::now_v7()),
+            })
+            .await;
+
+        assert!(matches!(result, Err(UseCaseError::NotFound(_))));
+    }
+}
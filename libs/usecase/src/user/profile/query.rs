@@ -0,0 +1,7 @@
+use domain::models::user::UserId;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserProfileQuery {
+    pub user_id: UserId,
+}
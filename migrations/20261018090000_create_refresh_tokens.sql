@@ -0,0 +1,40 @@
+-- Create refresh_tokens table for opaque refresh tokens with rotation
+CREATE TABLE refresh_tokens (
+    -- Primary Key
+    id UUID PRIMARY KEY,
+
+    -- Business Columns
+    family_id UUID NOT NULL,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    token_hash VARCHAR(255) NOT NULL,
+    issued_at TIMESTAMPTZ NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ,
+
+    -- Common Columns (Creation)
+    created_at TIMESTAMPTZ NOT NULL,
+    created_by VARCHAR(255) NOT NULL,
+    created_pgm_cd VARCHAR(255) NOT NULL,
+    created_tx_id VARCHAR(255) NOT NULL,
+
+    -- Common Columns (Update)
+    updated_at TIMESTAMPTZ NOT NULL,
+    updated_by VARCHAR(255) NOT NULL,
+    updated_pgm_cd VARCHAR(255) NOT NULL,
+    updated_tx_id VARCHAR(255) NOT NULL,
+
+    -- Common Columns (Optimistic Locking)
+    lock_no INTEGER NOT NULL DEFAULT 1,
+
+    -- Common Columns (Patch)
+    patched_at TIMESTAMPTZ,
+    patched_by VARCHAR(255),
+    patched_id VARCHAR(255)
+);
+
+-- Lookup by hash of the presented token
+CREATE UNIQUE INDEX idx_refresh_tokens_token_hash_unique ON refresh_tokens(token_hash);
+
+-- Family-wide revocation on reuse detection
+CREATE INDEX idx_refresh_tokens_family_id ON refresh_tokens(family_id);
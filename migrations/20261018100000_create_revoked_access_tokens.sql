@@ -0,0 +1,32 @@
+-- Create revoked_access_tokens table as a denylist of access tokens (jti) revoked before expiry
+CREATE TABLE revoked_access_tokens (
+    -- Primary Key
+    jti UUID PRIMARY KEY,
+
+    -- Business Columns
+    expires_at TIMESTAMPTZ NOT NULL,
+    revoked_at TIMESTAMPTZ NOT NULL,
+
+    -- Common Columns (Creation)
+    created_at TIMESTAMPTZ NOT NULL,
+    created_by VARCHAR(255) NOT NULL,
+    created_pgm_cd VARCHAR(255) NOT NULL,
+    created_tx_id VARCHAR(255) NOT NULL,
+
+    -- Common Columns (Update)
+    updated_at TIMESTAMPTZ NOT NULL,
+    updated_by VARCHAR(255) NOT NULL,
+    updated_pgm_cd VARCHAR(255) NOT NULL,
+    updated_tx_id VARCHAR(255) NOT NULL,
+
+    -- Common Columns (Optimistic Locking)
+    lock_no INTEGER NOT NULL DEFAULT 1,
+
+    -- Common Columns (Patch)
+    patched_at TIMESTAMPTZ,
+    patched_by VARCHAR(255),
+    patched_id VARCHAR(255)
+);
+
+-- Purge of entries whose token has already expired
+CREATE INDEX idx_revoked_access_tokens_expires_at ON revoked_access_tokens(expires_at);
@@ -0,0 +1,65 @@
+-- Create user_totp_credentials table for TOTP-based multi-factor authentication
+CREATE TABLE user_totp_credentials (
+    -- Primary Key
+    user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+
+    -- Business Columns
+    secret VARCHAR(255) NOT NULL,
+    confirmed_at TIMESTAMPTZ,
+    last_used_step BIGINT,
+
+    -- Common Columns (Creation)
+    created_at TIMESTAMPTZ NOT NULL,
+    created_by VARCHAR(255) NOT NULL,
+    created_pgm_cd VARCHAR(255) NOT NULL,
+    created_tx_id VARCHAR(255) NOT NULL,
+
+    -- Common Columns (Update)
+    updated_at TIMESTAMPTZ NOT NULL,
+    updated_by VARCHAR(255) NOT NULL,
+    updated_pgm_cd VARCHAR(255) NOT NULL,
+    updated_tx_id VARCHAR(255) NOT NULL,
+
+    -- Common Columns (Optimistic Locking)
+    lock_no INTEGER NOT NULL DEFAULT 1,
+
+    -- Common Columns (Patch)
+    patched_at TIMESTAMPTZ,
+    patched_by VARCHAR(255),
+    patched_id VARCHAR(255)
+);
+
+-- Create mfa_challenges table for the second step of login
+CREATE TABLE mfa_challenges (
+    -- Primary Key
+    id UUID PRIMARY KEY,
+
+    -- Business Columns
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    token_hash VARCHAR(255) NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    failed_attempts INTEGER NOT NULL DEFAULT 0,
+
+    -- Common Columns (Creation)
+    created_at TIMESTAMPTZ NOT NULL,
+    created_by VARCHAR(255) NOT NULL,
+    created_pgm_cd VARCHAR(255) NOT NULL,
+    created_tx_id VARCHAR(255) NOT NULL,
+
+    -- Common Columns (Update)
+    updated_at TIMESTAMPTZ NOT NULL,
+    updated_by VARCHAR(255) NOT NULL,
+    updated_pgm_cd VARCHAR(255) NOT NULL,
+    updated_tx_id VARCHAR(255) NOT NULL,
+
+    -- Common Columns (Optimistic Locking)
+    lock_no INTEGER NOT NULL DEFAULT 1,
+
+    -- Common Columns (Patch)
+    patched_at TIMESTAMPTZ,
+    patched_by VARCHAR(255),
+    patched_id VARCHAR(255)
+);
+
+-- Lookup by hash of the presented challenge token
+CREATE UNIQUE INDEX idx_mfa_challenges_token_hash_unique ON mfa_challenges(token_hash);
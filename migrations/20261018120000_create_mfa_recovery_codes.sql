@@ -0,0 +1,32 @@
+-- Create mfa_recovery_codes table for single-use MFA recovery codes
+CREATE TABLE mfa_recovery_codes (
+    -- Primary Key
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    seq SMALLINT NOT NULL,
+
+    -- Business Columns
+    code_hash VARCHAR(255) NOT NULL,
+    used_at TIMESTAMPTZ,
+
+    -- Common Columns (Creation)
+    created_at TIMESTAMPTZ NOT NULL,
+    created_by VARCHAR(255) NOT NULL,
+    created_pgm_cd VARCHAR(255) NOT NULL,
+    created_tx_id VARCHAR(255) NOT NULL,
+
+    -- Common Columns (Update)
+    updated_at TIMESTAMPTZ NOT NULL,
+    updated_by VARCHAR(255) NOT NULL,
+    updated_pgm_cd VARCHAR(255) NOT NULL,
+    updated_tx_id VARCHAR(255) NOT NULL,
+
+    -- Common Columns (Optimistic Locking)
+    lock_no INTEGER NOT NULL DEFAULT 1,
+
+    -- Common Columns (Patch)
+    patched_at TIMESTAMPTZ,
+    patched_by VARCHAR(255),
+    patched_id VARCHAR(255),
+
+    PRIMARY KEY (user_id, seq)
+);
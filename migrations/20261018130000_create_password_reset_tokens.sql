@@ -0,0 +1,36 @@
+-- Create password_reset_tokens table for the forgot-password flow
+CREATE TABLE password_reset_tokens (
+    -- Primary Key
+    id UUID PRIMARY KEY,
+
+    -- Business Columns
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    token_hash VARCHAR(255) NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+
+    -- Common Columns (Creation)
+    created_at TIMESTAMPTZ NOT NULL,
+    created_by VARCHAR(255) NOT NULL,
+    created_pgm_cd VARCHAR(255) NOT NULL,
+    created_tx_id VARCHAR(255) NOT NULL,
+
+    -- Common Columns (Update)
+    updated_at TIMESTAMPTZ NOT NULL,
+    updated_by VARCHAR(255) NOT NULL,
+    updated_pgm_cd VARCHAR(255) NOT NULL,
+    updated_tx_id VARCHAR(255) NOT NULL,
+
+    -- Common Columns (Optimistic Locking)
+    lock_no INTEGER NOT NULL DEFAULT 1,
+
+    -- Common Columns (Patch)
+    patched_at TIMESTAMPTZ,
+    patched_by VARCHAR(255),
+    patched_id VARCHAR(255)
+);
+
+-- Lookup by hash of the emailed reset token
+CREATE UNIQUE INDEX idx_password_reset_tokens_token_hash_unique ON password_reset_tokens(token_hash);
+
+-- Invalidate all outstanding tokens of a user
+CREATE INDEX idx_password_reset_tokens_user_id ON password_reset_tokens(user_id);
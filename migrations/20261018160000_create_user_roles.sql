@@ -0,0 +1,39 @@
+-- Create user_roles table for role-based access control
+CREATE TABLE user_roles (
+    -- Primary Key
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role VARCHAR(32) NOT NULL CHECK (role IN ('user', 'admin')),
+
+    -- Common Columns (Creation)
+    created_at TIMESTAMPTZ NOT NULL,
+    created_by VARCHAR(255) NOT NULL,
+    created_pgm_cd VARCHAR(255) NOT NULL,
+    created_tx_id VARCHAR(255) NOT NULL,
+
+    -- Common Columns (Update)
+    updated_at TIMESTAMPTZ NOT NULL,
+    updated_by VARCHAR(255) NOT NULL,
+    updated_pgm_cd VARCHAR(255) NOT NULL,
+    updated_tx_id VARCHAR(255) NOT NULL,
+
+    -- Common Columns (Optimistic Locking)
+    lock_no INTEGER NOT NULL DEFAULT 1,
+
+    -- Common Columns (Patch)
+    patched_at TIMESTAMPTZ,
+    patched_by VARCHAR(255),
+    patched_id VARCHAR(255),
+
+    PRIMARY KEY (user_id, role)
+);
+
+-- Existing users become regular users
+INSERT INTO user_roles (
+    user_id, role,
+    created_at, created_by, created_pgm_cd, created_tx_id,
+    updated_at, updated_by, updated_pgm_cd, updated_tx_id
+)
+SELECT id, 'user',
+    now(), 'system', 'migration', '20261018160000',
+    now(), 'system', 'migration', '20261018160000'
+FROM users;
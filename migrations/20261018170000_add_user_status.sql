@@ -0,0 +1,3 @@
+-- Track whether the account is usable or suspended by an administrator
+ALTER TABLE users ADD COLUMN status VARCHAR(16) NOT NULL DEFAULT 'active'
+    CONSTRAINT users_status_check CHECK (status IN ('active', 'suspended'));
@@ -0,0 +1,18 @@
+-- Extend the account status to a lifecycle of active, suspended, locked and deleted.
+-- Deleted accounts keep their row (and email) so audit columns and events stay resolvable.
+ALTER TABLE users DROP CONSTRAINT users_status_check;
+ALTER TABLE users ADD CONSTRAINT users_status_check
+    CHECK (status IN ('active', 'suspended', 'locked', 'deleted'));
+
+-- When the account entered its current non-active status, and the end of a lock
+ALTER TABLE users ADD COLUMN status_changed_at TIMESTAMPTZ;
+ALTER TABLE users ADD COLUMN locked_until TIMESTAMPTZ;
+
+-- Accounts suspended before this migration have no recorded time; use their last update
+UPDATE users SET status_changed_at = updated_at WHERE status = 'suspended';
+
+ALTER TABLE users ADD CONSTRAINT users_status_detail_check CHECK (
+    (status = 'active' AND locked_until IS NULL)
+    OR (status IN ('suspended', 'deleted') AND status_changed_at IS NOT NULL AND locked_until IS NULL)
+    OR (status = 'locked' AND status_changed_at IS NOT NULL AND locked_until IS NOT NULL)
+);
@@ -0,0 +1,36 @@
+-- Create login_failures table recording failed login attempts per account / client IP
+CREATE TABLE login_failures (
+    -- Primary Key
+    id BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+
+    -- Business Columns
+    -- "account:<email>" or "ip:<address>"
+    throttle_key VARCHAR(320) NOT NULL,
+    attempted_at TIMESTAMPTZ NOT NULL,
+
+    -- Common Columns (Creation)
+    created_at TIMESTAMPTZ NOT NULL,
+    created_by VARCHAR(255) NOT NULL,
+    created_pgm_cd VARCHAR(255) NOT NULL,
+    created_tx_id VARCHAR(255) NOT NULL,
+
+    -- Common Columns (Update)
+    updated_at TIMESTAMPTZ NOT NULL,
+    updated_by VARCHAR(255) NOT NULL,
+    updated_pgm_cd VARCHAR(255) NOT NULL,
+    updated_tx_id VARCHAR(255) NOT NULL,
+
+    -- Common Columns (Optimistic Locking)
+    lock_no INTEGER NOT NULL DEFAULT 1,
+
+    -- Common Columns (Patch)
+    patched_at TIMESTAMPTZ,
+    patched_by VARCHAR(255),
+    patched_id VARCHAR(255)
+);
+
+-- Lookup of failures within the sliding window
+CREATE INDEX idx_login_failures_key_attempted_at ON login_failures(throttle_key, attempted_at);
+
+-- Purge of failures outside every window
+CREATE INDEX idx_login_failures_attempted_at ON login_failures(attempted_at);
@@ -0,0 +1,2 @@
+-- When the password was last changed; access tokens issued before it are rejected
+ALTER TABLE users ADD COLUMN credentials_changed_at TIMESTAMPTZ;
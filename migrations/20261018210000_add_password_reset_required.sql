@@ -0,0 +1,2 @@
+-- When an administrator required a password reset; password sign-in is refused until it is reset
+ALTER TABLE users ADD COLUMN password_reset_required_at TIMESTAMPTZ;